@@ -0,0 +1,42 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use iref::Iri;
+use xsd_types::Datatype;
+
+const HITS: &[&str] = &[
+	"http://www.w3.org/2001/XMLSchema#boolean",
+	"http://www.w3.org/2001/XMLSchema#integer",
+	"http://www.w3.org/2001/XMLSchema#unsignedByte",
+	"http://www.w3.org/2001/XMLSchema#string",
+	"http://www.w3.org/2001/XMLSchema#dateTime",
+	"http://www.w3.org/2001/XMLSchema#QName",
+];
+
+const MISSES: &[&str] = &[
+	"http://www.w3.org/2001/XMLSchema#unknown",
+	"http://www.w3.org/2001/XMLSchema#Integer",
+	"http://www.w3.org/1999/02/22-rdf-syntax-ns#langString",
+	"http://example.org/vocab#integer",
+	"https://schema.org/Text",
+	"http://www.w3.org/2001/XMLSchema-datatypes#string",
+];
+
+fn from_iri(c: &mut Criterion) {
+	let mut group = c.benchmark_group("Datatype::from_iri");
+
+	for (name, inputs) in [("hits", HITS), ("misses", MISSES)] {
+		let iris: Vec<&Iri> = inputs.iter().map(|s| Iri::new(s).unwrap()).collect();
+		group.throughput(Throughput::Elements(iris.len() as u64));
+		group.bench_function(name, |b| {
+			b.iter(|| {
+				for iri in &iris {
+					black_box(Datatype::from_iri(black_box(iri)));
+				}
+			})
+		});
+	}
+
+	group.finish()
+}
+
+criterion_group!(benches, from_iri);
+criterion_main!(benches);
@@ -0,0 +1,55 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use xsd_types::lexical::{self, Lexical};
+
+const DATE_TIMES: &[&str] = &[
+	"2024-01-01T10:00:00Z",
+	"2024-02-29T23:59:59.123456789+05:30",
+	"-0044-03-15T12:00:00",
+	"202002-10-10T12:00:00.00001-05:00",
+	"2024-13-01T10:00:00Z",
+];
+
+fn validation(c: &mut Criterion) {
+	let mut group = c.benchmark_group("dateTime validation");
+	group.throughput(Throughput::Elements(DATE_TIMES.len() as u64));
+
+	group.bench_function("grammar", |b| {
+		b.iter(|| {
+			for input in DATE_TIMES {
+				black_box(lexical::DateTime::new(black_box(*input)).is_ok());
+			}
+		})
+	});
+
+	group.bench_function("scanner", |b| {
+		b.iter(|| {
+			for input in DATE_TIMES {
+				black_box(<lexical::DateTime as Lexical>::parse(black_box(*input)).is_ok());
+			}
+		})
+	});
+
+	group.finish()
+}
+
+fn parsing(c: &mut Criterion) {
+	let mut group = c.benchmark_group("value parsing");
+	group.throughput(Throughput::Elements(1));
+
+	group.bench_function("dateTime", |b| {
+		b.iter(|| black_box("2024-02-29T23:59:59.123456789+05:30").parse::<xsd_types::DateTime>())
+	});
+
+	group.bench_function("date", |b| {
+		b.iter(|| black_box("2024-02-29+05:30").parse::<xsd_types::Date>())
+	});
+
+	group.bench_function("time", |b| {
+		b.iter(|| black_box("23:59:59.123456789Z").parse::<xsd_types::Time>())
+	});
+
+	group.finish()
+}
+
+criterion_group!(benches, validation, parsing);
+criterion_main!(benches);
@@ -0,0 +1,76 @@
+//! Rewrites the XSD typed literals of an N-Triples document into their
+//! canonical lexical form.
+//!
+//! Reads the document from the file given as first argument, or uses a
+//! sample document if no argument is given. Lines that are not typed
+//! literals with an XSD datatype are left untouched, and invalid literals
+//! are reported on the standard error output.
+//!
+//! ```text
+//! cargo run --example canonicalize_ntriples -- data.nt
+//! ```
+use iref::Iri;
+
+const SAMPLE: &str = r#"<http://example.org/a> <http://example.org/size> "+0042"^^<http://www.w3.org/2001/XMLSchema#integer> .
+<http://example.org/a> <http://example.org/ratio> "01.50"^^<http://www.w3.org/2001/XMLSchema#decimal> .
+<http://example.org/a> <http://example.org/enabled> "1"^^<http://www.w3.org/2001/XMLSchema#boolean> .
+<http://example.org/a> <http://example.org/weight> "1200.0"^^<http://www.w3.org/2001/XMLSchema#double> .
+<http://example.org/a> <http://example.org/at> "2024-01-01T24:00:00Z"^^<http://www.w3.org/2001/XMLSchema#dateTime> .
+<http://example.org/a> <http://example.org/delay> "P0Y1M"^^<http://www.w3.org/2001/XMLSchema#duration> .
+<http://example.org/a> <http://example.org/label> "hello" .
+<http://example.org/a> <http://example.org/broken> "12a"^^<http://www.w3.org/2001/XMLSchema#int> ."#;
+
+/// Typed literal found at the end of an N-Triples statement.
+struct TypedLiteral<'a> {
+	/// Text preceding the literal (subject and predicate).
+	prefix: &'a str,
+
+	/// Lexical form, still escaped.
+	lexical_form: &'a str,
+
+	/// Datatype IRI.
+	datatype: &'a str,
+}
+
+impl<'a> TypedLiteral<'a> {
+	/// Splits the given statement around its object, if the object is a
+	/// typed literal.
+	fn find(line: &'a str) -> Option<Self> {
+		let statement = line.trim_end().strip_suffix('.')?.trim_end();
+		let (rest, datatype) = statement.strip_suffix('>')?.rsplit_once("\"^^<")?;
+		let start = rest.find('"')?;
+
+		Some(Self {
+			prefix: &line[..start],
+			lexical_form: &rest[start + 1..],
+			datatype,
+		})
+	}
+}
+
+fn main() {
+	let document = match std::env::args().nth(1) {
+		Some(path) => std::fs::read_to_string(path).expect("unable to read input file"),
+		None => SAMPLE.to_owned(),
+	};
+
+	for (n, line) in document.lines().enumerate() {
+		// For simplicity, literals containing escape sequences are left
+		// untouched.
+		let literal = TypedLiteral::find(line).filter(|l| !l.lexical_form.contains('\\'));
+
+		let canonical = literal.and_then(|l| {
+			let iri = Iri::new(l.datatype).ok()?;
+			match xsd_types::canonicalize(iri, l.lexical_form) {
+				Ok(canonical) => Some(format!("{}\"{canonical}\"^^<{}> .", l.prefix, l.datatype)),
+				Err(xsd_types::ParseError::UnknownDatatype) => None,
+				Err(e) => {
+					eprintln!("line {}: `{}`: {e}", n + 1, l.lexical_form);
+					None
+				}
+			}
+		});
+
+		println!("{}", canonical.as_deref().unwrap_or(line))
+	}
+}
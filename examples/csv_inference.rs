@@ -0,0 +1,59 @@
+//! Parses the columns of a CSV table into typed XSD values, inferring the
+//! datatype of each column.
+//!
+//! The datatype of a column is the first candidate datatype accepting every
+//! non-empty cell of the column, from the most to the least specific.
+//!
+//! ```text
+//! cargo run --example csv_inference
+//! ```
+use xsd_types::{
+	Datatype, XSD_BOOLEAN, XSD_DATE, XSD_DATE_TIME, XSD_DECIMAL, XSD_DOUBLE, XSD_INTEGER,
+	XSD_STRING,
+};
+
+const TABLE: &str = "\
+id,name,active,score,ratio,joined,last_seen
+1,Alice,true,17.5,1e-3,2021-03-14,2024-01-01T10:00:00Z
+2,Bob,false,3,0.25,2019-11-02,2024-02-29T23:59:59+01:00
+3,Carol,1,-0.5,INF,2022-07-30,
+42,Dave,0,+12.000,NaN,2020-01-01,2023-12-31T00:00:00";
+
+fn main() {
+	let candidates: Vec<Datatype> = [
+		XSD_BOOLEAN,
+		XSD_INTEGER,
+		XSD_DECIMAL,
+		XSD_DOUBLE,
+		XSD_DATE,
+		XSD_DATE_TIME,
+		XSD_STRING,
+	]
+	.into_iter()
+	.map(|iri| Datatype::from_iri(iri).unwrap())
+	.collect();
+
+	let mut lines = TABLE.lines();
+	let header: Vec<&str> = lines.next().unwrap().split(',').collect();
+	let rows: Vec<Vec<&str>> = lines.map(|line| line.split(',').collect()).collect();
+
+	for (c, name) in header.iter().enumerate() {
+		// Empty cells are missing values, not lexical forms.
+		let cells: Vec<&str> = rows
+			.iter()
+			.map(|row| row[c])
+			.filter(|cell| !cell.is_empty())
+			.collect();
+
+		let datatype = candidates
+			.iter()
+			.find(|dt| dt.parse_column(cells.iter().copied()).is_ok())
+			.expect("every lexical form is a valid `xsd:string`");
+
+		println!("{name}: {}", datatype.iri());
+
+		for (_, value) in datatype.parse_column(cells.iter().copied()).values {
+			println!("  {value}");
+		}
+	}
+}
@@ -0,0 +1,81 @@
+//! Validates literals against user-defined datatypes restricting built-in
+//! XSD datatypes with facets.
+//!
+//! ```text
+//! cargo run --example validate_facets --features regex
+//! ```
+use xsd_types::{
+	pattern::PatternFacet, BoundedInt, FacetLength, HexBinaryBuf, ParseXsdError, TokenBuf,
+};
+
+/// Percentage, restricting `xsd:integer` with `minInclusive` and
+/// `maxInclusive`.
+type Percentage = BoundedInt<0, 100>;
+
+/// Product code, restricting `xsd:token` with `pattern` and `maxLength`.
+fn check_product_code(lexical_form: &str) -> Result<TokenBuf, String> {
+	let patterns = PatternFacet::new([r"[A-Z]{3}-\d{4}", r"X-\i\c*"]).unwrap();
+
+	let token: TokenBuf = lexical_form
+		.parse()
+		.map_err(|_| "invalid `xsd:token`".to_owned())?;
+
+	if !patterns.is_satisfied_by(lexical_form) {
+		return Err("does not match any pattern".to_owned());
+	}
+
+	if !token.has_max_length(12) {
+		return Err(format!(
+			"{} characters long, at most 12 expected",
+			token.facet_length()
+		));
+	}
+
+	Ok(token)
+}
+
+/// Key digest, restricting `xsd:hexBinary` with `length`.
+fn check_digest(lexical_form: &str) -> Result<HexBinaryBuf, String> {
+	let digest: HexBinaryBuf = lexical_form
+		.parse()
+		.map_err(|_| "invalid `xsd:hexBinary`".to_owned())?;
+
+	// The length of binary values is measured in octets, not characters.
+	if !digest.has_length(4) {
+		return Err(format!("{} octets long, 4 expected", digest.facet_length()));
+	}
+
+	Ok(digest)
+}
+
+fn report<T: std::fmt::Display>(datatype: &str, lexical_form: &str, result: Result<T, String>) {
+	match result {
+		Ok(value) => println!("valid   {datatype} `{lexical_form}` = {value}"),
+		Err(e) => println!("invalid {datatype} `{lexical_form}`: {e}"),
+	}
+}
+
+fn main() {
+	for input in ["42", "+007", "100", "101", "-1", "12.5"] {
+		let result = input.parse::<Percentage>().map_err(|e| match e {
+			ParseXsdError::InvalidLexicalForm(_) => "invalid `xsd:integer`".to_owned(),
+			ParseXsdError::InvalidValue(e) => e.to_string(),
+		});
+
+		report("percentage", input, result);
+	}
+
+	for input in [
+		"ABC-1234",
+		"abc-1234",
+		"X-widget",
+		"X-extra-long-name",
+		"X-9",
+	] {
+		report("product code", input, check_product_code(input));
+	}
+
+	for input in ["DEADBEEF", "deadbeef00", "DEADBEE"] {
+		report("digest", input, check_digest(input));
+	}
+}
@@ -0,0 +1,17 @@
+use std::fmt;
+
+use xsd_types::ParseXsd;
+
+/// Parses the input as a value of type `V`, checking that its canonical
+/// representation parses back into the same value.
+///
+/// Parsing must never panic, even on lexical forms whose value is out of
+/// the supported range.
+pub fn check<V: ParseXsd + fmt::Display>(data: &str) {
+	if let Ok(value) = V::parse_xsd(data) {
+		let canonical = value.to_string();
+		let reparsed = V::parse_xsd(&canonical)
+			.unwrap_or_else(|_| panic!("canonical form `{canonical}` of `{data}` does not parse"));
+		assert_eq!(reparsed.to_string(), canonical)
+	}
+}
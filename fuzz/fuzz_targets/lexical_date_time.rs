@@ -0,0 +1,23 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use xsd_types::{
+	Date, DateTime, DateTimeStamp, GDay, GMonth, GMonthDay, GYear, GYearMonth, Time, YearConvention,
+};
+
+mod common;
+
+fuzz_target!(|data: &str| {
+	common::check::<DateTime>(data);
+	common::check::<DateTimeStamp>(data);
+	common::check::<Time>(data);
+	common::check::<Date>(data);
+	common::check::<GYearMonth>(data);
+	common::check::<GYear>(data);
+	common::check::<GMonthDay>(data);
+	common::check::<GDay>(data);
+	common::check::<GMonth>(data);
+
+	let _ = Date::parse_with(data, YearConvention::Xsd10);
+	let _ = DateTime::parse_with(data, YearConvention::Xsd10);
+	let _ = DateTimeStamp::parse_with(data, YearConvention::Xsd10);
+});
@@ -0,0 +1,13 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use xsd_types::{lexical, DayTimeDuration, Duration, YearMonthDuration};
+
+mod common;
+
+fuzz_target!(|data: &str| {
+	common::check::<Duration>(data);
+	common::check::<DayTimeDuration>(data);
+	common::check::<YearMonthDuration>(data);
+
+	let _ = lexical::duration::Parts::parse(data).map(|parts| parts.validate());
+});
@@ -0,0 +1,29 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use xsd_types::{
+	Boolean, Byte, Decimal, Double, Float, Int, Integer, Long, NegativeInteger, NonNegativeInteger,
+	NonPositiveInteger, PositiveInteger, Short, UnsignedByte, UnsignedInt, UnsignedLong,
+	UnsignedShort,
+};
+
+mod common;
+
+fuzz_target!(|data: &str| {
+	common::check::<Boolean>(data);
+	common::check::<Decimal>(data);
+	common::check::<Integer>(data);
+	common::check::<NonPositiveInteger>(data);
+	common::check::<NegativeInteger>(data);
+	common::check::<NonNegativeInteger>(data);
+	common::check::<PositiveInteger>(data);
+	common::check::<UnsignedLong>(data);
+	common::check::<UnsignedInt>(data);
+	common::check::<UnsignedShort>(data);
+	common::check::<UnsignedByte>(data);
+	common::check::<Long>(data);
+	common::check::<Int>(data);
+	common::check::<Short>(data);
+	common::check::<Byte>(data);
+	common::check::<Float>(data);
+	common::check::<Double>(data);
+});
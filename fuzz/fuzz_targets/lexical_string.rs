@@ -0,0 +1,24 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use xsd_types::{
+	AnyUriBuf, Base64BinaryBuf, HexBinaryBuf, IdBuf, IdRefBuf, LanguageBuf, NCNameBuf, NMTokenBuf,
+	NameBuf, NormalizedString, QNameBuf, TokenBuf,
+};
+
+mod common;
+
+fuzz_target!(|data: &str| {
+	common::check::<String>(data);
+	common::check::<NormalizedString>(data);
+	common::check::<TokenBuf>(data);
+	common::check::<LanguageBuf>(data);
+	common::check::<NameBuf>(data);
+	common::check::<NCNameBuf>(data);
+	common::check::<IdBuf>(data);
+	common::check::<IdRefBuf>(data);
+	common::check::<NMTokenBuf>(data);
+	common::check::<AnyUriBuf>(data);
+	common::check::<QNameBuf>(data);
+	common::check::<Base64BinaryBuf>(data);
+	common::check::<HexBinaryBuf>(data);
+});
@@ -0,0 +1,65 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use xsd_types::Datatype;
+
+/// Local names of every datatype.
+const DATATYPES: &[&str] = &[
+	"boolean",
+	"float",
+	"double",
+	"decimal",
+	"integer",
+	"nonPositiveInteger",
+	"negativeInteger",
+	"nonNegativeInteger",
+	"positiveInteger",
+	"unsignedLong",
+	"unsignedInt",
+	"unsignedShort",
+	"unsignedByte",
+	"long",
+	"int",
+	"short",
+	"byte",
+	"string",
+	"normalizedString",
+	"token",
+	"language",
+	"Name",
+	"NCName",
+	"ID",
+	"IDREF",
+	"NMTOKEN",
+	"duration",
+	"dayTimeDuration",
+	"yearMonthDuration",
+	"dateTime",
+	"dateTimeStamp",
+	"time",
+	"date",
+	"gYearMonth",
+	"gYear",
+	"gMonthDay",
+	"gDay",
+	"gMonth",
+	"base64Binary",
+	"hexBinary",
+	"anyURI",
+	"QName",
+];
+
+fuzz_target!(|data: &str| {
+	for name in DATATYPES {
+		let Some(datatype) = Datatype::from_local_name(name) else {
+			continue;
+		};
+
+		if let Ok(value) = datatype.parse(data) {
+			let canonical = value.to_string();
+			let reparsed = datatype.parse(&canonical).unwrap_or_else(|_| {
+				panic!("canonical form `{canonical}` of `{data}` does not parse as {name}")
+			});
+			assert_eq!(reparsed.to_string(), canonical)
+		}
+	}
+});
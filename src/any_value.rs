@@ -0,0 +1,138 @@
+use core::fmt;
+use iref::{Iri, IriBuf};
+
+use crate::{Datatype, ParseError, Value};
+
+/// Literal value of any datatype.
+///
+/// Literals with an XSD datatype are interpreted as a [`Value`], while
+/// literals of any other datatype are kept as an opaque lexical form, so
+/// that they can flow through the dynamic API untouched.
+///
+/// ```
+/// use static_iref::iri;
+/// use xsd_types::AnyValue;
+///
+/// let value = AnyValue::parse(iri!("http://www.w3.org/2001/XMLSchema#integer"), "+042").unwrap();
+/// assert_eq!(value.to_string(), "42");
+///
+/// let geometry = iri!("http://www.opengis.net/ont/geosparql#wktLiteral");
+/// let value = AnyValue::parse(geometry, "POINT(1 2)").unwrap();
+/// assert_eq!(value.datatype(), geometry);
+/// assert_eq!(value.to_string(), "POINT(1 2)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnyValue {
+	/// Value of an XSD datatype.
+	Xsd(Value),
+
+	/// Literal with a non-XSD datatype.
+	Other {
+		/// Datatype IRI.
+		datatype_iri: IriBuf,
+
+		/// Lexical form, as is.
+		lexical: String,
+	},
+}
+
+impl AnyValue {
+	/// Parses the given lexical form with the given datatype.
+	///
+	/// Fails if the datatype is an XSD datatype and the lexical form is
+	/// invalid. The lexical form of an unknown datatype is kept as is.
+	pub fn parse(datatype_iri: &Iri, lexical: &str) -> Result<Self, ParseError> {
+		match Datatype::from_iri(datatype_iri) {
+			Some(datatype) => datatype.parse(lexical).map(Self::Xsd),
+			None => Ok(Self::Other {
+				datatype_iri: datatype_iri.to_owned(),
+				lexical: lexical.to_owned(),
+			}),
+		}
+	}
+
+	/// Returns the datatype IRI of this value.
+	pub fn datatype(&self) -> &Iri {
+		match self {
+			Self::Xsd(value) => value.datatype().iri(),
+			Self::Other { datatype_iri, .. } => datatype_iri,
+		}
+	}
+
+	/// Returns the XSD datatype of this value, if any.
+	pub fn xsd_datatype(&self) -> Option<Datatype> {
+		match self {
+			Self::Xsd(value) => Some(value.datatype()),
+			Self::Other { .. } => None,
+		}
+	}
+
+	/// Returns the XSD value, if any.
+	pub fn as_xsd(&self) -> Option<&Value> {
+		match self {
+			Self::Xsd(value) => Some(value),
+			Self::Other { .. } => None,
+		}
+	}
+
+	/// Turns this into an XSD value, if any.
+	pub fn into_xsd(self) -> Result<Value, Self> {
+		match self {
+			Self::Xsd(value) => Ok(value),
+			other => Err(other),
+		}
+	}
+}
+
+impl From<Value> for AnyValue {
+	fn from(value: Value) -> Self {
+		Self::Xsd(value)
+	}
+}
+
+/// Writes the canonical lexical form of XSD values, and the stored lexical
+/// form of other values.
+impl fmt::Display for AnyValue {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Xsd(value) => value.fmt(f),
+			Self::Other { lexical, .. } => f.write_str(lexical),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use static_iref::iri;
+
+	#[test]
+	fn passthrough() {
+		let value = AnyValue::parse(iri!("http://www.w3.org/2001/XMLSchema#boolean"), "1").unwrap();
+		assert_eq!(value.xsd_datatype(), Some(Datatype::Boolean));
+		assert_eq!(value.to_string(), "true");
+
+		assert!(AnyValue::parse(iri!("http://www.w3.org/2001/XMLSchema#boolean"), "yes").is_err());
+
+		let html = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#HTML");
+		let value = AnyValue::parse(html, " <b>bold</b> ").unwrap();
+		assert_eq!(value.datatype(), html);
+		assert_eq!(value.xsd_datatype(), None);
+		assert_eq!(value.to_string(), " <b>bold</b> ");
+		assert!(value.into_xsd().is_err());
+	}
+
+	#[test]
+	fn equality() {
+		let integer = iri!("http://www.w3.org/2001/XMLSchema#integer");
+		let a = AnyValue::parse(integer, "+042").unwrap();
+		assert_eq!(a, AnyValue::parse(integer, "42").unwrap());
+		assert_ne!(a, AnyValue::parse(integer, "43").unwrap());
+		assert_eq!(format!("{a:?}"), r#"Xsd(xsd:integer("42"))"#);
+
+		let html = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#HTML");
+		let b = AnyValue::parse(html, "42").unwrap();
+		assert_ne!(a, b);
+		assert_eq!(b, AnyValue::parse(html, "42").unwrap())
+	}
+}
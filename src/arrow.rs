@@ -0,0 +1,519 @@
+//! Conversions between columns of lexical forms and [Apache Arrow] arrays.
+//!
+//! A column of lexical forms is converted into an Arrow array whose type
+//! depends on the XSD datatype of the column:
+//!
+//! | XSD datatype                                  | Arrow array                        |
+//! |-----------------------------------------------|------------------------------------|
+//! | `xsd:boolean`                                 | [`BooleanArray`]                   |
+//! | `xsd:float`, `xsd:double`                     | [`Float32Array`], [`Float64Array`] |
+//! | `xsd:long`, `xsd:int`, `xsd:short`, `xsd:byte` | [`Int64Array`] to [`Int8Array`]   |
+//! | `xsd:unsignedLong` to `xsd:unsignedByte`      | [`UInt64Array`] to [`UInt8Array`]  |
+//! | `xsd:decimal`                                 | [`Decimal128Array`] (scale [`DECIMAL_SCALE`]) |
+//! | other integer datatypes                       | [`Decimal128Array`] (scale 0)      |
+//! | `xsd:dateTime`, `xsd:dateTimeStamp`           | [`TimestampNanosecondArray`] (UTC) |
+//! | `xsd:date`                                    | [`Date32Array`]                    |
+//! | `xsd:time`                                    | [`Time64NanosecondArray`]          |
+//! | `xsd:base64Binary`, `xsd:hexBinary`           | [`BinaryArray`]                    |
+//! | any other datatype                            | [`StringArray`] (canonical forms)  |
+//!
+//! Date/time values without timezone are interpreted as UTC. The timezone of
+//! dates and times is dropped.
+//!
+//! [Apache Arrow]: <https://arrow.apache.org/>
+use std::sync::Arc;
+
+use arrow_array::{
+	Array, ArrayRef, BinaryArray, BooleanArray, Date32Array, Decimal128Array, Float32Array,
+	Float64Array, Int16Array, Int32Array, Int64Array, Int8Array, StringArray,
+	Time64NanosecondArray, TimestampNanosecondArray, UInt16Array, UInt32Array, UInt64Array,
+	UInt8Array,
+};
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive};
+
+use crate::{
+	Datatype, Date, DateTime, Decimal, DecimalDatatype, IntDatatype, IntegerDatatype, LongDatatype,
+	NonNegativeIntegerDatatype, ParseError, ShortDatatype, Time, UnsignedIntDatatype,
+	UnsignedLongDatatype, UnsignedShortDatatype, Value,
+};
+
+/// Scale of the Arrow decimal arrays holding `xsd:decimal` values.
+pub const DECIMAL_SCALE: i8 = 18;
+
+/// Precision of the Arrow decimal arrays.
+pub const DECIMAL_PRECISION: u8 = 38;
+
+/// Arrow conversion error.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ArrowConversionError {
+	/// A lexical form of the input column is invalid.
+	#[error("invalid lexical form at index {0}")]
+	Parse(usize, #[source] ParseError),
+
+	/// A value cannot be represented by the target array type.
+	#[error("value at index {0} is out of range")]
+	OutOfRange(usize),
+
+	/// The input array type does not match the datatype.
+	#[error("unexpected array type for `{}`", .0.iri())]
+	ArrayType(Datatype),
+}
+
+/// Arrow array type used to represent values of a given datatype.
+enum Kind {
+	Boolean,
+	Float32,
+	Float64,
+	Int8,
+	Int16,
+	Int32,
+	Int64,
+	UInt8,
+	UInt16,
+	UInt32,
+	UInt64,
+	Decimal(i8),
+	Timestamp,
+	Date32,
+	Time64,
+	Binary,
+	Utf8,
+}
+
+impl Kind {
+	fn of(datatype: Datatype) -> Self {
+		match datatype {
+			Datatype::Boolean => Self::Boolean,
+			Datatype::Float => Self::Float32,
+			Datatype::Double => Self::Float64,
+			Datatype::Decimal(DecimalDatatype::Decimal) => Self::Decimal(DECIMAL_SCALE),
+			Datatype::Decimal(DecimalDatatype::Integer(i)) => match i {
+				IntegerDatatype::Long(LongDatatype::Long) => Self::Int64,
+				IntegerDatatype::Long(LongDatatype::Int(IntDatatype::Int)) => Self::Int32,
+				IntegerDatatype::Long(LongDatatype::Int(IntDatatype::Short(s))) => match s {
+					ShortDatatype::Short => Self::Int16,
+					ShortDatatype::Byte => Self::Int8,
+				},
+				IntegerDatatype::NonNegativeInteger(NonNegativeIntegerDatatype::UnsignedLong(
+					u,
+				)) => match u {
+					UnsignedLongDatatype::UnsignedLong => Self::UInt64,
+					UnsignedLongDatatype::UnsignedInt(UnsignedIntDatatype::UnsignedInt) => {
+						Self::UInt32
+					}
+					UnsignedLongDatatype::UnsignedInt(UnsignedIntDatatype::UnsignedShort(s)) => {
+						match s {
+							UnsignedShortDatatype::UnsignedShort => Self::UInt16,
+							UnsignedShortDatatype::UnsignedByte => Self::UInt8,
+						}
+					}
+				},
+				_ => Self::Decimal(0),
+			},
+			Datatype::DateTime(_) => Self::Timestamp,
+			Datatype::Date => Self::Date32,
+			Datatype::Time => Self::Time64,
+			Datatype::Base64Binary | Datatype::HexBinary => Self::Binary,
+			_ => Self::Utf8,
+		}
+	}
+}
+
+macro_rules! primitive_array {
+	($values:ident, $array:ty, $variant:ident) => {
+		Arc::new(
+			$values
+				.into_iter()
+				.map(|v| match v {
+					Some(Value::$variant(v)) => Some(v),
+					None => None,
+					_ => unreachable!(),
+				})
+				.collect::<$array>(),
+		)
+	};
+}
+
+/// Converts a column of lexical forms of the given datatype into an Arrow
+/// array.
+///
+/// `None` entries are converted into nulls.
+pub fn to_array<'a>(
+	datatype: Datatype,
+	column: impl IntoIterator<Item = Option<&'a str>>,
+) -> Result<ArrayRef, ArrowConversionError> {
+	let values = column
+		.into_iter()
+		.enumerate()
+		.map(|(i, s)| {
+			s.map(|s| datatype.parse(s))
+				.transpose()
+				.map_err(|e| ArrowConversionError::Parse(i, e))
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	let array: ArrayRef = match Kind::of(datatype) {
+		Kind::Boolean => Arc::new(
+			values
+				.into_iter()
+				.map(|v| match v {
+					Some(Value::Boolean(b)) => Some(b.0),
+					None => None,
+					_ => unreachable!(),
+				})
+				.collect::<BooleanArray>(),
+		),
+		Kind::Float32 => Arc::new(
+			values
+				.into_iter()
+				.map(|v| match v {
+					Some(Value::Float(f)) => Some(f32::from(f)),
+					None => None,
+					_ => unreachable!(),
+				})
+				.collect::<Float32Array>(),
+		),
+		Kind::Float64 => Arc::new(
+			values
+				.into_iter()
+				.map(|v| match v {
+					Some(Value::Double(d)) => Some(f64::from(d)),
+					None => None,
+					_ => unreachable!(),
+				})
+				.collect::<Float64Array>(),
+		),
+		Kind::Int8 => primitive_array!(values, Int8Array, Byte),
+		Kind::Int16 => primitive_array!(values, Int16Array, Short),
+		Kind::Int32 => primitive_array!(values, Int32Array, Int),
+		Kind::Int64 => primitive_array!(values, Int64Array, Long),
+		Kind::UInt8 => primitive_array!(values, UInt8Array, UnsignedByte),
+		Kind::UInt16 => primitive_array!(values, UInt16Array, UnsignedShort),
+		Kind::UInt32 => primitive_array!(values, UInt32Array, UnsignedInt),
+		Kind::UInt64 => primitive_array!(values, UInt64Array, UnsignedLong),
+		Kind::Decimal(scale) => {
+			let factor = BigRational::from_integer(BigInt::from(10u32).pow(scale as u32));
+			let max = BigInt::from(10u32).pow(DECIMAL_PRECISION as u32);
+
+			let array = values
+				.into_iter()
+				.enumerate()
+				.map(|(i, v)| {
+					v.map(|v| {
+						let scaled = to_big_rational(v) * &factor;
+						if scaled.is_integer() && scaled.numer().abs() < max {
+							Ok(scaled.numer().to_i128().unwrap())
+						} else {
+							Err(ArrowConversionError::OutOfRange(i))
+						}
+					})
+					.transpose()
+				})
+				.collect::<Result<Decimal128Array, _>>()?;
+
+			Arc::new(
+				array
+					.with_precision_and_scale(DECIMAL_PRECISION, scale)
+					.unwrap(),
+			)
+		}
+		Kind::Timestamp => {
+			let utc = FixedOffset::east_opt(0).unwrap();
+			let array = values
+				.into_iter()
+				.enumerate()
+				.map(|(i, v)| {
+					v.map(|v| {
+						let date_time = match v {
+							Value::DateTime(d) => {
+								d.date_time.and_local_timezone(d.offset.unwrap_or(utc))
+							}
+							Value::DateTimeStamp(d) => d.date_time.and_local_timezone(d.offset),
+							_ => unreachable!(),
+						}
+						.unwrap();
+
+						date_time
+							.timestamp()
+							.checked_mul(1_000_000_000)
+							.and_then(|ns| {
+								ns.checked_add(date_time.timestamp_subsec_nanos() as i64)
+							})
+							.ok_or(ArrowConversionError::OutOfRange(i))
+					})
+					.transpose()
+				})
+				.collect::<Result<TimestampNanosecondArray, _>>()?;
+
+			Arc::new(array.with_timezone("UTC"))
+		}
+		Kind::Date32 => {
+			let array = values
+				.into_iter()
+				.enumerate()
+				.map(|(i, v)| {
+					v.map(|v| match v {
+						Value::Date(d) => d
+							.date
+							.signed_duration_since(epoch().date())
+							.num_days()
+							.try_into()
+							.map_err(|_| ArrowConversionError::OutOfRange(i)),
+						_ => unreachable!(),
+					})
+					.transpose()
+				})
+				.collect::<Result<Date32Array, _>>()?;
+
+			Arc::new(array)
+		}
+		Kind::Time64 => Arc::new(
+			values
+				.into_iter()
+				.map(|v| match v {
+					Some(Value::Time(t)) => Some(
+						t.time.num_seconds_from_midnight() as i64 * 1_000_000_000
+							+ t.time.nanosecond() as i64,
+					),
+					None => None,
+					_ => unreachable!(),
+				})
+				.collect::<Time64NanosecondArray>(),
+		),
+		Kind::Binary => Arc::new(
+			values
+				.into_iter()
+				.map(|v| match v {
+					Some(Value::Base64Binary(b)) => Some(b.into_bytes()),
+					Some(Value::HexBinary(b)) => Some(b.into_bytes()),
+					None => None,
+					_ => unreachable!(),
+				})
+				.collect::<BinaryArray>(),
+		),
+		Kind::Utf8 => Arc::new(
+			values
+				.into_iter()
+				.map(|v| v.map(|v| v.to_string()))
+				.collect::<StringArray>(),
+		),
+	};
+
+	Ok(array)
+}
+
+/// Converts an Arrow array into a column of canonical lexical forms of the
+/// given datatype.
+///
+/// Nulls are converted into `None` entries. The array type must match the
+/// one produced by [`to_array`] for the same datatype.
+pub fn from_array(
+	datatype: Datatype,
+	array: &dyn Array,
+) -> Result<Vec<Option<String>>, ArrowConversionError> {
+	macro_rules! downcast {
+		($ty:ty) => {
+			array
+				.as_any()
+				.downcast_ref::<$ty>()
+				.ok_or(ArrowConversionError::ArrayType(datatype))?
+		};
+	}
+
+	macro_rules! display_column {
+		($ty:ty) => {
+			downcast!($ty)
+				.iter()
+				.map(|v| v.map(|v| v.to_string()))
+				.collect()
+		};
+	}
+
+	let column = match Kind::of(datatype) {
+		Kind::Boolean => display_column!(BooleanArray),
+		Kind::Float32 => downcast!(Float32Array)
+			.iter()
+			.map(|v| v.map(|v| crate::Float::from(v).to_string()))
+			.collect(),
+		Kind::Float64 => downcast!(Float64Array)
+			.iter()
+			.map(|v| v.map(|v| crate::Double::from(v).to_string()))
+			.collect(),
+		Kind::Int8 => display_column!(Int8Array),
+		Kind::Int16 => display_column!(Int16Array),
+		Kind::Int32 => display_column!(Int32Array),
+		Kind::Int64 => display_column!(Int64Array),
+		Kind::UInt8 => display_column!(UInt8Array),
+		Kind::UInt16 => display_column!(UInt16Array),
+		Kind::UInt32 => display_column!(UInt32Array),
+		Kind::UInt64 => display_column!(UInt64Array),
+		Kind::Decimal(_) => {
+			let array = downcast!(Decimal128Array);
+			let scale = array.scale() as i32;
+			let ten = BigInt::from(10u32);
+			let factor = if scale >= 0 {
+				BigRational::new(1.into(), ten.pow(scale as u32))
+			} else {
+				BigRational::from_integer(ten.pow(scale.unsigned_abs()))
+			};
+
+			array
+				.iter()
+				.map(|v| {
+					v.map(|v| {
+						let r = BigRational::from_integer(v.into()) * &factor;
+						// SAFETY: `r` is an integer divided by a power of ten.
+						unchecked!(Decimal, r).to_string()
+					})
+				})
+				.collect()
+		}
+		Kind::Timestamp => {
+			let array = downcast!(TimestampNanosecondArray);
+			let has_timezone = array.timezone().is_some();
+
+			array
+				.iter()
+				.enumerate()
+				.map(|(i, v)| {
+					v.map(|ns| {
+						let date_time = epoch()
+							.checked_add_signed(chrono::Duration::nanoseconds(ns))
+							.ok_or(ArrowConversionError::OutOfRange(i))?;
+						let offset = has_timezone.then(|| FixedOffset::east_opt(0).unwrap());
+						Ok(DateTime::new(date_time, offset).to_string())
+					})
+					.transpose()
+				})
+				.collect::<Result<_, _>>()?
+		}
+		Kind::Date32 => downcast!(Date32Array)
+			.iter()
+			.enumerate()
+			.map(|(i, v)| {
+				v.map(|days| {
+					let date = epoch()
+						.date()
+						.checked_add_signed(chrono::Duration::days(days.into()))
+						.ok_or(ArrowConversionError::OutOfRange(i))?;
+					Ok(Date::new(date, None).to_string())
+				})
+				.transpose()
+			})
+			.collect::<Result<_, _>>()?,
+		Kind::Time64 => downcast!(Time64NanosecondArray)
+			.iter()
+			.enumerate()
+			.map(|(i, v)| {
+				v.map(|ns| {
+					let seconds = ns.div_euclid(1_000_000_000);
+					let nanoseconds = ns.rem_euclid(1_000_000_000);
+					let time = u32::try_from(seconds)
+						.ok()
+						.and_then(|s| {
+							NaiveTime::from_num_seconds_from_midnight_opt(s, nanoseconds as u32)
+						})
+						.ok_or(ArrowConversionError::OutOfRange(i))?;
+					Ok(Time::new(time, None).to_string())
+				})
+				.transpose()
+			})
+			.collect::<Result<_, _>>()?,
+		Kind::Binary => downcast!(BinaryArray)
+			.iter()
+			.map(|v| {
+				v.map(|bytes| match datatype {
+					Datatype::Base64Binary => {
+						crate::Base64BinaryBuf::from_bytes(bytes.to_vec()).to_string()
+					}
+					_ => crate::HexBinaryBuf::from_bytes(bytes.to_vec()).to_string(),
+				})
+			})
+			.collect(),
+		Kind::Utf8 => display_column!(StringArray),
+	};
+
+	Ok(column)
+}
+
+fn epoch() -> NaiveDateTime {
+	NaiveDate::from_ymd_opt(1970, 1, 1)
+		.unwrap()
+		.and_hms_opt(0, 0, 0)
+		.unwrap()
+}
+
+fn to_big_rational(value: Value) -> BigRational {
+	match value {
+		Value::Decimal(d) => d.into_big_rational(),
+		Value::Integer(i) => BigRational::from_integer(i.into()),
+		Value::NonPositiveInteger(i) => BigRational::from_integer(i.into_big_int()),
+		Value::NegativeInteger(i) => BigRational::from_integer(i.into_big_int()),
+		Value::NonNegativeInteger(i) => BigRational::from_integer(i.into_big_int()),
+		Value::PositiveInteger(i) => BigRational::from_integer(i.into_big_int()),
+		_ => unreachable!(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{XSD_DATE_TIME, XSD_DECIMAL, XSD_INT, XSD_INTEGER, XSD_STRING};
+
+	fn roundtrip(iri: &iref::Iri, column: &[Option<&str>]) -> Vec<Option<String>> {
+		let datatype = Datatype::from_iri(iri).unwrap();
+		let array = to_array(datatype, column.iter().copied()).unwrap();
+		from_array(datatype, array.as_ref()).unwrap()
+	}
+
+	#[test]
+	fn int() {
+		let column = roundtrip(XSD_INT, &[Some("1"), None, Some("-02")]);
+		assert_eq!(column, [Some("1".to_owned()), None, Some("-2".to_owned())])
+	}
+
+	#[test]
+	fn decimal() {
+		let column = roundtrip(XSD_DECIMAL, &[Some("1.50"), Some("-0.000001")]);
+		assert_eq!(
+			column,
+			[Some("1.5".to_owned()), Some("-0.000001".to_owned())]
+		);
+
+		let column = roundtrip(XSD_INTEGER, &[Some("123456789012345678901234567890")]);
+		assert_eq!(column, [Some("123456789012345678901234567890".to_owned())]);
+
+		let datatype = Datatype::from_iri(XSD_DECIMAL).unwrap();
+		assert!(matches!(
+			to_array(datatype, [Some("0.0000000000000000001")]),
+			Err(ArrowConversionError::OutOfRange(0))
+		))
+	}
+
+	#[test]
+	fn date_time() {
+		let column = roundtrip(
+			XSD_DATE_TIME,
+			&[
+				Some("2002-05-31T13:07:12.5+01:00"),
+				Some("2002-05-31T13:07:12"),
+			],
+		);
+		assert_eq!(
+			column,
+			[
+				Some("2002-05-31T12:07:12.5Z".to_owned()),
+				Some("2002-05-31T13:07:12Z".to_owned())
+			]
+		)
+	}
+
+	#[test]
+	fn string() {
+		let column = roundtrip(XSD_STRING, &[Some("foo"), None]);
+		assert_eq!(column, [Some("foo".to_owned()), None])
+	}
+}
@@ -0,0 +1,349 @@
+use crate::{
+	lexical, Datatype, DecimalDatatype, IntDatatype, IntegerDatatype, LongDatatype,
+	NonNegativeIntegerDatatype, NonPositiveIntegerDatatype, ParseError, ShortDatatype,
+	UnsignedIntDatatype, UnsignedLongDatatype, UnsignedShortDatatype, Value,
+};
+
+/// Result of parsing a column of lexical forms, with successfully parsed
+/// values and errors stored separately.
+///
+/// Each value or error is stored along with the index of its lexical form in
+/// the input column.
+#[derive(Debug, Default)]
+pub struct ParsedColumn {
+	/// Successfully parsed values, by ascending input index.
+	pub values: Vec<(usize, Value)>,
+
+	/// Parse errors, by ascending input index.
+	pub errors: Vec<(usize, ParseError)>,
+}
+
+impl ParsedColumn {
+	/// Checks if every lexical form of the column was successfully parsed.
+	pub fn is_ok(&self) -> bool {
+		self.errors.is_empty()
+	}
+
+	/// Returns the total number of parsed lexical forms.
+	pub fn len(&self) -> usize {
+		self.values.len() + self.errors.len()
+	}
+
+	/// Checks if the parsed column is empty.
+	pub fn is_empty(&self) -> bool {
+		self.values.is_empty() && self.errors.is_empty()
+	}
+}
+
+impl FromIterator<Result<Value, ParseError>> for ParsedColumn {
+	fn from_iter<T: IntoIterator<Item = Result<Value, ParseError>>>(iter: T) -> Self {
+		let mut result = Self::default();
+
+		for (i, r) in iter.into_iter().enumerate() {
+			match r {
+				Ok(value) => result.values.push((i, value)),
+				Err(e) => result.errors.push((i, e)),
+			}
+		}
+
+		result
+	}
+}
+
+/// Validity flags of a column of lexical forms, one bit per lexical form.
+///
+/// Bits are stored in 64-bit words, least significant bit first, as in the
+/// validity bitmaps of Apache Arrow.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ValidityBitmap {
+	words: Vec<u64>,
+	len: usize,
+}
+
+impl ValidityBitmap {
+	/// Creates an empty bitmap with room for `capacity` flags.
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self {
+			words: Vec::with_capacity((capacity + 63) / 64),
+			len: 0,
+		}
+	}
+
+	/// Appends a flag to the bitmap.
+	pub fn push(&mut self, valid: bool) {
+		if self.len % 64 == 0 {
+			self.words.push(0)
+		}
+
+		*self.words.last_mut().unwrap() |= (valid as u64) << (self.len % 64);
+		self.len += 1
+	}
+
+	/// Returns the number of flags.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Checks if the bitmap is empty.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Returns the flag at the given index, or `None` if it is out of bounds.
+	pub fn get(&self, i: usize) -> Option<bool> {
+		(i < self.len).then(|| self.words[i / 64] & (1 << (i % 64)) != 0)
+	}
+
+	/// Returns the number of valid entries.
+	pub fn count_valid(&self) -> usize {
+		self.words.iter().map(|w| w.count_ones() as usize).sum()
+	}
+
+	/// Checks if every entry is valid.
+	pub fn all_valid(&self) -> bool {
+		self.count_valid() == self.len
+	}
+
+	/// Returns an iterator over the flags.
+	pub fn iter(&self) -> impl '_ + Iterator<Item = bool> {
+		(0..self.len).map(|i| self.words[i / 64] & (1 << (i % 64)) != 0)
+	}
+
+	/// Returns the underlying words. Bits past [`Self::len`] are unset.
+	pub fn as_words(&self) -> &[u64] {
+		&self.words
+	}
+}
+
+impl FromIterator<bool> for ValidityBitmap {
+	fn from_iter<T: IntoIterator<Item = bool>>(iter: T) -> Self {
+		let iter = iter.into_iter();
+		let mut result = Self::with_capacity(iter.size_hint().0);
+
+		for valid in iter {
+			result.push(valid)
+		}
+
+		result
+	}
+}
+
+/// Checks that the input is an integer lexical form whose value is in the
+/// given bounds.
+///
+/// The lexical space of integers (`[+-]?[0-9]+`) is exactly the syntax
+/// accepted by [`str::parse`].
+fn is_integer_in(value: &str, min: i128, max: i128) -> bool {
+	value
+		.parse::<i128>()
+		.is_ok_and(|n| (min..=max).contains(&n))
+}
+
+impl Datatype {
+	/// Checks if the given input is a valid lexical form of this datatype,
+	/// without building its value.
+	///
+	/// Numeric, boolean and binary datatypes are checked without allocating,
+	/// stopping at the first invalid byte. Other datatypes fall back to
+	/// [`Self::parse`].
+	pub fn validate(&self, value: &str) -> bool {
+		match self {
+			Self::Boolean => lexical::Boolean::new(value).is_ok(),
+			Self::Float => lexical::Float::new(value).is_ok(),
+			Self::Double => lexical::Double::new(value).is_ok(),
+			Self::Decimal(DecimalDatatype::Decimal) => lexical::Decimal::new(value).is_ok(),
+			Self::Decimal(DecimalDatatype::Integer(t)) => match t {
+				IntegerDatatype::Integer => lexical::Integer::new(value).is_ok(),
+				IntegerDatatype::NonPositiveInteger(
+					NonPositiveIntegerDatatype::NonPositiveInteger,
+				) => lexical::NonPositiveInteger::new(value).is_ok(),
+				IntegerDatatype::NonPositiveInteger(
+					NonPositiveIntegerDatatype::NegativeInteger,
+				) => lexical::NegativeInteger::new(value).is_ok(),
+				IntegerDatatype::NonNegativeInteger(t) => match t {
+					NonNegativeIntegerDatatype::NonNegativeInteger => {
+						lexical::NonNegativeInteger::new(value).is_ok()
+					}
+					NonNegativeIntegerDatatype::PositiveInteger => {
+						lexical::PositiveInteger::new(value).is_ok()
+					}
+					NonNegativeIntegerDatatype::UnsignedLong(t) => {
+						let max = match t {
+							UnsignedLongDatatype::UnsignedLong => u64::MAX as i128,
+							UnsignedLongDatatype::UnsignedInt(UnsignedIntDatatype::UnsignedInt) => {
+								u32::MAX as i128
+							}
+							UnsignedLongDatatype::UnsignedInt(
+								UnsignedIntDatatype::UnsignedShort(
+									UnsignedShortDatatype::UnsignedShort,
+								),
+							) => u16::MAX as i128,
+							UnsignedLongDatatype::UnsignedInt(
+								UnsignedIntDatatype::UnsignedShort(
+									UnsignedShortDatatype::UnsignedByte,
+								),
+							) => u8::MAX as i128,
+						};
+
+						is_integer_in(value, 0, max)
+					}
+				},
+				IntegerDatatype::Long(t) => match t {
+					LongDatatype::Long => is_integer_in(value, i64::MIN as i128, i64::MAX as i128),
+					LongDatatype::Int(IntDatatype::Int) => {
+						is_integer_in(value, i32::MIN as i128, i32::MAX as i128)
+					}
+					LongDatatype::Int(IntDatatype::Short(ShortDatatype::Short)) => {
+						is_integer_in(value, i16::MIN as i128, i16::MAX as i128)
+					}
+					LongDatatype::Int(IntDatatype::Short(ShortDatatype::Byte)) => {
+						is_integer_in(value, i8::MIN as i128, i8::MAX as i128)
+					}
+				},
+			},
+			Self::Base64Binary => lexical::Base64Binary::new(value).is_ok(),
+			Self::HexBinary => lexical::HexBinary::new(value).is_ok(),
+			_ => self.parse(value).is_ok(),
+		}
+	}
+
+	/// Validates many lexical forms at once, returning a bitmap of the
+	/// valid ones.
+	///
+	/// This is faster than [`Self::parse_many`] when only the validity of
+	/// the inputs matters (e.g. for data-quality scans), since values are
+	/// not built.
+	///
+	/// ```
+	/// use xsd_types::{Datatype, XSD_UNSIGNED_BYTE};
+	///
+	/// let dt = Datatype::from_iri(XSD_UNSIGNED_BYTE).unwrap();
+	/// let validity = dt.validate_many(["12", "-0", "256", "+7", " 1"]);
+	/// assert_eq!(validity.iter().collect::<Vec<_>>(), [true, true, false, true, false]);
+	/// assert_eq!(validity.count_valid(), 3);
+	/// ```
+	pub fn validate_many<'a>(&self, inputs: impl IntoIterator<Item = &'a str>) -> ValidityBitmap {
+		inputs.into_iter().map(|s| self.validate(s)).collect()
+	}
+
+	/// Parses many lexical forms at once.
+	///
+	/// Results are returned in input order.
+	pub fn parse_many<'a>(
+		&self,
+		inputs: impl IntoIterator<Item = &'a str>,
+	) -> Vec<Result<Value, ParseError>> {
+		inputs.into_iter().map(|s| self.parse(s)).collect()
+	}
+
+	/// Parses a column of lexical forms, storing values and errors
+	/// separately.
+	pub fn parse_column<'a>(&self, inputs: impl IntoIterator<Item = &'a str>) -> ParsedColumn {
+		inputs.into_iter().map(|s| self.parse(s)).collect()
+	}
+
+	/// Parses many lexical forms at once, in parallel.
+	///
+	/// Results are returned in input order.
+	#[cfg(feature = "rayon")]
+	pub fn par_parse_many<S: AsRef<str> + Sync>(
+		&self,
+		inputs: &[S],
+	) -> Vec<Result<Value, ParseError>> {
+		use rayon::prelude::*;
+		inputs.par_iter().map(|s| self.parse(s.as_ref())).collect()
+	}
+
+	/// Parses a column of lexical forms in parallel, storing values and
+	/// errors separately.
+	#[cfg(feature = "rayon")]
+	pub fn par_parse_column<S: AsRef<str> + Sync>(&self, inputs: &[S]) -> ParsedColumn {
+		self.par_parse_many(inputs).into_iter().collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Datatype, XSD_INT};
+
+	#[test]
+	fn parse_column() {
+		let dt = Datatype::from_iri(XSD_INT).unwrap();
+		let inputs = ["1", "foo", "3", "99999999999"];
+
+		let results = dt.parse_many(inputs);
+		assert_eq!(results.len(), 4);
+		assert!(results[0].is_ok() && results[1].is_err());
+
+		let column = dt.parse_column(inputs);
+		assert_eq!(column.len(), 4);
+		assert_eq!(
+			column.values.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+			[0, 2]
+		);
+		assert_eq!(
+			column.errors.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+			[1, 3]
+		);
+		assert!(!column.is_ok())
+	}
+
+	#[test]
+	fn validate_many() {
+		use crate::{XSD_BOOLEAN, XSD_DATE, XSD_LONG, XSD_NEGATIVE_INTEGER};
+
+		let inputs = [
+			"",
+			"0",
+			"-0",
+			"+1",
+			"01",
+			"1.0",
+			"true",
+			"-1",
+			"2024-02-29",
+			"2023-02-29",
+			"9223372036854775807",
+			"9223372036854775808",
+			"-9223372036854775808",
+			"99999999999999999999999999999999999999999",
+		];
+
+		for iri in [
+			XSD_INT,
+			XSD_LONG,
+			XSD_NEGATIVE_INTEGER,
+			XSD_BOOLEAN,
+			XSD_DATE,
+		] {
+			let dt = Datatype::from_iri(iri).unwrap();
+			let validity = dt.validate_many(inputs);
+			assert_eq!(validity.len(), inputs.len());
+
+			for (i, input) in inputs.into_iter().enumerate() {
+				assert_eq!(
+					validity.get(i),
+					Some(dt.parse(input).is_ok()),
+					"{iri}: {input}"
+				)
+			}
+		}
+
+		let validity: super::ValidityBitmap = (0..130).map(|i| i % 3 == 0).collect();
+		assert_eq!(validity.as_words().len(), 3);
+		assert_eq!(validity.count_valid(), 44);
+		assert_eq!(validity.get(129), Some(true));
+		assert_eq!(validity.get(130), None);
+		assert!(!validity.all_valid())
+	}
+
+	#[cfg(feature = "rayon")]
+	#[test]
+	fn par_parse_column() {
+		let dt = Datatype::from_iri(XSD_INT).unwrap();
+		let inputs: Vec<String> = (0..1000).map(|i| i.to_string()).collect();
+		let column = dt.par_parse_column(&inputs);
+		assert!(column.is_ok());
+		assert!(column.values.iter().enumerate().all(|(i, (j, _))| i == *j))
+	}
+}
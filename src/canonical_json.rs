@@ -0,0 +1,157 @@
+use std::fmt::Write;
+
+use crate::Value;
+
+/// Writes the given string as a JSON string literal, following the escaping
+/// rules of RFC 8785.
+///
+/// Only `"`, `\` and control characters are escaped, using the short escape
+/// sequences when they exist and lowercase `\u00xx` sequences otherwise.
+fn write_json_string(s: &str, out: &mut String) {
+	out.push('"');
+
+	for c in s.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\u{8}' => out.push_str("\\b"),
+			'\t' => out.push_str("\\t"),
+			'\n' => out.push_str("\\n"),
+			'\u{c}' => out.push_str("\\f"),
+			'\r' => out.push_str("\\r"),
+			c if c < ' ' => write!(out, "\\u{:04x}", c as u32).unwrap(),
+			c => out.push(c),
+		}
+	}
+
+	out.push('"')
+}
+
+/// Compares two strings by UTF-16 code units, as required by RFC 8785 for
+/// property names.
+fn cmp_utf16(a: &str, b: &str) -> std::cmp::Ordering {
+	a.encode_utf16().cmp(b.encode_utf16())
+}
+
+impl Value {
+	/// Writes this value as a JSON-LD value object, in the canonical form
+	/// defined by the JSON Canonicalization Scheme (RFC 8785).
+	///
+	/// The object has an `@type` property holding the datatype IRI, and a
+	/// `@value` property holding the canonical lexical form of the value as
+	/// a string, so that no precision is lost. The output is thus fully
+	/// determined by the value, and can be embedded in signed documents.
+	///
+	/// ```
+	/// use xsd_types::{Datatype, XSD_DATE_TIME};
+	///
+	/// let value = Datatype::from_iri(XSD_DATE_TIME)
+	///     .unwrap()
+	///     .parse("2024-05-17T10:00:00.500+00:00")
+	///     .unwrap();
+	///
+	/// assert_eq!(
+	///     value.to_canonical_json_string(),
+	///     r#"{"@type":"http://www.w3.org/2001/XMLSchema#dateTime","@value":"2024-05-17T10:00:00.5Z"}"#
+	/// );
+	/// ```
+	///
+	/// See: <https://www.rfc-editor.org/rfc/rfc8785>
+	pub fn to_canonical_json_string(&self) -> String {
+		let mut result = String::new();
+		self.write_canonical_json(&mut result);
+		result
+	}
+
+	fn write_canonical_json(&self, out: &mut String) {
+		out.push_str("{\"@type\":");
+		write_json_string(self.datatype().iri().as_str(), out);
+		out.push_str(",\"@value\":");
+		write_json_string(&self.to_string(), out);
+		out.push('}')
+	}
+}
+
+/// Writes a map of values as a JSON object, in the canonical form defined by
+/// the JSON Canonicalization Scheme (RFC 8785).
+///
+/// Entries are sorted by key, comparing UTF-16 code units, and each value is
+/// written as by [`Value::to_canonical_json_string`]. If a key appears more
+/// than once, only its last entry is kept.
+///
+/// ```
+/// use xsd_types::{canonical_json_map, Value};
+///
+/// let map = [("b", Value::Boolean(true.into())), ("a", Value::Int(1))];
+/// assert_eq!(
+///     canonical_json_map(map.iter().map(|(k, v)| (*k, v))),
+///     r#"{"a":{"@type":"http://www.w3.org/2001/XMLSchema#int","@value":"1"},"b":{"@type":"http://www.w3.org/2001/XMLSchema#boolean","@value":"true"}}"#
+/// );
+/// ```
+pub fn canonical_json_map<'a, K: AsRef<str>>(
+	entries: impl IntoIterator<Item = (K, &'a Value)>,
+) -> String {
+	let mut entries: Vec<_> = entries.into_iter().collect();
+
+	// The sort is stable, so the last entry of each key comes last.
+	entries.sort_by(|(a, _), (b, _)| cmp_utf16(a.as_ref(), b.as_ref()));
+
+	let mut result = String::from("{");
+	let mut first = true;
+
+	for (i, (key, value)) in entries.iter().enumerate() {
+		if entries
+			.get(i + 1)
+			.is_some_and(|(next, _)| next.as_ref() == key.as_ref())
+		{
+			continue;
+		}
+
+		if !first {
+			result.push(',')
+		}
+
+		first = false;
+		write_json_string(key.as_ref(), &mut result);
+		result.push(':');
+		value.write_canonical_json(&mut result)
+	}
+
+	result.push('}');
+	result
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn escaping() {
+		let value = Value::String("a\"b\\c\u{1}\u{7f}é\n".to_owned());
+		assert_eq!(
+			value.to_canonical_json_string(),
+			"{\"@type\":\"http://www.w3.org/2001/XMLSchema#string\",\"@value\":\"a\\\"b\\\\c\\u0001\u{7f}é\\n\"}"
+		);
+	}
+
+	#[test]
+	fn key_order() {
+		let one = Value::Int(1);
+		let two = Value::Int(2);
+
+		// U+1F600 (encoded with surrogates) sorts before U+FF61 in UTF-16,
+		// but not in UTF-8.
+		let map = canonical_json_map([
+			("\u{ff61}", &one),
+			("\u{1f600}", &one),
+			("a", &one),
+			("a", &two),
+		]);
+		let keys: Vec<_> = map.match_indices("\":{").map(|(i, _)| &map[..i]).collect();
+		assert!(keys[0].ends_with("\"a"));
+		assert!(keys[1].ends_with('\u{1f600}'));
+		assert!(keys[2].ends_with('\u{ff61}'));
+		assert_eq!(map.matches("\"@value\":\"2\"").count(), 1);
+		assert_eq!(map.matches("\"@value\":\"1\"").count(), 2);
+	}
+}
@@ -0,0 +1,192 @@
+//! C ABI for validating and canonicalizing lexical forms.
+//!
+//! Datatypes are identified by IRI. Every function returns an [`XsdStatus`]
+//! code, whose values are stable across versions of this crate.
+//!
+//! The functions are exported with unmangled names. To call them from C,
+//! link against a `cdylib` or `staticlib` crate re-exporting this module.
+//!
+//! ```c
+//! char *canonical;
+//! if (xsd_canonicalize("http://www.w3.org/2001/XMLSchema#decimal", "+01.50", &canonical) == 0) {
+//!     puts(canonical); // 1.5
+//!     xsd_string_free(canonical);
+//! }
+//! ```
+use std::ffi::{c_char, CStr, CString};
+
+use iref::Iri;
+
+use crate::{Datatype, ParseError};
+
+/// Status code returned by the C ABI functions.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XsdStatus {
+	/// Success.
+	Ok = 0,
+
+	/// An argument pointer is null.
+	NullPointer = 1,
+
+	/// An argument string is not valid UTF-8.
+	Utf8 = 2,
+
+	/// The datatype IRI is not a valid IRI.
+	InvalidIri = 3,
+
+	/// The datatype IRI is not a known XSD datatype.
+	UnknownDatatype = 4,
+
+	/// The lexical form is empty, which the datatype does not allow.
+	EmptyLexicalForm = 5,
+
+	/// The lexical form is not valid for the datatype.
+	Invalid = 6,
+
+	/// The canonical form contains a nul character and cannot be returned
+	/// as a C string.
+	Nul = 7,
+
+	/// The datatype has an empty value space (`xsd:error`).
+	EmptyValueSpace = 8,
+}
+
+impl From<ParseError> for XsdStatus {
+	fn from(value: ParseError) -> Self {
+		match value {
+			ParseError::EmptyLexicalForm => Self::EmptyLexicalForm,
+			ParseError::Invalid => Self::Invalid,
+			ParseError::UnknownDatatype => Self::UnknownDatatype,
+			ParseError::EmptyValueSpace => Self::EmptyValueSpace,
+		}
+	}
+}
+
+unsafe fn to_str<'a>(ptr: *const c_char) -> Result<&'a str, XsdStatus> {
+	if ptr.is_null() {
+		return Err(XsdStatus::NullPointer);
+	}
+
+	CStr::from_ptr(ptr).to_str().map_err(|_| XsdStatus::Utf8)
+}
+
+unsafe fn to_datatype(ptr: *const c_char) -> Result<Datatype, XsdStatus> {
+	let iri = Iri::new(to_str(ptr)?).map_err(|_| XsdStatus::InvalidIri)?;
+	Datatype::from_iri(iri).ok_or(XsdStatus::UnknownDatatype)
+}
+
+unsafe fn canonicalize(
+	datatype_iri: *const c_char,
+	lexical_value: *const c_char,
+) -> Result<CString, XsdStatus> {
+	let datatype = to_datatype(datatype_iri)?;
+	let value = datatype.parse(to_str(lexical_value)?)?;
+	CString::new(value.to_string()).map_err(|_| XsdStatus::Nul)
+}
+
+/// Checks that `lexical_value` is a valid lexical form of the datatype
+/// identified by `datatype_iri`.
+///
+/// # Safety
+///
+/// Both arguments must be null or point to nul-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn xsd_validate(
+	datatype_iri: *const c_char,
+	lexical_value: *const c_char,
+) -> XsdStatus {
+	let result =
+		to_datatype(datatype_iri).and_then(|datatype| Ok(datatype.parse(to_str(lexical_value)?)?));
+
+	match result {
+		Ok(_) => XsdStatus::Ok,
+		Err(status) => status,
+	}
+}
+
+/// Computes the canonical form of `lexical_value`, a lexical form of the
+/// datatype identified by `datatype_iri`.
+///
+/// On success, a newly allocated nul-terminated string is stored in `output`.
+/// It must be released with [`xsd_string_free`]. On error, `output` is left
+/// untouched.
+///
+/// # Safety
+///
+/// `datatype_iri` and `lexical_value` must be null or point to
+/// nul-terminated strings. `output` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn xsd_canonicalize(
+	datatype_iri: *const c_char,
+	lexical_value: *const c_char,
+	output: *mut *mut c_char,
+) -> XsdStatus {
+	if output.is_null() {
+		return XsdStatus::NullPointer;
+	}
+
+	match canonicalize(datatype_iri, lexical_value) {
+		Ok(s) => {
+			*output = s.into_raw();
+			XsdStatus::Ok
+		}
+		Err(status) => status,
+	}
+}
+
+/// Releases a string returned by [`xsd_canonicalize`].
+///
+/// # Safety
+///
+/// `s` must be null or a string returned by [`xsd_canonicalize`] that was not
+/// already released.
+#[no_mangle]
+pub unsafe extern "C" fn xsd_string_free(s: *mut c_char) {
+	if !s.is_null() {
+		drop(CString::from_raw(s))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const XSD_DECIMAL: &str = "http://www.w3.org/2001/XMLSchema#decimal";
+	const XSD_BYTE: &str = "http://www.w3.org/2001/XMLSchema#byte";
+
+	fn validate(iri: &str, value: &str) -> XsdStatus {
+		let iri = CString::new(iri).unwrap();
+		let value = CString::new(value).unwrap();
+		unsafe { xsd_validate(iri.as_ptr(), value.as_ptr()) }
+	}
+
+	#[test]
+	fn canonicalize() {
+		let iri = CString::new(XSD_DECIMAL).unwrap();
+		let value = CString::new("+01.50").unwrap();
+		let mut output = std::ptr::null_mut();
+		let status = unsafe { xsd_canonicalize(iri.as_ptr(), value.as_ptr(), &mut output) };
+
+		assert_eq!(status, XsdStatus::Ok);
+		assert_eq!(unsafe { CStr::from_ptr(output) }.to_str().unwrap(), "1.5");
+		unsafe { xsd_string_free(output) }
+	}
+
+	#[test]
+	fn status_codes() {
+		assert_eq!(validate(XSD_BYTE, "12"), XsdStatus::Ok);
+		assert_eq!(validate(XSD_BYTE, "300"), XsdStatus::Invalid);
+		assert_eq!(validate(XSD_BYTE, ""), XsdStatus::EmptyLexicalForm);
+		assert_eq!(
+			validate("http://example.org/#byte", "12"),
+			XsdStatus::UnknownDatatype
+		);
+		assert_eq!(validate("not an iri", "12"), XsdStatus::InvalidIri);
+		assert_eq!(
+			unsafe { xsd_validate(std::ptr::null(), std::ptr::null()) },
+			XsdStatus::NullPointer
+		);
+		assert_eq!(XsdStatus::Invalid as i32, 6)
+	}
+}
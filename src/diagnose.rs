@@ -0,0 +1,344 @@
+use std::fmt;
+
+use crate::{
+	lenient::{self, Fix},
+	Datatype, DateTimeDatatype, ParseError,
+};
+
+/// Lexical forms of various datatypes, used to probe which prefixes of an
+/// invalid input can still be completed into a valid lexical form.
+const SAMPLES: &[&str] = &[
+	"0",
+	"1",
+	"-1",
+	"+1",
+	"10",
+	"0.5",
+	"-0.5",
+	"1E0",
+	"-1.5e-3",
+	"INF",
+	"-INF",
+	"NaN",
+	"true",
+	"false",
+	"P1Y2M3DT4H5M6.7S",
+	"-P1Y",
+	"P1D",
+	"PT1H",
+	"PT1.5S",
+	"2000-01-01T00:00:00Z",
+	"2000-01-01T00:00:00.5+01:00",
+	"2000-01-01T00:00:00",
+	"2000-01-01",
+	"2000-01-01Z",
+	"-0001-01-01",
+	"00:00:00",
+	"00:00:00.5Z",
+	"2000-01",
+	"2000",
+	"--01-01",
+	"---01",
+	"--01",
+	"0F",
+	"AA==",
+	"a",
+	"a-b",
+	"a:b",
+	"en-US",
+	"http://example.org/a#b",
+];
+
+/// Datatypes considered when looking for the nearest datatype accepting an
+/// invalid lexical form, in order of preference within each family.
+const CANDIDATES: &[&str] = &[
+	"integer",
+	"decimal",
+	"double",
+	"boolean",
+	"dateTime",
+	"date",
+	"time",
+	"gYearMonth",
+	"gYear",
+	"gMonthDay",
+	"duration",
+	"hexBinary",
+	"base64Binary",
+];
+
+/// Fix suggested by a [`Diagnosis`].
+///
+/// A suggestion is only made if the fixed input is accepted by the
+/// diagnosed datatype.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Suggestion {
+	/// Remove the leading and trailing whitespace (`" 42 "`).
+	Trim,
+
+	/// Use lowercase letters (`TRUE`).
+	Lowercase,
+
+	/// Use a dot as decimal separator (`3,14`).
+	DecimalPoint,
+
+	/// Separate the date and time with `T` instead of a space, as allowed
+	/// by RFC 3339 (`2024-01-01 10:00:00`).
+	DateTimeSeparator,
+
+	/// Use the lenient parser of the [`lenient`] module, which fixes the
+	/// listed deviations.
+	Lenient(Vec<Fix>),
+}
+
+impl fmt::Display for Suggestion {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Trim => write!(f, "remove leading and trailing whitespace"),
+			Self::Lowercase => write!(f, "use lowercase letters"),
+			Self::DecimalPoint => write!(f, "use `.` as decimal separator"),
+			Self::DateTimeSeparator => {
+				write!(
+					f,
+					"looks like RFC 3339, separate date and time with `T` instead of a space"
+				)
+			}
+			Self::Lenient(fixes) => {
+				write!(f, "use the lenient parser, which fixes: ")?;
+				for (i, fix) in fixes.iter().enumerate() {
+					if i > 0 {
+						write!(f, ", ")?;
+					}
+
+					fix.fmt(f)?;
+				}
+
+				Ok(())
+			}
+		}
+	}
+}
+
+/// Explanation of why a lexical form was rejected by a datatype.
+///
+/// Returned by [`Datatype::diagnose`], to build user-friendly error
+/// messages.
+#[derive(Debug)]
+pub struct Diagnosis {
+	/// Diagnosed datatype.
+	pub datatype: Datatype,
+
+	/// Parse error, or `None` if the input is a valid lexical form.
+	pub error: Option<ParseError>,
+
+	/// Byte offset of the first character of the input that cannot be part
+	/// of a valid lexical form, or the length of the input if it is a valid
+	/// but incomplete prefix.
+	///
+	/// This is `None` if the input is valid, or if the offset could not be
+	/// determined.
+	pub offset: Option<usize>,
+
+	/// Other datatype of the same family (numeric, temporal, etc.) accepting
+	/// the input, if any.
+	pub nearest: Option<Datatype>,
+
+	/// Suggested fix, if any.
+	pub suggestion: Option<Suggestion>,
+}
+
+impl Diagnosis {
+	/// Checks if the input is a valid lexical form.
+	pub fn is_valid(&self) -> bool {
+		self.error.is_none()
+	}
+}
+
+impl fmt::Display for Diagnosis {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match &self.error {
+			None => write!(f, "valid <{}> lexical form", self.datatype.iri()),
+			Some(e) => {
+				write!(f, "invalid <{}> lexical form ({e})", self.datatype.iri())?;
+
+				if let Some(offset) = self.offset {
+					write!(f, " at byte offset {offset}")?;
+				}
+
+				if let Some(suggestion) = &self.suggestion {
+					write!(f, "; {suggestion}")?;
+				}
+
+				if let Some(nearest) = self.nearest {
+					write!(f, "; this is a valid <{}> lexical form", nearest.iri())?;
+				}
+
+				Ok(())
+			}
+		}
+	}
+}
+
+impl Datatype {
+	/// Explains why the given input is not a valid lexical form of this
+	/// datatype.
+	///
+	/// ```
+	/// use xsd_types::{Datatype, Suggestion, XSD_BOOLEAN, XSD_DATE};
+	///
+	/// let date = Datatype::from_iri(XSD_DATE).unwrap();
+	/// let diagnosis = date.diagnose("2024-13-01");
+	/// assert_eq!(diagnosis.offset, Some(6));
+	///
+	/// let boolean = Datatype::from_iri(XSD_BOOLEAN).unwrap();
+	/// let diagnosis = boolean.diagnose("TRUE");
+	/// assert_eq!(diagnosis.suggestion, Some(Suggestion::Lowercase));
+	/// ```
+	pub fn diagnose(&self, input: &str) -> Diagnosis {
+		match self.parse(input) {
+			Ok(_) => Diagnosis {
+				datatype: *self,
+				error: None,
+				offset: None,
+				nearest: None,
+				suggestion: None,
+			},
+			Err(e) => Diagnosis {
+				datatype: *self,
+				error: Some(e),
+				offset: self.first_invalid_offset(input),
+				nearest: self.nearest(input),
+				suggestion: self.suggest(input),
+			},
+		}
+	}
+
+	/// Checks if the given prefix can be completed into a valid lexical
+	/// form, using the suffixes of the given samples.
+	fn is_viable_prefix(&self, samples: &[&str], prefix: &str) -> bool {
+		samples.iter().any(|s| {
+			(0..=s.len())
+				.filter(|&j| s.is_char_boundary(j))
+				.any(|j| self.parse(&format!("{prefix}{}", &s[j..])).is_ok())
+		})
+	}
+
+	fn first_invalid_offset(&self, input: &str) -> Option<usize> {
+		let samples: Vec<_> = SAMPLES
+			.iter()
+			.copied()
+			.filter(|s| self.parse(s).is_ok())
+			.collect();
+
+		if samples.is_empty() {
+			return None;
+		}
+
+		for (i, c) in input.char_indices() {
+			if !self.is_viable_prefix(&samples, &input[..i + c.len_utf8()]) {
+				return Some(i);
+			}
+		}
+
+		Some(input.len())
+	}
+
+	fn nearest(&self, input: &str) -> Option<Datatype> {
+		let family = |dt: &Datatype| {
+			(
+				dt.is_numeric(),
+				dt.is_temporal(),
+				dt.is_binary(),
+				dt.is_string_like(),
+			)
+		};
+
+		// Binary datatypes accept most alphanumeric inputs, so datatypes of
+		// other families are not considered.
+		CANDIDATES
+			.iter()
+			.filter_map(|name| Datatype::from_local_name(name))
+			.filter(|dt| dt != self && family(dt) == family(self))
+			.find(|dt| dt.parse(input).is_ok())
+	}
+
+	fn suggest(&self, input: &str) -> Option<Suggestion> {
+		let accepts = |fixed: &str| fixed != input && self.parse(fixed).is_ok();
+
+		if accepts(input.trim()) {
+			return Some(Suggestion::Trim);
+		}
+
+		if accepts(&input.to_lowercase()) {
+			return Some(Suggestion::Lowercase);
+		}
+
+		if accepts(&input.replacen(',', ".", 1)) {
+			return Some(Suggestion::DecimalPoint);
+		}
+
+		if accepts(&input.replacen(' ', "T", 1)) {
+			return Some(Suggestion::DateTimeSeparator);
+		}
+
+		let fixes = match self {
+			Self::DateTime(DateTimeDatatype::DateTime) => {
+				lenient::parse_date_time(input).ok().map(|l| l.fixes)
+			}
+			Self::DateTime(DateTimeDatatype::DateTimeStamp) => {
+				lenient::parse_date_time_stamp(input).ok().map(|l| l.fixes)
+			}
+			Self::Time => lenient::parse_time(input).ok().map(|l| l.fixes),
+			_ => None,
+		};
+
+		fixes
+			.filter(|fixes| !fixes.is_empty())
+			.map(Suggestion::Lenient)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{XSD_BYTE, XSD_DATE_TIME, XSD_DECIMAL, XSD_INTEGER};
+
+	#[test]
+	fn diagnose() {
+		let integer = Datatype::from_iri(XSD_INTEGER).unwrap();
+		assert!(integer.diagnose("42").is_valid());
+
+		let d = integer.diagnose("12x");
+		assert_eq!(d.offset, Some(2));
+		assert_eq!(d.nearest, None);
+
+		let d = integer.diagnose(" 42 ");
+		assert_eq!(d.suggestion, Some(Suggestion::Trim));
+
+		let d = integer.diagnose("4.5");
+		assert_eq!(d.nearest, Datatype::from_iri(XSD_DECIMAL));
+
+		let byte = Datatype::from_iri(XSD_BYTE).unwrap();
+		let d = byte.diagnose("300");
+		assert_eq!(d.offset, Some(2));
+		assert_eq!(d.nearest, Some(integer));
+
+		let decimal = Datatype::from_iri(XSD_DECIMAL).unwrap();
+		assert_eq!(
+			decimal.diagnose("3,14").suggestion,
+			Some(Suggestion::DecimalPoint)
+		);
+
+		let date_time = Datatype::from_iri(XSD_DATE_TIME).unwrap();
+		let d = date_time.diagnose("2024-01-01 10:00:00");
+		assert_eq!(d.offset, Some(10));
+		assert_eq!(d.suggestion, Some(Suggestion::DateTimeSeparator));
+
+		let d = date_time.diagnose("2024-01-01T10:00");
+		assert_eq!(d.offset, Some(16));
+		assert_eq!(
+			d.suggestion,
+			Some(Suggestion::Lenient(vec![Fix::MissingSeconds]))
+		);
+	}
+}
@@ -0,0 +1,162 @@
+use std::fmt;
+
+use crate::Datatype;
+
+/// Constraining facet.
+///
+/// See: <https://www.w3.org/TR/xmlschema11-2/#rf-facets>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Facet {
+	Length,
+	MinLength,
+	MaxLength,
+	Pattern,
+	Enumeration,
+	WhiteSpace,
+	MaxInclusive,
+	MaxExclusive,
+	MinExclusive,
+	MinInclusive,
+	TotalDigits,
+	FractionDigits,
+	Assertions,
+	ExplicitTimezone,
+}
+
+impl Facet {
+	/// Returns the name of the facet, as used in XML Schema documents.
+	pub fn name(&self) -> &'static str {
+		match self {
+			Self::Length => "length",
+			Self::MinLength => "minLength",
+			Self::MaxLength => "maxLength",
+			Self::Pattern => "pattern",
+			Self::Enumeration => "enumeration",
+			Self::WhiteSpace => "whiteSpace",
+			Self::MaxInclusive => "maxInclusive",
+			Self::MaxExclusive => "maxExclusive",
+			Self::MinExclusive => "minExclusive",
+			Self::MinInclusive => "minInclusive",
+			Self::TotalDigits => "totalDigits",
+			Self::FractionDigits => "fractionDigits",
+			Self::Assertions => "assertions",
+			Self::ExplicitTimezone => "explicitTimezone",
+		}
+	}
+}
+
+impl fmt::Display for Facet {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.name().fmt(f)
+	}
+}
+
+/// Facets applicable to union datatypes.
+const UNION_FACETS: &[Facet] = &[Facet::Pattern, Facet::Enumeration, Facet::Assertions];
+
+const BOOLEAN_FACETS: &[Facet] = &[Facet::Pattern, Facet::WhiteSpace, Facet::Assertions];
+
+const LENGTH_FACETS: &[Facet] = &[
+	Facet::Length,
+	Facet::MinLength,
+	Facet::MaxLength,
+	Facet::Pattern,
+	Facet::Enumeration,
+	Facet::WhiteSpace,
+	Facet::Assertions,
+];
+
+const ORDERED_FACETS: &[Facet] = &[
+	Facet::Pattern,
+	Facet::Enumeration,
+	Facet::WhiteSpace,
+	Facet::MaxInclusive,
+	Facet::MaxExclusive,
+	Facet::MinExclusive,
+	Facet::MinInclusive,
+	Facet::Assertions,
+];
+
+const DECIMAL_FACETS: &[Facet] = &[
+	Facet::Pattern,
+	Facet::Enumeration,
+	Facet::WhiteSpace,
+	Facet::MaxInclusive,
+	Facet::MaxExclusive,
+	Facet::MinExclusive,
+	Facet::MinInclusive,
+	Facet::TotalDigits,
+	Facet::FractionDigits,
+	Facet::Assertions,
+];
+
+const DATE_TIME_FACETS: &[Facet] = &[
+	Facet::Pattern,
+	Facet::Enumeration,
+	Facet::WhiteSpace,
+	Facet::MaxInclusive,
+	Facet::MaxExclusive,
+	Facet::MinExclusive,
+	Facet::MinInclusive,
+	Facet::Assertions,
+	Facet::ExplicitTimezone,
+];
+
+impl Datatype {
+	/// Returns the constraining facets that can be used to restrict this
+	/// datatype, as listed by the specification.
+	///
+	/// Derived datatypes accept the facets of their primitive datatype.
+	///
+	/// ```
+	/// use xsd_types::{Datatype, Facet, XSD_INT};
+	///
+	/// let int = Datatype::from_iri(XSD_INT).unwrap();
+	/// assert!(int.applicable_facets().contains(&Facet::TotalDigits));
+	/// assert!(!int.applicable_facets().contains(&Facet::Length));
+	/// ```
+	///
+	/// See: <https://www.w3.org/TR/xmlschema11-2/#built-in-primitive-datatypes>
+	pub fn applicable_facets(&self) -> &'static [Facet] {
+		match self {
+			Self::Boolean => BOOLEAN_FACETS,
+			Self::Float | Self::Double | Self::Duration(_) => ORDERED_FACETS,
+			Self::Decimal(_) => DECIMAL_FACETS,
+			Self::DateTime(_)
+			| Self::Time
+			| Self::Date
+			| Self::GYearMonth
+			| Self::GYear
+			| Self::GMonthDay
+			| Self::GDay
+			| Self::GMonth => DATE_TIME_FACETS,
+			Self::String(_) | Self::Base64Binary | Self::HexBinary | Self::AnyUri | Self::QName => {
+				LENGTH_FACETS
+			}
+			Self::Error => UNION_FACETS,
+		}
+	}
+
+	/// Checks if the given facet can be used to restrict this datatype.
+	pub fn accepts_facet(&self, facet: Facet) -> bool {
+		self.applicable_facets().contains(&facet)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{XSD_BOOLEAN, XSD_DATE, XSD_DAY_TIME_DURATION, XSD_HEX_BINARY, XSD_TOKEN};
+
+	#[test]
+	fn applicable_facets() {
+		let datatype = |iri| Datatype::from_iri(iri).unwrap();
+
+		assert!(datatype(XSD_TOKEN).accepts_facet(Facet::MaxLength));
+		assert!(datatype(XSD_HEX_BINARY).accepts_facet(Facet::Length));
+		assert!(!datatype(XSD_BOOLEAN).accepts_facet(Facet::Enumeration));
+		assert!(datatype(XSD_DATE).accepts_facet(Facet::ExplicitTimezone));
+		assert!(!datatype(XSD_DAY_TIME_DURATION).accepts_facet(Facet::ExplicitTimezone));
+		assert_eq!(Facet::WhiteSpace.to_string(), "whiteSpace");
+	}
+}
@@ -0,0 +1,234 @@
+use crate::{
+	Datatype, DateTimeDatatype, DecimalDatatype, DurationDatatype, IntDatatype, IntegerDatatype,
+	LongDatatype, NCNameDatatype, NameDatatype, NonNegativeIntegerDatatype,
+	NonPositiveIntegerDatatype, NormalizedStringDatatype, ShortDatatype, StringDatatype,
+	TokenDatatype, UnsignedIntDatatype, UnsignedLongDatatype, UnsignedShortDatatype, Value,
+};
+
+impl Datatype {
+	/// Checks if this is a numeric datatype (`xsd:float`, `xsd:double`,
+	/// `xsd:decimal` or any of its derived datatypes).
+	pub fn is_numeric(&self) -> bool {
+		matches!(self, Self::Float | Self::Double | Self::Decimal(_))
+	}
+
+	/// Checks if this is a date, time or duration datatype, including the
+	/// Gregorian fragments (`xsd:gYear`, `xsd:gMonthDay`, etc.).
+	pub fn is_temporal(&self) -> bool {
+		matches!(
+			self,
+			Self::Duration(_)
+				| Self::DateTime(_)
+				| Self::Time | Self::Date
+				| Self::GYearMonth
+				| Self::GYear
+				| Self::GMonthDay
+				| Self::GDay | Self::GMonth
+		)
+	}
+
+	/// Checks if this is a binary datatype (`xsd:base64Binary` or
+	/// `xsd:hexBinary`).
+	pub fn is_binary(&self) -> bool {
+		matches!(self, Self::Base64Binary | Self::HexBinary)
+	}
+
+	/// Checks if this is `xsd:string` or any of its derived datatypes
+	/// (`xsd:token`, `xsd:language`, `xsd:NCName`, etc.).
+	pub fn is_string_like(&self) -> bool {
+		matches!(self, Self::String(_))
+	}
+
+	/// Checks if the values of this datatype carry a timezone offset.
+	///
+	/// The timezone is optional in every lexical form of these datatypes,
+	/// except for `xsd:dateTimeStamp` where it is required. Durations have
+	/// no timezone.
+	pub fn expects_timezone(&self) -> bool {
+		self.is_temporal() && !matches!(self, Self::Duration(_))
+	}
+
+	/// Checks if the lexical forms of this datatype must include a timezone
+	/// offset (`xsd:dateTimeStamp`).
+	pub fn requires_timezone(&self) -> bool {
+		matches!(self, Self::DateTime(DateTimeDatatype::DateTimeStamp))
+	}
+}
+
+impl Value {
+	/// Checks if this value is an instance of the given datatype, taking
+	/// derivation into account.
+	///
+	/// A value is an instance of its own datatype and of every datatype it
+	/// is derived from. For instance an `xsd:byte` value is also an
+	/// `xsd:integer` and an `xsd:decimal`, but not an `xsd:unsignedByte`,
+	/// even if it is positive.
+	///
+	/// ```
+	/// use xsd_types::{Datatype, Value, XSD_DECIMAL, XSD_UNSIGNED_BYTE};
+	///
+	/// let value = Value::Byte(12);
+	/// assert!(value.is_instance_of(Datatype::from_iri(XSD_DECIMAL).unwrap()));
+	/// assert!(!value.is_instance_of(Datatype::from_iri(XSD_UNSIGNED_BYTE).unwrap()));
+	/// ```
+	pub fn is_instance_of(&self, datatype: Datatype) -> bool {
+		self.datatype().is_derived_from(datatype)
+	}
+}
+
+impl Datatype {
+	/// Checks if this datatype is `base` or is derived from it, directly or
+	/// not.
+	pub fn is_derived_from(&self, base: Datatype) -> bool {
+		match (*self, base) {
+			(Self::Decimal(a), Self::Decimal(b)) => decimal_derived_from(a, b),
+			(Self::String(a), Self::String(b)) => string_derived_from(a, b),
+			(Self::Duration(a), Self::Duration(b)) => a == b || b == DurationDatatype::Duration,
+			(Self::DateTime(a), Self::DateTime(b)) => a == b || b == DateTimeDatatype::DateTime,
+			(a, b) => a == b,
+		}
+	}
+}
+
+fn decimal_derived_from(a: DecimalDatatype, b: DecimalDatatype) -> bool {
+	match (a, b) {
+		(_, DecimalDatatype::Decimal) => true,
+		(DecimalDatatype::Integer(a), DecimalDatatype::Integer(b)) => integer_derived_from(a, b),
+		_ => false,
+	}
+}
+
+fn integer_derived_from(a: IntegerDatatype, b: IntegerDatatype) -> bool {
+	match (a, b) {
+		(_, IntegerDatatype::Integer) => true,
+		(IntegerDatatype::NonPositiveInteger(a), IntegerDatatype::NonPositiveInteger(b)) => {
+			a == b || b == NonPositiveIntegerDatatype::NonPositiveInteger
+		}
+		(IntegerDatatype::NonNegativeInteger(a), IntegerDatatype::NonNegativeInteger(b)) => {
+			non_negative_integer_derived_from(a, b)
+		}
+		(IntegerDatatype::Long(a), IntegerDatatype::Long(b)) => long_derived_from(a, b),
+		_ => false,
+	}
+}
+
+fn non_negative_integer_derived_from(
+	a: NonNegativeIntegerDatatype,
+	b: NonNegativeIntegerDatatype,
+) -> bool {
+	match (a, b) {
+		(_, NonNegativeIntegerDatatype::NonNegativeInteger) => true,
+		(
+			NonNegativeIntegerDatatype::UnsignedLong(a),
+			NonNegativeIntegerDatatype::UnsignedLong(b),
+		) => unsigned_long_derived_from(a, b),
+		(a, b) => a == b,
+	}
+}
+
+fn unsigned_long_derived_from(a: UnsignedLongDatatype, b: UnsignedLongDatatype) -> bool {
+	match (a, b) {
+		(_, UnsignedLongDatatype::UnsignedLong) => true,
+		(UnsignedLongDatatype::UnsignedInt(a), UnsignedLongDatatype::UnsignedInt(b)) => {
+			match (a, b) {
+				(_, UnsignedIntDatatype::UnsignedInt) => true,
+				(UnsignedIntDatatype::UnsignedShort(a), UnsignedIntDatatype::UnsignedShort(b)) => {
+					a == b || b == UnsignedShortDatatype::UnsignedShort
+				}
+				_ => false,
+			}
+		}
+		_ => false,
+	}
+}
+
+fn long_derived_from(a: LongDatatype, b: LongDatatype) -> bool {
+	match (a, b) {
+		(_, LongDatatype::Long) => true,
+		(LongDatatype::Int(a), LongDatatype::Int(b)) => match (a, b) {
+			(_, IntDatatype::Int) => true,
+			(IntDatatype::Short(a), IntDatatype::Short(b)) => a == b || b == ShortDatatype::Short,
+			_ => false,
+		},
+		_ => false,
+	}
+}
+
+fn string_derived_from(a: StringDatatype, b: StringDatatype) -> bool {
+	match (a, b) {
+		(_, StringDatatype::String) => true,
+		(StringDatatype::NormalizedString(a), StringDatatype::NormalizedString(b)) => {
+			match (a, b) {
+				(_, NormalizedStringDatatype::NormalizedString) => true,
+				(NormalizedStringDatatype::Token(a), NormalizedStringDatatype::Token(b)) => {
+					token_derived_from(a, b)
+				}
+				_ => false,
+			}
+		}
+		_ => false,
+	}
+}
+
+fn token_derived_from(a: TokenDatatype, b: TokenDatatype) -> bool {
+	match (a, b) {
+		(_, TokenDatatype::Token) => true,
+		(TokenDatatype::Name(a), TokenDatatype::Name(b)) => match (a, b) {
+			(_, NameDatatype::Name) => true,
+			(NameDatatype::NCName(a), NameDatatype::NCName(b)) => {
+				a == b || b == NCNameDatatype::NCName
+			}
+			_ => false,
+		},
+		(a, b) => a == b,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		Datatype, Value, XSD_BASE64_BINARY, XSD_BYTE, XSD_DATE_TIME, XSD_DATE_TIME_STAMP,
+		XSD_DAY_TIME_DURATION, XSD_DECIMAL, XSD_DOUBLE, XSD_G_YEAR, XSD_ID, XSD_LANGUAGE, XSD_NAME,
+		XSD_NON_NEGATIVE_INTEGER, XSD_POSITIVE_INTEGER, XSD_STRING, XSD_TOKEN, XSD_UNSIGNED_BYTE,
+		XSD_YEAR_MONTH_DURATION,
+	};
+
+	#[test]
+	fn families() {
+		let byte = Datatype::from_iri(XSD_UNSIGNED_BYTE).unwrap();
+		assert!(byte.is_numeric() && !byte.is_temporal());
+		assert!(Datatype::from_iri(XSD_DOUBLE).unwrap().is_numeric());
+
+		let language = Datatype::from_iri(XSD_LANGUAGE).unwrap();
+		assert!(language.is_string_like() && !language.is_binary());
+		assert!(Datatype::from_iri(XSD_BASE64_BINARY).unwrap().is_binary());
+
+		let duration = Datatype::from_iri(XSD_DAY_TIME_DURATION).unwrap();
+		assert!(duration.is_temporal() && !duration.expects_timezone());
+
+		let g_year = Datatype::from_iri(XSD_G_YEAR).unwrap();
+		assert!(g_year.expects_timezone() && !g_year.requires_timezone());
+
+		let stamp = Datatype::from_iri(XSD_DATE_TIME_STAMP).unwrap();
+		assert!(stamp.expects_timezone() && stamp.requires_timezone())
+	}
+
+	#[test]
+	fn derivation() {
+		let datatype = |iri| Datatype::from_iri(iri).unwrap();
+
+		let byte = Value::UnsignedByte(1);
+		assert!(byte.is_instance_of(datatype(XSD_UNSIGNED_BYTE)));
+		assert!(byte.is_instance_of(datatype(XSD_NON_NEGATIVE_INTEGER)));
+		assert!(byte.is_instance_of(datatype(XSD_DECIMAL)));
+		assert!(!byte.is_instance_of(datatype(XSD_POSITIVE_INTEGER)));
+		assert!(!byte.is_instance_of(datatype(XSD_BYTE)));
+
+		assert!(datatype(XSD_ID).is_derived_from(datatype(XSD_NAME)));
+		assert!(!datatype(XSD_LANGUAGE).is_derived_from(datatype(XSD_NAME)));
+		assert!(!datatype(XSD_STRING).is_derived_from(datatype(XSD_TOKEN)));
+		assert!(datatype(XSD_DATE_TIME_STAMP).is_derived_from(datatype(XSD_DATE_TIME)));
+		assert!(!datatype(XSD_DAY_TIME_DURATION).is_derived_from(datatype(XSD_YEAR_MONTH_DURATION)));
+		assert!(!datatype(XSD_DOUBLE).is_derived_from(datatype(XSD_DECIMAL)));
+	}
+}
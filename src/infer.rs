@@ -0,0 +1,234 @@
+use crate::{
+	Datatype, DateTimeDatatype, DecimalDatatype, DurationDatatype, IntDatatype, IntegerDatatype,
+	LongDatatype, ShortDatatype, StringDatatype,
+};
+
+/// Datatype inferred for integer lexical forms.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntegerWidth {
+	/// Always `xsd:integer`.
+	#[default]
+	Integer,
+
+	/// `xsd:long` when the value fits in 64 bits, `xsd:integer` otherwise.
+	Long,
+
+	/// Narrowest of `xsd:byte`, `xsd:short`, `xsd:int` and `xsd:long` that
+	/// can hold the value, `xsd:integer` otherwise.
+	Narrowest,
+}
+
+/// Datatype inferred for numbers with a fractional part and no exponent
+/// (`1.5`).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FractionalNumber {
+	/// `xsd:decimal`, which keeps the number exact.
+	#[default]
+	Decimal,
+
+	/// `xsd:double`.
+	Double,
+}
+
+/// Rules used to infer the datatype of a lexical form.
+///
+/// Candidate datatypes are tried in the following order, the first one
+/// accepting the input being selected:
+///
+/// 1. `xsd:boolean` for `true` and `false`, if [`Self::booleans`] is set;
+/// 2. an integer datatype, selected by [`Self::integer_width`];
+/// 3. `xsd:decimal` or `xsd:double` for numbers without exponent, as
+///    selected by [`Self::fractional_number`];
+/// 4. `xsd:double` for numbers with exponent (`1.5E2`), `INF`, `-INF` and
+///    `NaN`;
+/// 5. `xsd:dateTime`, `xsd:date`, `xsd:time` and `xsd:duration`, if
+///    [`Self::temporal`] is set;
+/// 6. `xsd:string` otherwise.
+///
+/// ```
+/// use xsd_types::{InferenceProfile, XSD_BYTE, XSD_DECIMAL, XSD_DOUBLE};
+///
+/// let profile = InferenceProfile::csvw();
+/// assert_eq!(profile.infer("12").iri(), XSD_BYTE);
+/// assert_eq!(profile.infer("1.5").iri(), XSD_DECIMAL);
+/// assert_eq!(profile.infer("1.5E2").iri(), XSD_DOUBLE);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InferenceProfile {
+	/// Datatype of integers.
+	pub integer_width: IntegerWidth,
+
+	/// Datatype of numbers with a fractional part and no exponent.
+	pub fractional_number: FractionalNumber,
+
+	/// Whether `true` and `false` are inferred as `xsd:boolean`.
+	pub booleans: bool,
+
+	/// Whether dates, times and durations are inferred.
+	pub temporal: bool,
+}
+
+impl Default for InferenceProfile {
+	fn default() -> Self {
+		Self::rdb2rdf()
+	}
+}
+
+impl InferenceProfile {
+	/// Profile following the natural mapping of SQL values of the RDB2RDF
+	/// direct mapping and R2RML: integers are `xsd:integer`, exact numbers
+	/// `xsd:decimal`, and approximate numbers `xsd:double`.
+	///
+	/// This is the default profile.
+	///
+	/// See: <https://www.w3.org/TR/r2rml/#natural-mapping>
+	pub fn rdb2rdf() -> Self {
+		Self {
+			integer_width: IntegerWidth::Integer,
+			fractional_number: FractionalNumber::Decimal,
+			booleans: true,
+			temporal: true,
+		}
+	}
+
+	/// Profile for generating CSV on the Web table schemas, which can use
+	/// any built-in datatype: integers get the narrowest of `xsd:byte`,
+	/// `xsd:short`, `xsd:int` and `xsd:long`.
+	///
+	/// See: <https://www.w3.org/TR/tabular-data-model/#datatypes>
+	pub fn csvw() -> Self {
+		Self {
+			integer_width: IntegerWidth::Narrowest,
+			..Self::rdb2rdf()
+		}
+	}
+
+	/// Infers the datatype of the given lexical form.
+	pub fn infer(&self, lexical: &str) -> Datatype {
+		if self.booleans && (lexical == "true" || lexical == "false") {
+			return Datatype::Boolean;
+		}
+
+		if is_valid(integer(IntegerDatatype::Integer), lexical) {
+			return self.integer_datatype(lexical);
+		}
+
+		if is_valid(Datatype::Decimal(DecimalDatatype::Decimal), lexical) {
+			return match self.fractional_number {
+				FractionalNumber::Decimal => Datatype::Decimal(DecimalDatatype::Decimal),
+				FractionalNumber::Double => Datatype::Double,
+			};
+		}
+
+		if is_valid(Datatype::Double, lexical) {
+			return Datatype::Double;
+		}
+
+		if self.temporal {
+			for datatype in [
+				Datatype::DateTime(DateTimeDatatype::DateTime),
+				Datatype::Date,
+				Datatype::Time,
+				Datatype::Duration(DurationDatatype::Duration),
+			] {
+				if is_valid(datatype, lexical) {
+					return datatype;
+				}
+			}
+		}
+
+		Datatype::String(StringDatatype::String)
+	}
+
+	fn integer_datatype(&self, lexical: &str) -> Datatype {
+		let fits_long = lexical.parse::<i64>().is_ok();
+
+		match self.integer_width {
+			IntegerWidth::Integer => integer(IntegerDatatype::Integer),
+			IntegerWidth::Long if fits_long => integer(IntegerDatatype::Long(LongDatatype::Long)),
+			IntegerWidth::Narrowest if fits_long => {
+				let int = if lexical.parse::<i8>().is_ok() {
+					IntDatatype::Short(ShortDatatype::Byte)
+				} else if lexical.parse::<i16>().is_ok() {
+					IntDatatype::Short(ShortDatatype::Short)
+				} else if lexical.parse::<i32>().is_ok() {
+					IntDatatype::Int
+				} else {
+					return integer(IntegerDatatype::Long(LongDatatype::Long));
+				};
+
+				integer(IntegerDatatype::Long(LongDatatype::Int(int)))
+			}
+			_ => integer(IntegerDatatype::Integer),
+		}
+	}
+}
+
+fn integer(datatype: IntegerDatatype) -> Datatype {
+	Datatype::Decimal(DecimalDatatype::Integer(datatype))
+}
+
+fn is_valid(datatype: Datatype, lexical: &str) -> bool {
+	datatype.parse(lexical).is_ok()
+}
+
+impl Datatype {
+	/// Infers the datatype of the given lexical form, using the default
+	/// [`InferenceProfile`].
+	///
+	/// ```
+	/// use xsd_types::{Datatype, XSD_DATE, XSD_DECIMAL, XSD_DOUBLE, XSD_INTEGER, XSD_STRING};
+	///
+	/// assert_eq!(Datatype::infer("42").iri(), XSD_INTEGER);
+	/// assert_eq!(Datatype::infer("1.5").iri(), XSD_DECIMAL);
+	/// assert_eq!(Datatype::infer("1.5E2").iri(), XSD_DOUBLE);
+	/// assert_eq!(Datatype::infer("2024-05-17").iri(), XSD_DATE);
+	/// assert_eq!(Datatype::infer("hello").iri(), XSD_STRING);
+	/// ```
+	pub fn infer(lexical: &str) -> Self {
+		InferenceProfile::default().infer(lexical)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn profiles() {
+		let name = |datatype: Datatype| {
+			datatype
+				.iri()
+				.as_str()
+				.rsplit('#')
+				.next()
+				.unwrap()
+				.to_owned()
+		};
+
+		let csvw = InferenceProfile::csvw();
+		assert_eq!(name(csvw.infer("-128")), "byte");
+		assert_eq!(name(csvw.infer("128")), "short");
+		assert_eq!(name(csvw.infer("+40000")), "int");
+		assert_eq!(name(csvw.infer("3000000000")), "long");
+		assert_eq!(name(csvw.infer("10000000000000000000")), "integer");
+
+		let profile = InferenceProfile {
+			integer_width: IntegerWidth::Long,
+			fractional_number: FractionalNumber::Double,
+			booleans: false,
+			temporal: false,
+		};
+		assert_eq!(name(profile.infer("12")), "long");
+		assert_eq!(name(profile.infer("1.5")), "double");
+		assert_eq!(name(profile.infer("true")), "string");
+		assert_eq!(name(profile.infer("P1D")), "string");
+
+		let default = InferenceProfile::default();
+		assert_eq!(name(default.infer("true")), "boolean");
+		assert_eq!(name(default.infer("-INF")), "double");
+		assert_eq!(name(default.infer("10:00:00Z")), "time");
+		assert_eq!(name(default.infer("P1D")), "duration");
+		assert_eq!(name(default.infer("")), "string");
+	}
+}
@@ -0,0 +1,172 @@
+//! Lenient date/time parsing.
+//!
+//! Real-world data frequently deviates from the XSD lexical spaces in small
+//! and predictable ways. The functions of this module accept the most
+//! common deviations, rewrite the input into a valid lexical form, and
+//! report the list of applied [`Fix`]es. This is meant for data-cleaning
+//! pipelines that prefer to accept and normalize rather than reject.
+//!
+//! ```
+//! use xsd_types::lenient::{self, Fix};
+//!
+//! let parsed = lenient::parse_date_time("2024-01-01T10:00+0200").unwrap();
+//! assert_eq!(parsed.lexical_form, "2024-01-01T10:00:00+02:00");
+//! assert_eq!(parsed.fixes, [Fix::MissingSeconds, Fix::OffsetWithoutColon]);
+//! ```
+use std::fmt;
+
+use crate::{DateTime, DateTimeStamp, ParseError, ParseXsd, Time};
+
+/// Deviation from the XSD lexical space fixed by the lenient parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fix {
+	/// The seconds were missing (`10:00`), and have been set to zero
+	/// (`10:00:00`).
+	MissingSeconds,
+
+	/// A comma was used as decimal separator in the seconds (`10:00:00,5`),
+	/// and has been replaced by a dot (`10:00:00.5`).
+	CommaDecimalSeparator,
+
+	/// The timezone offset had no colon between hours and minutes
+	/// (`+0200`), and one has been inserted (`+02:00`).
+	OffsetWithoutColon,
+}
+
+impl fmt::Display for Fix {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::MissingSeconds => write!(f, "missing seconds"),
+			Self::CommaDecimalSeparator => write!(f, "comma decimal separator"),
+			Self::OffsetWithoutColon => write!(f, "timezone offset without colon"),
+		}
+	}
+}
+
+/// Value parsed by the lenient parser.
+#[derive(Debug, Clone)]
+pub struct Lenient<T> {
+	/// Parsed value.
+	pub value: T,
+
+	/// Normalized lexical form the value was parsed from.
+	pub lexical_form: String,
+
+	/// Fixes applied to the input to obtain `lexical_form`, in input order.
+	///
+	/// This is empty if the input was already a valid lexical form.
+	pub fixes: Vec<Fix>,
+}
+
+impl<T> Lenient<T> {
+	/// Checks if the input was already a valid lexical form.
+	pub fn is_exact(&self) -> bool {
+		self.fixes.is_empty()
+	}
+}
+
+/// Parses an `xsd:dateTime` value, accepting common deviations.
+pub fn parse_date_time(s: &str) -> Result<Lenient<DateTime>, ParseError> {
+	let (lexical_form, fixes) = normalize_date_time(s);
+	parse(lexical_form, fixes)
+}
+
+/// Parses an `xsd:dateTimeStamp` value, accepting common deviations.
+pub fn parse_date_time_stamp(s: &str) -> Result<Lenient<DateTimeStamp>, ParseError> {
+	let (lexical_form, fixes) = normalize_date_time(s);
+	parse(lexical_form, fixes)
+}
+
+/// Parses an `xsd:time` value, accepting common deviations.
+pub fn parse_time(s: &str) -> Result<Lenient<Time>, ParseError> {
+	let mut fixes = Vec::new();
+	let lexical_form = normalize_time(s, &mut fixes);
+	parse(lexical_form, fixes)
+}
+
+fn parse<T: ParseXsd>(lexical_form: String, fixes: Vec<Fix>) -> Result<Lenient<T>, ParseError> {
+	match T::parse_xsd(&lexical_form).map_err(|_| ()) {
+		Ok(value) => Ok(Lenient {
+			value,
+			lexical_form,
+			fixes,
+		}),
+		Err(()) if lexical_form.is_empty() => Err(ParseError::EmptyLexicalForm),
+		Err(()) => Err(ParseError::Invalid),
+	}
+}
+
+fn normalize_date_time(s: &str) -> (String, Vec<Fix>) {
+	let mut fixes = Vec::new();
+	let lexical_form = match s.split_once('T') {
+		Some((date, time)) => format!("{date}T{}", normalize_time(time, &mut fixes)),
+		None => s.to_owned(),
+	};
+
+	(lexical_form, fixes)
+}
+
+/// Normalizes a time followed by an optional timezone.
+fn normalize_time(s: &str, fixes: &mut Vec<Fix>) -> String {
+	let (time, timezone) = match s.find(['Z', '+', '-']) {
+		Some(i) => s.split_at(i),
+		None => (s, ""),
+	};
+
+	let mut result = String::with_capacity(s.len() + 3);
+
+	let bytes = time.as_bytes();
+	if bytes.len() == 5 && bytes[2] == b':' && is_digits(&bytes[..2]) && is_digits(&bytes[3..]) {
+		result.push_str(time);
+		result.push_str(":00");
+		fixes.push(Fix::MissingSeconds)
+	} else if time.contains(',') {
+		result.push_str(&time.replace(',', "."));
+		fixes.push(Fix::CommaDecimalSeparator)
+	} else {
+		result.push_str(time)
+	}
+
+	let bytes = timezone.as_bytes();
+	if bytes.len() == 5 && is_digits(&bytes[1..]) {
+		result.push_str(&timezone[..3]);
+		result.push(':');
+		result.push_str(&timezone[3..]);
+		fixes.push(Fix::OffsetWithoutColon)
+	} else {
+		result.push_str(timezone)
+	}
+
+	result
+}
+
+fn is_digits(bytes: &[u8]) -> bool {
+	bytes.iter().all(u8::is_ascii_digit)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn fixes() {
+		let parsed = parse_date_time("2024-01-01T10:00:00,5Z").unwrap();
+		assert_eq!(parsed.lexical_form, "2024-01-01T10:00:00.5Z");
+		assert_eq!(parsed.fixes, [Fix::CommaDecimalSeparator]);
+		assert_eq!(parsed.value.to_string(), "2024-01-01T10:00:00.5Z");
+
+		let parsed = parse_date_time("2024-01-01T10:00:00-0530").unwrap();
+		assert_eq!(parsed.fixes, [Fix::OffsetWithoutColon]);
+		assert_eq!(parsed.value.to_string(), "2024-01-01T10:00:00-05:30");
+
+		let parsed = parse_date_time_stamp("2024-01-01T10:00Z").unwrap();
+		assert_eq!(parsed.fixes, [Fix::MissingSeconds]);
+
+		let parsed = parse_time("10:00").unwrap();
+		assert_eq!(parsed.lexical_form, "10:00:00");
+
+		assert!(parse_date_time("2024-01-01T10:00:00Z").unwrap().is_exact());
+		assert!(parse_date_time("2024-01-01T10").is_err());
+		assert!(parse_date_time_stamp("2024-01-01T10:00").is_err())
+	}
+}
@@ -1,19 +1,19 @@
-use iref::{InvalidUri, Uri, UriBuf};
+use crate::{AnyUri, AnyUriBuf, InvalidAnyUri};
 
 use super::{Lexical, LexicalFormOf};
 
-impl Lexical for Uri {
-	type Error = InvalidUri<String>;
+impl Lexical for AnyUri {
+	type Error = InvalidAnyUri<String>;
 
 	fn parse(value: &str) -> Result<&Self, Self::Error> {
-		Uri::new(value).map_err(|_| InvalidUri(value.to_owned()))
+		Self::new(value).map_err(|_| InvalidAnyUri(value.to_owned()))
 	}
 }
 
-impl LexicalFormOf<UriBuf> for Uri {
+impl LexicalFormOf<AnyUriBuf> for AnyUri {
 	type ValueError = std::convert::Infallible;
 
-	fn try_as_value(&self) -> Result<UriBuf, Self::ValueError> {
+	fn try_as_value(&self) -> Result<AnyUriBuf, Self::ValueError> {
 		Ok(self.to_owned())
 	}
 }
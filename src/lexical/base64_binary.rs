@@ -75,7 +75,7 @@ impl PartialOrd for Base64Binary {
 
 impl Default for Base64BinaryBuf {
 	fn default() -> Self {
-		unsafe { Self::new_unchecked(Vec::new()) }
+		unchecked!(Self, Vec::new())
 	}
 }
 
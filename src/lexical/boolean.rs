@@ -36,7 +36,32 @@ lexical_form! {
 
 impl Boolean {
 	pub fn value(&self) -> crate::Boolean {
-		crate::Boolean(matches!(&self.0, b"true" | b"1"))
+		crate::Boolean(self.as_bool())
+	}
+
+	/// Returns the boolean value as a Rust `bool`.
+	pub fn as_bool(&self) -> bool {
+		matches!(&self.0, b"true" | b"1")
+	}
+
+	/// Checks if this boolean uses the numeric form (`0` or `1`) rather than
+	/// the literal form (`false` or `true`).
+	pub fn is_numeric(&self) -> bool {
+		matches!(&self.0, b"0" | b"1")
+	}
+
+	/// Checks if this boolean is in canonical form (`true` or `false`).
+	pub fn is_canonical(&self) -> bool {
+		!self.is_numeric()
+	}
+
+	/// Returns the canonical form of this boolean.
+	pub fn canonical(&self) -> &Self {
+		if self.as_bool() {
+			unchecked!(Self, b"true")
+		} else {
+			unchecked!(Self, b"false")
+		}
 	}
 }
 
@@ -66,12 +91,24 @@ impl PartialOrd for Boolean {
 	}
 }
 
+impl PartialOrd for BooleanBuf {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for BooleanBuf {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.as_boolean().cmp(other.as_boolean())
+	}
+}
+
 impl From<bool> for BooleanBuf {
 	fn from(b: bool) -> Self {
 		if b {
-			unsafe { BooleanBuf::new_unchecked(vec![b't', b'r', b'u', b'e']) }
+			unchecked!(BooleanBuf, vec![b't', b'r', b'u', b'e'])
 		} else {
-			unsafe { BooleanBuf::new_unchecked(vec![b'f', b'a', b'l', b's', b'e']) }
+			unchecked!(BooleanBuf, vec![b'f', b'a', b'l', b's', b'e'])
 		}
 	}
 }
@@ -103,3 +140,28 @@ impl From<BooleanBuf> for bool {
 fn check_bytes(s: &[u8]) -> bool {
 	matches!(s, b"true" | b"false" | b"0" | b"1")
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn forms() {
+		for (input, value, numeric) in [
+			("true", true, false),
+			("false", false, false),
+			("1", true, true),
+			("0", false, true),
+		] {
+			let b = Boolean::new(input).unwrap();
+			assert_eq!(b.as_bool(), value);
+			assert_eq!(b.is_numeric(), numeric);
+			assert_eq!(b.as_str(), input);
+			assert_eq!(b.to_owned().as_str(), input);
+			assert!(b.canonical().is_canonical());
+			assert_eq!(b, b.canonical())
+		}
+
+		assert!(Boolean::new("TRUE").is_err())
+	}
+}
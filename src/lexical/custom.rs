@@ -0,0 +1,262 @@
+//! Lexical types for datatypes defined outside of this crate.
+
+/// Casts a string slice into a lexical type generated by [`impl_lexical!`].
+///
+/// Not part of the public API: `cast` must be a plain pointer cast into a
+/// `#[repr(transparent)]` wrapper around `str`, as generated by the macro.
+#[doc(hidden)]
+#[allow(unsafe_code)]
+pub fn custom_lexical_cast<T: ?Sized>(value: &str, cast: fn(*const str) -> *const T) -> &T {
+	// SAFETY: `cast` preserves the address and metadata of `value`, and `T`
+	// has the same layout as `str`.
+	unsafe { &*cast(value) }
+}
+
+/// Defines a lexical type, and its owned counterpart, validated by the given
+/// function.
+///
+/// This generates the boilerplate required to plug a custom datatype into
+/// [`ParseXsd`](crate::ParseXsd): an unsized `$ty(str)` lexical type
+/// implementing [`Lexical`](crate::lexical::Lexical), an owned `$buffer_ty`
+/// type, and a `$error_ty<T = String>(pub T)` error type holding the invalid
+/// input. Only the conversion into the value, through
+/// [`LexicalFormOf`](crate::lexical::LexicalFormOf), is left to implement.
+///
+/// The `check` function takes a `&str` and returns `true` if it is a valid
+/// lexical form. The generated code has no `unsafe` block, so the macro can be
+/// used in `#![forbid(unsafe_code)]` crates.
+///
+/// ```
+/// use xsd_types::{lexical::LexicalFormOf, ParseXsd};
+///
+/// fn is_hex_color(s: &str) -> bool {
+///     s.len() == 7 && s.starts_with('#') && s[1..].bytes().all(|c| c.is_ascii_hexdigit())
+/// }
+///
+/// xsd_types::impl_lexical! {
+///     /// Lexical form of a color, such as `#ff8000`.
+///     pub struct HexColor, HexColorBuf, InvalidHexColor: is_hex_color;
+/// }
+///
+/// pub struct Color(u8, u8, u8);
+///
+/// impl LexicalFormOf<Color> for HexColor {
+///     type ValueError = std::convert::Infallible;
+///
+///     fn try_as_value(&self) -> Result<Color, Self::ValueError> {
+///         let component = |i: usize| u8::from_str_radix(&self.as_str()[i..(i + 2)], 16).unwrap();
+///         Ok(Color(component(1), component(3), component(5)))
+///     }
+/// }
+///
+/// impl ParseXsd for Color {
+///     type LexicalForm = HexColor;
+/// }
+///
+/// let Color(r, g, b) = Color::parse_xsd("#ff8000").ok().unwrap();
+/// assert_eq!((r, g, b), (255, 128, 0));
+/// assert!(Color::parse_xsd("orange").is_err());
+/// ```
+#[macro_export]
+macro_rules! impl_lexical {
+	{
+		$(#[$meta:meta])*
+		$vis:vis struct $ty:ident, $buffer_ty:ident, $error_ty:ident: $check:path;
+	} => {
+		$(#[$meta])*
+		#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+		#[repr(transparent)]
+		$vis struct $ty(str);
+
+		/// Owned lexical form.
+		#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+		$vis struct $buffer_ty(String);
+
+		/// Invalid lexical form.
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+		$vis struct $error_ty<T = String>(pub T);
+
+		impl<T: ::std::fmt::Display> ::std::fmt::Display for $error_ty<T> {
+			fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+				write!(f, concat!("invalid `", stringify!($ty), "` lexical form `{}`"), self.0)
+			}
+		}
+
+		impl<T: ::std::fmt::Debug + ::std::fmt::Display> ::std::error::Error for $error_ty<T> {}
+
+		impl $ty {
+			/// Checks that the input is a valid lexical form.
+			pub fn new<T: ?Sized + AsRef<str>>(value: &T) -> Result<&Self, $error_ty<&T>> {
+				if $check(value.as_ref()) {
+					Ok(Self::cast(value.as_ref()))
+				} else {
+					Err($error_ty(value))
+				}
+			}
+
+			fn cast(value: &str) -> &Self {
+				$crate::lexical::custom_lexical_cast(value, |value| value as *const Self)
+			}
+
+			pub fn as_str(&self) -> &str {
+				&self.0
+			}
+		}
+
+		impl $crate::lexical::Lexical for $ty {
+			type Error = $error_ty;
+
+			fn parse(value: &str) -> Result<&Self, Self::Error> {
+				Self::new(value).map_err(|_| $error_ty(value.to_owned()))
+			}
+		}
+
+		impl ::std::ops::Deref for $ty {
+			type Target = str;
+
+			fn deref(&self) -> &str {
+				&self.0
+			}
+		}
+
+		impl AsRef<str> for $ty {
+			fn as_ref(&self) -> &str {
+				&self.0
+			}
+		}
+
+		impl ::std::borrow::ToOwned for $ty {
+			type Owned = $buffer_ty;
+
+			fn to_owned(&self) -> $buffer_ty {
+				$buffer_ty(self.0.to_owned())
+			}
+		}
+
+		impl ::std::fmt::Display for $ty {
+			fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+				::std::fmt::Display::fmt(&self.0, f)
+			}
+		}
+
+		impl ::std::fmt::Debug for $ty {
+			fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+				::std::fmt::Debug::fmt(&self.0, f)
+			}
+		}
+
+		impl $buffer_ty {
+			/// Checks that the input is a valid lexical form.
+			pub fn new(value: String) -> Result<Self, $error_ty> {
+				if $check(&value) {
+					Ok(Self(value))
+				} else {
+					Err($error_ty(value))
+				}
+			}
+
+			pub fn as_lexical(&self) -> &$ty {
+				<$ty>::cast(&self.0)
+			}
+
+			pub fn into_string(self) -> String {
+				self.0
+			}
+		}
+
+		impl ::std::ops::Deref for $buffer_ty {
+			type Target = $ty;
+
+			fn deref(&self) -> &$ty {
+				self.as_lexical()
+			}
+		}
+
+		impl AsRef<$ty> for $buffer_ty {
+			fn as_ref(&self) -> &$ty {
+				self.as_lexical()
+			}
+		}
+
+		impl AsRef<str> for $buffer_ty {
+			fn as_ref(&self) -> &str {
+				&self.0
+			}
+		}
+
+		impl ::std::borrow::Borrow<$ty> for $buffer_ty {
+			fn borrow(&self) -> &$ty {
+				self.as_lexical()
+			}
+		}
+
+		impl ::std::str::FromStr for $buffer_ty {
+			type Err = $error_ty;
+
+			fn from_str(s: &str) -> Result<Self, $error_ty> {
+				Self::new(s.to_owned())
+			}
+		}
+
+		impl ::std::fmt::Display for $buffer_ty {
+			fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+				::std::fmt::Display::fmt(&self.0, f)
+			}
+		}
+
+		impl ::std::fmt::Debug for $buffer_ty {
+			fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+				::std::fmt::Debug::fmt(&self.0, f)
+			}
+		}
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{lexical::LexicalFormOf, ParseXsd, ParseXsdError};
+
+	#[allow(dead_code)]
+	#[forbid(unsafe_code)]
+	mod even {
+		fn is_even(s: &str) -> bool {
+			s.parse::<u32>().is_ok_and(|n| n % 2 == 0)
+		}
+
+		crate::impl_lexical! {
+			pub struct Even, EvenBuf, InvalidEven: is_even;
+		}
+	}
+
+	use even::*;
+
+	struct Half(u32);
+
+	impl LexicalFormOf<Half> for Even {
+		type ValueError = std::convert::Infallible;
+
+		fn try_as_value(&self) -> Result<Half, Self::ValueError> {
+			Ok(Half(self.as_str().parse::<u32>().unwrap() / 2))
+		}
+	}
+
+	impl ParseXsd for Half {
+		type LexicalForm = Even;
+	}
+
+	#[test]
+	fn custom_datatype() {
+		assert_eq!(Half::parse_xsd("42").ok().map(|h| h.0), Some(21));
+		assert!(matches!(
+			Half::parse_xsd("7"),
+			Err(ParseXsdError::InvalidLexicalForm(InvalidEven(s))) if s == "7"
+		));
+
+		let buffer: EvenBuf = "8".parse().unwrap();
+		assert_eq!(buffer.as_lexical(), Even::new("8").unwrap());
+		assert_eq!(
+			Even::new("1").unwrap_err().to_string(),
+			"invalid `Even` lexical form `1`"
+		);
+	}
+}
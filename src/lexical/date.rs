@@ -2,7 +2,7 @@ use static_regular_grammar::RegularGrammar;
 
 use crate::{lexical::parse_timezone, utils::byte_index_of};
 
-use super::{Lexical, LexicalFormOf};
+use super::{scan, Grammar, GrammarToken, Lexical, LexicalFormOf, TokenClass};
 
 /// Date.
 ///
@@ -11,10 +11,11 @@ use super::{Lexical, LexicalFormOf};
 ///
 /// year = [ "-" ] year-number
 ///
-/// year-number = *3DIGIT NZDIGIT
-///             / *2DIGIT NZDIGIT DIGIT
-///             / *1DIGIT NZDIGIT 2DIGIT
+/// year-number = "000" NZDIGIT
+///             / "00" NZDIGIT DIGIT
+///             / "0" NZDIGIT 2DIGIT
 ///             / NZDIGIT 3*DIGIT
+///             / "0000"
 ///
 /// month = "0" NZDIGIT
 ///       / "1" ( "0" / "1" / "2" )
@@ -34,6 +35,40 @@ use super::{Lexical, LexicalFormOf};
 #[grammar(sized(DateBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
 pub struct Date(str);
 
+impl Grammar for Date {
+	const ABNF: &'static str = r#"date = year "-" month "-" day [timezone]
+
+year = [ "-" ] year-number
+
+year-number = "000" NZDIGIT
+            / "00" NZDIGIT DIGIT
+            / "0" NZDIGIT 2DIGIT
+            / NZDIGIT 3*DIGIT
+            / "0000"
+
+month = "0" NZDIGIT
+      / "1" ( "0" / "1" / "2" )
+
+day = "0" NZDIGIT
+    / ("1" / "2") DIGIT
+    / "3" ("0" / "1")
+
+minute = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
+
+timezone = ("+" / "-") ((("0" DIGIT / "1" ("0" / "1" / "2" / "3")) ":" minute) / "14:00")
+         / %s"Z"
+
+NZDIGIT = "1" / "2" / "3" / "4" / "5" / "6" / "7" / "8" / "9"
+"#;
+
+	const TOKENS: &'static [GrammarToken] = &[
+		GrammarToken::new("year", TokenClass::Number),
+		GrammarToken::new("month", TokenClass::Number),
+		GrammarToken::new("day", TokenClass::Number),
+		GrammarToken::new("timezone", TokenClass::Timezone),
+	];
+}
+
 impl Date {
 	pub fn parts(&self) -> Parts {
 		let year_end = byte_index_of(self.0.as_bytes(), 4, b'-').unwrap();
@@ -51,13 +86,24 @@ impl Date {
 			},
 		}
 	}
+
+	/// Checks if the timezone is written with the `Z` designator. See
+	/// [`DateTime::timezone_is_z`](super::DateTime::timezone_is_z).
+	pub fn timezone_is_z(&self) -> Option<bool> {
+		self.parts().timezone.map(|tz| tz == "Z")
+	}
 }
 
 impl Lexical for Date {
 	type Error = InvalidDate<String>;
 
 	fn parse(value: &str) -> Result<&Self, Self::Error> {
-		Self::new(value).map_err(|_| InvalidDate(value.to_owned()))
+		if scan::is_date(value.as_bytes()) {
+			// SAFETY: the scanner accepts the same language as the grammar.
+			Ok(unchecked!(Self, value))
+		} else {
+			Err(InvalidDate(value.to_owned()))
+		}
 	}
 }
 
@@ -88,8 +134,22 @@ impl<'a> Parts<'a> {
 	}
 
 	fn to_date(&self) -> Result<crate::Date, crate::InvalidDateValue> {
+		self.to_date_with(crate::YearConvention::default())
+	}
+
+	pub(crate) fn to_date_with(
+		&self,
+		convention: crate::YearConvention,
+	) -> Result<crate::Date, crate::InvalidDateValue> {
+		let year = self
+			.year
+			.parse()
+			.ok()
+			.and_then(|year| convention.to_xsd11(year))
+			.ok_or(crate::InvalidDateValue)?;
+
 		let date = chrono::NaiveDate::from_ymd_opt(
-			self.year.parse().unwrap(),
+			year,
 			self.month.parse().unwrap(),
 			self.day.parse().unwrap(),
 		)
@@ -128,4 +188,11 @@ mod tests {
 			assert_eq!(value.to_string().as_str(), input)
 		}
 	}
+
+	#[test]
+	fn short_years() {
+		for input in ["1-05-31", "201-05-31", "-201-05-31Z"] {
+			assert!(Date::new(input).is_err())
+		}
+	}
 }
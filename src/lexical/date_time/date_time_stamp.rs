@@ -1,8 +1,10 @@
 use static_regular_grammar::RegularGrammar;
 
+use crate::lexical::{Grammar, GrammarToken, TokenClass};
+
 use crate::{utils::byte_index_of, InvalidDateTimeStampValue};
 
-use super::{Lexical, LexicalFormOf};
+use super::{super::scan, parse_seconds_decimal, parse_timezone, Lexical, LexicalFormOf};
 
 /// Date and time with required timezone offset.
 ///
@@ -14,10 +16,11 @@ use super::{Lexical, LexicalFormOf};
 ///
 /// year = [ "-" ] year-number
 ///
-/// year-number = *3DIGIT NZDIGIT
-///             / *2DIGIT NZDIGIT DIGIT
-///             / *1DIGIT NZDIGIT 2DIGIT
+/// year-number = "000" NZDIGIT
+///             / "00" NZDIGIT DIGIT
+///             / "0" NZDIGIT 2DIGIT
 ///             / NZDIGIT 3*DIGIT
+///             / "0000"
 ///
 /// month = "0" NZDIGIT
 ///       / "1" ( "0" / "1" / "2" )
@@ -45,6 +48,54 @@ use super::{Lexical, LexicalFormOf};
 #[grammar(sized(DateTimeStampBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
 pub struct DateTimeStamp(str);
 
+impl Grammar for DateTimeStamp {
+	const ABNF: &'static str = r#"date-time = year "-" month "-" day %s"T" time timezone
+
+time = hour ":" minute ":" second ["." fraction]
+     / "24:00:00" ["." 1*"0"]
+
+year = [ "-" ] year-number
+
+year-number = "000" NZDIGIT
+            / "00" NZDIGIT DIGIT
+            / "0" NZDIGIT 2DIGIT
+            / NZDIGIT 3*DIGIT
+            / "0000"
+
+month = "0" NZDIGIT
+      / "1" ( "0" / "1" / "2" )
+
+day = "0" NZDIGIT
+    / ("1" / "2") DIGIT
+    / "3" ("0" / "1")
+
+hour = ("0" / "1") DIGIT
+     / "2" ("0" / "1" / "2" / "3")
+
+minute = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
+
+second = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
+
+fraction = 1*DIGIT
+
+timezone = ("+" / "-") ((("0" DIGIT / "1" ("0" / "1" / "2" / "3")) ":" minute) / "14:00")
+         / %s"Z"
+
+NZDIGIT = "1" / "2" / "3" / "4" / "5" / "6" / "7" / "8" / "9"
+"#;
+
+	const TOKENS: &'static [GrammarToken] = &[
+		GrammarToken::new("year", TokenClass::Number),
+		GrammarToken::new("month", TokenClass::Number),
+		GrammarToken::new("day", TokenClass::Number),
+		GrammarToken::new("hour", TokenClass::Number),
+		GrammarToken::new("minute", TokenClass::Number),
+		GrammarToken::new("second", TokenClass::Number),
+		GrammarToken::new("fraction", TokenClass::Number),
+		GrammarToken::new("timezone", TokenClass::Timezone),
+	];
+}
+
 impl DateTimeStamp {
 	pub fn parts(&self) -> Parts {
 		let year_end = byte_index_of(self.0.as_bytes(), 4, b'-').unwrap();
@@ -65,13 +116,24 @@ impl DateTimeStamp {
 			timezone: &self.0[second_end..],
 		}
 	}
+
+	/// Checks if the timezone is written with the `Z` designator. See
+	/// [`DateTime::timezone_is_z`](super::DateTime::timezone_is_z).
+	pub fn timezone_is_z(&self) -> bool {
+		self.parts().timezone == "Z"
+	}
 }
 
 impl Lexical for DateTimeStamp {
 	type Error = InvalidDateTimeStamp<String>;
 
 	fn parse(value: &str) -> Result<&Self, Self::Error> {
-		Self::new(value).map_err(|_| InvalidDateTimeStamp(value.to_owned()))
+		if scan::is_date_time_stamp(value.as_bytes()) {
+			// SAFETY: the scanner accepts the same language as the grammar.
+			Ok(unchecked!(Self, value))
+		} else {
+			Err(InvalidDateTimeStamp(value.to_owned()))
+		}
 	}
 }
 
@@ -116,8 +178,22 @@ impl<'a> Parts<'a> {
 	}
 
 	fn to_datetime(&self) -> Result<crate::DateTimeStamp, crate::InvalidDateTimeStampValue> {
+		self.to_datetime_with(crate::YearConvention::default())
+	}
+
+	pub(crate) fn to_datetime_with(
+		&self,
+		convention: crate::YearConvention,
+	) -> Result<crate::DateTimeStamp, crate::InvalidDateTimeStampValue> {
+		let year = self
+			.year
+			.parse()
+			.ok()
+			.and_then(|year| convention.to_xsd11(year))
+			.ok_or(crate::InvalidDateTimeStampValue)?;
+
 		let date = chrono::NaiveDate::from_ymd_opt(
-			self.year.parse().unwrap(),
+			year,
 			self.month.parse().unwrap(),
 			self.day.parse().unwrap(),
 		)
@@ -142,38 +218,6 @@ impl<'a> Parts<'a> {
 	}
 }
 
-/// Parses a decimal number representing seconds and returns the represented
-/// number of seconds and nanoseconds.
-pub(crate) fn parse_seconds_decimal(decimal: &str) -> (u32, u32) {
-	match decimal.split_once('.') {
-		Some((integer, fract)) => {
-			let seconds = integer.parse().unwrap();
-			let fract = if fract.len() > 9 { &fract[..9] } else { fract };
-			let nano_seconds = fract.parse::<u32>().unwrap() * 10u32.pow(9 - fract.len() as u32);
-
-			(seconds, nano_seconds)
-		}
-		None => (decimal.parse().unwrap(), 0),
-	}
-}
-
-pub(crate) fn parse_timezone(tz: &str) -> chrono::FixedOffset {
-	const HOUR: i32 = 3600;
-	const MINUTE: i32 = 60;
-
-	match tz {
-		"Z" => chrono::FixedOffset::east_opt(0).unwrap(),
-		"14:00" => chrono::FixedOffset::east_opt(14 * HOUR).unwrap(),
-		n => {
-			let (h, m) = n.split_once(':').unwrap();
-			chrono::FixedOffset::east_opt(
-				h.parse::<i32>().unwrap() * HOUR + m.parse::<i32>().unwrap() * MINUTE,
-			)
-			.unwrap()
-		}
-	}
-}
-
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -211,4 +255,13 @@ mod tests {
 			assert_eq!(value.to_string().as_str(), input)
 		}
 	}
+	#[test]
+	fn timezone_is_z() {
+		assert!(DateTimeStamp::new("2002-05-31T13:07:12Z")
+			.unwrap()
+			.timezone_is_z());
+		assert!(!DateTimeStamp::new("2002-05-31T13:07:12-00:00")
+			.unwrap()
+			.timezone_is_z())
+	}
 }
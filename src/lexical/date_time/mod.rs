@@ -1,8 +1,10 @@
 use static_regular_grammar::RegularGrammar;
 
+use crate::lexical::{Grammar, GrammarToken, TokenClass};
+
 use crate::{utils::byte_index_of, InvalidDateTimeValue};
 
-use super::{Lexical, LexicalFormOf};
+use super::{scan, Lexical, LexicalFormOf};
 
 pub mod date_time_stamp;
 pub use date_time_stamp::{DateTimeStamp, DateTimeStampBuf, InvalidDateTimeStamp};
@@ -17,10 +19,11 @@ pub use date_time_stamp::{DateTimeStamp, DateTimeStampBuf, InvalidDateTimeStamp}
 ///
 /// year = [ "-" ] year-number
 ///
-/// year-number = *3DIGIT NZDIGIT
-///             / *2DIGIT NZDIGIT DIGIT
-///             / *1DIGIT NZDIGIT 2DIGIT
+/// year-number = "000" NZDIGIT
+///             / "00" NZDIGIT DIGIT
+///             / "0" NZDIGIT 2DIGIT
 ///             / NZDIGIT 3*DIGIT
+///             / "0000"
 ///
 /// month = "0" NZDIGIT
 ///       / "1" ( "0" / "1" / "2" )
@@ -48,6 +51,54 @@ pub use date_time_stamp::{DateTimeStamp, DateTimeStampBuf, InvalidDateTimeStamp}
 #[grammar(sized(DateTimeBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
 pub struct DateTime(str);
 
+impl Grammar for DateTime {
+	const ABNF: &'static str = r#"date-time = year "-" month "-" day %s"T" time [timezone]
+
+time = hour ":" minute ":" second ["." fraction]
+     / "24:00:00" ["." 1*"0"]
+
+year = [ "-" ] year-number
+
+year-number = "000" NZDIGIT
+            / "00" NZDIGIT DIGIT
+            / "0" NZDIGIT 2DIGIT
+            / NZDIGIT 3*DIGIT
+            / "0000"
+
+month = "0" NZDIGIT
+      / "1" ( "0" / "1" / "2" )
+
+day = "0" NZDIGIT
+    / ("1" / "2") DIGIT
+    / "3" ("0" / "1")
+
+hour = ("0" / "1") DIGIT
+     / "2" ("0" / "1" / "2" / "3")
+
+minute = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
+
+second = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
+
+fraction = 1*DIGIT
+
+timezone = ("+" / "-") ((("0" DIGIT / "1" ("0" / "1" / "2" / "3")) ":" minute) / "14:00")
+         / %s"Z"
+
+NZDIGIT = "1" / "2" / "3" / "4" / "5" / "6" / "7" / "8" / "9"
+"#;
+
+	const TOKENS: &'static [GrammarToken] = &[
+		GrammarToken::new("year", TokenClass::Number),
+		GrammarToken::new("month", TokenClass::Number),
+		GrammarToken::new("day", TokenClass::Number),
+		GrammarToken::new("hour", TokenClass::Number),
+		GrammarToken::new("minute", TokenClass::Number),
+		GrammarToken::new("second", TokenClass::Number),
+		GrammarToken::new("fraction", TokenClass::Number),
+		GrammarToken::new("timezone", TokenClass::Timezone),
+	];
+}
+
 impl DateTime {
 	pub fn parts(&self) -> Parts {
 		let year_end = byte_index_of(self.0.as_bytes(), 4, b'-').unwrap();
@@ -72,13 +123,35 @@ impl DateTime {
 			},
 		}
 	}
+
+	/// Checks if the timezone is written with the `Z` designator, rather than
+	/// as a numeric offset (such as `+00:00`).
+	///
+	/// Returns `None` if there is no timezone. This distinction is not kept
+	/// by the value, which always writes a zero offset as `Z`.
+	///
+	/// ```
+	/// use xsd_types::lexical::DateTime;
+	///
+	/// assert_eq!(DateTime::new("2024-05-17T10:00:00Z").unwrap().timezone_is_z(), Some(true));
+	/// assert_eq!(DateTime::new("2024-05-17T10:00:00+00:00").unwrap().timezone_is_z(), Some(false));
+	/// assert_eq!(DateTime::new("2024-05-17T10:00:00").unwrap().timezone_is_z(), None);
+	/// ```
+	pub fn timezone_is_z(&self) -> Option<bool> {
+		self.parts().timezone.map(|tz| tz == "Z")
+	}
 }
 
 impl Lexical for DateTime {
 	type Error = InvalidDateTime<String>;
 
 	fn parse(value: &str) -> Result<&Self, Self::Error> {
-		Self::new(value).map_err(|_| InvalidDateTime(value.to_owned()))
+		if scan::is_date_time(value.as_bytes()) {
+			// SAFETY: the scanner accepts the same language as the grammar.
+			Ok(unchecked!(Self, value))
+		} else {
+			Err(InvalidDateTime(value.to_owned()))
+		}
 	}
 }
 
@@ -122,9 +195,36 @@ impl<'a> Parts<'a> {
 		}
 	}
 
+	/// Splits the given date/time lexical representation into its parts.
+	///
+	/// Returns an error if the input is not a valid XSD date/time lexical
+	/// representation.
+	pub fn parse(input: &'a str) -> Result<Self, InvalidDateTime<&'a str>> {
+		if scan::is_date_time(input.as_bytes()) {
+			// SAFETY: the scanner accepts the same language as the grammar.
+			Ok(unchecked!(DateTime, input).parts())
+		} else {
+			Err(InvalidDateTime(input))
+		}
+	}
+
 	fn to_datetime(&self) -> Result<crate::DateTime, crate::InvalidDateTimeValue> {
+		self.to_datetime_with(crate::YearConvention::default())
+	}
+
+	pub(crate) fn to_datetime_with(
+		&self,
+		convention: crate::YearConvention,
+	) -> Result<crate::DateTime, crate::InvalidDateTimeValue> {
+		let year = self
+			.year
+			.parse()
+			.ok()
+			.and_then(|year| convention.to_xsd11(year))
+			.ok_or(crate::InvalidDateTimeValue)?;
+
 		let date = chrono::NaiveDate::from_ymd_opt(
-			self.year.parse().unwrap(),
+			year,
 			self.month.parse().unwrap(),
 			self.day.parse().unwrap(),
 		)
@@ -151,34 +251,107 @@ impl<'a> Parts<'a> {
 
 /// Parses a decimal number representing seconds and returns the represented
 /// number of seconds and nanoseconds.
+///
+/// Digits after the ninth fractional digit are ignored. The input must be a
+/// valid seconds lexical representation.
 pub(crate) fn parse_seconds_decimal(decimal: &str) -> (u32, u32) {
-	match decimal.split_once('.') {
-		Some((integer, fract)) => {
-			let seconds = integer.parse().unwrap();
-			let fract = if fract.len() > 9 { &fract[..9] } else { fract };
-			let nano_seconds = fract.parse::<u32>().unwrap() * 10u32.pow(9 - fract.len() as u32);
+	try_parse_seconds_decimal(decimal).unwrap()
+}
 
-			(seconds, nano_seconds)
-		}
-		None => (decimal.parse().unwrap(), 0),
+/// Parses a decimal number representing seconds, like
+/// [`parse_seconds_decimal`], returning `None` if the number of seconds does
+/// not fit in an `u32`.
+///
+/// The integer part may be empty (as in the `.5` duration seconds).
+pub(crate) fn try_parse_seconds_decimal(decimal: &str) -> Option<(u32, u32)> {
+	let (integer, fraction) = decimal.split_once('.').unwrap_or((decimal, ""));
+	let seconds = if integer.is_empty() {
+		0
+	} else {
+		integer.parse().ok()?
+	};
+
+	Some((seconds, parse_nanoseconds(fraction)))
+}
+
+/// Parses the fractional digits of a number of seconds into a number of
+/// nanoseconds, ignoring the digits after the ninth.
+pub(crate) fn parse_nanoseconds(fraction: &str) -> u32 {
+	let fraction = fraction.as_bytes();
+	(0..9).fold(0, |n, i| {
+		n * 10 + fraction.get(i).map(|d| (d - b'0') as u32).unwrap_or(0)
+	})
+}
+
+/// Parses the seconds component of a date/time or time lexical
+/// representation, such as [`Parts::seconds`], and returns the represented
+/// number of seconds and nanoseconds.
+///
+/// Digits after the ninth fractional digit are ignored. Returns `None` if the
+/// input is not an unsigned decimal number, or if the number of seconds does
+/// not fit in an `u32`.
+///
+/// ```
+/// use xsd_types::lexical::date_time::try_parse_seconds;
+///
+/// assert_eq!(try_parse_seconds("07.25"), Some((7, 250_000_000)));
+/// assert_eq!(try_parse_seconds("-07"), None);
+/// ```
+pub fn try_parse_seconds(seconds: &str) -> Option<(u32, u32)> {
+	let (integer, fraction) = seconds.split_once('.').unwrap_or((seconds, ""));
+	let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+
+	if (integer.is_empty() && fraction.is_empty()) || !is_digits(integer) || !is_digits(fraction) {
+		return None;
 	}
+
+	try_parse_seconds_decimal(seconds)
 }
 
-pub(crate) fn parse_timezone(tz: &str) -> chrono::FixedOffset {
+/// Parses a timezone lexical representation (`Z` or `(+|-)hh:mm`), such as
+/// [`Parts::timezone`].
+///
+/// Returns `None` if the input is not a valid timezone lexical
+/// representation, between `-14:00` and `+14:00`.
+///
+/// ```
+/// use xsd_types::lexical::date_time::try_parse_timezone;
+///
+/// assert_eq!(try_parse_timezone("-05:30").unwrap().local_minus_utc(), -19800);
+/// assert_eq!(try_parse_timezone("+14:30"), None);
+/// ```
+pub fn try_parse_timezone(tz: &str) -> Option<chrono::FixedOffset> {
 	const HOUR: i32 = 3600;
 	const MINUTE: i32 = 60;
 
-	match tz {
-		"Z" => chrono::FixedOffset::east_opt(0).unwrap(),
-		"14:00" => chrono::FixedOffset::east_opt(14 * HOUR).unwrap(),
-		n => {
-			let (h, m) = n.split_once(':').unwrap();
-			chrono::FixedOffset::east_opt(
-				h.parse::<i32>().unwrap() * HOUR + m.parse::<i32>().unwrap() * MINUTE,
-			)
-			.unwrap()
-		}
+	if tz == "Z" {
+		return chrono::FixedOffset::east_opt(0);
 	}
+
+	let sign = match tz.as_bytes().first()? {
+		b'+' => 1,
+		b'-' => -1,
+		_ => return None,
+	};
+
+	let (h, m) = tz[1..].split_once(':')?;
+	if h.len() != 2 || m.len() != 2 || !(h.bytes().chain(m.bytes())).all(|b| b.is_ascii_digit()) {
+		return None;
+	}
+
+	let (h, m) = (h.parse::<i32>().ok()?, m.parse::<i32>().ok()?);
+	if m >= 60 || (h, m) > (14, 0) {
+		return None;
+	}
+
+	chrono::FixedOffset::east_opt(sign * (h * HOUR + m * MINUTE))
+}
+
+/// Parses a timezone lexical representation (`Z` or `(+|-)hh:mm`).
+///
+/// The input must be a valid timezone lexical representation.
+pub(crate) fn parse_timezone(tz: &str) -> chrono::FixedOffset {
+	try_parse_timezone(tz).unwrap()
 }
 
 #[cfg(test)]
@@ -225,9 +398,41 @@ mod tests {
 		for (input, parts) in vectors {
 			let lexical_repr = DateTime::new(input).unwrap();
 			assert_eq!(lexical_repr.parts(), parts);
+			assert!(Parts::parse(input).is_ok_and(|p| p == parts));
 
 			let value = lexical_repr.try_as_value().unwrap();
 			assert_eq!(value.to_string().as_str(), input)
 		}
 	}
+
+	#[test]
+	fn timezones() {
+		for (tz, seconds) in [
+			("Z", 0),
+			("+05:30", 19800),
+			("-05:30", -19800),
+			("-00:30", -1800),
+			("+00:00", 0),
+			("-14:00", -50400),
+		] {
+			assert_eq!(parse_timezone(tz).local_minus_utc(), seconds)
+		}
+
+		for tz in [
+			"", "z", "05:30", "+5:30", "+05:60", "+14:01", "+15:00", "-05:3a", "+05-30",
+		] {
+			assert_eq!(try_parse_timezone(tz), None)
+		}
+	}
+
+	#[test]
+	fn seconds() {
+		assert_eq!(try_parse_seconds("00"), Some((0, 0)));
+		assert_eq!(try_parse_seconds("59.0000000019"), Some((59, 1)));
+		assert_eq!(try_parse_seconds(".5"), Some((0, 500_000_000)));
+
+		for seconds in ["", ".", "+01", "1e3", "1.5.", "4294967296"] {
+			assert_eq!(try_parse_seconds(seconds), None)
+		}
+	}
 }
@@ -1,11 +1,10 @@
 use crate::lexical::lexical_form;
 
-use super::{Decimal, DecimalBuf, Overflow, Sign};
+use super::{Decimal, DecimalBuf, Digits, Overflow, Sign};
 use std::borrow::{Borrow, ToOwned};
 use std::cmp::Ordering;
 use std::fmt;
 use std::hash::{Hash, Hasher};
-use std::str::FromStr;
 
 mod non_negative_integer;
 mod non_positive_integer;
@@ -46,6 +45,22 @@ lexical_form! {
 }
 
 impl Integer {
+	/// Returns the lexical form to parse into a Rust integer type, where a
+	/// signed zero such as `-0` is replaced by `0` so that it also converts
+	/// into unsigned types.
+	pub(super) fn primitive_str(&self) -> &str {
+		if self
+			.as_str()
+			.trim_start_matches(['+', '-'])
+			.bytes()
+			.all(|b| b == b'0')
+		{
+			"0"
+		} else {
+			self.as_str()
+		}
+	}
+
 	/// Returns `true` if `self` is positive
 	/// and `false` is the number is zero or negative.
 	pub fn is_positive(&self) -> bool {
@@ -122,28 +137,44 @@ impl Integer {
 			match c {
 				b'+' | b'-' => (),
 				b'0' => last_zero = i,
-				_ => return unsafe { NonNegativeInteger::new_unchecked(&self.0[i..]) },
+				_ => return unchecked!(NonNegativeInteger, &self.0[i..]),
 			}
 		}
 
-		unsafe { NonNegativeInteger::new_unchecked(&self.0[last_zero..]) }
+		unchecked!(NonNegativeInteger, &self.0[last_zero..])
+	}
+
+	/// Returns an iterator over the significant digits of `self`, ignoring
+	/// the sign and leading zeros.
+	///
+	/// Zero has a single digit.
+	pub fn digits(&self) -> Digits<'_> {
+		Digits::new(self.abs().as_bytes())
+	}
+
+	/// Returns the number of significant digits of `self`, ignoring the sign
+	/// and leading zeros.
+	///
+	/// Zero has a single digit.
+	pub fn num_digits(&self) -> usize {
+		self.abs().as_bytes().len()
 	}
 
 	/// Returns the canonical form of `self` (without leading zeros).
 	pub fn canonical(&self) -> &Self {
 		if self.is_zero() {
-			unsafe { Self::new_unchecked(&self.0[self.0.len() - 1..]) }
+			unchecked!(Self, &self.0[self.0.len() - 1..])
 		} else {
 			let mut last_zero = 0;
 			for (i, c) in self.0.iter().enumerate() {
 				match c {
 					b'+' => (),
 					b'0' => last_zero = i,
-					_ => return unsafe { Self::new_unchecked(&self.0[i..]) },
+					_ => return unchecked!(Self, &self.0[i..]),
 				}
 			}
 
-			unsafe { Self::new_unchecked(&self.0[last_zero..]) }
+			unchecked!(Self, &self.0[last_zero..])
 		}
 	}
 
@@ -154,7 +185,7 @@ impl Integer {
 
 	#[inline(always)]
 	pub fn value(&self) -> crate::Integer {
-		crate::Integer::from_str(self.as_str()).unwrap()
+		crate::Integer::from_bigint(self.digits().to_bigint(self.sign()))
 	}
 }
 
@@ -211,11 +242,23 @@ impl PartialOrd for Integer {
 
 impl IntegerBuf {
 	pub fn zero() -> Self {
-		unsafe { Self::new_unchecked("0".to_string()) }
+		unchecked!(Self, "0".to_string())
 	}
 
 	pub fn one() -> Self {
-		unsafe { Self::new_unchecked("1".to_string()) }
+		unchecked!(Self, "1".to_string())
+	}
+
+	/// Creates the canonical lexical representation of the integer
+	/// represented by `value` in the given radix.
+	///
+	/// See [`crate::Integer::from_radix`] for details on the accepted input.
+	///
+	/// # Panics
+	///
+	/// Panics if `radix` is not in the range `2..=36`.
+	pub fn from_radix(value: &str, radix: u32) -> Result<Self, crate::InvalidRadixInteger> {
+		crate::Integer::from_radix(value, radix).map(|n| n.lexical_representation())
 	}
 }
 
@@ -242,7 +285,7 @@ macro_rules! number_conversion {
 		$(
 			impl From<$ty> for IntegerBuf {
 				fn from(i: $ty) -> Self {
-					unsafe { IntegerBuf::new_unchecked(i.to_string()) }
+					unchecked!(IntegerBuf, i.to_string())
 				}
 			}
 
@@ -250,7 +293,9 @@ macro_rules! number_conversion {
 				type Error = Overflow;
 
 				fn try_from(i: &'a Integer) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					i.primitive_str()
+						.parse()
+						.map_err(|_| Overflow::new(i.as_str(), stringify!($ty)))
 				}
 			}
 
@@ -258,7 +303,7 @@ macro_rules! number_conversion {
 				type Error = Overflow;
 
 				fn try_from(i: IntegerBuf) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					Self::try_from(i.as_integer())
 				}
 			}
 		)*
@@ -351,6 +396,32 @@ mod tests {
 		Integer::new("-42").unwrap();
 	}
 
+	#[test]
+	fn parse_zero() {
+		use crate::lexical::{NonNegativeInteger, NonPositiveInteger};
+
+		for input in ["0", "+0", "-0", "-00"] {
+			assert!(NonNegativeInteger::new(input).is_ok());
+			assert!(NonPositiveInteger::new(input).is_ok())
+		}
+
+		assert!(NonNegativeInteger::new("-").is_err());
+		assert!(NonPositiveInteger::new("+").is_err());
+		assert!(NonPositiveInteger::new("01").is_err())
+	}
+
+	#[test]
+	fn digits_01() {
+		let i = Integer::new("-00120").unwrap();
+		assert_eq!(i.digits().collect::<Vec<_>>(), [1, 2, 0]);
+		assert_eq!(i.num_digits(), 3)
+	}
+
+	#[test]
+	fn digits_02() {
+		assert_eq!(Integer::new("+000").unwrap().num_digits(), 1)
+	}
+
 	#[test]
 	fn abs_01() {
 		assert_eq!(Integer::new("01").unwrap().abs().as_str(), "1")
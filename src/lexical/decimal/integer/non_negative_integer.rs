@@ -5,7 +5,6 @@ use std::borrow::{Borrow, ToOwned};
 use std::cmp::Ordering;
 use std::fmt;
 use std::hash::{Hash, Hasher};
-use std::str::FromStr;
 
 mod positive_integer;
 
@@ -88,11 +87,11 @@ impl NonNegativeInteger {
 			match c {
 				b'+' => (),
 				b'0' => last_zero = i,
-				_ => return unsafe { Self::new_unchecked(&self.0[i..]) },
+				_ => return unchecked!(Self, &self.0[i..]),
 			}
 		}
 
-		unsafe { Self::new_unchecked(&self.0[last_zero..]) }
+		unchecked!(Self, &self.0[last_zero..])
 	}
 
 	#[inline(always)]
@@ -102,10 +101,12 @@ impl NonNegativeInteger {
 
 	#[inline(always)]
 	pub fn value(&self) -> crate::NonNegativeInteger {
-		use num_bigint::BigInt;
-		unsafe {
-			crate::NonNegativeInteger::new_unchecked(BigInt::from_str(self.as_str()).unwrap())
-		}
+		// This is safe because the digits of a non negative integer
+		// lexical form are interpreted with the matching sign.
+		unchecked!(
+			crate::NonNegativeInteger,
+			self.as_integer().digits().to_bigint(Sign::Positive)
+		)
 	}
 }
 
@@ -162,11 +163,11 @@ impl PartialOrd for NonNegativeInteger {
 
 impl NonNegativeIntegerBuf {
 	pub fn zero() -> Self {
-		unsafe { Self::new_unchecked("0".to_string()) }
+		unchecked!(Self, "0".to_string())
 	}
 
 	pub fn one() -> Self {
-		unsafe { Self::new_unchecked("1".to_string()) }
+		unchecked!(Self, "1".to_string())
 	}
 }
 
@@ -193,7 +194,7 @@ macro_rules! number_conversion {
 		$(
 			impl From<$ty> for NonNegativeIntegerBuf {
 				fn from(i: $ty) -> Self {
-					unsafe { NonNegativeIntegerBuf::new_unchecked(i.to_string()) }
+					unchecked!(NonNegativeIntegerBuf, i.to_string())
 				}
 			}
 
@@ -201,7 +202,9 @@ macro_rules! number_conversion {
 				type Error = Overflow;
 
 				fn try_from(i: &'a NonNegativeInteger) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					i.as_str()
+						.parse()
+						.map_err(|_| Overflow::new(i.as_str(), stringify!($ty)))
 				}
 			}
 
@@ -209,7 +212,9 @@ macro_rules! number_conversion {
 				type Error = Overflow;
 
 				fn try_from(i: NonNegativeIntegerBuf) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					i.as_str()
+						.parse()
+						.map_err(|_| Overflow::new(i.as_str(), stringify!($ty)))
 				}
 			}
 		)*
@@ -240,6 +245,7 @@ fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 		Initial,
 		NonEmptyInteger,
 		Integer,
+		NonEmptyZero,
 		Zero,
 	}
 
@@ -249,7 +255,7 @@ fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 		state = match state {
 			State::Initial => match chars.next() {
 				Some(b'+') => State::NonEmptyInteger,
-				Some(b'-') => State::Zero,
+				Some(b'-') => State::NonEmptyZero,
 				Some(b'0'..=b'9') => State::Integer,
 				_ => break false,
 			},
@@ -262,10 +268,15 @@ fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 				Some(_) => break false,
 				None => break true,
 			},
-			State::Zero => match chars.next() {
+			State::NonEmptyZero => match chars.next() {
 				Some(b'0') => State::Zero,
 				_ => break false,
 			},
+			State::Zero => match chars.next() {
+				Some(b'0') => State::Zero,
+				Some(_) => break false,
+				None => break true,
+			},
 		}
 	}
 }
@@ -1,11 +1,12 @@
-use crate::lexical::{lexical_form, Decimal, DecimalBuf, Integer, IntegerBuf, NonNegativeInteger};
+use crate::lexical::{
+	lexical_form, Decimal, DecimalBuf, Integer, IntegerBuf, NonNegativeInteger, Sign,
+};
 
 use super::{NonNegativeIntegerBuf, Overflow};
 use std::borrow::{Borrow, ToOwned};
 use std::cmp::Ordering;
 use std::fmt;
 use std::hash::{Hash, Hasher};
-use std::str::FromStr;
 
 lexical_form! {
 	/// Positive integer number.
@@ -49,11 +50,11 @@ impl PositiveInteger {
 			match c {
 				b'+' => (),
 				b'0' => last_zero = i,
-				_ => return unsafe { Self::new_unchecked(&self.0[i..]) },
+				_ => return unchecked!(Self, &self.0[i..]),
 			}
 		}
 
-		unsafe { Self::new_unchecked(&self.0[last_zero..]) }
+		unchecked!(Self, &self.0[last_zero..])
 	}
 
 	fn as_canonical_str(&self) -> &str {
@@ -62,8 +63,10 @@ impl PositiveInteger {
 
 	#[inline(always)]
 	pub fn value(&self) -> crate::PositiveInteger {
-		use num_bigint::BigInt;
-		unsafe { crate::PositiveInteger::new_unchecked(BigInt::from_str(self.as_str()).unwrap()) }
+		unchecked!(
+			crate::PositiveInteger,
+			self.as_integer().digits().to_bigint(Sign::Positive)
+		)
 	}
 }
 
@@ -95,7 +98,7 @@ impl PartialOrd for PositiveInteger {
 
 impl PositiveIntegerBuf {
 	pub fn one() -> Self {
-		unsafe { Self::new_unchecked("1".to_string()) }
+		unchecked!(Self, "1".to_string())
 	}
 }
 
@@ -116,7 +119,7 @@ macro_rules! number_conversion {
 		$(
 			impl From<$ty> for PositiveIntegerBuf {
 				fn from(i: $ty) -> Self {
-					unsafe { PositiveIntegerBuf::new_unchecked(i.to_string()) }
+					unchecked!(PositiveIntegerBuf, i.to_string())
 				}
 			}
 
@@ -124,7 +127,9 @@ macro_rules! number_conversion {
 				type Error = Overflow;
 
 				fn try_from(i: &'a PositiveInteger) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					i.as_str()
+						.parse()
+						.map_err(|_| Overflow::new(i.as_str(), stringify!($ty)))
 				}
 			}
 
@@ -132,7 +137,9 @@ macro_rules! number_conversion {
 				type Error = Overflow;
 
 				fn try_from(i: PositiveIntegerBuf) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					i.as_str()
+						.parse()
+						.map_err(|_| Overflow::new(i.as_str(), stringify!($ty)))
 				}
 			}
 		)*
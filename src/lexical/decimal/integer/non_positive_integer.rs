@@ -5,7 +5,6 @@ use std::borrow::{Borrow, ToOwned};
 use std::cmp::Ordering;
 use std::fmt;
 use std::hash::{Hash, Hasher};
-use std::str::FromStr;
 
 mod negative_integer;
 
@@ -90,19 +89,21 @@ impl NonPositiveInteger {
 			match c {
 				b'+' | b'-' => (),
 				b'0' => last_zero = i,
-				_ => return unsafe { NonNegativeInteger::new_unchecked(&self.0[i..]) },
+				_ => return unchecked!(NonNegativeInteger, &self.0[i..]),
 			}
 		}
 
-		unsafe { NonNegativeInteger::new_unchecked(&self.0[last_zero..]) }
+		unchecked!(NonNegativeInteger, &self.0[last_zero..])
 	}
 
 	#[inline(always)]
 	pub fn value(&self) -> crate::NonPositiveInteger {
-		use num_bigint::BigInt;
-		unsafe {
-			crate::NonPositiveInteger::new_unchecked(BigInt::from_str(self.as_str()).unwrap())
-		}
+		// This is safe because the digits of a non positive integer
+		// lexical form are interpreted with the matching sign.
+		unchecked!(
+			crate::NonPositiveInteger,
+			self.as_integer().digits().to_bigint(Sign::Negative)
+		)
 	}
 }
 
@@ -165,11 +166,11 @@ impl PartialOrd for NonPositiveInteger {
 
 impl NonPositiveIntegerBuf {
 	pub fn zero() -> Self {
-		unsafe { Self::new_unchecked("0".to_string()) }
+		unchecked!(Self, "0".to_string())
 	}
 
 	pub fn minus_one() -> Self {
-		unsafe { Self::new_unchecked("-1".to_string()) }
+		unchecked!(Self, "-1".to_string())
 	}
 }
 
@@ -196,7 +197,7 @@ macro_rules! number_conversion {
 		$(
 			impl From<$ty> for NonPositiveIntegerBuf {
 				fn from(i: $ty) -> Self {
-					unsafe { NonPositiveIntegerBuf::new_unchecked(i.to_string()) }
+					unchecked!(NonPositiveIntegerBuf, i.to_string())
 				}
 			}
 
@@ -204,7 +205,9 @@ macro_rules! number_conversion {
 				type Error = Overflow;
 
 				fn try_from(i: &'a NonPositiveInteger) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					i.as_str()
+						.parse()
+						.map_err(|_| Overflow::new(i.as_str(), stringify!($ty)))
 				}
 			}
 
@@ -212,7 +215,9 @@ macro_rules! number_conversion {
 				type Error = Overflow;
 
 				fn try_from(i: NonPositiveIntegerBuf) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					i.as_str()
+						.parse()
+						.map_err(|_| Overflow::new(i.as_str(), stringify!($ty)))
 				}
 			}
 		)*
@@ -243,6 +248,7 @@ fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 		Initial,
 		NonEmptyInteger,
 		Integer,
+		NonEmptyZero,
 		Zero,
 	}
 
@@ -252,7 +258,8 @@ fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 		state = match state {
 			State::Initial => match chars.next() {
 				Some(b'-') => State::NonEmptyInteger,
-				Some(b'+' | b'0') => State::Zero,
+				Some(b'+') => State::NonEmptyZero,
+				Some(b'0') => State::Zero,
 				_ => break false,
 			},
 			State::NonEmptyInteger => match chars.next() {
@@ -264,10 +271,15 @@ fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 				Some(_) => break false,
 				None => break true,
 			},
-			State::Zero => match chars.next() {
+			State::NonEmptyZero => match chars.next() {
 				Some(b'0') => State::Zero,
 				_ => break false,
 			},
+			State::Zero => match chars.next() {
+				Some(b'0') => State::Zero,
+				Some(_) => break false,
+				None => break true,
+			},
 		}
 	}
 }
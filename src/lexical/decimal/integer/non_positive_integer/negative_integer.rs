@@ -1,6 +1,6 @@
 use crate::lexical::{
 	lexical_form, Decimal, DecimalBuf, Integer, IntegerBuf, NonNegativeInteger,
-	NonNegativeIntegerBuf,
+	NonNegativeIntegerBuf, Sign,
 };
 
 use super::Overflow;
@@ -8,7 +8,6 @@ use std::borrow::{Borrow, ToOwned};
 use std::cmp::Ordering;
 use std::fmt;
 use std::hash::{Hash, Hasher};
-use std::str::FromStr;
 
 lexical_form! {
 	/// Negative integer number.
@@ -52,11 +51,11 @@ impl NegativeInteger {
 			match c {
 				b'-' => (),
 				b'0' => last_zero = i,
-				_ => return unsafe { NonNegativeInteger::new_unchecked(&self.0[i..]) },
+				_ => return unchecked!(NonNegativeInteger, &self.0[i..]),
 			}
 		}
 
-		unsafe { NonNegativeInteger::new_unchecked(&self.0[last_zero..]) }
+		unchecked!(NonNegativeInteger, &self.0[last_zero..])
 	}
 
 	fn as_canonical_str(&self) -> &str {
@@ -65,8 +64,10 @@ impl NegativeInteger {
 
 	#[inline(always)]
 	pub fn value(&self) -> crate::NegativeInteger {
-		use num_bigint::BigInt;
-		unsafe { crate::NegativeInteger::new_unchecked(BigInt::from_str(self.as_str()).unwrap()) }
+		unchecked!(
+			crate::NegativeInteger,
+			self.as_integer().digits().to_bigint(Sign::Negative)
+		)
 	}
 }
 
@@ -100,7 +101,7 @@ impl PartialOrd for NegativeInteger {
 
 impl NegativeIntegerBuf {
 	pub fn minus_one() -> Self {
-		unsafe { Self::new_unchecked("-1".to_string()) }
+		unchecked!(Self, "-1".to_string())
 	}
 }
 
@@ -121,7 +122,7 @@ macro_rules! number_conversion {
 		$(
 			impl From<$ty> for NegativeIntegerBuf {
 				fn from(i: $ty) -> Self {
-					unsafe { NegativeIntegerBuf::new_unchecked(i.to_string()) }
+					unchecked!(NegativeIntegerBuf, i.to_string())
 				}
 			}
 
@@ -129,7 +130,9 @@ macro_rules! number_conversion {
 				type Error = Overflow;
 
 				fn try_from(i: &'a NegativeInteger) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					i.as_str()
+						.parse()
+						.map_err(|_| Overflow::new(i.as_str(), stringify!($ty)))
 				}
 			}
 
@@ -137,7 +140,9 @@ macro_rules! number_conversion {
 				type Error = Overflow;
 
 				fn try_from(i: NegativeIntegerBuf) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					i.as_str()
+						.parse()
+						.map_err(|_| Overflow::new(i.as_str(), stringify!($ty)))
 				}
 			}
 		)*
@@ -179,7 +184,8 @@ fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 				_ => break false,
 			},
 			State::NonEmptyInteger => match chars.next() {
-				Some(b'0'..=b'9') => State::Integer,
+				Some(b'0') => State::NonEmptyInteger,
+				Some(b'1'..=b'9') => State::Integer,
 				_ => break false,
 			},
 			State::Integer => match chars.next() {
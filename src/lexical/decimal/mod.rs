@@ -1,4 +1,4 @@
-use super::lexical_form;
+use super::{lexical_form, non_zero_conversion};
 use std::borrow::{Borrow, ToOwned};
 use std::cmp::Ordering;
 use std::fmt;
@@ -26,8 +26,80 @@ impl Sign {
 	}
 }
 
+/// Iterator over the decimal digits of a number lexical representation.
+///
+/// Each item is the numeric value of a digit, between 0 and 9.
+#[derive(Debug, Clone)]
+pub struct Digits<'a>(std::slice::Iter<'a, u8>);
+
+impl<'a> Digits<'a> {
+	fn new(bytes: &'a [u8]) -> Self {
+		Self(bytes.iter())
+	}
+
+	/// Converts the digits into a big integer with the given sign, without
+	/// going through string parsing.
+	pub(crate) fn to_bigint(&self, sign: Sign) -> num_bigint::BigInt {
+		let sign = match sign {
+			Sign::Negative => num_bigint::Sign::Minus,
+			Sign::Zero => num_bigint::Sign::NoSign,
+			Sign::Positive => num_bigint::Sign::Plus,
+		};
+
+		let digits: Vec<u8> = self.clone().collect();
+		num_bigint::BigInt::from_radix_be(sign, &digits, 10).unwrap()
+	}
+}
+
+impl<'a> Iterator for Digits<'a> {
+	type Item = u8;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.0.next().map(|c| c - b'0')
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.0.size_hint()
+	}
+}
+
+impl<'a> DoubleEndedIterator for Digits<'a> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.0.next_back().map(|c| c - b'0')
+	}
+}
+
+impl<'a> ExactSizeIterator for Digits<'a> {}
+
 /// Error thrown when a conversion function overflowed.
-pub struct Overflow;
+///
+/// Returned when converting a lexical number into a Rust primitive type that
+/// cannot represent it.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("`{value}` does not fit in `{target}`")]
+pub struct Overflow {
+	value: String,
+	target: &'static str,
+}
+
+impl Overflow {
+	pub(crate) fn new(value: &str, target: &'static str) -> Self {
+		Self {
+			value: value.to_owned(),
+			target,
+		}
+	}
+
+	/// Returns the lexical form of the value that overflowed.
+	pub fn value(&self) -> &str {
+		&self.value
+	}
+
+	/// Returns the name of the target type.
+	pub fn target(&self) -> &'static str {
+		self.target
+	}
+}
 
 mod integer;
 
@@ -125,24 +197,34 @@ impl Decimal {
 		Sign::Zero
 	}
 
+	/// Returns the integer part of this decimal.
+	///
+	/// An empty or sign-only integer part, as in `.5` or `-.5`, is returned
+	/// as `0`.
 	#[inline(always)]
 	pub fn integer_part(&self) -> &Integer {
-		match self.split_once('.') {
-			Some((integer_part, _)) => unsafe { Integer::new_unchecked(integer_part) },
-			None => unsafe { Integer::new_unchecked(self) },
+		let integer_part = match self.split_once('.') {
+			Some((integer_part, _)) => integer_part,
+			None => self,
+		};
+
+		if integer_part.trim_start_matches(['+', '-']).is_empty() {
+			unchecked!(Integer, "0")
+		} else {
+			unchecked!(Integer, integer_part)
 		}
 	}
 
 	#[inline(always)]
 	pub fn fractional_part(&self) -> Option<&FractionalPart> {
 		self.split_once('.')
-			.map(|(_, fractional_part)| unsafe { FractionalPart::new_unchecked(fractional_part) })
+			.map(|(_, fractional_part)| unchecked!(FractionalPart, fractional_part))
 	}
 
 	#[inline(always)]
 	pub fn trimmed_fractional_part(&self) -> Option<&FractionalPart> {
 		self.split_once('.').and_then(|(_, fractional_part)| {
-			let f = unsafe { FractionalPart::new_unchecked(fractional_part) }.trimmed();
+			let f = unchecked!(FractionalPart, fractional_part).trimmed();
 			if f.is_empty() {
 				None
 			} else {
@@ -151,17 +233,50 @@ impl Decimal {
 		})
 	}
 
+	/// Returns the lexical form of the integer part, if the fractional part
+	/// is zero.
+	fn integral_str(&self) -> Option<&str> {
+		if self.trimmed_fractional_part().is_some() {
+			return None;
+		}
+
+		Some(self.integer_part().primitive_str())
+	}
+
 	#[inline(always)]
 	pub fn parts(&self) -> (&Integer, Option<&FractionalPart>) {
-		match self.split_once('.') {
-			Some((i, f)) => unsafe {
-				(
-					Integer::new_unchecked(i),
-					Some(FractionalPart::new_unchecked(f)),
-				)
-			},
-			None => unsafe { (Integer::new_unchecked(self), None) },
-		}
+		(self.integer_part(), self.fractional_part())
+	}
+
+	/// Returns the total number of significant digits of `self`, ignoring
+	/// the sign, leading zeros and trailing fractional zeros.
+	///
+	/// This is the number of digits constrained by the
+	/// [`totalDigits`](https://www.w3.org/TR/xmlschema11-2/#rf-totalDigits)
+	/// facet. Zero has a single digit.
+	pub fn total_digits(&self) -> usize {
+		let integer_part = self.integer_part();
+		let integer_digits = if integer_part.is_zero() {
+			0
+		} else {
+			integer_part.num_digits()
+		};
+
+		// Leading zeros of the fractional part count when the integer part
+		// is zero, since `0.05` needs two digits to be represented.
+		(integer_digits + self.fraction_digits()).max(1)
+	}
+
+	/// Returns the number of fractional digits of `self`, ignoring trailing
+	/// zeros.
+	///
+	/// This is the number of digits constrained by the
+	/// [`fractionDigits`](https://www.w3.org/TR/xmlschema11-2/#rf-fractionDigits)
+	/// facet.
+	pub fn fraction_digits(&self) -> usize {
+		self.trimmed_fractional_part()
+			.map(|f| f.as_bytes().len())
+			.unwrap_or(0)
 	}
 
 	pub fn value(&self) -> crate::Decimal {
@@ -169,10 +284,12 @@ impl Decimal {
 	}
 }
 
+/// Compares the represented values, consistently with `Hash` and `Ord`.
 impl PartialEq for Decimal {
 	fn eq(&self, other: &Self) -> bool {
-		self.integer_part() == other.integer_part()
-			&& self.fractional_part() == other.fractional_part()
+		self.sign() == other.sign()
+			&& self.integer_part() == other.integer_part()
+			&& self.trimmed_fractional_part() == other.trimmed_fractional_part()
 	}
 }
 
@@ -226,20 +343,36 @@ impl PartialOrd for Decimal {
 	}
 }
 
+impl PartialOrd for DecimalBuf {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for DecimalBuf {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.as_decimal().cmp(other.as_decimal())
+	}
+}
+
 macro_rules! integer_conversion {
 	{ $($ty:ty),* } => {
 		$(
 			impl From<$ty> for DecimalBuf {
 				fn from(i: $ty) -> Self {
-					unsafe { DecimalBuf::new_unchecked(i.to_string()) }
+					unchecked!(DecimalBuf, i.to_string())
 				}
 			}
 
+			/// Fails if the decimal has a non-zero fractional part, or if
+			/// its integer part does not fit in the target type.
 			impl<'a> TryFrom<&'a Decimal> for $ty {
 				type Error = Overflow;
 
 				fn try_from(i: &'a Decimal) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					i.integral_str()
+						.and_then(|s| s.parse().ok())
+						.ok_or_else(|| Overflow::new(i.as_str(), stringify!($ty)))
 				}
 			}
 
@@ -247,7 +380,7 @@ macro_rules! integer_conversion {
 				type Error = Overflow;
 
 				fn try_from(i: DecimalBuf) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					Self::try_from(i.as_decimal())
 				}
 			}
 		)*
@@ -263,16 +396,20 @@ integer_conversion! {
 	i32,
 	u64,
 	i64,
+	u128,
+	i128,
 	usize,
 	isize
 }
 
+non_zero_conversion!(Decimal, DecimalBuf);
+
 const DTOA_CONFIG: pretty_dtoa::FmtFloatConfig =
 	pretty_dtoa::FmtFloatConfig::default().force_no_e_notation();
 
 impl From<f32> for DecimalBuf {
 	fn from(i: f32) -> Self {
-		unsafe { DecimalBuf::new_unchecked(pretty_dtoa::ftoa(i, DTOA_CONFIG)) }
+		unchecked!(DecimalBuf, pretty_dtoa::ftoa(i, DTOA_CONFIG))
 	}
 }
 
@@ -294,7 +431,7 @@ impl TryFrom<DecimalBuf> for f32 {
 
 impl From<f64> for DecimalBuf {
 	fn from(i: f64) -> Self {
-		unsafe { DecimalBuf::new_unchecked(pretty_dtoa::dtoa(i, DTOA_CONFIG)) }
+		unchecked!(DecimalBuf, pretty_dtoa::dtoa(i, DTOA_CONFIG))
 	}
 }
 
@@ -314,28 +451,53 @@ impl TryFrom<DecimalBuf> for f64 {
 	}
 }
 
+/// Error raised when a byte string is not a fractional part lexical
+/// representation.
+#[derive(Debug)]
+pub struct InvalidFractionalPart;
+
+#[cfg_attr(feature = "forbid-unsafe", derive(ref_cast::RefCastCustom))]
+#[repr(transparent)]
 pub struct FractionalPart([u8]);
 
 impl FractionalPart {
-	/// Creates a new fractional part from a byte slice.
-	///
-	/// # Safety
-	///
-	/// The input byte slice must be a valid fractional part lexical
-	/// representation.
+	/// Creates a new fractional part from a byte slice, made of decimal
+	/// digits only.
 	#[inline(always)]
-	pub unsafe fn new_unchecked<S: ?Sized + AsRef<[u8]>>(s: &S) -> &Self {
-		std::mem::transmute(s.as_ref())
+	pub fn new<S: ?Sized + AsRef<[u8]>>(s: &S) -> Result<&Self, InvalidFractionalPart> {
+		let bytes = s.as_ref();
+		if bytes.iter().all(u8::is_ascii_digit) {
+			Ok(Self::cast(bytes))
+		} else {
+			Err(InvalidFractionalPart)
+		}
+	}
+
+	unchecked_constructor! {
+		/// Creates a new fractional part from a byte slice.
+		///
+		/// # Safety
+		///
+		/// The input byte slice must be a valid fractional part lexical
+		/// representation.
+		#[inline(always)]
+		pub unsafe fn new_unchecked<S: ?Sized + AsRef<[u8]>>(s: &S) -> &Self {
+			Self::cast(s.as_ref())
+		}
+	}
+
+	transparent_cast! {
+		const fn cast(s: &[u8]) -> &Self;
 	}
 
 	#[inline(always)]
 	pub fn empty<'a>() -> &'a Self {
-		unsafe { Self::new_unchecked(b"") }
+		Self::cast(b"")
 	}
 
 	#[inline(always)]
 	pub fn as_str(&self) -> &str {
-		unsafe { core::str::from_utf8_unchecked(&self.0) }
+		crate::utils::str_from_validated_utf8(&self.0)
 	}
 
 	#[inline(always)]
@@ -359,7 +521,7 @@ impl FractionalPart {
 			}
 		}
 
-		unsafe { Self::new_unchecked(&self.0[0..end]) }
+		Self::cast(&self.0[0..end])
 	}
 }
 
@@ -426,7 +588,7 @@ fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 			},
 			State::NonEmptyInteger => match chars.next() {
 				Some(b'0'..=b'9') => State::Integer,
-				Some(b'.') => State::Decimal,
+				Some(b'.') => State::NonEmptyDecimal,
 				_ => break false,
 			},
 			State::Integer => match chars.next() {
@@ -506,6 +668,21 @@ mod tests {
 		Decimal::new("42.0").unwrap();
 	}
 
+	#[test]
+	fn digits_01() {
+		let d = Decimal::new("-012.3400").unwrap();
+		assert_eq!(d.total_digits(), 4);
+		assert_eq!(d.fraction_digits(), 2)
+	}
+
+	#[test]
+	fn digits_02() {
+		let d = Decimal::new("0.050").unwrap();
+		assert_eq!(d.total_digits(), 2);
+		assert_eq!(d.fraction_digits(), 2);
+		assert_eq!(Decimal::new("-0.0").unwrap().total_digits(), 1)
+	}
+
 	#[test]
 	fn format_01() {
 		assert_eq!(DecimalBuf::from(1.0e10f32).to_string(), "10000000000.0")
@@ -530,4 +707,95 @@ mod tests {
 	fn cmp_04() {
 		assert!(Decimal::new("-0.123").unwrap() > Decimal::new("-0.1234").unwrap())
 	}
+
+	#[test]
+	#[should_panic]
+	fn parse_sign_only() {
+		Decimal::new("-.").unwrap();
+	}
+
+	#[test]
+	fn value() {
+		for (input, expected) in [
+			("-1.5", "-1.5"),
+			("-0.000001", "-0.000001"),
+			(".5", "0.5"),
+			("-.5", "-0.5"),
+			("5.", "5"),
+			("+012.340", "12.34"),
+		] {
+			assert_eq!(Decimal::new(input).unwrap().value().to_string(), expected)
+		}
+	}
+	#[test]
+	fn overflow() {
+		let e = u8::try_from(Integer::new("256").unwrap()).unwrap_err();
+		assert_eq!(e.value(), "256");
+		assert_eq!(e.target(), "u8");
+		assert_eq!(e.to_string(), "`256` does not fit in `u8`");
+		assert_eq!(i8::try_from(Integer::new("-128").unwrap()), Ok(-128))
+	}
+
+	#[test]
+	fn integer_conversion() {
+		assert_eq!(i64::try_from(Decimal::new("-12.000").unwrap()), Ok(-12));
+		assert_eq!(u8::try_from(Decimal::new("+.0").unwrap()), Ok(0));
+		assert_eq!(u8::try_from(Decimal::new("-0").unwrap()), Ok(0));
+		assert_eq!(u64::try_from(Decimal::new("-00.000").unwrap()), Ok(0));
+		assert_eq!(u8::try_from(Integer::new("-0").unwrap()), Ok(0));
+		assert!(i64::try_from(Decimal::new("12.5").unwrap()).is_err());
+		assert_eq!(
+			u128::try_from(Decimal::new("340282366920938463463374607431768211455").unwrap()),
+			Ok(u128::MAX)
+		);
+
+		assert!(std::num::NonZeroU32::try_from(Decimal::new("0.0").unwrap()).is_err());
+		assert_eq!(
+			std::num::NonZeroI128::try_from(Decimal::new("-7").unwrap()).map(|n| n.get()),
+			Ok(-7)
+		)
+	}
+
+	#[test]
+	fn borrowed_keys() {
+		use std::borrow::Cow;
+		use std::collections::{BTreeSet, HashMap};
+
+		let mut map: HashMap<DecimalBuf, u32> = HashMap::new();
+		map.insert(Decimal::new("1.50").unwrap().to_owned(), 1);
+		assert_eq!(map.get(Decimal::new("01.5").unwrap()), Some(&1));
+
+		let set: BTreeSet<IntegerBuf> = [Integer::new("10").unwrap().to_owned()].into();
+		assert!(set.contains(Integer::new("+010").unwrap()));
+
+		let cow: Cow<Decimal> = Cow::Borrowed(Decimal::new("2.0").unwrap());
+		assert_eq!(cow.into_owned(), Decimal::new("2").unwrap())
+	}
+
+	#[test]
+	fn eq_sign() {
+		assert_ne!(Decimal::new("-0.5").unwrap(), Decimal::new("0.5").unwrap());
+		assert_ne!(Decimal::new("-1").unwrap(), Decimal::new("+1").unwrap());
+		assert_eq!(Decimal::new("-0.0").unwrap(), Decimal::new("0").unwrap());
+		assert_eq!(Decimal::new("+1.50").unwrap(), Decimal::new("1.5").unwrap())
+	}
+
+	/// Exercises the pointer casts of the constructors, meant to be run
+	/// under Miri.
+	#[test]
+	fn pointer_casts() {
+		let bytes = b"-12.50".to_vec();
+		let decimal = Decimal::new(&bytes).unwrap();
+		assert_eq!(decimal.as_bytes().as_ptr(), bytes.as_ptr());
+		assert_eq!(decimal.integer_part().as_str(), "-12");
+		assert_eq!(decimal.fractional_part().unwrap().as_str(), "50");
+
+		let buf = unchecked!(IntegerBuf, String::from("42"));
+		assert_eq!(buf.as_integer().as_str(), "42");
+		assert_eq!(buf.into_string(), "42");
+
+		let mut raw = [0xde, 0xad];
+		let hex = crate::HexBinary::new_mut(&mut raw);
+		assert_eq!(hex.as_bytes(), [0xde, 0xad]);
+	}
 }
@@ -1,4 +1,4 @@
-use super::{lexical_form, Decimal, Float, FloatBuf, Integer, Overflow};
+use super::{lexical_form, non_zero_conversion, Decimal, Float, FloatBuf, Integer, Overflow};
 use std::borrow::{Borrow, ToOwned};
 use std::fmt;
 use std::hash::Hash;
@@ -33,9 +33,9 @@ lexical_form! {
 	parent_forms: {}
 }
 
-pub const NAN: &Double = unsafe { Double::new_unchecked_from_slice(b"NaN") };
-pub const POSITIVE_INFINITY: &Double = unsafe { Double::new_unchecked_from_slice(b"INF") };
-pub const NEGATIVE_INFINITY: &Double = unsafe { Double::new_unchecked_from_slice(b"-INF") };
+pub const NAN: &Double = Double::cast(b"NaN");
+pub const POSITIVE_INFINITY: &Double = Double::cast(b"INF");
+pub const NEGATIVE_INFINITY: &Double = Double::cast(b"-INF");
 
 impl Double {
 	pub fn is_infinite(&self) -> bool {
@@ -63,8 +63,8 @@ impl Double {
 	pub fn mantissa(&self) -> Option<&Decimal> {
 		if self.is_finite() {
 			Some(match self.exponent_separator_index() {
-				Some(e) => unsafe { Decimal::new_unchecked(&self[..e]) },
-				None => unsafe { Decimal::new_unchecked(self) },
+				Some(e) => unchecked!(Decimal, &self[..e]),
+				None => unchecked!(Decimal, self),
 			})
 		} else {
 			None
@@ -74,7 +74,7 @@ impl Double {
 	pub fn exponent(&self) -> Option<&Integer> {
 		if self.is_finite() {
 			self.exponent_separator_index()
-				.map(|e| unsafe { Integer::new_unchecked(&self[(e + 1)..]) })
+				.map(|e| unchecked!(Integer, &self[(e + 1)..]))
 		} else {
 			None
 		}
@@ -104,7 +104,7 @@ macro_rules! integer_conversion {
 		$(
 			impl From<$ty> for DoubleBuf {
 				fn from(i: $ty) -> Self {
-					unsafe { DoubleBuf::new_unchecked(i.to_string()) }
+					unchecked!(DoubleBuf, i.to_string())
 				}
 			}
 
@@ -112,7 +112,9 @@ macro_rules! integer_conversion {
 				type Error = Overflow;
 
 				fn try_from(i: &'a Double) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					i.as_str()
+						.parse()
+						.map_err(|_| Overflow::new(i.as_str(), stringify!($ty)))
 				}
 			}
 
@@ -120,7 +122,9 @@ macro_rules! integer_conversion {
 				type Error = Overflow;
 
 				fn try_from(i: DoubleBuf) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					i.as_str()
+						.parse()
+						.map_err(|_| Overflow::new(i.as_str(), stringify!($ty)))
 				}
 			}
 		)*
@@ -136,16 +140,20 @@ integer_conversion! {
 	i32,
 	u64,
 	i64,
+	u128,
+	i128,
 	usize,
 	isize
 }
 
+non_zero_conversion!(Double, DoubleBuf);
+
 const DTOA_CONFIG: pretty_dtoa::FmtFloatConfig = pretty_dtoa::FmtFloatConfig::default();
 
 impl From<f32> for DoubleBuf {
 	fn from(i: f32) -> Self {
 		if i.is_finite() {
-			unsafe { DoubleBuf::new_unchecked(pretty_dtoa::ftoa(i, DTOA_CONFIG)) }
+			unchecked!(DoubleBuf, pretty_dtoa::ftoa(i, DTOA_CONFIG))
 		} else if i.is_nan() {
 			DoubleBuf::nan()
 		} else if i.is_sign_positive() {
@@ -183,7 +191,7 @@ impl From<FloatBuf> for f64 {
 impl From<f64> for DoubleBuf {
 	fn from(i: f64) -> Self {
 		if i.is_finite() {
-			unsafe { DoubleBuf::new_unchecked(pretty_dtoa::dtoa(i, DTOA_CONFIG)) }
+			unchecked!(DoubleBuf, pretty_dtoa::dtoa(i, DTOA_CONFIG))
 		} else if i.is_nan() {
 			DoubleBuf::nan()
 		} else if i.is_sign_positive() {
@@ -197,7 +205,7 @@ impl From<f64> for DoubleBuf {
 impl<'a> From<&'a Decimal> for &'a Double {
 	#[inline(always)]
 	fn from(d: &'a Decimal) -> Self {
-		unsafe { Double::new_unchecked(d) }
+		unchecked!(Double, d)
 	}
 }
 
@@ -247,7 +255,7 @@ fn check_normal<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 			},
 			State::NonEmptyInteger => match chars.next() {
 				Some(b'0'..=b'9') => State::Integer,
-				Some(b'.') => State::Decimal,
+				Some(b'.') => State::NonEmptyDecimal,
 				_ => break false,
 			},
 			State::Integer => match chars.next() {
@@ -408,6 +416,12 @@ mod tests {
 		assert_eq!(a, b)
 	}
 
+	#[test]
+	#[should_panic]
+	fn parse_23() {
+		Double::new("+.E1").unwrap();
+	}
+
 	#[test]
 	fn format_01() {
 		assert_eq!(DoubleBuf::from(1.0e10f64).to_string(), "1.0e10")
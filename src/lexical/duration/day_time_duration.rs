@@ -1,8 +1,10 @@
-use crate::lexical::date_time::parse_seconds_decimal;
+use crate::lexical::date_time::try_parse_seconds_decimal;
 
-use super::{Lexical, LexicalFormOf};
+use super::{sum_components, Lexical, LexicalFormOf};
 use static_regular_grammar::RegularGrammar;
 
+use crate::lexical::{Grammar, GrammarToken, TokenClass};
+
 /// Day Time Duration.
 ///
 /// ```abnf
@@ -24,6 +26,30 @@ use static_regular_grammar::RegularGrammar;
 #[grammar(sized(DayTimeDurationBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
 pub struct DayTimeDuration(str);
 
+impl Grammar for DayTimeDuration {
+	const ABNF: &'static str = r#"duration = [ "-" ] %s"P" day-time
+
+day-time = (day [ time ]) / time
+
+day = 1*DIGIT %s"D"
+
+time = %s"T" ((hour [ minute ] [ second ]) / (minute [ second ]) / second)
+
+hour = 1*DIGIT %s"H"
+
+minute = 1*DIGIT %s"M"
+
+second = ((1*DIGIT ["." *DIGIT] ) / "." 1*DIGIT) %s"S"
+"#;
+
+	const TOKENS: &'static [GrammarToken] = &[
+		GrammarToken::new("day", TokenClass::Component),
+		GrammarToken::new("hour", TokenClass::Component),
+		GrammarToken::new("minute", TokenClass::Component),
+		GrammarToken::new("second", TokenClass::Component),
+	];
+}
+
 impl DayTimeDuration {
 	pub fn parts(&self) -> Parts {
 		enum State {
@@ -102,10 +128,10 @@ impl Lexical for DayTimeDuration {
 }
 
 impl LexicalFormOf<crate::DayTimeDuration> for DayTimeDuration {
-	type ValueError = std::convert::Infallible;
+	type ValueError = crate::DurationOverflow;
 
 	fn try_as_value(&self) -> Result<crate::DayTimeDuration, Self::ValueError> {
-		Ok(self.parts().to_duration())
+		self.parts().to_duration()
 	}
 }
 
@@ -134,33 +160,26 @@ impl<'a> Parts<'a> {
 			second,
 		}
 	}
-	fn to_duration(&self) -> crate::DayTimeDuration {
-		let mut seconds = 0u32;
-
-		if let Some(d) = self.day {
-			let d: u32 = d.parse().unwrap();
-			seconds += d * 24 * 60 * 60;
-		}
-
-		if let Some(h) = self.hour {
-			let h: u32 = h.parse().unwrap();
-			seconds += h * 60 * 60;
-		}
-
-		if let Some(m) = self.minute {
-			let m: u32 = m.parse().unwrap();
-			seconds += m * 60;
-		}
+	fn to_duration(&self) -> Result<crate::DayTimeDuration, crate::DurationOverflow> {
+		let mut seconds = sum_components(&[
+			(self.day, 24 * 60 * 60),
+			(self.hour, 60 * 60),
+			(self.minute, 60),
+		])?;
 
 		let mut nano_seconds = 0u32;
 
 		if let Some(s) = self.second {
-			let (s, ns) = parse_seconds_decimal(s);
-			seconds += s;
+			let (s, ns) = try_parse_seconds_decimal(s).ok_or(crate::DurationOverflow)?;
+			seconds = seconds.checked_add(s).ok_or(crate::DurationOverflow)?;
 			nano_seconds = ns;
 		}
 
-		crate::DayTimeDuration::new(self.is_negative, seconds, nano_seconds)
+		Ok(crate::DayTimeDuration::new(
+			self.is_negative,
+			seconds,
+			nano_seconds,
+		))
 	}
 }
 
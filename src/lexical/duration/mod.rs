@@ -1,6 +1,11 @@
-use super::{date_time::parse_seconds_decimal, Lexical, LexicalFormOf};
+use super::{
+	date_time::{parse_nanoseconds, try_parse_seconds_decimal},
+	Lexical, LexicalFormOf, Overflow,
+};
 use static_regular_grammar::RegularGrammar;
 
+use crate::lexical::{Grammar, GrammarToken, TokenClass};
+
 pub mod day_time_duration;
 pub use day_time_duration::{DayTimeDuration, DayTimeDurationBuf, InvalidDayTimeDuration};
 
@@ -34,6 +39,38 @@ pub use year_month_duration::{InvalidYearMonthDuration, YearMonthDuration, YearM
 #[grammar(sized(DurationBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
 pub struct Duration(str);
 
+impl Grammar for Duration {
+	const ABNF: &'static str = r#"duration = [ "-" ] %s"P" ((year-month [ day-time ]) / day-time)
+
+year-month = (year [ month ]) / month
+
+year = 1*DIGIT %s"Y"
+
+month = 1*DIGIT %s"M"
+
+day-time = (day [ time ]) / time
+
+day = 1*DIGIT %s"D"
+
+time = %s"T" ((hour [ minute ] [ second ]) / (minute [ second ]) / second)
+
+hour = 1*DIGIT %s"H"
+
+minute = 1*DIGIT %s"M"
+
+second = ((1*DIGIT ["." *DIGIT] ) / "." 1*DIGIT) %s"S"
+"#;
+
+	const TOKENS: &'static [GrammarToken] = &[
+		GrammarToken::new("year", TokenClass::Component),
+		GrammarToken::new("month", TokenClass::Component),
+		GrammarToken::new("day", TokenClass::Component),
+		GrammarToken::new("hour", TokenClass::Component),
+		GrammarToken::new("minute", TokenClass::Component),
+		GrammarToken::new("second", TokenClass::Component),
+	];
+}
+
 impl Duration {
 	pub fn parts(&self) -> Parts {
 		enum State {
@@ -113,6 +150,180 @@ impl Duration {
 
 		result
 	}
+
+	/// Checks if the duration is negative (starts with `-`).
+	pub fn is_negative(&self) -> bool {
+		self.0.starts_with('-')
+	}
+
+	/// Returns the number of years, if the duration has a year component.
+	///
+	/// Returns `Ok(None)` if the component is absent, and an error if it does
+	/// not fit in a `u64`.
+	pub fn years(&self) -> Result<Option<u64>, Overflow> {
+		parse_integer_component(self.parts().year)
+	}
+
+	/// Returns the number of months, if the duration has a month component.
+	///
+	/// Returns `Ok(None)` if the component is absent, and an error if it does
+	/// not fit in a `u64`.
+	pub fn months(&self) -> Result<Option<u64>, Overflow> {
+		parse_integer_component(self.parts().month)
+	}
+
+	/// Returns the number of days, if the duration has a day component.
+	///
+	/// Returns `Ok(None)` if the component is absent, and an error if it does
+	/// not fit in a `u64`.
+	pub fn days(&self) -> Result<Option<u64>, Overflow> {
+		parse_integer_component(self.parts().day)
+	}
+
+	/// Returns the number of hours, if the duration has an hour component.
+	///
+	/// Returns `Ok(None)` if the component is absent, and an error if it does
+	/// not fit in a `u64`.
+	pub fn hours(&self) -> Result<Option<u64>, Overflow> {
+		parse_integer_component(self.parts().hour)
+	}
+
+	/// Returns the number of minutes, if the duration has a minute component.
+	///
+	/// Returns `Ok(None)` if the component is absent, and an error if it does
+	/// not fit in a `u64`.
+	pub fn minutes(&self) -> Result<Option<u64>, Overflow> {
+		parse_integer_component(self.parts().minute)
+	}
+
+	/// Returns the number of seconds and nanoseconds, if the duration has a
+	/// second component.
+	///
+	/// Digits beyond the nanosecond are ignored. Returns `Ok(None)` if the
+	/// component is absent, and an error if its integer part does not fit in
+	/// a `u64`.
+	///
+	/// ```
+	/// use xsd_types::lexical::Duration;
+	///
+	/// let duration = Duration::new("-P1Y2DT.25S").unwrap();
+	/// assert!(duration.is_negative());
+	/// assert_eq!(duration.years(), Ok(Some(1)));
+	/// assert_eq!(duration.months(), Ok(None));
+	/// assert_eq!(duration.days(), Ok(Some(2)));
+	/// assert_eq!(duration.fractional_seconds(), Ok(Some((0, 250_000_000))));
+	/// ```
+	pub fn fractional_seconds(&self) -> Result<Option<(u64, u32)>, Overflow> {
+		let Some(second) = self.parts().second else {
+			return Ok(None);
+		};
+
+		let (integer, fraction) = second.split_once('.').unwrap_or((second, ""));
+		let seconds = if integer.is_empty() {
+			0
+		} else {
+			integer.parse().map_err(|_| Overflow::new(second, "u64"))?
+		};
+
+		Ok(Some((seconds, parse_nanoseconds(fraction))))
+	}
+}
+
+/// Parses an integer duration component, checking for overflow.
+fn parse_integer_component(value: Option<&str>) -> Result<Option<u64>, Overflow> {
+	value
+		.map(|value| value.parse().map_err(|_| Overflow::new(value, "u64")))
+		.transpose()
+}
+
+impl<T: AsRef<str>> InvalidDuration<T> {
+	/// Returns the reason why the input is not a valid duration lexical form,
+	/// or `None` if it actually is one.
+	pub fn reason(&self) -> Option<DurationError> {
+		Parts::parse(self.0.as_ref()).err()
+	}
+}
+
+/// Duration component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DurationComponent {
+	Year,
+	Month,
+	Day,
+	Hour,
+	Minute,
+	Second,
+}
+
+impl DurationComponent {
+	fn is_valid_number(self, s: &str) -> bool {
+		fn is_digits(s: &str) -> bool {
+			s.bytes().all(|c| c.is_ascii_digit())
+		}
+
+		match self {
+			Self::Second => {
+				let (integer_part, fractional_part) = s.split_once('.').unwrap_or((s, ""));
+				is_digits(integer_part)
+					&& is_digits(fractional_part)
+					&& !(integer_part.is_empty() && fractional_part.is_empty())
+			}
+			_ => !s.is_empty() && is_digits(s),
+		}
+	}
+}
+
+impl std::fmt::Display for DurationComponent {
+	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+		match self {
+			Self::Year => write!(f, "year"),
+			Self::Month => write!(f, "month"),
+			Self::Day => write!(f, "day"),
+			Self::Hour => write!(f, "hour"),
+			Self::Minute => write!(f, "minute"),
+			Self::Second => write!(f, "second"),
+		}
+	}
+}
+
+/// Detailed duration error.
+///
+/// See [`Parts::parse`] and [`Parts::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum DurationError {
+	/// The input does not start with the `P` duration designator (optionally
+	/// preceded by a `-` sign).
+	#[error("missing `P` duration designator")]
+	MissingDurationDesignator,
+
+	/// The duration has no component (`P`).
+	#[error("duration has no component")]
+	NoComponent,
+
+	/// The `T` time designator is not followed by any time component (`P1DT`).
+	#[error("no time component after the `T` designator")]
+	NoTimeComponent,
+
+	/// The `T` time designator appears twice.
+	#[error("unexpected `T` time designator")]
+	UnexpectedTimeDesignator,
+
+	/// A number is not followed by any component designator (`P1`).
+	#[error("missing component designator")]
+	MissingComponentDesignator,
+
+	/// The number of a component is malformed (`P1Y-2M`).
+	#[error("malformed {0} component")]
+	MalformedComponent(DurationComponent),
+
+	/// A component is repeated, out of order (`P1M2Y`) or on the wrong side
+	/// of the `T` time designator (`P1H`).
+	#[error("unexpected {0} component")]
+	UnexpectedComponent(DurationComponent),
+
+	/// A component makes the duration overflow the supported value range.
+	#[error("{0} component out of range")]
+	OutOfRange(DurationComponent),
 }
 
 impl Lexical for Duration {
@@ -124,10 +335,10 @@ impl Lexical for Duration {
 }
 
 impl LexicalFormOf<crate::Duration> for Duration {
-	type ValueError = std::convert::Infallible;
+	type ValueError = crate::DurationOverflow;
 
 	fn try_as_value(&self) -> Result<crate::Duration, Self::ValueError> {
-		Ok(self.parts().to_duration())
+		self.parts().to_duration()
 	}
 }
 
@@ -162,48 +373,194 @@ impl<'a> Parts<'a> {
 			second,
 		}
 	}
-	fn to_duration(&self) -> crate::Duration {
-		let mut months = 0u32;
+	/// Parses the parts of a duration lexical form, reporting in detail why
+	/// the input is invalid.
+	///
+	/// This accepts exactly the lexical forms accepted by [`Duration::new`].
+	pub fn parse(value: &'a str) -> Result<Self, DurationError> {
+		use DurationComponent::*;
+
+		let (is_negative, unsigned) = match value.strip_prefix('-') {
+			Some(rest) => (true, rest),
+			None => (false, value),
+		};
+
+		let body = unsigned
+			.strip_prefix('P')
+			.ok_or(DurationError::MissingDurationDesignator)?;
+
+		let mut result = Self::new(is_negative, None, None, None, None, None, None);
+		let mut time = false;
+		let mut last = None;
+		let mut start = 0;
+
+		for (i, c) in body.char_indices() {
+			let component = match (c, time) {
+				('T', false) => {
+					if start != i {
+						return Err(DurationError::MissingComponentDesignator);
+					}
+
+					time = true;
+					start = i + 1;
+					continue;
+				}
+				('T', true) => return Err(DurationError::UnexpectedTimeDesignator),
+				('Y', false) => Year,
+				('M', false) => Month,
+				('D', false) => Day,
+				('H', true) => Hour,
+				('M', true) => Minute,
+				('S', true) => Second,
+				('Y', true) => return Err(DurationError::UnexpectedComponent(Year)),
+				('D', true) => return Err(DurationError::UnexpectedComponent(Day)),
+				('H', false) => return Err(DurationError::UnexpectedComponent(Hour)),
+				('S', false) => return Err(DurationError::UnexpectedComponent(Second)),
+				_ => continue,
+			};
+
+			if last.is_some_and(|last| last >= component) {
+				return Err(DurationError::UnexpectedComponent(component));
+			}
+
+			let number = &body[start..i];
+			if !component.is_valid_number(number) {
+				return Err(DurationError::MalformedComponent(component));
+			}
 
-		if let Some(y) = self.year {
-			let y: u32 = y.parse().unwrap();
-			months += y * 12;
+			*result.component_mut(component) = Some(number);
+			last = Some(component);
+			start = i + 1;
 		}
 
-		if let Some(m) = self.month {
-			let m: u32 = m.parse().unwrap();
-			months += m;
+		if start != body.len() {
+			return Err(DurationError::MissingComponentDesignator);
 		}
 
-		let mut seconds = 0u32;
+		if time && last.map_or(true, |last| last < Hour) {
+			return Err(DurationError::NoTimeComponent);
+		}
 
-		if let Some(d) = self.day {
-			let d: u32 = d.parse().unwrap();
-			seconds += d * 24 * 60 * 60;
+		if last.is_none() {
+			return Err(DurationError::NoComponent);
 		}
 
-		if let Some(h) = self.hour {
-			let h: u32 = h.parse().unwrap();
-			seconds += h * 60 * 60;
+		Ok(result)
+	}
+
+	fn component_mut(&mut self, component: DurationComponent) -> &mut Option<&'a str> {
+		match component {
+			DurationComponent::Year => &mut self.year,
+			DurationComponent::Month => &mut self.month,
+			DurationComponent::Day => &mut self.day,
+			DurationComponent::Hour => &mut self.hour,
+			DurationComponent::Minute => &mut self.minute,
+			DurationComponent::Second => &mut self.second,
 		}
+	}
+
+	/// Checks that these parts describe a valid duration.
+	///
+	/// The duration must have at least one component, every component must be
+	/// a well-formed number, and the duration must fit in the value space
+	/// supported by [`crate::Duration`].
+	pub fn validate(&self) -> Result<(), DurationError> {
+		use DurationComponent::*;
+
+		let components = [
+			(Year, self.year),
+			(Month, self.month),
+			(Day, self.day),
+			(Hour, self.hour),
+			(Minute, self.minute),
+			(Second, self.second),
+		];
 
-		if let Some(m) = self.minute {
-			let m: u32 = m.parse().unwrap();
-			seconds += m * 60;
+		if components.iter().all(|(_, value)| value.is_none()) {
+			return Err(DurationError::NoComponent);
 		}
 
+		let mut months = 0u32;
+		let mut seconds = 0u32;
+
+		for (component, value) in components {
+			let Some(value) = value else { continue };
+
+			if !component.is_valid_number(value) {
+				return Err(DurationError::MalformedComponent(component));
+			}
+
+			let (total, factor) = match component {
+				Year => (&mut months, 12),
+				Month => (&mut months, 1),
+				Day => (&mut seconds, 24 * 60 * 60),
+				Hour => (&mut seconds, 60 * 60),
+				Minute => (&mut seconds, 60),
+				Second => (&mut seconds, 1),
+			};
+
+			*total =
+				add_component(*total, value, factor).ok_or(DurationError::OutOfRange(component))?;
+		}
+
+		Ok(())
+	}
+
+	fn to_duration(&self) -> Result<crate::Duration, crate::DurationOverflow> {
+		let months = sum_components(&[(self.year, 12), (self.month, 1)])?;
+		let mut seconds = sum_components(&[
+			(self.day, 24 * 60 * 60),
+			(self.hour, 60 * 60),
+			(self.minute, 60),
+		])?;
+
 		let mut nano_seconds = 0u32;
 
 		if let Some(s) = self.second {
-			let (s, ns) = parse_seconds_decimal(s);
-			seconds += s;
+			let (s, ns) = try_parse_seconds_decimal(s).ok_or(crate::DurationOverflow)?;
+			seconds = seconds.checked_add(s).ok_or(crate::DurationOverflow)?;
 			nano_seconds = ns;
 		}
 
-		crate::Duration::new(self.is_negative, months, seconds, nano_seconds)
+		Ok(crate::Duration::new(
+			self.is_negative,
+			months,
+			seconds,
+			nano_seconds,
+		))
 	}
 }
 
+/// Adds the integer part of the given duration component, multiplied by its
+/// factor, to `total`, returning `None` if the result overflows.
+fn add_component(total: u32, value: &str, factor: u32) -> Option<u32> {
+	let integer_part = match value.split_once('.') {
+		Some(("", _)) => "0",
+		Some((integer_part, _)) => integer_part,
+		None => value,
+	};
+
+	integer_part
+		.parse::<u32>()
+		.ok()?
+		.checked_mul(factor)?
+		.checked_add(total)
+}
+
+/// Computes the sum of the given integer duration components, multiplied by
+/// their factor, failing if the result overflows.
+pub(crate) fn sum_components(
+	components: &[(Option<&str>, u32)],
+) -> Result<u32, crate::DurationOverflow> {
+	components
+		.iter()
+		.filter_map(|(value, factor)| value.map(|value| (value, *factor)))
+		.try_fold(0u32, |total, (value, factor)| {
+			add_component(total, value, factor)
+		})
+		.ok_or(crate::DurationOverflow)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -239,9 +596,91 @@ mod tests {
 		for (input, parts, normalized) in vectors {
 			let lexical_repr = Duration::new(input).unwrap();
 			assert_eq!(lexical_repr.parts(), parts);
+			assert_eq!(Parts::parse(input).unwrap(), parts);
+			assert!(parts.validate().is_ok());
 
 			let value = lexical_repr.try_as_value().unwrap();
 			assert_eq!(value.to_string().as_str(), normalized)
 		}
 	}
+
+	#[test]
+	fn detailed_errors() {
+		use DurationComponent::*;
+
+		for (input, error) in [
+			("", DurationError::MissingDurationDesignator),
+			("1Y", DurationError::MissingDurationDesignator),
+			("P", DurationError::NoComponent),
+			("-P", DurationError::NoComponent),
+			("PT", DurationError::NoTimeComponent),
+			("P1DT", DurationError::NoTimeComponent),
+			("P1Y-2M", DurationError::MalformedComponent(Month)),
+			("PT.S", DurationError::MalformedComponent(Second)),
+			("P1M2Y", DurationError::UnexpectedComponent(Year)),
+			("P1Y1Y", DurationError::UnexpectedComponent(Year)),
+			("P1H", DurationError::UnexpectedComponent(Hour)),
+			("PT1D", DurationError::UnexpectedComponent(Day)),
+			("P1DT1HT", DurationError::UnexpectedTimeDesignator),
+			("P1", DurationError::MissingComponentDesignator),
+			("P1T1H", DurationError::MissingComponentDesignator),
+		] {
+			assert!(Duration::new(input).is_err());
+			assert_eq!(Parts::parse(input), Err(error), "{input:?}");
+			assert_eq!(
+				Duration::parse(input).unwrap_err().reason(),
+				Some(error),
+				"{input:?}"
+			)
+		}
+
+		assert_eq!(
+			Parts::parse("P1000000000Y").unwrap().validate(),
+			Err(DurationError::OutOfRange(Year))
+		);
+		assert_eq!(
+			Parts::new(false, None, None, None, None, None, None).validate(),
+			Err(DurationError::NoComponent)
+		)
+	}
+
+	#[test]
+	fn value_out_of_range() {
+		for input in [
+			"P99999999999Y",
+			"P400000000Y",
+			"PT99999999999S",
+			"P50000DT1H",
+		] {
+			let lexical_repr = Duration::new(input).unwrap();
+			assert_eq!(
+				lexical_repr.try_as_value().map(|d| d.to_string()),
+				Err(crate::DurationOverflow)
+			);
+		}
+
+		let value = Duration::new("PT.5S").unwrap().try_as_value().unwrap();
+		assert_eq!((value.seconds(), value.nano_seconds()), (0, 500_000_000));
+	}
+
+	#[test]
+	fn typed_fields() {
+		let duration = Duration::new("P99999999999Y1MT2H3M4.0000000015S").unwrap();
+		assert!(!duration.is_negative());
+		assert_eq!(duration.years(), Ok(Some(99_999_999_999)));
+		assert_eq!(duration.months(), Ok(Some(1)));
+		assert_eq!(duration.days(), Ok(None));
+		assert_eq!(duration.hours(), Ok(Some(2)));
+		assert_eq!(duration.minutes(), Ok(Some(3)));
+		assert_eq!(duration.fractional_seconds(), Ok(Some((4, 1))));
+
+		let duration = Duration::new("P99999999999999999999D").unwrap();
+		let e = duration.days().unwrap_err();
+		assert_eq!(e.value(), "99999999999999999999");
+		assert_eq!(e.target(), "u64");
+		assert_eq!(duration.fractional_seconds(), Ok(None));
+
+		let duration = Duration::new("PT99999999999999999999.5S").unwrap();
+		assert!(duration.fractional_seconds().is_err())
+	}
 }
@@ -1,6 +1,8 @@
-use super::{Lexical, LexicalFormOf};
+use super::{sum_components, Lexical, LexicalFormOf};
 use static_regular_grammar::RegularGrammar;
 
+use crate::lexical::{Grammar, GrammarToken, TokenClass};
+
 /// Year Month Duration.
 ///
 /// ```abnf
@@ -16,6 +18,22 @@ use static_regular_grammar::RegularGrammar;
 #[grammar(sized(YearMonthDurationBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
 pub struct YearMonthDuration(str);
 
+impl Grammar for YearMonthDuration {
+	const ABNF: &'static str = r#"duration = [ "-" ] %s"P" year-month
+
+year-month = (year [ month ]) / month
+
+year = 1*DIGIT %s"Y"
+
+month = 1*DIGIT %s"M"
+"#;
+
+	const TOKENS: &'static [GrammarToken] = &[
+		GrammarToken::new("year", TokenClass::Component),
+		GrammarToken::new("month", TokenClass::Component),
+	];
+}
+
 impl YearMonthDuration {
 	pub fn parts(&self) -> Parts {
 		enum State {
@@ -74,10 +92,10 @@ impl Lexical for YearMonthDuration {
 }
 
 impl LexicalFormOf<crate::YearMonthDuration> for YearMonthDuration {
-	type ValueError = std::convert::Infallible;
+	type ValueError = crate::DurationOverflow;
 
 	fn try_as_value(&self) -> Result<crate::YearMonthDuration, Self::ValueError> {
-		Ok(self.parts().to_duration())
+		self.parts().to_duration()
 	}
 }
 
@@ -96,20 +114,9 @@ impl<'a> Parts<'a> {
 			month,
 		}
 	}
-	fn to_duration(&self) -> crate::YearMonthDuration {
-		let mut months = 0u32;
-
-		if let Some(y) = self.year {
-			let y: u32 = y.parse().unwrap();
-			months += y * 12;
-		}
-
-		if let Some(m) = self.month {
-			let m: u32 = m.parse().unwrap();
-			months += m;
-		}
-
-		crate::YearMonthDuration::new(self.is_negative, months)
+	fn to_duration(&self) -> Result<crate::YearMonthDuration, crate::DurationOverflow> {
+		let months = sum_components(&[(self.year, 12), (self.month, 1)])?;
+		Ok(crate::YearMonthDuration::new(self.is_negative, months))
 	}
 }
 
@@ -1,4 +1,7 @@
-use super::{lexical_form, Decimal, Integer, NonNegativeInteger, NonPositiveInteger, Overflow};
+use super::{
+	lexical_form, non_zero_conversion, Decimal, Integer, NonNegativeInteger, NonPositiveInteger,
+	Overflow,
+};
 use std::borrow::{Borrow, ToOwned};
 use std::fmt;
 use std::hash::Hash;
@@ -33,9 +36,9 @@ lexical_form! {
 	parent_forms: {}
 }
 
-pub const NAN: &Float = unsafe { Float::new_unchecked_from_slice(b"NaN") };
-pub const POSITIVE_INFINITY: &Float = unsafe { Float::new_unchecked_from_slice(b"INF") };
-pub const NEGATIVE_INFINITY: &Float = unsafe { Float::new_unchecked_from_slice(b"-INF") };
+pub const NAN: &Float = Float::cast(b"NaN");
+pub const POSITIVE_INFINITY: &Float = Float::cast(b"INF");
+pub const NEGATIVE_INFINITY: &Float = Float::cast(b"-INF");
 
 impl Float {
 	pub fn is_infinite(&self) -> bool {
@@ -63,8 +66,8 @@ impl Float {
 	pub fn mantissa(&self) -> Option<&Decimal> {
 		if self.is_finite() {
 			Some(match self.exponent_separator_index() {
-				Some(e) => unsafe { Decimal::new_unchecked(&self[..e]) },
-				None => unsafe { Decimal::new_unchecked(self) },
+				Some(e) => unchecked!(Decimal, &self[..e]),
+				None => unchecked!(Decimal, self),
 			})
 		} else {
 			None
@@ -74,7 +77,7 @@ impl Float {
 	pub fn exponent(&self) -> Option<&Integer> {
 		if self.is_finite() {
 			self.exponent_separator_index()
-				.map(|e| unsafe { Integer::new_unchecked(&self[(e + 1)..]) })
+				.map(|e| unchecked!(Integer, &self[(e + 1)..]))
 		} else {
 			None
 		}
@@ -104,7 +107,7 @@ macro_rules! integer_conversion {
 		$(
 			impl From<$ty> for FloatBuf {
 				fn from(i: $ty) -> Self {
-					unsafe { FloatBuf::new_unchecked(i.to_string()) }
+					unchecked!(FloatBuf, i.to_string())
 				}
 			}
 
@@ -112,7 +115,9 @@ macro_rules! integer_conversion {
 				type Error = Overflow;
 
 				fn try_from(i: &'a Float) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					i.as_str()
+						.parse()
+						.map_err(|_| Overflow::new(i.as_str(), stringify!($ty)))
 				}
 			}
 
@@ -120,7 +125,9 @@ macro_rules! integer_conversion {
 				type Error = Overflow;
 
 				fn try_from(i: FloatBuf) -> Result<Self, Overflow> {
-					i.as_str().parse().map_err(|_| Overflow)
+					i.as_str()
+						.parse()
+						.map_err(|_| Overflow::new(i.as_str(), stringify!($ty)))
 				}
 			}
 		)*
@@ -136,16 +143,20 @@ integer_conversion! {
 	i32,
 	u64,
 	i64,
+	u128,
+	i128,
 	usize,
 	isize
 }
 
+non_zero_conversion!(Float, FloatBuf);
+
 const DTOA_CONFIG: pretty_dtoa::FmtFloatConfig = pretty_dtoa::FmtFloatConfig::default();
 
 impl From<f32> for FloatBuf {
 	fn from(i: f32) -> Self {
 		if i.is_finite() {
-			unsafe { FloatBuf::new_unchecked(pretty_dtoa::ftoa(i, DTOA_CONFIG)) }
+			unchecked!(FloatBuf, pretty_dtoa::ftoa(i, DTOA_CONFIG))
 		} else if i.is_nan() {
 			FloatBuf::nan()
 		} else if i.is_sign_positive() {
@@ -171,7 +182,7 @@ impl From<FloatBuf> for f32 {
 impl From<f64> for FloatBuf {
 	fn from(i: f64) -> Self {
 		if i.is_finite() {
-			unsafe { FloatBuf::new_unchecked(pretty_dtoa::dtoa(i, DTOA_CONFIG)) }
+			unchecked!(FloatBuf, pretty_dtoa::dtoa(i, DTOA_CONFIG))
 		} else if i.is_nan() {
 			FloatBuf::nan()
 		} else if i.is_sign_positive() {
@@ -185,21 +196,21 @@ impl From<f64> for FloatBuf {
 impl<'a> From<&'a Integer> for &'a Float {
 	#[inline(always)]
 	fn from(d: &'a Integer) -> Self {
-		unsafe { Float::new_unchecked(d) }
+		unchecked!(Float, d)
 	}
 }
 
 impl<'a> From<&'a NonNegativeInteger> for &'a Float {
 	#[inline(always)]
 	fn from(d: &'a NonNegativeInteger) -> Self {
-		unsafe { Float::new_unchecked(d) }
+		unchecked!(Float, d)
 	}
 }
 
 impl<'a> From<&'a NonPositiveInteger> for &'a Float {
 	#[inline(always)]
 	fn from(d: &'a NonPositiveInteger) -> Self {
-		unsafe { Float::new_unchecked(d) }
+		unchecked!(Float, d)
 	}
 }
 
@@ -249,7 +260,7 @@ fn check_normal<C: Iterator<Item = u8>>(mut chars: C) -> bool {
 			},
 			State::NonEmptyInteger => match chars.next() {
 				Some(b'0'..=b'9') => State::Integer,
-				Some(b'.') => State::Decimal,
+				Some(b'.') => State::NonEmptyDecimal,
 				_ => break false,
 			},
 			State::Integer => match chars.next() {
@@ -410,6 +421,12 @@ mod tests {
 		assert_eq!(a, b)
 	}
 
+	#[test]
+	#[should_panic]
+	fn parse_23() {
+		Float::new("+.E1").unwrap();
+	}
+
 	#[test]
 	fn format_01() {
 		assert_eq!(FloatBuf::from(1.0e10f32).to_string(), "1.0e10")
@@ -2,7 +2,7 @@ use static_regular_grammar::RegularGrammar;
 
 use crate::lexical::parse_timezone;
 
-use super::{Lexical, LexicalFormOf};
+use super::{Grammar, GrammarToken, Lexical, LexicalFormOf, TokenClass};
 
 /// GDay.
 ///
@@ -24,6 +24,27 @@ use super::{Lexical, LexicalFormOf};
 #[grammar(sized(GDayBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
 pub struct GDay(str);
 
+impl Grammar for GDay {
+	const ABNF: &'static str = r#"g-day = "---" day [timezone]
+
+day = "0" NZDIGIT
+    / ("1" / "2") DIGIT
+    / "3" ("0" / "1")
+
+minute = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
+
+timezone = ("+" / "-") ((("0" DIGIT / "1" ("0" / "1" / "2" / "3")) ":" minute) / "14:00")
+         / %s"Z"
+
+NZDIGIT = "1" / "2" / "3" / "4" / "5" / "6" / "7" / "8" / "9"
+"#;
+
+	const TOKENS: &'static [GrammarToken] = &[
+		GrammarToken::new("day", TokenClass::Number),
+		GrammarToken::new("timezone", TokenClass::Timezone),
+	];
+}
+
 impl GDay {
 	pub fn parts(&self) -> Parts {
 		Parts {
@@ -35,6 +56,12 @@ impl GDay {
 			},
 		}
 	}
+
+	/// Checks if the timezone is written with the `Z` designator. See
+	/// [`DateTime::timezone_is_z`](super::DateTime::timezone_is_z).
+	pub fn timezone_is_z(&self) -> Option<bool> {
+		self.parts().timezone.map(|tz| tz == "Z")
+	}
 }
 
 impl Lexical for GDay {
@@ -2,7 +2,7 @@ use static_regular_grammar::RegularGrammar;
 
 use crate::lexical::parse_timezone;
 
-use super::{Lexical, LexicalFormOf};
+use super::{Grammar, GrammarToken, Lexical, LexicalFormOf, TokenClass};
 
 /// GMonth.
 ///
@@ -23,6 +23,26 @@ use super::{Lexical, LexicalFormOf};
 #[grammar(sized(GMonthBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
 pub struct GMonth(str);
 
+impl Grammar for GMonth {
+	const ABNF: &'static str = r#"g-month = "--" month [timezone]
+
+month = "0" NZDIGIT
+      / "1" ( "0" / "1" / "2" )
+
+minute = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
+
+timezone = ("+" / "-") ((("0" DIGIT / "1" ("0" / "1" / "2" / "3")) ":" minute) / "14:00")
+         / %s"Z"
+
+NZDIGIT = "1" / "2" / "3" / "4" / "5" / "6" / "7" / "8" / "9"
+"#;
+
+	const TOKENS: &'static [GrammarToken] = &[
+		GrammarToken::new("month", TokenClass::Number),
+		GrammarToken::new("timezone", TokenClass::Timezone),
+	];
+}
+
 impl GMonth {
 	pub fn parts(&self) -> Parts {
 		Parts {
@@ -34,6 +54,12 @@ impl GMonth {
 			},
 		}
 	}
+
+	/// Checks if the timezone is written with the `Z` designator. See
+	/// [`DateTime::timezone_is_z`](super::DateTime::timezone_is_z).
+	pub fn timezone_is_z(&self) -> Option<bool> {
+		self.parts().timezone.map(|tz| tz == "Z")
+	}
 }
 
 impl Lexical for GMonth {
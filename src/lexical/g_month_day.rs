@@ -2,7 +2,7 @@ use static_regular_grammar::RegularGrammar;
 
 use crate::lexical::parse_timezone;
 
-use super::{Lexical, LexicalFormOf};
+use super::{Grammar, GrammarToken, Lexical, LexicalFormOf, TokenClass};
 
 /// GMonthDay.
 ///
@@ -27,6 +27,31 @@ use super::{Lexical, LexicalFormOf};
 #[grammar(sized(GMonthDayBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
 pub struct GMonthDay(str);
 
+impl Grammar for GMonthDay {
+	const ABNF: &'static str = r#"g-month-day = "--" month "-" day [timezone]
+
+month = "0" NZDIGIT
+      / "1" ( "0" / "1" / "2" )
+
+day = "0" NZDIGIT
+    / ("1" / "2") DIGIT
+    / "3" ("0" / "1")
+
+minute = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
+
+timezone = ("+" / "-") ((("0" DIGIT / "1" ("0" / "1" / "2" / "3")) ":" minute) / "14:00")
+         / %s"Z"
+
+NZDIGIT = "1" / "2" / "3" / "4" / "5" / "6" / "7" / "8" / "9"
+"#;
+
+	const TOKENS: &'static [GrammarToken] = &[
+		GrammarToken::new("month", TokenClass::Number),
+		GrammarToken::new("day", TokenClass::Number),
+		GrammarToken::new("timezone", TokenClass::Timezone),
+	];
+}
+
 impl GMonthDay {
 	pub fn parts(&self) -> Parts {
 		Parts {
@@ -39,6 +64,12 @@ impl GMonthDay {
 			},
 		}
 	}
+
+	/// Checks if the timezone is written with the `Z` designator. See
+	/// [`DateTime::timezone_is_z`](super::DateTime::timezone_is_z).
+	pub fn timezone_is_z(&self) -> Option<bool> {
+		self.parts().timezone.map(|tz| tz == "Z")
+	}
 }
 
 impl Lexical for GMonthDay {
@@ -103,4 +134,16 @@ mod tests {
 			assert_eq!(value.to_string().as_str(), input)
 		}
 	}
+	#[test]
+	fn timezone_is_z() {
+		assert_eq!(
+			GMonthDay::new("--03-31Z").unwrap().timezone_is_z(),
+			Some(true)
+		);
+		assert_eq!(
+			GMonthDay::new("--03-31+00:00").unwrap().timezone_is_z(),
+			Some(false)
+		);
+		assert_eq!(GMonthDay::new("--03-31").unwrap().timezone_is_z(), None)
+	}
 }
@@ -2,7 +2,7 @@ use static_regular_grammar::RegularGrammar;
 
 use crate::{lexical::parse_timezone, utils::byte_index_of};
 
-use super::{Lexical, LexicalFormOf};
+use super::{Grammar, GrammarToken, Lexical, LexicalFormOf, TokenClass};
 
 /// GYear.
 ///
@@ -11,10 +11,11 @@ use super::{Lexical, LexicalFormOf};
 ///
 /// year = [ "-" ] year-number
 ///
-/// year-number = *3DIGIT NZDIGIT
-///             / *2DIGIT NZDIGIT DIGIT
-///             / *1DIGIT NZDIGIT 2DIGIT
+/// year-number = "000" NZDIGIT
+///             / "00" NZDIGIT DIGIT
+///             / "0" NZDIGIT 2DIGIT
 ///             / NZDIGIT 3*DIGIT
+///             / "0000"
 ///
 /// minute = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
 ///
@@ -27,6 +28,31 @@ use super::{Lexical, LexicalFormOf};
 #[grammar(sized(GYearBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
 pub struct GYear(str);
 
+impl Grammar for GYear {
+	const ABNF: &'static str = r#"g-year = year [timezone]
+
+year = [ "-" ] year-number
+
+year-number = "000" NZDIGIT
+            / "00" NZDIGIT DIGIT
+            / "0" NZDIGIT 2DIGIT
+            / NZDIGIT 3*DIGIT
+            / "0000"
+
+minute = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
+
+timezone = ("+" / "-") ((("0" DIGIT / "1" ("0" / "1" / "2" / "3")) ":" minute) / "14:00")
+         / %s"Z"
+
+NZDIGIT = "1" / "2" / "3" / "4" / "5" / "6" / "7" / "8" / "9"
+"#;
+
+	const TOKENS: &'static [GrammarToken] = &[
+		GrammarToken::new("year", TokenClass::Number),
+		GrammarToken::new("timezone", TokenClass::Timezone),
+	];
+}
+
 impl GYear {
 	pub fn parts(&self) -> Parts {
 		let year_end =
@@ -41,6 +67,12 @@ impl GYear {
 			},
 		}
 	}
+
+	/// Checks if the timezone is written with the `Z` designator. See
+	/// [`DateTime::timezone_is_z`](super::DateTime::timezone_is_z).
+	pub fn timezone_is_z(&self) -> Option<bool> {
+		self.parts().timezone.map(|tz| tz == "Z")
+	}
 }
 
 impl Lexical for GYear {
@@ -52,10 +84,10 @@ impl Lexical for GYear {
 }
 
 impl LexicalFormOf<crate::GYear> for GYear {
-	type ValueError = std::convert::Infallible;
+	type ValueError = crate::YearOutOfRange;
 
 	fn try_as_value(&self) -> Result<crate::GYear, Self::ValueError> {
-		Ok(self.parts().to_g_year_month())
+		self.parts().to_g_year()
 	}
 }
 
@@ -70,11 +102,22 @@ impl<'a> Parts<'a> {
 		Self { year, timezone }
 	}
 
-	fn to_g_year_month(&self) -> crate::GYear {
-		crate::GYear::new(
-			self.year.parse().unwrap(),
-			self.timezone.map(parse_timezone),
-		)
+	fn to_g_year(&self) -> Result<crate::GYear, crate::YearOutOfRange> {
+		self.to_g_year_with(crate::YearConvention::default())
+	}
+
+	pub(crate) fn to_g_year_with(
+		&self,
+		convention: crate::YearConvention,
+	) -> Result<crate::GYear, crate::YearOutOfRange> {
+		let year = self
+			.year
+			.parse()
+			.ok()
+			.and_then(|year| convention.to_xsd11(year))
+			.ok_or(crate::YearOutOfRange)?;
+
+		Ok(crate::GYear::new(year, self.timezone.map(parse_timezone)))
 	}
 }
 
@@ -98,4 +141,25 @@ mod tests {
 			assert_eq!(value.to_string().as_str(), input)
 		}
 	}
+
+	#[test]
+	fn short_years() {
+		for input in ["1", "-1", "201", "-201Z"] {
+			assert!(GYear::new(input).is_err())
+		}
+	}
+
+	#[test]
+	fn year_out_of_range() {
+		for input in ["99999999999", "-99999999999Z", "2147483648"] {
+			let lexical_repr = GYear::new(input).unwrap();
+			assert_eq!(
+				lexical_repr.try_as_value().map(|y| y.year()),
+				Err(crate::YearOutOfRange)
+			);
+		}
+
+		assert!("99999999999-01-01".parse::<crate::Date>().is_err());
+		assert!("99999999999-01".parse::<crate::GYearMonth>().is_err());
+	}
 }
@@ -2,7 +2,7 @@ use static_regular_grammar::RegularGrammar;
 
 use crate::{lexical::parse_timezone, utils::byte_index_of};
 
-use super::{Lexical, LexicalFormOf};
+use super::{Grammar, GrammarToken, Lexical, LexicalFormOf, TokenClass};
 
 /// GYearMonth.
 ///
@@ -11,10 +11,11 @@ use super::{Lexical, LexicalFormOf};
 ///
 /// year = [ "-" ] year-number
 ///
-/// year-number = *3DIGIT NZDIGIT
-///             / *2DIGIT NZDIGIT DIGIT
-///             / *1DIGIT NZDIGIT 2DIGIT
+/// year-number = "000" NZDIGIT
+///             / "00" NZDIGIT DIGIT
+///             / "0" NZDIGIT 2DIGIT
 ///             / NZDIGIT 3*DIGIT
+///             / "0000"
 ///
 /// month = "0" NZDIGIT
 ///       / "1" ( "0" / "1" / "2" )
@@ -30,6 +31,35 @@ use super::{Lexical, LexicalFormOf};
 #[grammar(sized(GYearMonthBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
 pub struct GYearMonth(str);
 
+impl Grammar for GYearMonth {
+	const ABNF: &'static str = r#"g-year-month = year "-" month [timezone]
+
+year = [ "-" ] year-number
+
+year-number = "000" NZDIGIT
+            / "00" NZDIGIT DIGIT
+            / "0" NZDIGIT 2DIGIT
+            / NZDIGIT 3*DIGIT
+            / "0000"
+
+month = "0" NZDIGIT
+      / "1" ( "0" / "1" / "2" )
+
+minute = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
+
+timezone = ("+" / "-") ((("0" DIGIT / "1" ("0" / "1" / "2" / "3")) ":" minute) / "14:00")
+         / %s"Z"
+
+NZDIGIT = "1" / "2" / "3" / "4" / "5" / "6" / "7" / "8" / "9"
+"#;
+
+	const TOKENS: &'static [GrammarToken] = &[
+		GrammarToken::new("year", TokenClass::Number),
+		GrammarToken::new("month", TokenClass::Number),
+		GrammarToken::new("timezone", TokenClass::Timezone),
+	];
+}
+
 impl GYearMonth {
 	pub fn parts(&self) -> Parts {
 		let year_end = byte_index_of(self.0.as_bytes(), 4, b'-').unwrap();
@@ -45,6 +75,12 @@ impl GYearMonth {
 			},
 		}
 	}
+
+	/// Checks if the timezone is written with the `Z` designator. See
+	/// [`DateTime::timezone_is_z`](super::DateTime::timezone_is_z).
+	pub fn timezone_is_z(&self) -> Option<bool> {
+		self.parts().timezone.map(|tz| tz == "Z")
+	}
 }
 
 impl Lexical for GYearMonth {
@@ -56,10 +92,10 @@ impl Lexical for GYearMonth {
 }
 
 impl LexicalFormOf<crate::GYearMonth> for GYearMonth {
-	type ValueError = std::convert::Infallible;
+	type ValueError = crate::YearOutOfRange;
 
 	fn try_as_value(&self) -> Result<crate::GYearMonth, Self::ValueError> {
-		Ok(self.parts().to_g_year_month())
+		self.parts().to_g_year_month()
 	}
 }
 
@@ -79,13 +115,27 @@ impl<'a> Parts<'a> {
 		}
 	}
 
-	fn to_g_year_month(&self) -> crate::GYearMonth {
-		crate::GYearMonth::new(
-			self.year.parse().unwrap(),
+	fn to_g_year_month(&self) -> Result<crate::GYearMonth, crate::YearOutOfRange> {
+		self.to_g_year_month_with(crate::YearConvention::default())
+	}
+
+	pub(crate) fn to_g_year_month_with(
+		&self,
+		convention: crate::YearConvention,
+	) -> Result<crate::GYearMonth, crate::YearOutOfRange> {
+		let year = self
+			.year
+			.parse()
+			.ok()
+			.and_then(|year| convention.to_xsd11(year))
+			.ok_or(crate::YearOutOfRange)?;
+
+		Ok(crate::GYearMonth::new(
+			year,
 			self.month.parse().unwrap(),
 			self.timezone.map(parse_timezone),
 		)
-		.unwrap()
+		.unwrap())
 	}
 }
 
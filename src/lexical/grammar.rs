@@ -0,0 +1,159 @@
+/// Class of a grammar token, for syntax highlighting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TokenClass {
+	/// Numeric field (year, month, hour, fractional seconds, etc.).
+	Number,
+
+	/// Duration component: a number followed by its designator (`Y`, `M`,
+	/// `D`, `H` or `S`).
+	Component,
+
+	/// Timezone offset.
+	Timezone,
+
+	/// XML name, or part of a name.
+	Name,
+}
+
+/// ABNF rule matching a token of a given class.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GrammarToken {
+	/// Name of the ABNF rule.
+	pub rule: &'static str,
+
+	/// Class of the tokens matched by the rule.
+	pub class: TokenClass,
+}
+
+impl GrammarToken {
+	pub const fn new(rule: &'static str, class: TokenClass) -> Self {
+		Self { rule, class }
+	}
+}
+
+/// Lexical type defined by an ABNF grammar.
+///
+/// This exposes the authoritative definition of the lexical space, so that
+/// tooling (syntax highlighters, client-side validators, etc.) does not have
+/// to duplicate it.
+///
+/// ```
+/// use xsd_types::lexical::{GYear, Grammar, TokenClass};
+///
+/// assert!(GYear::ABNF.starts_with("g-year = year [timezone]"));
+/// assert!(GYear::TOKENS
+///     .iter()
+///     .any(|t| t.rule == "timezone" && t.class == TokenClass::Timezone));
+/// ```
+pub trait Grammar {
+	/// ABNF grammar (RFC 5234, with RFC 7405 case-sensitive strings) of the
+	/// lexical space.
+	///
+	/// The first rule is the start rule. Core rules (`DIGIT`, `ALPHA`) are
+	/// not included.
+	const ABNF: &'static str;
+
+	/// Rules of [`Self::ABNF`] matching tokens, with their class.
+	const TOKENS: &'static [GrammarToken];
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::lexical::{
+		Date, DateTime, DateTimeStamp, DayTimeDuration, Duration, GDay, GMonth, GMonthDay, GYear,
+		GYearMonth, QName, Time, YearMonthDuration,
+	};
+
+	/// Returns the names of the rules defined by the given grammar.
+	fn rules(abnf: &str) -> Vec<&str> {
+		abnf.lines()
+			.filter(|line| !line.starts_with(' '))
+			.filter_map(|line| line.split_once(" = "))
+			.map(|(name, _)| name)
+			.collect()
+	}
+
+	fn check<G: ?Sized + Grammar>() {
+		let rules = rules(G::ABNF);
+		assert!(!rules.is_empty());
+
+		for token in G::TOKENS {
+			assert!(
+				rules.contains(&token.rule),
+				"undefined rule `{}`",
+				token.rule
+			)
+		}
+	}
+
+	/// Returns the ABNF grammar in the documentation of the given source
+	/// file, from which the lexical type is derived.
+	fn doc_grammar(source: &str) -> String {
+		let mut lines = source
+			.lines()
+			.skip_while(|line| *line != "/// ```abnf")
+			.skip(1)
+			.take_while(|line| *line != "/// ```");
+
+		let mut abnf = String::new();
+		for line in &mut lines {
+			abnf.push_str(
+				line.strip_prefix("///")
+					.unwrap()
+					.strip_prefix(' ')
+					.unwrap_or(""),
+			);
+			abnf.push('\n')
+		}
+
+		abnf
+	}
+
+	#[test]
+	fn abnf_matches_documentation() {
+		for (abnf, source) in [
+			(DateTime::ABNF, include_str!("date_time/mod.rs")),
+			(
+				DateTimeStamp::ABNF,
+				include_str!("date_time/date_time_stamp.rs"),
+			),
+			(Date::ABNF, include_str!("date.rs")),
+			(Time::ABNF, include_str!("time.rs")),
+			(GYearMonth::ABNF, include_str!("g_year_month.rs")),
+			(GYear::ABNF, include_str!("g_year.rs")),
+			(GMonthDay::ABNF, include_str!("g_month_day.rs")),
+			(GDay::ABNF, include_str!("g_day.rs")),
+			(GMonth::ABNF, include_str!("g_month.rs")),
+			(Duration::ABNF, include_str!("duration/mod.rs")),
+			(
+				DayTimeDuration::ABNF,
+				include_str!("duration/day_time_duration.rs"),
+			),
+			(
+				YearMonthDuration::ABNF,
+				include_str!("duration/year_month_duration.rs"),
+			),
+			(QName::ABNF, include_str!("q_name.rs")),
+		] {
+			assert_eq!(abnf, doc_grammar(source))
+		}
+	}
+
+	#[test]
+	fn token_rules_are_defined() {
+		check::<DateTime>();
+		check::<DateTimeStamp>();
+		check::<Date>();
+		check::<Time>();
+		check::<GYearMonth>();
+		check::<GYear>();
+		check::<GMonthDay>();
+		check::<GDay>();
+		check::<GMonth>();
+		check::<Duration>();
+		check::<DayTimeDuration>();
+		check::<YearMonthDuration>();
+		check::<QName>();
+	}
+}
@@ -36,9 +36,35 @@ lexical_form! {
 }
 
 impl HexBinary {
+	/// Returns the hexadecimal digits, with ASCII uppercase letters mapped
+	/// to lowercase.
+	///
+	/// Lexical forms are compared, hashed and ordered through this
+	/// sequence, so that `0a` and `0A` (which denote the same value) are
+	/// equal.
 	#[inline(always)]
-	fn as_canonical_str(&self) -> &str {
-		self.as_str()
+	fn folded_bytes(&self) -> impl '_ + Iterator<Item = u8> {
+		self.as_bytes().iter().map(u8::to_ascii_lowercase)
+	}
+
+	/// Returns this lexical form with ASCII uppercase letters mapped to
+	/// lowercase.
+	///
+	/// Only ASCII letters are mapped, so the result does not depend on the
+	/// locale. Note that the canonical representation of `xsd:hexBinary`
+	/// values uses uppercase letters.
+	///
+	/// ```
+	/// use xsd_types::lexical::HexBinary;
+	///
+	/// let hex = HexBinary::new("0FB7").unwrap();
+	/// assert_eq!(hex.to_ascii_lowercase_canonical().as_str(), "0fb7");
+	/// assert_eq!(hex, HexBinary::new("0fB7").unwrap());
+	/// ```
+	pub fn to_ascii_lowercase_canonical(&self) -> HexBinaryBuf {
+		// SAFETY: mapping ASCII letters to lowercase preserves valid
+		// hexadecimal digits.
+		unchecked!(HexBinaryBuf, self.as_bytes().to_ascii_lowercase())
 	}
 
 	#[inline(always)]
@@ -49,7 +75,7 @@ impl HexBinary {
 
 impl PartialEq for HexBinary {
 	fn eq(&self, other: &Self) -> bool {
-		self.as_canonical_str() == other.as_canonical_str()
+		self.as_bytes().eq_ignore_ascii_case(other.as_bytes())
 	}
 }
 
@@ -57,13 +83,16 @@ impl Eq for HexBinary {}
 
 impl Hash for HexBinary {
 	fn hash<H: Hasher>(&self, h: &mut H) {
-		self.as_canonical_str().hash(h)
+		h.write_usize(self.as_bytes().len());
+		for b in self.folded_bytes() {
+			h.write_u8(b)
+		}
 	}
 }
 
 impl Ord for HexBinary {
 	fn cmp(&self, other: &Self) -> Ordering {
-		self.as_canonical_str().cmp(other.as_canonical_str())
+		self.folded_bytes().cmp(other.folded_bytes())
 	}
 }
 
@@ -75,7 +104,7 @@ impl PartialOrd for HexBinary {
 
 impl Default for HexBinaryBuf {
 	fn default() -> Self {
-		unsafe { Self::new_unchecked(Vec::new()) }
+		unchecked!(Self, Vec::new())
 	}
 }
 
@@ -96,26 +125,56 @@ fn check_bytes(s: &[u8]) -> bool {
 }
 
 fn check<C: Iterator<Item = u8>>(mut chars: C) -> bool {
-	enum State {
-		Data,
-		Padding,
-	}
-
-	let mut state = State::Data;
-
 	loop {
-		state = match state {
-			State::Data => match chars.next() {
-				Some(b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'+' | b'/') => State::Data,
-				Some(b'=') => State::Padding,
-				None => break true,
-				_ => break false,
-			},
-			State::Padding => match chars.next() {
-				Some(b'=') => State::Padding,
-				None => break true,
+		match chars.next() {
+			Some(a) if a.is_ascii_hexdigit() => match chars.next() {
+				Some(b) if b.is_ascii_hexdigit() => (),
 				_ => break false,
 			},
+			Some(_) => break false,
+			None => break true,
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::collections::hash_map::DefaultHasher;
+
+	fn hash(value: &HexBinary) -> u64 {
+		let mut h = DefaultHasher::new();
+		value.hash(&mut h);
+		h.finish()
+	}
+
+	#[test]
+	fn parse() {
+		assert!(HexBinary::new("").is_ok());
+		assert!(HexBinary::new("0fB7").is_ok());
+		assert!(HexBinary::new("0FB").is_err());
+		assert!(HexBinary::new("AQID").is_err());
+		assert!(HexBinary::new("0F=").is_err());
+	}
+
+	#[test]
+	fn ascii_case_folding() {
+		let a = HexBinary::new("0aFf").unwrap();
+		let b = HexBinary::new("0AfF").unwrap();
+		assert_eq!(a, b);
+		assert_eq!(hash(a), hash(b));
+		assert_eq!(a.cmp(b), Ordering::Equal);
+		assert_eq!(a.to_ascii_lowercase_canonical().as_str(), "0aff");
+		assert_eq!(
+			HexBinary::new("0F")
+				.unwrap()
+				.cmp(HexBinary::new("a0").unwrap()),
+			Ordering::Less
+		);
+
+		// Letters that have non-ASCII case mappings in some locales are not
+		// hexadecimal digits.
+		assert!(HexBinary::new("0I").is_err());
+		assert!(HexBinary::new("0\u{130}").is_err());
+	}
+}
@@ -1,6 +1,7 @@
 mod any_uri;
 mod base64_binary;
 mod boolean;
+mod custom;
 pub mod date;
 pub mod date_time;
 mod decimal;
@@ -12,21 +13,26 @@ pub mod g_month;
 pub mod g_month_day;
 pub mod g_year;
 pub mod g_year_month;
+mod grammar;
 mod hex_binary;
 mod q_name;
+mod scan;
 mod string;
 pub mod time;
+mod value_eq;
 
 pub use base64_binary::*;
 pub use boolean::*;
+#[doc(hidden)]
+pub use custom::custom_lexical_cast;
 pub use date::{Date, DateBuf, InvalidDate};
 pub(crate) use date_time::parse_timezone;
 pub use date_time::{DateTime, DateTimeBuf, DateTimeStamp, DateTimeStampBuf, InvalidDateTime};
 pub use decimal::*;
 pub use double::{Double, DoubleBuf, InvalidDouble};
 pub use duration::{
-	DayTimeDuration, DayTimeDurationBuf, Duration, DurationBuf, InvalidDuration, YearMonthDuration,
-	YearMonthDurationBuf,
+	DayTimeDuration, DayTimeDurationBuf, Duration, DurationBuf, DurationComponent, DurationError,
+	InvalidDuration, YearMonthDuration, YearMonthDurationBuf,
 };
 pub use float::{Float, FloatBuf, InvalidFloat};
 pub use g_day::{GDay, GDayBuf, InvalidGDay};
@@ -34,15 +40,40 @@ pub use g_month::{GMonth, GMonthBuf, InvalidGMonth};
 pub use g_month_day::{GMonthDay, GMonthDayBuf, InvalidGMonthDay};
 pub use g_year::{GYear, GYearBuf, InvalidGYear};
 pub use g_year_month::{GYearMonth, GYearMonthBuf, InvalidGYearMonth};
+pub use grammar::*;
 pub use hex_binary::*;
 pub use q_name::*;
 pub use string::*;
 pub use time::{InvalidTime, Time, TimeBuf};
 
+use date_time::InvalidDateTimeStamp;
+use duration::{InvalidDayTimeDuration, InvalidYearMonthDuration};
+
+grammar_errors!(
+	InvalidDate,
+	InvalidDateTime,
+	InvalidDateTimeStamp,
+	InvalidTime,
+	InvalidDuration,
+	InvalidDayTimeDuration,
+	InvalidYearMonthDuration,
+	InvalidGYearMonth,
+	InvalidGYear,
+	InvalidGMonthDay,
+	InvalidGDay,
+	InvalidGMonth,
+	InvalidQName
+);
+
 /// Lexical type.
+///
+/// Unlike [`FromValue`](crate::FromValue), this trait is not sealed: it can
+/// be implemented by lexical types of datatypes defined outside of this
+/// crate, for instance with the [`impl_lexical!`](crate::impl_lexical) macro.
 pub trait Lexical {
 	type Error;
 
+	/// Checks that the input is a valid lexical form.
 	fn parse(value: &str) -> Result<&Self, Self::Error>;
 }
 
@@ -54,7 +85,13 @@ impl Lexical for str {
 	}
 }
 
+/// Lexical form of values of type `V`.
+///
+/// Implementing this trait, along with [`ParseXsd`](crate::ParseXsd) for
+/// `V`, is enough to parse custom values with [`crate::parse`].
 pub trait LexicalFormOf<V>: Lexical {
+	/// Error returned when the lexical form has no corresponding value (for
+	/// instance when it is out of the supported range).
 	type ValueError;
 
 	fn try_as_value(&self) -> Result<V, Self::ValueError>;
@@ -63,9 +100,9 @@ pub trait LexicalFormOf<V>: Lexical {
 	where
 		Self: LexicalFormOf<V, ValueError = std::convert::Infallible>,
 	{
-		unsafe {
-			// SAFETY: the error type is not constructible.
-			self.try_as_value().unwrap_unchecked()
+		match self.try_as_value() {
+			Ok(value) => value,
+			Err(e) => match e {},
 		}
 	}
 }
@@ -89,10 +126,20 @@ macro_rules! lexical_form {
 		as_ref: $as_ref:ident,
 		parent_forms: { $( $as_parent_form:ident: $parent_form:ty, $parent_buf_form:ty ),* }
 	} => {
-		#[derive(Debug)]
+		#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 		pub struct $error_ty;
 
+		impl std::fmt::Display for $error_ty {
+			fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+				f.write_str(concat!("invalid `", stringify!($ty), "` lexical form"))
+			}
+		}
+
+		impl std::error::Error for $error_ty {}
+
 		$(#[$ty_meta])*
+		#[cfg_attr(feature = "forbid-unsafe", derive(ref_cast::RefCastCustom))]
+		#[repr(transparent)]
 		pub struct $ty([u8]);
 
 		$(#[$buffer_ty_meta])*
@@ -104,27 +151,35 @@ macro_rules! lexical_form {
 			#[inline(always)]
 			pub fn new<S: ?Sized + AsRef<[u8]>>(s: &S) -> Result<&Self, $error_ty> {
 				if check_bytes(s.as_ref()) {
-					Ok(unsafe { Self::new_unchecked(s) })
+					Ok(Self::cast(s.as_ref()))
 				} else {
 					Err($error_ty)
 				}
 			}
 
-			$(#[$new_unchecked_meta])*
-			#[inline(always)]
-			pub unsafe fn new_unchecked<S: ?Sized + AsRef<[u8]>>(s: &S) -> &Self {
-				std::mem::transmute(s.as_ref())
+			unchecked_constructor! {
+				$(#[$new_unchecked_meta])*
+				#[inline(always)]
+				pub unsafe fn new_unchecked<S: ?Sized + AsRef<[u8]>>(s: &S) -> &Self {
+					Self::cast(s.as_ref())
+				}
 			}
 
-			$(#[$new_unchecked_meta])*
-			#[inline(always)]
-			pub const unsafe fn new_unchecked_from_slice(s: &[u8]) -> &Self {
-				std::mem::transmute(s)
+			unchecked_constructor! {
+				$(#[$new_unchecked_meta])*
+				#[inline(always)]
+				pub const unsafe fn new_unchecked_from_slice(s: &[u8]) -> &Self {
+					Self::cast(s)
+				}
+			}
+
+			transparent_cast! {
+				const fn cast(s: &[u8]) -> &Self;
 			}
 
 			#[inline(always)]
 			pub fn as_str(&self) -> &str {
-				unsafe { core::str::from_utf8_unchecked(&self.0) }
+				crate::utils::str_from_validated_utf8(&self.0)
 			}
 
 			#[inline(always)]
@@ -136,7 +191,7 @@ macro_rules! lexical_form {
 		impl std::fmt::Debug for $ty {
 			#[inline(always)]
 			fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-				self.as_str().fmt(f)
+				f.debug_tuple(stringify!($ty)).field(&self.as_str()).finish()
 			}
 		}
 
@@ -175,7 +230,7 @@ macro_rules! lexical_form {
 
 			#[inline(always)]
 			fn to_owned(&self) -> $buffer_ty {
-				unsafe { <$buffer_ty>::new_unchecked(self.as_str().to_owned()) }
+				$buffer_ty(self.0.to_vec())
 			}
 		}
 
@@ -200,21 +255,23 @@ macro_rules! lexical_form {
 				s: S,
 			) -> Result<Self, ($error_ty, S)> {
 				if check_bytes(s.as_ref()) {
-					Ok(unsafe { Self::new_unchecked(s) })
+					Ok(Self(s.into()))
 				} else {
 					Err(($error_ty, s))
 				}
 			}
 
-			$(#[$new_unchecked_meta])*
-			#[inline(always)]
-			pub unsafe fn new_unchecked(s: impl Into<Vec<u8>>) -> Self {
-				std::mem::transmute(s.into())
+			unchecked_constructor! {
+				$(#[$new_unchecked_meta])*
+				#[inline(always)]
+				pub unsafe fn new_unchecked(s: impl Into<Vec<u8>>) -> Self {
+					Self(s.into())
+				}
 			}
 
 			#[inline(always)]
 			pub fn $as_ref(&self) -> &$ty {
-				unsafe { <$ty>::new_unchecked(&self.0) }
+				<$ty>::cast(&self.0)
 			}
 
 			#[inline(always)]
@@ -223,12 +280,8 @@ macro_rules! lexical_form {
 			}
 
 			#[inline(always)]
-			pub fn into_string(mut self) -> String {
-				let buf = self.0.as_mut_ptr();
-				let len = self.0.len();
-				let capacity = self.0.capacity();
-				core::mem::forget(self);
-				unsafe { String::from_raw_parts(buf, len, capacity) }
+			pub fn into_string(self) -> String {
+				crate::utils::string_from_validated_utf8(self.0)
 			}
 
 			#[inline(always)]
@@ -290,6 +343,15 @@ macro_rules! lexical_form {
 			}
 		}
 
+		/// Consistent with the hash of the borrowed lexical form, so that
+		/// maps with owned keys can be queried by borrowed keys.
+		impl std::hash::Hash for $buffer_ty {
+			#[inline(always)]
+			fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+				std::hash::Hash::hash(self.$as_ref(), state)
+			}
+		}
+
 		impl fmt::Display for $buffer_ty {
 			#[inline(always)]
 			fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -300,7 +362,7 @@ macro_rules! lexical_form {
 		impl std::fmt::Debug for $buffer_ty {
 			#[inline(always)]
 			fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-				self.0.fmt(f)
+				f.debug_tuple(stringify!($buffer_ty)).field(&self.as_str()).finish()
 			}
 		}
 
@@ -336,7 +398,7 @@ macro_rules! lexical_form {
 			impl<'a> From<&'a $ty> for &'a $parent_form {
 				#[inline(always)]
 				fn from(value: &'a $ty) -> Self {
-					unsafe { <$parent_form>::new_unchecked(value) }
+					unchecked!($parent_form, value)
 				}
 			}
 
@@ -356,7 +418,7 @@ macro_rules! lexical_form {
 				fn try_from(i: $parent_buf_form) -> Result<Self, Self::Error> {
 					match Self::new(i.into_string()) {
 						Ok(d) => Ok(d),
-						Err((e, s)) => Err((e, unsafe { <$parent_buf_form>::new_unchecked(s) })),
+						Err((e, s)) => Err((e, unchecked!($parent_buf_form, s))),
 					}
 				}
 			}
@@ -386,3 +448,77 @@ macro_rules! lexical_form {
 }
 
 pub(crate) use lexical_form;
+
+/// Implements the conversions from a lexical number into the non-zero Rust
+/// integer types, on top of the conversions into the primitive integer
+/// types.
+macro_rules! non_zero_conversion {
+	($ty:ident, $buffer_ty:ident) => {
+		non_zero_conversion! {
+			$ty, $buffer_ty:
+				NonZeroU8(u8), NonZeroI8(i8),
+				NonZeroU16(u16), NonZeroI16(i16),
+				NonZeroU32(u32), NonZeroI32(i32),
+				NonZeroU64(u64), NonZeroI64(i64),
+				NonZeroU128(u128), NonZeroI128(i128),
+				NonZeroUsize(usize), NonZeroIsize(isize)
+		}
+	};
+	($ty:ident, $buffer_ty:ident: $($non_zero:ident($int:ty)),*) => {
+		$(
+			impl<'a> TryFrom<&'a $ty> for std::num::$non_zero {
+				type Error = $crate::lexical::Overflow;
+
+				fn try_from(i: &'a $ty) -> Result<Self, Self::Error> {
+					<$int>::try_from(i)
+						.ok()
+						.and_then(Self::new)
+						.ok_or_else(|| {
+							$crate::lexical::Overflow::new(i.as_str(), stringify!($non_zero))
+						})
+				}
+			}
+
+			impl TryFrom<$buffer_ty> for std::num::$non_zero {
+				type Error = $crate::lexical::Overflow;
+
+				fn try_from(i: $buffer_ty) -> Result<Self, Self::Error> {
+					Self::try_from(&*i)
+				}
+			}
+		)*
+	};
+}
+
+pub(crate) use non_zero_conversion;
+
+/// Implements `Debug` for the sized types generated by `RegularGrammar`,
+/// printing the type name along with the lexical form, as the types defined
+/// with `lexical_form!` do.
+macro_rules! grammar_buffer_debug {
+	($($ty:ident),*) => {
+		$(
+			impl std::fmt::Debug for $ty {
+				fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+					f.debug_tuple(stringify!($ty)).field(&self.as_str()).finish()
+				}
+			}
+		)*
+	};
+}
+
+grammar_buffer_debug!(
+	DateBuf,
+	DateTimeBuf,
+	DateTimeStampBuf,
+	DurationBuf,
+	DayTimeDurationBuf,
+	YearMonthDurationBuf,
+	TimeBuf,
+	GYearMonthBuf,
+	GYearBuf,
+	GMonthDayBuf,
+	GDayBuf,
+	GMonthBuf,
+	QNameBuf
+);
@@ -1,6 +1,6 @@
 use static_regular_grammar::RegularGrammar;
 
-use super::{Lexical, LexicalFormOf};
+use super::{Grammar, GrammarToken, Lexical, LexicalFormOf, TokenClass};
 
 /// Qualified Name.
 ///
@@ -22,9 +22,48 @@ use super::{Lexical, LexicalFormOf};
 /// NCNameChar = NCNameStartChar / "-" / "." / DIGIT / %xB7 / %x0300-036F / %x203F-2040
 /// ```
 #[derive(RegularGrammar, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[grammar(sized(QNameBuf, derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)))]
+#[grammar(sized(QNameBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
 pub struct QName(str);
 
+impl Grammar for QName {
+	const ABNF: &'static str = r#"QName = PrefixedName / UnprefixedName
+
+PrefixedName = Prefix ":" LocalPart
+
+UnprefixedName = LocalPart
+
+Prefix = NCName
+
+LocalPart = NCName
+
+NCName = NCNameStartChar *NCNameChar
+
+NCNameStartChar = ALPHA / "_" / %xC0-D6 / %xD8-F6 / %xF8-2FF / %x370-37D / %x37F-1FFF / %x200C-200D / %x2070-218F / %x2C00-2FEF / %x3001-D7FF / %xF900-FDCF / %xFDF0-FFFD / %x10000-EFFFF
+
+NCNameChar = NCNameStartChar / "-" / "." / DIGIT / %xB7 / %x0300-036F / %x203F-2040
+"#;
+
+	const TOKENS: &'static [GrammarToken] = &[
+		GrammarToken::new("Prefix", TokenClass::Name),
+		GrammarToken::new("LocalPart", TokenClass::Name),
+	];
+}
+
+impl QName {
+	/// Returns the prefix of this qualified name, if any.
+	pub fn prefix(&self) -> Option<&str> {
+		self.0.split_once(':').map(|(prefix, _)| prefix)
+	}
+
+	/// Returns the local part of this qualified name.
+	pub fn local_part(&self) -> &str {
+		match self.0.split_once(':') {
+			Some((_, local_part)) => local_part,
+			None => &self.0,
+		}
+	}
+}
+
 impl Lexical for QName {
 	type Error = InvalidQName<String>;
 
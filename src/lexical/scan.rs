@@ -0,0 +1,208 @@
+//! Fast validation of date and time lexical forms.
+//!
+//! Apart from the year, every field of a date or time has a fixed width, so
+//! instead of running the grammar automaton byte by byte, the separators
+//! (`-`, `T`, `:`) are checked at fixed offsets and the digits of each field
+//! are checked in bulk. The accepted language is exactly the one of the
+//! corresponding grammar.
+
+/// Checks that every byte is an ASCII digit.
+///
+/// This is written without early return so that it can be vectorized.
+#[inline(always)]
+fn all_digits(bytes: &[u8]) -> bool {
+	bytes
+		.iter()
+		.fold(true, |acc, b| acc & (b.wrapping_sub(b'0') < 10))
+}
+
+/// Returns the value of a two digits field, if it only contains digits.
+#[inline(always)]
+fn two_digits(bytes: &[u8], i: usize) -> Option<u8> {
+	match bytes.get(i..i + 2)? {
+		[a, b] if all_digits(&[*a, *b]) => Some((a - b'0') * 10 + (b - b'0')),
+		_ => None,
+	}
+}
+
+#[inline(always)]
+fn expect(bytes: &[u8], i: usize, c: u8) -> Option<()> {
+	(bytes.get(i) == Some(&c)).then_some(())
+}
+
+/// Validates a `year "-" month "-" day` prefix, and returns the index of
+/// the end of the day field.
+fn date(bytes: &[u8]) -> Option<usize> {
+	let start = (bytes.first() == Some(&b'-')) as usize;
+	let year_end = start
+		+ bytes[start..]
+			.iter()
+			.position(|b| !b.is_ascii_digit())
+			.unwrap_or(bytes.len() - start);
+
+	let year = &bytes[start..year_end];
+	if year.len() < 4 || (year.len() > 4 && year[0] == b'0') {
+		return None;
+	}
+
+	expect(bytes, year_end, b'-')?;
+	let month = two_digits(bytes, year_end + 1)?;
+	expect(bytes, year_end + 3, b'-')?;
+	let day = two_digits(bytes, year_end + 4)?;
+
+	((1..=12).contains(&month) && (1..=31).contains(&day)).then_some(year_end + 6)
+}
+
+/// Validates a `hour ":" minute ":" second ["." fraction]` field starting at
+/// `start`, and returns the index of its end.
+fn time(bytes: &[u8], start: usize) -> Option<usize> {
+	let hour = two_digits(bytes, start)?;
+	expect(bytes, start + 2, b':')?;
+	let minute = two_digits(bytes, start + 3)?;
+	expect(bytes, start + 5, b':')?;
+	let second = two_digits(bytes, start + 6)?;
+
+	let mut end = start + 8;
+	if bytes.get(end) == Some(&b'.') {
+		let fraction_len = bytes[end + 1..]
+			.iter()
+			.position(|b| !b.is_ascii_digit())
+			.unwrap_or(bytes.len() - end - 1);
+
+		if fraction_len == 0 {
+			return None;
+		}
+
+		// `24:00:00` can only be followed by a zero fraction.
+		if hour == 24
+			&& bytes[end + 1..end + 1 + fraction_len]
+				.iter()
+				.any(|b| *b != b'0')
+		{
+			return None;
+		}
+
+		end += 1 + fraction_len
+	}
+
+	let valid = if hour == 24 {
+		minute == 0 && second == 0
+	} else {
+		hour < 24 && minute < 60 && second < 60
+	};
+
+	valid.then_some(end)
+}
+
+/// Validates a timezone, which must span the whole input.
+fn timezone(bytes: &[u8]) -> bool {
+	match bytes {
+		b"Z" => true,
+		[b'+' | b'-', rest @ ..] => {
+			rest.len() == 5
+				&& rest[2] == b':'
+				&& match (two_digits(rest, 0), two_digits(rest, 3)) {
+					(Some(h), Some(m)) => (h < 14 && m < 60) || (h == 14 && m == 0),
+					_ => false,
+				}
+		}
+		_ => false,
+	}
+}
+
+/// Validates an optional timezone, which must span the whole input.
+fn optional_timezone(bytes: &[u8]) -> bool {
+	bytes.is_empty() || timezone(bytes)
+}
+
+/// Checks that the input is a valid `xsd:dateTime` lexical form.
+pub(crate) fn is_date_time(bytes: &[u8]) -> bool {
+	date_time_end(bytes).is_some_and(|end| optional_timezone(&bytes[end..]))
+}
+
+/// Checks that the input is a valid `xsd:dateTimeStamp` lexical form.
+pub(crate) fn is_date_time_stamp(bytes: &[u8]) -> bool {
+	date_time_end(bytes).is_some_and(|end| timezone(&bytes[end..]))
+}
+
+/// Checks that the input is a valid `xsd:date` lexical form.
+pub(crate) fn is_date(bytes: &[u8]) -> bool {
+	date(bytes).is_some_and(|end| optional_timezone(&bytes[end..]))
+}
+
+/// Checks that the input is a valid `xsd:time` lexical form.
+pub(crate) fn is_time(bytes: &[u8]) -> bool {
+	time(bytes, 0).is_some_and(|end| optional_timezone(&bytes[end..]))
+}
+
+/// Validates the date and time of a date/time, and returns the index of the
+/// end of the seconds field.
+fn date_time_end(bytes: &[u8]) -> Option<usize> {
+	let day_end = date(bytes)?;
+	expect(bytes, day_end, b'T')?;
+	time(bytes, day_end + 1)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::lexical::{Date, DateTime, DateTimeStamp, Time};
+
+	/// Checks that the fast validation accepts exactly the same inputs as
+	/// the grammar.
+	#[test]
+	fn same_language() {
+		let date_times = [
+			"2002-05-31T13:07:12",
+			"2002-05-31T13:07:12Z",
+			"-2002-05-31T13:07:12+01:00",
+			"202002-10-10T12:00:00.00001-05:00",
+			"0000-01-01T00:00:00",
+			"00000-01-01T00:00:00",
+			"002-01-01T00:00:00",
+			"2002-00-10T12:00:00",
+			"2002-13-10T12:00:00",
+			"2002-12-00T12:00:00",
+			"2002-12-32T12:00:00",
+			"2002-12-31T24:00:00",
+			"2002-12-31T24:00:00.000",
+			"2002-12-31T24:00:00.001",
+			"2002-12-31T24:00:01",
+			"2002-12-31T23:60:00",
+			"2002-12-31T23:59:60",
+			"2002-12-31T23:59:59.",
+			"2002-12-31T23:59:59+14:00",
+			"2002-12-31T23:59:59+14:01",
+			"2002-12-31T23:59:59-13:59",
+			"2002-12-31T23:59:59+1:00",
+			"2002-12-31T23:59:59+01:00Z",
+			"2002-12-31T23:59:59z",
+			"2002-12-31t23:59:59",
+			"2002-12-31",
+			"2002-12-31T",
+			"--2002-12-31T00:00:00",
+			"",
+		];
+
+		for input in date_times {
+			assert_eq!(
+				is_date_time(input.as_bytes()),
+				DateTime::new(input).is_ok(),
+				"{input}"
+			);
+			assert_eq!(
+				is_date_time_stamp(input.as_bytes()),
+				DateTimeStamp::new(input).is_ok(),
+				"{input}"
+			);
+
+			if let Some((date, _)) = input.split_once('T') {
+				assert_eq!(is_date(date.as_bytes()), Date::new(date).is_ok(), "{date}");
+			}
+
+			if let Some((_, time)) = input.split_once('T') {
+				assert_eq!(is_time(time.as_bytes()), Time::new(time).is_ok(), "{time}");
+			}
+		}
+	}
+}
@@ -2,7 +2,10 @@ use static_regular_grammar::RegularGrammar;
 
 use crate::{utils::byte_index_of, InvalidTimeValue};
 
-use super::{date_time::parse_seconds_decimal, parse_timezone, Lexical, LexicalFormOf};
+use super::{
+	date_time::parse_seconds_decimal, parse_timezone, scan, Grammar, GrammarToken, Lexical,
+	LexicalFormOf, TokenClass,
+};
 
 /// Time.
 ///
@@ -28,6 +31,34 @@ use super::{date_time::parse_seconds_decimal, parse_timezone, Lexical, LexicalFo
 #[grammar(sized(TimeBuf, derive(PartialEq, Eq, PartialOrd, Ord, Hash)))]
 pub struct Time(str);
 
+impl Grammar for Time {
+	const ABNF: &'static str = r#"xsd-time = time [timezone]
+
+time = hour ":" minute ":" second ["." fraction]
+     / "24:00:00" ["." 1*"0"]
+
+hour = ("0" / "1") DIGIT
+     / "2" ("0" / "1" / "2" / "3")
+
+minute = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
+
+second = ("0" / "1" / "2" / "3" / "4" / "5") DIGIT
+
+fraction = 1*DIGIT
+
+timezone = ("+" / "-") ((("0" DIGIT / "1" ("0" / "1" / "2" / "3")) ":" minute) / "14:00")
+         / %s"Z"
+"#;
+
+	const TOKENS: &'static [GrammarToken] = &[
+		GrammarToken::new("hour", TokenClass::Number),
+		GrammarToken::new("minute", TokenClass::Number),
+		GrammarToken::new("second", TokenClass::Number),
+		GrammarToken::new("fraction", TokenClass::Number),
+		GrammarToken::new("timezone", TokenClass::Timezone),
+	];
+}
+
 impl Time {
 	fn parts(&self) -> Parts {
 		let seconds_end =
@@ -43,13 +74,24 @@ impl Time {
 			},
 		}
 	}
+
+	/// Checks if the timezone is written with the `Z` designator. See
+	/// [`DateTime::timezone_is_z`](super::DateTime::timezone_is_z).
+	pub fn timezone_is_z(&self) -> Option<bool> {
+		self.parts().timezone.map(|tz| tz == "Z")
+	}
 }
 
 impl Lexical for Time {
 	type Error = InvalidTime<String>;
 
 	fn parse(value: &str) -> Result<&Self, Self::Error> {
-		Self::new(value).map_err(|_| InvalidTime(value.to_owned()))
+		if scan::is_time(value.as_bytes()) {
+			// SAFETY: the scanner accepts the same language as the grammar.
+			Ok(unchecked!(Self, value))
+		} else {
+			Err(InvalidTime(value.to_owned()))
+		}
 	}
 }
 
@@ -103,6 +145,20 @@ impl<'a> Parts<'a> {
 mod tests {
 	use super::*;
 
+	#[test]
+	fn explicit_timezone() {
+		assert_eq!(Time::new("10:00:00Z").unwrap().timezone_is_z(), Some(true));
+		assert_eq!(
+			Time::new("10:00:00-00:00").unwrap().timezone_is_z(),
+			Some(false)
+		);
+		assert_eq!(Time::new("10:00:00").unwrap().timezone_is_z(), None);
+
+		let value = Time::new("10:00:00+00:00").unwrap().try_as_value().unwrap();
+		assert!(value.has_timezone());
+		assert_eq!(value.to_string(), "10:00:00Z");
+	}
+
 	#[test]
 	fn parsing() {
 		let vectors = [
@@ -0,0 +1,67 @@
+//! Comparisons between lexical forms and values.
+//!
+//! A lexical form is equal to a value if it maps to that value. Lexical
+//! forms outside the value space (such as `2024-02-30T00:00:00`) are not
+//! equal to any value.
+use super::{
+	Boolean, BooleanBuf, DateTime, DateTimeBuf, Decimal, DecimalBuf, Integer, IntegerBuf,
+	LexicalFormOf,
+};
+
+macro_rules! value_eq {
+	($($ty:ident, $buffer_ty:ident => $value_ty:ty),*) => {
+		$(
+			impl PartialEq<$value_ty> for $ty {
+				fn eq(&self, other: &$value_ty) -> bool {
+					LexicalFormOf::<$value_ty>::try_as_value(self).map_or(false, |v| v == *other)
+				}
+			}
+
+			impl PartialEq<$value_ty> for $buffer_ty {
+				fn eq(&self, other: &$value_ty) -> bool {
+					**self == *other
+				}
+			}
+
+			impl PartialEq<$ty> for $value_ty {
+				fn eq(&self, other: &$ty) -> bool {
+					*other == *self
+				}
+			}
+
+			impl PartialEq<$buffer_ty> for $value_ty {
+				fn eq(&self, other: &$buffer_ty) -> bool {
+					**other == *self
+				}
+			}
+		)*
+	};
+}
+
+value_eq! {
+	Boolean, BooleanBuf => crate::Boolean,
+	Integer, IntegerBuf => crate::Integer,
+	Decimal, DecimalBuf => crate::Decimal,
+	DateTime, DateTimeBuf => crate::DateTime
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn lexical_value_eq() {
+		assert_eq!(*Integer::new("+007").unwrap(), crate::Integer::from(7i32));
+		assert_eq!(crate::Integer::from(-1i32), *Integer::new("-01").unwrap());
+		assert_eq!(
+			"1.50".parse::<DecimalBuf>().unwrap(),
+			"1.5".parse::<crate::Decimal>().unwrap()
+		);
+		assert_eq!(*Boolean::new("1").unwrap(), crate::Boolean(true));
+		assert_ne!(*Boolean::new("0").unwrap(), crate::Boolean(true));
+
+		let date_time: crate::DateTime = "2024-01-01T02:00:00+02:00".parse().unwrap();
+		assert_eq!(*DateTime::new("2024-01-01T00:00:00Z").unwrap(), date_time);
+		assert_ne!(*DateTime::new("2024-02-30T00:00:00Z").unwrap(), date_time)
+	}
+}
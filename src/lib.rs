@@ -48,22 +48,169 @@
 //! ```
 //!
 //! [`Value`]: crate::Value
+//!
+//! ## Prelude
+//!
+//! The [`prelude`] module re-exports the most commonly used traits and
+//! functions, including the [`parse`] and [`parse_value`] entry points.
+//!
+//! ```
+//! use xsd_types::prelude::*;
+//! let value: xsd_types::Integer = parse("42").ok().unwrap();
+//! ```
+#![cfg_attr(feature = "forbid-unsafe", deny(unsafe_code))]
+#![cfg_attr(feature = "nightly", feature(f16, f128))]
 use iref::Iri;
 use static_iref::iri;
 
+/// Declares an unchecked constructor.
+///
+/// Unchecked constructors are `unsafe` whatever the enabled features, so that
+/// the `forbid-unsafe` feature does not change the public API. This is the
+/// only place where `unsafe_code` is allowed with `forbid-unsafe`, where the
+/// crate itself only uses the checked constructors.
+macro_rules! unchecked_constructor {
+	($item:item) => {
+		#[allow(unsafe_code)]
+		$item
+	};
+}
+
+/// Calls the unchecked constructor `new_unchecked` of the given type, on
+/// arguments known to be valid.
+///
+/// With the `forbid-unsafe` feature, the checked constructor `new` is called
+/// instead, and an invalid input panics.
+macro_rules! unchecked {
+	($ty:ty, $($arg:expr),+ $(,)?) => {{
+		#[cfg(not(feature = "forbid-unsafe"))]
+		let value = unsafe { <$ty>::new_unchecked($($arg),+) };
+
+		#[cfg(feature = "forbid-unsafe")]
+		let value = match <$ty>::new($($arg),+) {
+			Ok(value) => value,
+			Err(_) => panic!(concat!("invalid `", stringify!($ty), "`")),
+		};
+
+		value
+	}};
+}
+
+/// Defines a function casting a reference into a reference to `Self`, a
+/// `#[repr(transparent)]` wrapper around the input type.
+///
+/// With the `forbid-unsafe` feature, the cast is generated by `ref-cast`,
+/// which checks the layout of `Self` at compile time.
+macro_rules! transparent_cast {
+	($(#[$meta:meta])* $vis:vis const fn $name:ident($arg:ident: &$from:ty) -> &Self;) => {
+		$(#[$meta])*
+		#[cfg(feature = "forbid-unsafe")]
+		#[ref_cast::ref_cast_custom]
+		$vis const fn $name($arg: &$from) -> &Self;
+
+		$(#[$meta])*
+		#[cfg(not(feature = "forbid-unsafe"))]
+		#[inline(always)]
+		$vis const fn $name($arg: &$from) -> &Self {
+			// SAFETY: `Self` is a `#[repr(transparent)]` wrapper around the
+			//         input type, hence both pointers have the same layout
+			//         and metadata.
+			unsafe { &*($arg as *const $from as *const Self) }
+		}
+	};
+	($(#[$meta:meta])* $vis:vis fn $name:ident($arg:ident: &mut $from:ty) -> &mut Self;) => {
+		$(#[$meta])*
+		#[cfg(feature = "forbid-unsafe")]
+		#[ref_cast::ref_cast_custom]
+		$vis fn $name($arg: &mut $from) -> &mut Self;
+
+		$(#[$meta])*
+		#[cfg(not(feature = "forbid-unsafe"))]
+		#[inline(always)]
+		$vis fn $name($arg: &mut $from) -> &mut Self {
+			// SAFETY: `Self` is a `#[repr(transparent)]` wrapper around the
+			//         input type, hence both pointers have the same layout
+			//         and metadata.
+			unsafe { &mut *($arg as *mut $from as *mut Self) }
+		}
+	};
+}
+
+/// Implements `Clone`, `Copy`, `PartialEq`, `Eq` and `Hash` for the syntax
+/// errors generated by `RegularGrammar`, which only derive `Debug`.
+macro_rules! grammar_errors {
+	($($ty:ident),*) => {
+		$(
+			impl<T: Clone> Clone for $ty<T> {
+				fn clone(&self) -> Self {
+					Self(self.0.clone())
+				}
+			}
+
+			impl<T: Copy> Copy for $ty<T> {}
+
+			impl<T: PartialEq> PartialEq for $ty<T> {
+				fn eq(&self, other: &Self) -> bool {
+					self.0 == other.0
+				}
+			}
+
+			impl<T: Eq> Eq for $ty<T> {}
+
+			impl<T: std::hash::Hash> std::hash::Hash for $ty<T> {
+				fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+					self.0.hash(state)
+				}
+			}
+		)*
+	};
+}
+
 /// Lexical domain types.
 pub mod lexical;
 pub(crate) mod utils;
 
 /// Value domain types.
+///
+/// All owned value types (and [`Value`] itself) are `Send + Sync + 'static`.
+/// This is part of the public API, checked at compile time.
 pub mod value;
 
 use lexical::{Lexical, LexicalFormOf};
 pub use value::*;
 
+mod any_value;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+mod bulk;
+mod canonical_json;
+#[cfg(feature = "capi")]
+#[allow(unsafe_code)]
+pub mod capi;
+mod diagnose;
+mod facet;
+mod family;
+mod infer;
+pub mod lenient;
+#[cfg(feature = "regex")]
+pub mod pattern;
+#[cfg(feature = "pyo3")]
+pub mod python;
+mod sql;
 mod types;
-
+mod union;
+#[cfg(feature = "wasm-bindgen")]
+pub mod wasm;
+
+pub use any_value::*;
+pub use bulk::*;
+pub use canonical_json::*;
+pub use diagnose::*;
+pub use facet::*;
+pub use infer::*;
+pub use sql::*;
 pub use types::*;
+pub use union::*;
 
 /// XSD primitive datatype.
 pub enum PrimitiveDatatype {
@@ -88,6 +235,39 @@ pub enum PrimitiveDatatype {
 	Notation,
 }
 
+/// XSD namespace, prefix of every XSD datatype IRI.
+pub const XSD_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// Finds the datatype identified by the given IRI.
+///
+/// The datatype is looked up by the fragment of `iri`, using
+/// `from_local_name`, before `iri` is compared against the datatype IRI
+/// constant, so that IRIs equal modulo normalization are recognized.
+pub(crate) fn datatype_from_iri<T>(
+	iri: &Iri,
+	from_local_name: impl FnOnce(&str) -> Option<T>,
+	datatype_iri: impl FnOnce(&T) -> &'static Iri,
+) -> Option<T> {
+	let fragment = iri.fragment()?;
+	let t = if fragment.as_str().contains('%') {
+		from_local_name(&fragment.as_pct_str().decode())
+	} else {
+		from_local_name(fragment.as_str())
+	}?;
+
+	(iri == datatype_iri(&t)).then_some(t)
+}
+
+/// <http://www.w3.org/2001/XMLSchema#anySimpleType> datatype IRI.
+///
+/// Base type of every simple datatype. It has no [`Datatype`] counterpart.
+pub const XSD_ANY_SIMPLE_TYPE: &Iri = iri!("http://www.w3.org/2001/XMLSchema#anySimpleType");
+
+/// <http://www.w3.org/2001/XMLSchema#anyAtomicType> datatype IRI.
+///
+/// Base type of every atomic datatype. It has no [`Datatype`] counterpart.
+pub const XSD_ANY_ATOMIC_TYPE: &Iri = iri!("http://www.w3.org/2001/XMLSchema#anyAtomicType");
+
 /// <http://www.w3.org/2001/XMLSchema#duration> datatype IRI.
 pub const XSD_DURATION: &Iri = iri!("http://www.w3.org/2001/XMLSchema#duration");
 
@@ -143,6 +323,12 @@ pub const XSD_FLOAT: &Iri = iri!("http://www.w3.org/2001/XMLSchema#float");
 /// <http://www.w3.org/2001/XMLSchema#decimal> datatype IRI.
 pub const XSD_DECIMAL: &Iri = iri!("http://www.w3.org/2001/XMLSchema#decimal");
 
+/// <http://www.w3.org/2001/XMLSchema#precisionDecimal> datatype IRI.
+///
+/// This datatype was dropped from the final XSD 1.1 recommendation, but is
+/// still found in some data. It has no [`Datatype`] counterpart.
+pub const XSD_PRECISION_DECIMAL: &Iri = iri!("http://www.w3.org/2001/XMLSchema#precisionDecimal");
+
 /// <http://www.w3.org/2001/XMLSchema#double> datatype IRI.
 pub const XSD_DOUBLE: &Iri = iri!("http://www.w3.org/2001/XMLSchema#double");
 
@@ -191,6 +377,9 @@ pub const XSD_IDREFS: &Iri = iri!("http://www.w3.org/2001/XMLSchema#IDREFS");
 /// <http://www.w3.org/2001/XMLSchema#ENTITIES> datatype IRI.
 pub const XSD_ENTITIES: &Iri = iri!("http://www.w3.org/2001/XMLSchema#ENTITIES");
 
+/// <http://www.w3.org/2001/XMLSchema#error> datatype IRI (XSD 1.1).
+pub const XSD_ERROR: &Iri = iri!("http://www.w3.org/2001/XMLSchema#error");
+
 /// <http://www.w3.org/2001/XMLSchema#integer> datatype IRI.
 pub const XSD_INTEGER: &Iri = iri!("http://www.w3.org/2001/XMLSchema#integer");
 
@@ -244,12 +433,467 @@ pub trait ParseXsd: Sized {
 	}
 }
 
+/// Parses a value directly from its XSD lexical form.
+///
+/// The target datatype is inferred from the Rust type `T`.
+///
+/// ```
+/// let value: xsd_types::Decimal = xsd_types::parse("3.14").ok().unwrap();
+/// let byte: xsd_types::Byte = xsd_types::parse("-12").ok().unwrap();
+/// ```
+pub fn parse<T: ParseXsd>(lexical_value: &str) -> ParseXsdResult<T, T::LexicalForm> {
+	T::parse_xsd(lexical_value)
+}
+
+/// Parses a value of the given datatype from its XSD lexical form.
+///
+/// This is equivalent to [`Datatype::parse`].
+///
+/// ```
+/// use xsd_types::{Datatype, XSD_DATE};
+/// let value = xsd_types::parse_value(Datatype::from_iri(XSD_DATE).unwrap(), "1758-12-25").unwrap();
+/// ```
+pub fn parse_value(datatype: Datatype, lexical_value: &str) -> Result<Value, ParseError> {
+	datatype.parse(lexical_value)
+}
+
+/// Returns the canonical form of the given lexical form of the datatype
+/// identified by `datatype_iri`.
+///
+/// This parses the lexical form as a value of the datatype, and then formats
+/// the value using its canonical representation. Returns
+/// [`ParseError::UnknownDatatype`] if `datatype_iri` is not an XSD datatype.
+///
+/// ```
+/// use xsd_types::XSD_DECIMAL;
+/// assert_eq!(xsd_types::canonicalize(XSD_DECIMAL, "+01.500").unwrap(), "1.5");
+/// ```
+pub fn canonicalize(datatype_iri: &Iri, lexical_value: &str) -> Result<String, ParseError> {
+	Datatype::from_iri(datatype_iri)
+		.ok_or(ParseError::UnknownDatatype)?
+		.parse(lexical_value)
+		.map(|value| value.to_string())
+}
+
+/// Commonly used traits, types and functions.
+///
+/// ```
+/// use xsd_types::prelude::*;
+/// ```
+pub mod prelude {
+	pub use crate::lexical::{Lexical, LexicalFormOf};
+	pub use crate::{
+		canonicalize, parse, parse_value, Datatype, ParseXsd, ParseXsdError, Value, ValueRef,
+		XsdValue,
+	};
+}
+
 /// XSD lexical parse result.
 pub type ParseXsdResult<T, L> =
 	Result<T, ParseXsdError<<L as Lexical>::Error, <L as LexicalFormOf<T>>::ValueError>>;
 
 /// XSD lexical parse error.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum ParseXsdError<L, V> {
+	#[error("invalid lexical form: {0}")]
 	InvalidLexicalForm(L),
+
+	#[error("invalid value: {0}")]
 	InvalidValue(V),
 }
+
+/// Compile-time check that owned types are `Send + Sync + 'static`.
+const _: () = {
+	fn assert_thread_safe<T: Send + Sync + 'static>() {}
+
+	#[allow(dead_code)]
+	fn assert_all() {
+		assert_thread_safe::<Value>();
+		assert_thread_safe::<Datatype>();
+		assert_thread_safe::<ParseError>();
+		assert_thread_safe::<UnionValue>();
+		assert_thread_safe::<AnyValue>();
+
+		assert_thread_safe::<Boolean>();
+		assert_thread_safe::<Decimal>();
+		assert_thread_safe::<Integer>();
+		assert_thread_safe::<NonPositiveInteger>();
+		assert_thread_safe::<NegativeInteger>();
+		assert_thread_safe::<NonNegativeInteger>();
+		assert_thread_safe::<PositiveInteger>();
+		assert_thread_safe::<Long>();
+		assert_thread_safe::<Int>();
+		assert_thread_safe::<Short>();
+		assert_thread_safe::<Byte>();
+		assert_thread_safe::<UnsignedLong>();
+		assert_thread_safe::<UnsignedInt>();
+		assert_thread_safe::<UnsignedShort>();
+		assert_thread_safe::<UnsignedByte>();
+		assert_thread_safe::<BoundedInt<0, 1>>();
+		assert_thread_safe::<Float>();
+		assert_thread_safe::<Double>();
+
+		assert_thread_safe::<String>();
+		assert_thread_safe::<NormalizedString>();
+		assert_thread_safe::<TokenBuf>();
+		assert_thread_safe::<LanguageBuf>();
+		assert_thread_safe::<NameBuf>();
+		assert_thread_safe::<NCNameBuf>();
+		assert_thread_safe::<IdBuf>();
+		assert_thread_safe::<IdRefBuf>();
+		assert_thread_safe::<NMTokenBuf>();
+		assert_thread_safe::<NMTokensBuf>();
+		assert_thread_safe::<IdRefsBuf>();
+
+		assert_thread_safe::<Duration>();
+		assert_thread_safe::<DayTimeDuration>();
+		assert_thread_safe::<YearMonthDuration>();
+		assert_thread_safe::<DateTime>();
+		assert_thread_safe::<DateTimeStamp>();
+		assert_thread_safe::<Time>();
+		assert_thread_safe::<Date>();
+		assert_thread_safe::<GYearMonth>();
+		assert_thread_safe::<GYear>();
+		assert_thread_safe::<GMonthDay>();
+		assert_thread_safe::<GDay>();
+		assert_thread_safe::<GMonth>();
+		assert_thread_safe::<Seconds>();
+		assert_thread_safe::<Interval>();
+
+		assert_thread_safe::<Base64BinaryBuf>();
+		assert_thread_safe::<HexBinaryBuf>();
+		assert_thread_safe::<AnyUriBuf>();
+		assert_thread_safe::<QNameBuf>();
+
+		assert_thread_safe::<lexical::BooleanBuf>();
+		assert_thread_safe::<lexical::DecimalBuf>();
+		assert_thread_safe::<lexical::IntegerBuf>();
+		assert_thread_safe::<lexical::NonPositiveIntegerBuf>();
+		assert_thread_safe::<lexical::NegativeIntegerBuf>();
+		assert_thread_safe::<lexical::NonNegativeIntegerBuf>();
+		assert_thread_safe::<lexical::PositiveIntegerBuf>();
+		assert_thread_safe::<lexical::FloatBuf>();
+		assert_thread_safe::<lexical::DoubleBuf>();
+		assert_thread_safe::<lexical::DurationBuf>();
+		assert_thread_safe::<lexical::DayTimeDurationBuf>();
+		assert_thread_safe::<lexical::YearMonthDurationBuf>();
+		assert_thread_safe::<lexical::DateTimeBuf>();
+		assert_thread_safe::<lexical::DateTimeStampBuf>();
+		assert_thread_safe::<lexical::TimeBuf>();
+		assert_thread_safe::<lexical::DateBuf>();
+		assert_thread_safe::<lexical::GYearMonthBuf>();
+		assert_thread_safe::<lexical::GYearBuf>();
+		assert_thread_safe::<lexical::GMonthDayBuf>();
+		assert_thread_safe::<lexical::GDayBuf>();
+		assert_thread_safe::<lexical::GMonthBuf>();
+		assert_thread_safe::<lexical::Base64BinaryBuf>();
+		assert_thread_safe::<lexical::HexBinaryBuf>();
+	}
+};
+
+/// Compile-time check that public error types are cloneable, comparable and
+/// `Send + Sync + 'static`.
+const _: () = {
+	fn assert_error<E: std::error::Error + Clone + Eq + Send + Sync + 'static>() {}
+
+	#[allow(dead_code)]
+	fn assert_all() {
+		assert_error::<ParseError>();
+		assert_error::<ParseXsdError<lexical::InvalidInteger, IntegerOutOfRange>>();
+		assert_error::<ConstructError>();
+		assert_error::<TypeError>();
+
+		assert_error::<lexical::InvalidBoolean>();
+		assert_error::<lexical::InvalidDecimal>();
+		assert_error::<lexical::InvalidInteger>();
+		assert_error::<lexical::InvalidNonPositiveInteger>();
+		assert_error::<lexical::InvalidNegativeInteger>();
+		assert_error::<lexical::InvalidNonNegativeInteger>();
+		assert_error::<lexical::InvalidPositiveInteger>();
+		assert_error::<lexical::InvalidFloat>();
+		assert_error::<lexical::InvalidDouble>();
+		assert_error::<lexical::InvalidBase64Binary>();
+		assert_error::<lexical::InvalidHexBinary>();
+		assert_error::<lexical::InvalidDate<String>>();
+		assert_error::<lexical::InvalidDateTime<String>>();
+		assert_error::<lexical::date_time::InvalidDateTimeStamp<String>>();
+		assert_error::<lexical::InvalidTime<String>>();
+		assert_error::<lexical::InvalidDuration<String>>();
+		assert_error::<lexical::duration::InvalidDayTimeDuration<String>>();
+		assert_error::<lexical::duration::InvalidYearMonthDuration<String>>();
+		assert_error::<lexical::InvalidGYearMonth<String>>();
+		assert_error::<lexical::InvalidGYear<String>>();
+		assert_error::<lexical::InvalidGMonthDay<String>>();
+		assert_error::<lexical::InvalidGDay<String>>();
+		assert_error::<lexical::InvalidGMonth<String>>();
+		assert_error::<lexical::InvalidQName<String>>();
+
+		assert_error::<IntegerOutOfTargetBounds>();
+		assert_error::<IntegerOutOfRange>();
+		assert_error::<InvalidRadixInteger>();
+		assert_error::<IntegerIsNegative>();
+		assert_error::<IntegerIsPositive>();
+		assert_error::<IntegerIsNotPositive>();
+		assert_error::<IntegerIsNotNegative>();
+		assert_error::<DecimalIsNotNonNegativeInteger>();
+		assert_error::<NonNegativeIntegerOutOfTargetBounds>();
+		assert_error::<NonPositiveIntegerOutOfTargetBounds>();
+		assert_error::<FromDecimalError>();
+		assert_error::<NoDecimalRepresentation>();
+		assert_error::<NonDecimalFloat>();
+
+		assert_error::<InvalidNormalizedStr>();
+		assert_error::<InvalidToken>();
+		assert_error::<InvalidLanguage<String>>();
+		assert_error::<InvalidName<String>>();
+		assert_error::<InvalidNCName<String>>();
+		assert_error::<InvalidId<String>>();
+		assert_error::<InvalidIdRef<String>>();
+		assert_error::<InvalidNMToken<String>>();
+		assert_error::<InvalidNameChar>();
+		assert_error::<InvalidAnyUri>();
+		assert_error::<InvalidBase64>();
+		assert_error::<InvalidHex>();
+
+		assert_error::<DurationOverflow>();
+		assert_error::<YearOutOfRange>();
+		assert_error::<Iso8601DurationError>();
+		assert_error::<MissingTimezone>();
+		assert_error::<TimezoneError>();
+		assert_error::<InvalidDateTimeValue>();
+		assert_error::<InvalidDateTimeStampValue>();
+		assert_error::<InvalidSortableKey>();
+		assert_error::<InvalidDateValue>();
+		assert_error::<InvalidComponent>();
+		assert_error::<InvalidTimeValue>();
+		assert_error::<DateTimeFromStrError>();
+		assert_error::<DateTimeStampFromStrError>();
+		assert_error::<DateFromStrError>();
+		assert_error::<SecondsOutOfRange>();
+		assert_error::<InvalidPartialDate>();
+		assert_error::<Indeterminate>();
+		assert_error::<InvalidInterval>();
+	}
+};
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_empty_lexical_form() {
+		for iri in [
+			XSD_STRING,
+			XSD_NORMALIZED_STRING,
+			XSD_TOKEN,
+			XSD_BASE64_BINARY,
+		] {
+			assert!(Datatype::from_iri(iri).unwrap().parse("").is_ok())
+		}
+
+		for iri in [
+			XSD_BOOLEAN,
+			XSD_INTEGER,
+			XSD_DATE_TIME,
+			XSD_NC_NAME,
+			XSD_LANGUAGE,
+		] {
+			assert!(matches!(
+				Datatype::from_iri(iri).unwrap().parse(""),
+				Err(ParseError::EmptyLexicalForm)
+			))
+		}
+	}
+
+	#[test]
+	fn canonicalize() {
+		assert_eq!(super::canonicalize(XSD_INTEGER, "+0012").unwrap(), "12");
+		assert_eq!(super::canonicalize(XSD_BOOLEAN, "1").unwrap(), "true");
+		assert!(matches!(
+			super::canonicalize(XSD_INTEGER, "1.5"),
+			Err(ParseError::Invalid)
+		));
+		assert!(matches!(
+			super::canonicalize(iri!("http://example.org/#integer"), "1"),
+			Err(ParseError::UnknownDatatype)
+		))
+	}
+
+	#[test]
+	fn value_conversions() {
+		let value = Value::from(-12i8);
+		assert_eq!(value.datatype(), Datatype::from_iri(XSD_BYTE).unwrap());
+		assert_eq!(i8::try_from(value).unwrap(), -12);
+
+		let value: Value = "2002-05-31".parse::<Date>().unwrap().into();
+		assert!(matches!(value, Value::Date(_)));
+		assert!(Decimal::try_from(value).is_err());
+
+		let value: Value = String::from("foo").into();
+		let value = TokenBuf::try_from(value).unwrap_err();
+		assert_eq!(String::from(value), "foo")
+	}
+
+	#[test]
+	fn debug_output() {
+		let integer = lexical::Integer::new("42").unwrap();
+		assert_eq!(format!("{integer:?}"), "Integer(\"42\")");
+		assert_eq!(format!("{:?}", integer.to_owned()), "IntegerBuf(\"42\")");
+
+		let date = lexical::DateBuf::new("2024-05-17Z".to_owned()).unwrap();
+		assert_eq!(format!("{date:?}"), "DateBuf(\"2024-05-17Z\")");
+
+		let value = Datatype::from_iri(XSD_INTEGER)
+			.unwrap()
+			.parse("+042")
+			.unwrap();
+		assert_eq!(format!("{value:?}"), "xsd:integer(\"42\")");
+		assert_eq!(format!("{:?}", value.as_ref()), "xsd:integer(\"42\")")
+	}
+
+	#[test]
+	fn error_datatype() {
+		let error = Datatype::from_iri(XSD_ERROR).unwrap();
+		assert_eq!(error.iri(), XSD_ERROR);
+		for value in ["", "error", "0"] {
+			assert!(matches!(
+				error.parse(value),
+				Err(ParseError::EmptyValueSpace)
+			))
+		}
+	}
+
+	#[test]
+	fn restricted_durations() {
+		let day_time = Datatype::from_iri(XSD_DAY_TIME_DURATION).unwrap();
+		let year_month = Datatype::from_iri(XSD_YEAR_MONTH_DURATION).unwrap();
+
+		assert_eq!(day_time.parse("P1DT2H").unwrap().datatype(), day_time);
+		assert!(day_time.parse("P1Y").is_err());
+		assert!(day_time.parse("P1Y2DT3H").is_err());
+
+		assert_eq!(year_month.parse("P1Y2M").unwrap().datatype(), year_month);
+		assert!(year_month.parse("P1D").is_err());
+		assert!(year_month.parse("P1YT1H").is_err());
+
+		let value: DayTimeDuration = "PT1S".parse().unwrap();
+		assert_eq!(XsdValue::datatype(&value), day_time);
+		let value: YearMonthDuration = "P1M".parse().unwrap();
+		assert_eq!(XsdValue::datatype(&value), year_month)
+	}
+
+	#[test]
+	fn signed_zero() {
+		let neg: Double = "-0".parse().unwrap();
+		let pos: Double = "0".parse().unwrap();
+		assert!(neg.is_negative_zero());
+		assert!(!pos.is_negative_zero());
+		assert!(neg.xsd_eq(&pos));
+		assert!(!neg.is_identical(&pos));
+		assert!(neg.signum().is_negative_zero());
+		assert!(!Double::NAN.xsd_eq(&Double::NAN));
+		assert!(Double::NAN.is_identical(&Double::NAN));
+		assert_eq!(f64::from((Double::new(1.0) / neg).signum()), -1.0);
+
+		let neg: Float = "-0".parse().unwrap();
+		assert!(neg.is_negative_zero());
+		assert!(neg.xsd_eq(&Float::new(0.0)));
+
+		let neg: Decimal = "-0".parse().unwrap();
+		assert!(!neg.is_negative_zero());
+		assert!(neg.signum().is_zero());
+		assert_eq!(
+			Decimal::from(Integer::from(-12i32)).signum().to_string(),
+			"-1"
+		)
+	}
+
+	#[test]
+	fn datatype_ordering() {
+		let decimal = Datatype::from_iri(XSD_DECIMAL).unwrap();
+		let integer = Datatype::from_iri(XSD_INTEGER).unwrap();
+		let long = Datatype::from_iri(XSD_LONG).unwrap();
+		let string = Datatype::from_iri(XSD_STRING).unwrap();
+		assert!(Datatype::from_iri(XSD_BOOLEAN).unwrap() < decimal);
+		assert!(decimal < integer);
+		assert!(integer < long);
+		assert!(long < string)
+	}
+
+	#[test]
+	fn canonical_value_ordering() {
+		let a = Value::Integer(2i32.into());
+		let b = Value::Integer(10i32.into());
+		let c = Value::String("a".to_owned());
+		assert_eq!(a.canonical_cmp(&b), std::cmp::Ordering::Less);
+		assert_eq!(b.canonical_cmp(&c), std::cmp::Ordering::Less);
+		assert_eq!(c.canonical_cmp(&c), std::cmp::Ordering::Equal)
+	}
+
+	#[test]
+	fn from_str() {
+		assert_eq!("1".parse::<Boolean>().unwrap(), Boolean(true));
+		assert_eq!(
+			"12:30:00Z".parse::<Time>().unwrap().to_string(),
+			"12:30:00Z"
+		);
+		assert_eq!(
+			"2024-02".parse::<GYearMonth>().unwrap().to_string(),
+			"2024-02"
+		);
+		assert_eq!("-0044".parse::<GYear>().unwrap().to_string(), "-0044");
+		assert_eq!(
+			"--02-29".parse::<GMonthDay>().unwrap().to_string(),
+			"--02-29"
+		);
+		assert_eq!("---05".parse::<GDay>().unwrap().to_string(), "---05");
+		assert_eq!("--12".parse::<GMonth>().unwrap().to_string(), "--12");
+		assert!("25:00:00".parse::<Time>().is_err());
+		assert!("--13".parse::<GMonth>().is_err());
+
+		assert_eq!("+042".parse::<PositiveInteger>().unwrap().to_string(), "42");
+		assert_eq!(
+			"-042".parse::<NegativeInteger>().unwrap().to_string(),
+			"-42"
+		);
+		assert!("0".parse::<PositiveInteger>().is_err());
+		assert!("-0".parse::<NegativeInteger>().is_err())
+	}
+
+	#[test]
+	fn bounded_integer_range() {
+		match Byte::parse_xsd("300") {
+			Err(ParseXsdError::InvalidValue(e)) => {
+				assert_eq!(e.expected_range(), -128..=127);
+				assert_eq!(e.datatype(), Datatype::from_iri(XSD_BYTE).unwrap())
+			}
+			_ => panic!("expected a range error"),
+		}
+
+		match UnsignedShort::parse_xsd("70000") {
+			Err(ParseXsdError::InvalidValue(e)) => assert_eq!(e.expected_range(), 0..=65535),
+			_ => panic!("expected a range error"),
+		}
+	}
+
+	#[test]
+	fn from_iri() {
+		assert_eq!(
+			Datatype::from_iri(XSD_UNSIGNED_BYTE).unwrap().iri(),
+			XSD_UNSIGNED_BYTE
+		);
+		assert_eq!(DecimalDatatype::from_iri(XSD_BYTE).unwrap().iri(), XSD_BYTE);
+		assert!(DecimalDatatype::from_iri(XSD_STRING).is_none());
+		assert!(Datatype::from_iri(iri!("http://www.w3.org/2001/XMLSchema#foo")).is_none());
+		assert!(Datatype::from_iri(iri!("http://example.org/#integer")).is_none());
+
+		// IRIs equal modulo normalization.
+		for iri in [
+			iri!("http://www.w3.org/2001/%58MLSchema#integer"),
+			iri!("http://www.w3.org/2001/XMLSchema#%69nteger"),
+		] {
+			assert_eq!(iri, XSD_INTEGER);
+			assert_eq!(Datatype::from_iri(iri).unwrap().iri(), XSD_INTEGER)
+		}
+	}
+}
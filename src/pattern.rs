@@ -0,0 +1,481 @@
+//! XML Schema regular expressions and the `pattern` facet.
+//!
+//! The regular expression dialect of XML Schema differs from the one of the
+//! [`regex`] crate:
+//! - expressions are implicitly anchored at both ends;
+//! - `^` and `$` are ordinary characters;
+//! - `.` matches any character but `\n` and `\r`;
+//! - `\i` and `\c` match XML name (start) characters;
+//! - character classes support subtraction (`[a-z-[aeiou]]`);
+//! - `\s`, `\w` and `\d` are defined differently.
+//!
+//! XML Schema expressions are translated into equivalent [`regex`]
+//! expressions before being compiled.
+//!
+//! ```
+//! use xsd_types::pattern::Pattern;
+//!
+//! let pattern = Pattern::new(r"\i\c*-[0-9]+").unwrap();
+//! assert!(pattern.is_match("item-42"));
+//! assert!(!pattern.is_match("42-item"));
+//!
+//! let consonants = Pattern::new("[a-z-[aeiou]]+").unwrap();
+//! assert!(consonants.is_match("xyz"));
+//! assert!(!consonants.is_match("abc"));
+//! ```
+use std::fmt;
+
+use regex::Regex;
+
+/// Invalid XML Schema regular expression.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum InvalidPattern {
+	#[error("invalid regular expression `{pattern}` at offset {offset}")]
+	Syntax { pattern: String, offset: usize },
+
+	#[error("unsupported Unicode block escape `{0}`")]
+	UnsupportedBlock(String),
+
+	#[error(transparent)]
+	Regex(#[from] regex::Error),
+}
+
+impl Eq for InvalidPattern {}
+
+/// Compiled XML Schema regular expression.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+	source: String,
+	regex: Regex,
+}
+
+impl Pattern {
+	/// Compiles the given XML Schema regular expression.
+	pub fn new(pattern: &str) -> Result<Self, InvalidPattern> {
+		Ok(Self {
+			source: pattern.to_owned(),
+			regex: Regex::new(&translate(pattern)?)?,
+		})
+	}
+
+	/// Returns the XML Schema regular expression.
+	pub fn as_str(&self) -> &str {
+		&self.source
+	}
+
+	/// Returns the compiled regular expression.
+	pub fn as_regex(&self) -> &Regex {
+		&self.regex
+	}
+
+	/// Checks if the whole input matches this regular expression.
+	pub fn is_match(&self, value: &str) -> bool {
+		self.regex.is_match(value)
+	}
+}
+
+impl fmt::Display for Pattern {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.source.fmt(f)
+	}
+}
+
+/// `pattern` facet.
+///
+/// Pattern facets constrain the lexical space of a datatype. When the same
+/// derivation step specifies multiple patterns, a lexical form is valid if
+/// it matches at least one of them.
+#[derive(Debug, Default, Clone)]
+pub struct PatternFacet(Vec<Pattern>);
+
+impl PatternFacet {
+	/// Creates a new facet from the given XML Schema regular expressions.
+	pub fn new<'a>(patterns: impl IntoIterator<Item = &'a str>) -> Result<Self, InvalidPattern> {
+		patterns.into_iter().map(Pattern::new).collect()
+	}
+
+	/// Returns the patterns of this facet.
+	pub fn patterns(&self) -> &[Pattern] {
+		&self.0
+	}
+
+	/// Adds a pattern to this facet.
+	pub fn push(&mut self, pattern: Pattern) {
+		self.0.push(pattern)
+	}
+
+	/// Checks if the given lexical form satisfies this facet.
+	///
+	/// A facet without patterns is satisfied by every lexical form.
+	pub fn is_satisfied_by(&self, lexical_form: &str) -> bool {
+		self.0.is_empty() || self.0.iter().any(|p| p.is_match(lexical_form))
+	}
+}
+
+impl FromIterator<Pattern> for PatternFacet {
+	fn from_iter<T: IntoIterator<Item = Pattern>>(iter: T) -> Self {
+		Self(iter.into_iter().collect())
+	}
+}
+
+/// XML name start characters (`\i`), as `regex` class items.
+const NAME_START_CHARS: &str = "\\x{3A}A-Z\\x{5F}a-z\\x{C0}-\\x{D6}\\x{D8}-\\x{F6}\\x{F8}-\\x{2FF}\
+	\\x{370}-\\x{37D}\\x{37F}-\\x{1FFF}\\x{200C}-\\x{200D}\\x{2070}-\\x{218F}\\x{2C00}-\\x{2FEF}\
+	\\x{3001}-\\x{D7FF}\\x{F900}-\\x{FDCF}\\x{FDF0}-\\x{FFFD}\\x{10000}-\\x{EFFFF}";
+
+/// XML name characters (`\c`) that are not name start characters, as
+/// `regex` class items.
+const NAME_CHARS: &str = "\\x{2D}\\x{2E}0-9\\x{B7}\\x{300}-\\x{36F}\\x{203F}-\\x{2040}";
+
+/// Translates an XML Schema regular expression into an equivalent `regex`
+/// expression.
+pub fn translate(pattern: &str) -> Result<String, InvalidPattern> {
+	let mut translator = Translator {
+		pattern,
+		chars: pattern.char_indices().collect(),
+		pos: 0,
+		output: String::from("^(?:"),
+	};
+
+	translator.regex()?;
+
+	if translator.pos < translator.chars.len() {
+		// Unbalanced `)`.
+		return Err(translator.error());
+	}
+
+	translator.output.push_str(")$");
+	Ok(translator.output)
+}
+
+enum Escape {
+	Char(char),
+	Class(String),
+}
+
+struct Translator<'a> {
+	pattern: &'a str,
+	chars: Vec<(usize, char)>,
+	pos: usize,
+	output: String,
+}
+
+impl Translator<'_> {
+	fn error(&self) -> InvalidPattern {
+		InvalidPattern::Syntax {
+			pattern: self.pattern.to_owned(),
+			offset: self
+				.chars
+				.get(self.pos)
+				.map(|(i, _)| *i)
+				.unwrap_or(self.pattern.len()),
+		}
+	}
+
+	fn peek(&self) -> Option<char> {
+		self.peek_at(0)
+	}
+
+	fn peek_at(&self, n: usize) -> Option<char> {
+		self.chars.get(self.pos + n).map(|(_, c)| *c)
+	}
+
+	fn next(&mut self) -> Result<char, InvalidPattern> {
+		let c = self.peek().ok_or_else(|| self.error())?;
+		self.pos += 1;
+		Ok(c)
+	}
+
+	fn eat(&mut self, c: char) -> bool {
+		if self.peek() == Some(c) {
+			self.pos += 1;
+			true
+		} else {
+			false
+		}
+	}
+
+	fn expect(&mut self, c: char) -> Result<(), InvalidPattern> {
+		if self.eat(c) {
+			Ok(())
+		} else {
+			Err(self.error())
+		}
+	}
+
+	fn regex(&mut self) -> Result<(), InvalidPattern> {
+		self.branch()?;
+		while self.eat('|') {
+			self.output.push('|');
+			self.branch()?;
+		}
+
+		Ok(())
+	}
+
+	fn branch(&mut self) -> Result<(), InvalidPattern> {
+		while !matches!(self.peek(), None | Some('|' | ')')) {
+			self.atom()?;
+			self.quantifier()?;
+		}
+
+		Ok(())
+	}
+
+	fn atom(&mut self) -> Result<(), InvalidPattern> {
+		match self.peek().ok_or_else(|| self.error())? {
+			'(' => {
+				self.pos += 1;
+				self.output.push_str("(?:");
+				self.regex()?;
+				self.expect(')')?;
+				self.output.push(')')
+			}
+			'[' => {
+				let class = self.char_class_expr()?;
+				self.output.push_str(&class)
+			}
+			'.' => {
+				self.pos += 1;
+				self.output.push_str("[^\\n\\r]")
+			}
+			'\\' => {
+				self.pos += 1;
+				match self.escape()? {
+					Escape::Char(c) => push_literal(&mut self.output, c),
+					Escape::Class(class) => self.output.push_str(&class),
+				}
+			}
+			'?' | '*' | '+' | '{' | '}' | ']' => return Err(self.error()),
+			c => {
+				self.pos += 1;
+				push_literal(&mut self.output, c)
+			}
+		}
+
+		Ok(())
+	}
+
+	fn quantifier(&mut self) -> Result<(), InvalidPattern> {
+		match self.peek() {
+			Some(c @ ('?' | '*' | '+')) => {
+				self.pos += 1;
+				self.output.push(c)
+			}
+			Some('{') => {
+				self.pos += 1;
+				self.output.push('{');
+				self.digits()?;
+				if self.eat(',') {
+					self.output.push(',');
+					if self.peek() != Some('}') {
+						self.digits()?
+					}
+				}
+				self.expect('}')?;
+				self.output.push('}')
+			}
+			_ => return Ok(()),
+		}
+
+		// Quantifiers cannot be stacked, and there are no lazy quantifiers.
+		if matches!(self.peek(), Some('?' | '*' | '+' | '{')) {
+			Err(self.error())
+		} else {
+			Ok(())
+		}
+	}
+
+	fn digits(&mut self) -> Result<(), InvalidPattern> {
+		let start = self.pos;
+		while let Some(c @ '0'..='9') = self.peek() {
+			self.pos += 1;
+			self.output.push(c)
+		}
+
+		if self.pos == start {
+			Err(self.error())
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Parses an escape sequence, after the `\`.
+	fn escape(&mut self) -> Result<Escape, InvalidPattern> {
+		let class = match self.next()? {
+			'n' => return Ok(Escape::Char('\n')),
+			'r' => return Ok(Escape::Char('\r')),
+			't' => return Ok(Escape::Char('\t')),
+			c @ ('\\' | '|' | '.' | '?' | '*' | '+' | '(' | ')' | '{' | '}' | '-' | '[' | ']'
+			| '^') => return Ok(Escape::Char(c)),
+			's' => "[\\x{20}\\t\\n\\r]".to_owned(),
+			'S' => "[^\\x{20}\\t\\n\\r]".to_owned(),
+			'i' => format!("[{NAME_START_CHARS}]"),
+			'I' => format!("[^{NAME_START_CHARS}]"),
+			'c' => format!("[{NAME_START_CHARS}{NAME_CHARS}]"),
+			'C' => format!("[^{NAME_START_CHARS}{NAME_CHARS}]"),
+			'd' => "\\p{Nd}".to_owned(),
+			'D' => "\\P{Nd}".to_owned(),
+			'w' => "[^\\p{P}\\p{Z}\\p{C}]".to_owned(),
+			'W' => "[\\p{P}\\p{Z}\\p{C}]".to_owned(),
+			c @ ('p' | 'P') => {
+				self.expect('{')?;
+				let mut name = String::new();
+				while let Some(n) = self
+					.peek()
+					.filter(|n| n.is_ascii_alphanumeric() || *n == '-')
+				{
+					self.pos += 1;
+					name.push(n)
+				}
+				if name.is_empty() {
+					return Err(self.error());
+				}
+				self.expect('}')?;
+
+				if name.starts_with("Is") {
+					return Err(InvalidPattern::UnsupportedBlock(format!("\\{c}{{{name}}}")));
+				}
+
+				format!("\\{c}{{{name}}}")
+			}
+			_ => {
+				self.pos -= 1;
+				return Err(self.error());
+			}
+		};
+
+		Ok(Escape::Class(class))
+	}
+
+	/// Parses a character class expression, starting at the `[`.
+	fn char_class_expr(&mut self) -> Result<String, InvalidPattern> {
+		self.expect('[')?;
+		let negated = self.eat('^');
+		let mut items = String::new();
+		let mut subtraction = None;
+
+		loop {
+			match self.peek() {
+				Some(']') if !items.is_empty() => {
+					self.pos += 1;
+					break;
+				}
+				Some('-') if !items.is_empty() && self.peek_at(1) == Some('[') => {
+					self.pos += 1;
+					subtraction = Some(self.char_class_expr()?);
+					self.expect(']')?;
+					break;
+				}
+				_ => self.class_item(&mut items)?,
+			}
+		}
+
+		let base = format!("[{}{items}]", if negated { "^" } else { "" });
+		Ok(match subtraction {
+			Some(subtraction) => format!("[{base}--{subtraction}]"),
+			None => base,
+		})
+	}
+
+	fn class_item(&mut self, items: &mut String) -> Result<(), InvalidPattern> {
+		let start = match self.class_char()? {
+			Escape::Char(c) => c,
+			Escape::Class(class) => {
+				items.push_str(&class);
+				return Ok(());
+			}
+		};
+
+		if self.peek() == Some('-') && !matches!(self.peek_at(1), Some(']' | '[')) {
+			self.pos += 1;
+			let end = match self.class_char()? {
+				Escape::Char(c) if c >= start => c,
+				_ => return Err(self.error()),
+			};
+
+			push_literal(items, start);
+			items.push('-');
+			push_literal(items, end)
+		} else {
+			push_literal(items, start)
+		}
+
+		Ok(())
+	}
+
+	fn class_char(&mut self) -> Result<Escape, InvalidPattern> {
+		match self.next()? {
+			'\\' => self.escape(),
+			'[' | ']' => {
+				self.pos -= 1;
+				Err(self.error())
+			}
+			c => Ok(Escape::Char(c)),
+		}
+	}
+}
+
+/// Pushes a literal character, escaping it if it is not alphanumeric so
+/// that it never has a special meaning.
+fn push_literal(output: &mut String, c: char) {
+	if c.is_ascii_alphanumeric() {
+		output.push(c)
+	} else {
+		output.push_str(&format!("\\x{{{:X}}}", c as u32))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn xsd_dialect() {
+		for (pattern, valid, invalid) in [
+			("a^b$", &["a^b$"][..], &["ab"][..]),
+			(
+				"[0-9]{3}-[0-9]{4}",
+				&["555-1234"],
+				&["5551234", "x555-1234"],
+			),
+			(".+", &["a b"], &["a\nb", "a\rb", ""]),
+			(r"\i\c*", &["_foo.bar-1"], &["1foo", "foo bar"]),
+			("[a-z-[aeiou]]+", &["xyz"], &["xaz"]),
+			("[^a-z-[x]]", &["A"], &["a", "x"]),
+			(r"\s\S", &[" a"], &["a "]),
+			(r"\p{Lu}\P{Lu}", &["Ab"], &["AB"]),
+			("[-a]+|b{2,}", &["-a-", "bbb"], &["b"]),
+			(r"\d+(\.\d+)?", &["1.5", "42"], &["1.", ".5"]),
+			("", &[""], &["a"]),
+		] {
+			let pattern = Pattern::new(pattern).unwrap();
+			for s in valid {
+				assert!(pattern.is_match(s), "{pattern} should match {s:?}")
+			}
+			for s in invalid {
+				assert!(!pattern.is_match(s), "{pattern} should not match {s:?}")
+			}
+		}
+
+		for pattern in [
+			"a**", "a??", "(?i)a", "a)", "[]", "[a", "{1}", r"\x", "a{,2}",
+		] {
+			assert!(Pattern::new(pattern).is_err(), "{pattern}")
+		}
+
+		assert!(matches!(
+			Pattern::new(r"\p{IsBasicLatin}"),
+			Err(InvalidPattern::UnsupportedBlock(_))
+		))
+	}
+
+	#[test]
+	fn facet() {
+		let facet = PatternFacet::new(["[0-9]+", "N/A"]).unwrap();
+		assert!(facet.is_satisfied_by("42"));
+		assert!(facet.is_satisfied_by("N/A"));
+		assert!(!facet.is_satisfied_by("n/a"));
+		assert!(PatternFacet::default().is_satisfied_by("anything"))
+	}
+}
@@ -0,0 +1,186 @@
+//! Python bindings, generated with [PyO3].
+//!
+//! ```python
+//! import xsd_types
+//!
+//! decimal = xsd_types.Datatype.from_iri("http://www.w3.org/2001/XMLSchema#decimal")
+//! value = decimal.parse("+01.50")
+//! str(value)          # '1.5'
+//! value.to_python()   # Decimal('1.5')
+//! xsd_types.canonicalize("http://www.w3.org/2001/XMLSchema#decimal", "+01.50")  # '1.5'
+//! ```
+//!
+//! [PyO3]: <https://pyo3.rs/>
+use chrono::Timelike;
+use iref::Iri;
+use num_bigint::BigInt;
+use pyo3::{
+	exceptions::PyValueError,
+	prelude::*,
+	types::{PyBytes, PyModule},
+};
+
+use crate::{Datatype, ParseError, Value};
+
+impl From<ParseError> for PyErr {
+	fn from(value: ParseError) -> Self {
+		PyValueError::new_err(value.to_string())
+	}
+}
+
+/// XSD datatype.
+#[pyclass(name = "Datatype", module = "xsd_types", frozen)]
+#[derive(Clone, Copy)]
+pub struct PyDatatype(pub Datatype);
+
+#[pymethods]
+impl PyDatatype {
+	/// Returns the datatype identified by the given IRI.
+	#[staticmethod]
+	fn from_iri(iri: &str) -> PyResult<Self> {
+		datatype(iri).map(Self)
+	}
+
+	/// IRI of the datatype.
+	#[getter]
+	fn iri(&self) -> &'static str {
+		self.0.iri().as_str()
+	}
+
+	/// Parses a value of this datatype from its lexical form.
+	fn parse(&self, lexical_value: &str) -> PyResult<PyValue> {
+		Ok(PyValue(self.0.parse(lexical_value)?))
+	}
+
+	fn __repr__(&self) -> String {
+		format!("Datatype({:?})", self.0.iri().as_str())
+	}
+
+	fn __eq__(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+
+/// XSD value.
+#[pyclass(name = "Value", module = "xsd_types", frozen)]
+#[derive(Clone)]
+pub struct PyValue(pub Value);
+
+#[pymethods]
+impl PyValue {
+	/// Datatype of the value.
+	#[getter]
+	fn datatype(&self) -> PyDatatype {
+		PyDatatype(self.0.datatype())
+	}
+
+	/// Converts the value into a native Python object.
+	///
+	/// Numbers are converted into `bool`, `int`, `float` or
+	/// `decimal.Decimal`, strings into `str`, binary values into `bytes` and
+	/// date/time values into `datetime` objects. Values that have no lossless
+	/// native representation (e.g. durations, or date/times with sub-microsecond
+	/// precision) are returned unchanged.
+	fn to_python(&self, py: Python) -> PyResult<PyObject> {
+		to_python(py, &self.0).map(|o| o.unwrap_or_else(|| self.clone().into_py(py)))
+	}
+
+	fn __str__(&self) -> String {
+		self.0.to_string()
+	}
+
+	fn __repr__(&self) -> String {
+		format!(
+			"Value({:?}, {:?})",
+			self.0.to_string(),
+			self.0.datatype().iri().as_str()
+		)
+	}
+}
+
+/// Returns the canonical form of the given lexical form of the datatype
+/// identified by `datatype_iri`.
+#[pyfunction]
+fn canonicalize(datatype_iri: &str, lexical_value: &str) -> PyResult<String> {
+	Ok(datatype(datatype_iri)?.parse(lexical_value)?.to_string())
+}
+
+/// Python module definition.
+#[pymodule]
+pub fn xsd_types(_py: Python, m: &PyModule) -> PyResult<()> {
+	m.add_class::<PyDatatype>()?;
+	m.add_class::<PyValue>()?;
+	m.add_function(wrap_pyfunction!(canonicalize, m)?)?;
+	Ok(())
+}
+
+fn datatype(iri: &str) -> PyResult<Datatype> {
+	let iri = Iri::new(iri).map_err(|_| PyValueError::new_err("invalid IRI"))?;
+	Datatype::from_iri(iri).ok_or_else(|| ParseError::UnknownDatatype.into())
+}
+
+/// Checks that the given time has an exact microsecond representation.
+fn is_microsecond_precise(time: &impl Timelike) -> bool {
+	time.nanosecond() % 1_000 == 0 && time.nanosecond() < 1_000_000_000
+}
+
+/// Checks that the given year is within the range of Python's `datetime`.
+fn is_python_year(year: i32) -> bool {
+	(1..=9999).contains(&year)
+}
+
+fn to_python(py: Python, value: &Value) -> PyResult<Option<PyObject>> {
+	use chrono::Datelike;
+
+	if let Some(s) = value.as_str() {
+		return Ok(Some(s.into_py(py)));
+	}
+
+	let object = match value {
+		Value::Boolean(b) => b.0.into_py(py),
+		Value::Float(f) => f64::from(*f).into_py(py),
+		Value::Double(d) => f64::from(*d).into_py(py),
+		Value::Decimal(d) => PyModule::import(py, "decimal")?
+			.getattr("Decimal")?
+			.call1((d.to_string(),))?
+			.into_py(py),
+		Value::Integer(i) => BigInt::from(i.clone()).into_py(py),
+		Value::NonPositiveInteger(i) => i.clone().into_big_int().into_py(py),
+		Value::NegativeInteger(i) => i.clone().into_big_int().into_py(py),
+		Value::NonNegativeInteger(i) => i.clone().into_big_int().into_py(py),
+		Value::PositiveInteger(i) => i.clone().into_big_int().into_py(py),
+		Value::UnsignedLong(i) => (*i).into_py(py),
+		Value::UnsignedInt(i) => (*i).into_py(py),
+		Value::UnsignedShort(i) => (*i).into_py(py),
+		Value::UnsignedByte(i) => (*i).into_py(py),
+		Value::Long(i) => (*i).into_py(py),
+		Value::Int(i) => (*i).into_py(py),
+		Value::Short(i) => (*i).into_py(py),
+		Value::Byte(i) => (*i).into_py(py),
+		Value::DateTime(d)
+			if is_microsecond_precise(&d.date_time) && is_python_year(d.date_time.year()) =>
+		{
+			match d.offset {
+				Some(offset) => d.date_time.and_local_timezone(offset).unwrap().into_py(py),
+				None => d.date_time.into_py(py),
+			}
+		}
+		Value::DateTimeStamp(d)
+			if is_microsecond_precise(&d.date_time) && is_python_year(d.date_time.year()) =>
+		{
+			d.date_time
+				.and_local_timezone(d.offset)
+				.unwrap()
+				.into_py(py)
+		}
+		Value::Date(d) if d.offset.is_none() && is_python_year(d.date.year()) => d.date.into_py(py),
+		Value::Time(t) if t.offset.is_none() && is_microsecond_precise(&t.time) => {
+			t.time.into_py(py)
+		}
+		Value::Base64Binary(b) => PyBytes::new(py, b.as_bytes()).into_py(py),
+		Value::HexBinary(b) => PyBytes::new(py, b.as_bytes()).into_py(py),
+		_ => return Ok(None),
+	};
+
+	Ok(Some(object))
+}
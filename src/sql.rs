@@ -0,0 +1,200 @@
+use crate::{Datatype, XSD_NAMESPACE};
+
+/// SQL dialect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SqlDialect {
+	Postgres,
+	Sqlite,
+}
+
+/// Column types of the numeric, temporal and binary datatypes, by XSD local
+/// name, as (PostgreSQL, SQLite) pairs.
+///
+/// Every other datatype is stored as `TEXT` in both dialects. Arbitrary
+/// precision numbers are stored as `TEXT` in SQLite since its `NUMERIC`
+/// affinity may lose precision.
+const SQL_TYPES: &[(&str, &str, &str)] = &[
+	("boolean", "BOOLEAN", "INTEGER"),
+	("float", "REAL", "REAL"),
+	("double", "DOUBLE PRECISION", "REAL"),
+	("decimal", "NUMERIC", "TEXT"),
+	("integer", "NUMERIC", "TEXT"),
+	("nonPositiveInteger", "NUMERIC", "TEXT"),
+	("negativeInteger", "NUMERIC", "TEXT"),
+	("nonNegativeInteger", "NUMERIC", "TEXT"),
+	("positiveInteger", "NUMERIC", "TEXT"),
+	("unsignedLong", "NUMERIC(20, 0)", "TEXT"),
+	("unsignedInt", "BIGINT", "INTEGER"),
+	("unsignedShort", "INTEGER", "INTEGER"),
+	("unsignedByte", "SMALLINT", "INTEGER"),
+	("long", "BIGINT", "INTEGER"),
+	("int", "INTEGER", "INTEGER"),
+	("short", "SMALLINT", "INTEGER"),
+	("byte", "SMALLINT", "INTEGER"),
+	("duration", "INTERVAL", "TEXT"),
+	("dayTimeDuration", "INTERVAL", "TEXT"),
+	("yearMonthDuration", "INTERVAL", "TEXT"),
+	("dateTime", "TIMESTAMP", "TEXT"),
+	("dateTimeStamp", "TIMESTAMPTZ", "TEXT"),
+	("time", "TIME", "TEXT"),
+	("date", "DATE", "TEXT"),
+	("base64Binary", "BYTEA", "BLOB"),
+	("hexBinary", "BYTEA", "BLOB"),
+];
+
+/// Suggested datatypes for SQL column type names, as (SQL type, XSD local
+/// name) pairs, for each dialect.
+const POSTGRES_DATATYPES: &[(&str, &str)] = &[
+	("BOOLEAN", "boolean"),
+	("BOOL", "boolean"),
+	("SMALLINT", "short"),
+	("INT2", "short"),
+	("INTEGER", "int"),
+	("INT", "int"),
+	("INT4", "int"),
+	("BIGINT", "long"),
+	("INT8", "long"),
+	("NUMERIC", "decimal"),
+	("DECIMAL", "decimal"),
+	("REAL", "float"),
+	("FLOAT4", "float"),
+	("DOUBLE PRECISION", "double"),
+	("FLOAT8", "double"),
+	("TEXT", "string"),
+	("VARCHAR", "string"),
+	("CHARACTER VARYING", "string"),
+	("CHAR", "string"),
+	("CHARACTER", "string"),
+	("INTERVAL", "duration"),
+	("TIMESTAMP", "dateTime"),
+	("TIMESTAMP WITHOUT TIME ZONE", "dateTime"),
+	("TIMESTAMPTZ", "dateTimeStamp"),
+	("TIMESTAMP WITH TIME ZONE", "dateTimeStamp"),
+	("TIME", "time"),
+	("TIME WITHOUT TIME ZONE", "time"),
+	("DATE", "date"),
+	("BYTEA", "hexBinary"),
+];
+
+const SQLITE_DATATYPES: &[(&str, &str)] = &[
+	("INTEGER", "long"),
+	("INT", "long"),
+	("BIGINT", "long"),
+	("SMALLINT", "short"),
+	("TINYINT", "byte"),
+	("BOOLEAN", "boolean"),
+	("REAL", "double"),
+	("DOUBLE", "double"),
+	("FLOAT", "double"),
+	("NUMERIC", "decimal"),
+	("DECIMAL", "decimal"),
+	("TEXT", "string"),
+	("VARCHAR", "string"),
+	("CHAR", "string"),
+	("CLOB", "string"),
+	("BLOB", "base64Binary"),
+	("DATE", "date"),
+	("DATETIME", "dateTime"),
+];
+
+impl Datatype {
+	/// Returns the suggested column type to store values of this datatype in
+	/// the given SQL dialect.
+	///
+	/// ```
+	/// use xsd_types::{Datatype, SqlDialect, XSD_LONG};
+	///
+	/// let long = Datatype::from_iri(XSD_LONG).unwrap();
+	/// assert_eq!(long.sql_type(SqlDialect::Postgres), "BIGINT");
+	/// assert_eq!(long.sql_type(SqlDialect::Sqlite), "INTEGER");
+	/// ```
+	pub fn sql_type(&self, dialect: SqlDialect) -> &'static str {
+		let iri = self.iri().as_str();
+		let name = &iri[XSD_NAMESPACE.len()..];
+		SQL_TYPES
+			.iter()
+			.find(|(n, _, _)| *n == name)
+			.map(|(_, postgres, sqlite)| match dialect {
+				SqlDialect::Postgres => *postgres,
+				SqlDialect::Sqlite => *sqlite,
+			})
+			.unwrap_or("TEXT")
+	}
+
+	/// Returns the suggested datatype for values of the given SQL column
+	/// type, if any.
+	///
+	/// The type name is case-insensitive, and any parameter list (as in
+	/// `VARCHAR(255)`) is ignored.
+	pub fn from_sql_type(sql_type: &str, dialect: SqlDialect) -> Option<Self> {
+		let name = match sql_type.find('(') {
+			Some(i) => &sql_type[..i],
+			None => sql_type,
+		}
+		.trim();
+
+		let table = match dialect {
+			SqlDialect::Postgres => POSTGRES_DATATYPES,
+			SqlDialect::Sqlite => SQLITE_DATATYPES,
+		};
+
+		table
+			.iter()
+			.find(|(t, _)| t.eq_ignore_ascii_case(name))
+			.and_then(|(_, local_name)| Self::from_local_name(local_name))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		XSD_DATE_TIME_STAMP, XSD_DECIMAL, XSD_HEX_BINARY, XSD_INT, XSD_LONG, XSD_STRING, XSD_TOKEN,
+	};
+
+	#[test]
+	fn sql_types() {
+		let decimal = Datatype::from_iri(XSD_DECIMAL).unwrap();
+		assert_eq!(decimal.sql_type(SqlDialect::Postgres), "NUMERIC");
+		assert_eq!(decimal.sql_type(SqlDialect::Sqlite), "TEXT");
+
+		let token = Datatype::from_iri(XSD_TOKEN).unwrap();
+		assert_eq!(token.sql_type(SqlDialect::Postgres), "TEXT");
+
+		let stamp = Datatype::from_iri(XSD_DATE_TIME_STAMP).unwrap();
+		assert_eq!(stamp.sql_type(SqlDialect::Postgres), "TIMESTAMPTZ");
+		assert_eq!(
+			Datatype::from_sql_type("timestamp with time zone", SqlDialect::Postgres),
+			Some(stamp)
+		);
+
+		assert_eq!(
+			Datatype::from_sql_type("varchar(255)", SqlDialect::Postgres),
+			Datatype::from_iri(XSD_STRING)
+		);
+		assert_eq!(
+			Datatype::from_sql_type("INTEGER", SqlDialect::Postgres),
+			Datatype::from_iri(XSD_INT)
+		);
+		assert_eq!(
+			Datatype::from_sql_type("INTEGER", SqlDialect::Sqlite),
+			Datatype::from_iri(XSD_LONG)
+		);
+		assert_eq!(
+			Datatype::from_sql_type("bytea", SqlDialect::Postgres),
+			Datatype::from_iri(XSD_HEX_BINARY)
+		);
+		assert!(Datatype::from_sql_type("JSONB", SqlDialect::Postgres).is_none())
+	}
+
+	#[test]
+	fn sql_types_are_datatypes() {
+		for (name, _, _) in SQL_TYPES {
+			assert!(Datatype::from_local_name(name).is_some())
+		}
+
+		for (_, name) in POSTGRES_DATATYPES.iter().chain(SQLITE_DATATYPES) {
+			assert!(Datatype::from_local_name(name).is_some())
+		}
+	}
+}
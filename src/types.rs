@@ -8,23 +8,56 @@ use super::{
 	YearMonthDuration,
 };
 use crate::{
-	ParseXsd, XsdValue, XSD_ANY_URI, XSD_BASE64_BINARY, XSD_BOOLEAN, XSD_BYTE, XSD_DATE,
-	XSD_DATE_TIME, XSD_DATE_TIME_STAMP, XSD_DAY_TIME_DURATION, XSD_DECIMAL, XSD_DOUBLE,
-	XSD_DURATION, XSD_FLOAT, XSD_G_DAY, XSD_G_MONTH, XSD_G_MONTH_DAY, XSD_G_YEAR, XSD_G_YEAR_MONTH,
-	XSD_HEX_BINARY, XSD_ID, XSD_IDREF, XSD_INT, XSD_INTEGER, XSD_LANGUAGE, XSD_LONG, XSD_NAME,
-	XSD_NC_NAME, XSD_NEGATIVE_INTEGER, XSD_NMTOKEN, XSD_NON_NEGATIVE_INTEGER,
-	XSD_NON_POSITIVE_INTEGER, XSD_NORMALIZED_STRING, XSD_POSITIVE_INTEGER, XSD_Q_NAME, XSD_SHORT,
-	XSD_STRING, XSD_TIME, XSD_TOKEN, XSD_UNSIGNED_BYTE, XSD_UNSIGNED_INT, XSD_UNSIGNED_LONG,
-	XSD_UNSIGNED_SHORT, XSD_YEAR_MONTH_DURATION,
+	datatype_from_iri, value::sealed, FromValue, ParseXsd, XsdValue, XSD_ANY_URI,
+	XSD_BASE64_BINARY, XSD_BOOLEAN, XSD_BYTE, XSD_DATE, XSD_DATE_TIME, XSD_DATE_TIME_STAMP,
+	XSD_DAY_TIME_DURATION, XSD_DECIMAL, XSD_DOUBLE, XSD_DURATION, XSD_ERROR, XSD_FLOAT, XSD_G_DAY,
+	XSD_G_MONTH, XSD_G_MONTH_DAY, XSD_G_YEAR, XSD_G_YEAR_MONTH, XSD_HEX_BINARY, XSD_ID, XSD_IDREF,
+	XSD_INT, XSD_INTEGER, XSD_LANGUAGE, XSD_LONG, XSD_NAME, XSD_NC_NAME, XSD_NEGATIVE_INTEGER,
+	XSD_NMTOKEN, XSD_NON_NEGATIVE_INTEGER, XSD_NON_POSITIVE_INTEGER, XSD_NORMALIZED_STRING,
+	XSD_POSITIVE_INTEGER, XSD_Q_NAME, XSD_SHORT, XSD_STRING, XSD_TIME, XSD_TOKEN,
+	XSD_UNSIGNED_BYTE, XSD_UNSIGNED_INT, XSD_UNSIGNED_LONG, XSD_UNSIGNED_SHORT,
+	XSD_YEAR_MONTH_DURATION,
 };
 use iref::Iri;
 use std::fmt;
 /// XSD value parse error.
-#[derive(Debug, thiserror::Error)]
-#[error("XSD value syntax error")]
-pub struct ParseError;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ParseError {
+	/// The lexical form is empty, which the datatype does not allow.
+	#[error("empty lexical form")]
+	EmptyLexicalForm,
+	/// The lexical form is not valid for the datatype.
+	#[error("XSD value syntax error")]
+	Invalid,
+	/// The datatype IRI is not a known XSD datatype.
+	#[error("unknown XSD datatype")]
+	UnknownDatatype,
+	/// The datatype has an empty value space (`xsd:error`), hence no
+	/// lexical form is valid.
+	#[error("empty value space")]
+	EmptyValueSpace,
+}
+impl ParseError {
+	pub(crate) fn invalid(value: &str) -> Self {
+		if value.is_empty() {
+			Self::EmptyLexicalForm
+		} else {
+			Self::Invalid
+		}
+	}
+}
 /// XSD datatype (primitive or not).
+///
+/// # Ordering
+///
+/// Datatypes are ordered following a pre-order traversal of the datatype
+/// hierarchy: a datatype always comes before the datatypes derived from
+/// it, and sibling datatypes are ordered by declaration order. This order
+/// is stable across versions, new datatypes being added after their
+/// existing siblings.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
 pub enum Datatype {
 	Boolean,
 	Float,
@@ -44,64 +77,122 @@ pub enum Datatype {
 	HexBinary,
 	AnyUri,
 	QName,
+	/// `xsd:error` datatype, with empty lexical and value spaces (XSD 1.1).
+	Error,
 }
 impl Datatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_BOOLEAN {
-			return Some(Self::Boolean);
-		}
-		if iri == XSD_FLOAT {
-			return Some(Self::Float);
-		}
-		if iri == XSD_DOUBLE {
-			return Some(Self::Double);
-		}
-		if let Some(t) = DecimalDatatype::from_iri(iri) {
-			return Some(Self::Decimal(t));
-		}
-		if let Some(t) = StringDatatype::from_iri(iri) {
-			return Some(Self::String(t));
-		}
-		if let Some(t) = DurationDatatype::from_iri(iri) {
-			return Some(Self::Duration(t));
-		}
-		if let Some(t) = DateTimeDatatype::from_iri(iri) {
-			return Some(Self::DateTime(t));
-		}
-		if iri == XSD_TIME {
-			return Some(Self::Time);
-		}
-		if iri == XSD_DATE {
-			return Some(Self::Date);
-		}
-		if iri == XSD_G_YEAR_MONTH {
-			return Some(Self::GYearMonth);
-		}
-		if iri == XSD_G_YEAR {
-			return Some(Self::GYear);
-		}
-		if iri == XSD_G_MONTH_DAY {
-			return Some(Self::GMonthDay);
-		}
-		if iri == XSD_G_DAY {
-			return Some(Self::GDay);
-		}
-		if iri == XSD_G_MONTH {
-			return Some(Self::GMonth);
-		}
-		if iri == XSD_BASE64_BINARY {
-			return Some(Self::Base64Binary);
-		}
-		if iri == XSD_HEX_BINARY {
-			return Some(Self::HexBinary);
-		}
-		if iri == XSD_ANY_URI {
-			return Some(Self::AnyUri);
-		}
-		if iri == XSD_Q_NAME {
-			return Some(Self::QName);
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace
+	/// (for instance `integer`).
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"boolean" => Some(Self::Boolean),
+			"float" => Some(Self::Float),
+			"double" => Some(Self::Double),
+			"decimal" => Some(Self::Decimal(DecimalDatatype::Decimal)),
+			"integer" => Some(Self::Decimal(DecimalDatatype::Integer(
+				IntegerDatatype::Integer,
+			))),
+			"nonPositiveInteger" => Some(Self::Decimal(DecimalDatatype::Integer(
+				IntegerDatatype::NonPositiveInteger(NonPositiveIntegerDatatype::NonPositiveInteger),
+			))),
+			"negativeInteger" => Some(Self::Decimal(DecimalDatatype::Integer(
+				IntegerDatatype::NonPositiveInteger(NonPositiveIntegerDatatype::NegativeInteger),
+			))),
+			"nonNegativeInteger" => Some(Self::Decimal(DecimalDatatype::Integer(
+				IntegerDatatype::NonNegativeInteger(NonNegativeIntegerDatatype::NonNegativeInteger),
+			))),
+			"positiveInteger" => Some(Self::Decimal(DecimalDatatype::Integer(
+				IntegerDatatype::NonNegativeInteger(NonNegativeIntegerDatatype::PositiveInteger),
+			))),
+			"unsignedLong" => Some(Self::Decimal(DecimalDatatype::Integer(
+				IntegerDatatype::NonNegativeInteger(NonNegativeIntegerDatatype::UnsignedLong(
+					UnsignedLongDatatype::UnsignedLong,
+				)),
+			))),
+			"unsignedInt" => Some(Self::Decimal(DecimalDatatype::Integer(
+				IntegerDatatype::NonNegativeInteger(NonNegativeIntegerDatatype::UnsignedLong(
+					UnsignedLongDatatype::UnsignedInt(UnsignedIntDatatype::UnsignedInt),
+				)),
+			))),
+			"unsignedShort" => Some(Self::Decimal(DecimalDatatype::Integer(
+				IntegerDatatype::NonNegativeInteger(NonNegativeIntegerDatatype::UnsignedLong(
+					UnsignedLongDatatype::UnsignedInt(UnsignedIntDatatype::UnsignedShort(
+						UnsignedShortDatatype::UnsignedShort,
+					)),
+				)),
+			))),
+			"unsignedByte" => Some(Self::Decimal(DecimalDatatype::Integer(
+				IntegerDatatype::NonNegativeInteger(NonNegativeIntegerDatatype::UnsignedLong(
+					UnsignedLongDatatype::UnsignedInt(UnsignedIntDatatype::UnsignedShort(
+						UnsignedShortDatatype::UnsignedByte,
+					)),
+				)),
+			))),
+			"long" => Some(Self::Decimal(DecimalDatatype::Integer(
+				IntegerDatatype::Long(LongDatatype::Long),
+			))),
+			"int" => Some(Self::Decimal(DecimalDatatype::Integer(
+				IntegerDatatype::Long(LongDatatype::Int(IntDatatype::Int)),
+			))),
+			"short" => Some(Self::Decimal(DecimalDatatype::Integer(
+				IntegerDatatype::Long(LongDatatype::Int(IntDatatype::Short(ShortDatatype::Short))),
+			))),
+			"byte" => Some(Self::Decimal(DecimalDatatype::Integer(
+				IntegerDatatype::Long(LongDatatype::Int(IntDatatype::Short(ShortDatatype::Byte))),
+			))),
+			"string" => Some(Self::String(StringDatatype::String)),
+			"normalizedString" => Some(Self::String(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::NormalizedString,
+			))),
+			"token" => Some(Self::String(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::Token(TokenDatatype::Token),
+			))),
+			"language" => Some(Self::String(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::Token(TokenDatatype::Language),
+			))),
+			"Name" => Some(Self::String(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::Token(TokenDatatype::Name(NameDatatype::Name)),
+			))),
+			"NCName" => Some(Self::String(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::Token(TokenDatatype::Name(NameDatatype::NCName(
+					NCNameDatatype::NCName,
+				))),
+			))),
+			"ID" => Some(Self::String(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::Token(TokenDatatype::Name(NameDatatype::NCName(
+					NCNameDatatype::Id,
+				))),
+			))),
+			"IDREF" => Some(Self::String(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::Token(TokenDatatype::Name(NameDatatype::NCName(
+					NCNameDatatype::IdRef,
+				))),
+			))),
+			"NMTOKEN" => Some(Self::String(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::Token(TokenDatatype::NMToken),
+			))),
+			"duration" => Some(Self::Duration(DurationDatatype::Duration)),
+			"dayTimeDuration" => Some(Self::Duration(DurationDatatype::DayTimeDuration)),
+			"yearMonthDuration" => Some(Self::Duration(DurationDatatype::YearMonthDuration)),
+			"dateTime" => Some(Self::DateTime(DateTimeDatatype::DateTime)),
+			"dateTimeStamp" => Some(Self::DateTime(DateTimeDatatype::DateTimeStamp)),
+			"time" => Some(Self::Time),
+			"date" => Some(Self::Date),
+			"gYearMonth" => Some(Self::GYearMonth),
+			"gYear" => Some(Self::GYear),
+			"gMonthDay" => Some(Self::GMonthDay),
+			"gDay" => Some(Self::GDay),
+			"gMonth" => Some(Self::GMonth),
+			"base64Binary" => Some(Self::Base64Binary),
+			"hexBinary" => Some(Self::HexBinary),
+			"anyURI" => Some(Self::AnyUri),
+			"QName" => Some(Self::QName),
+			"error" => Some(Self::Error),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -123,60 +214,65 @@ impl Datatype {
 			Self::HexBinary => XSD_HEX_BINARY,
 			Self::AnyUri => XSD_ANY_URI,
 			Self::QName => XSD_Q_NAME,
+			Self::Error => XSD_ERROR,
 		}
 	}
 	pub fn parse(&self, value: &str) -> Result<Value, ParseError> {
 		match self {
 			Self::Boolean => ParseXsd::parse_xsd(value)
 				.map(Value::Boolean)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::Float => ParseXsd::parse_xsd(value)
 				.map(Value::Float)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::Double => ParseXsd::parse_xsd(value)
 				.map(Value::Double)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::Decimal(t) => t.parse(value).map(Into::into),
 			Self::String(t) => t.parse(value).map(Into::into),
 			Self::Duration(t) => t.parse(value).map(Into::into),
 			Self::DateTime(t) => t.parse(value).map(Into::into),
 			Self::Time => ParseXsd::parse_xsd(value)
 				.map(Value::Time)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::Date => ParseXsd::parse_xsd(value)
 				.map(Value::Date)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::GYearMonth => ParseXsd::parse_xsd(value)
 				.map(Value::GYearMonth)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::GYear => ParseXsd::parse_xsd(value)
 				.map(Value::GYear)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::GMonthDay => ParseXsd::parse_xsd(value)
 				.map(Value::GMonthDay)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::GDay => ParseXsd::parse_xsd(value)
 				.map(Value::GDay)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::GMonth => ParseXsd::parse_xsd(value)
 				.map(Value::GMonth)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::Base64Binary => ParseXsd::parse_xsd(value)
 				.map(Value::Base64Binary)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::HexBinary => ParseXsd::parse_xsd(value)
 				.map(Value::HexBinary)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::AnyUri => ParseXsd::parse_xsd(value)
 				.map(Value::AnyUri)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::QName => ParseXsd::parse_xsd(value)
 				.map(Value::QName)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
+			Self::Error => Err(ParseError::EmptyValueSpace),
 		}
 	}
 }
 /// [`Decimal`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum DecimalDatatype {
 	Decimal,
@@ -184,13 +280,63 @@ pub enum DecimalDatatype {
 }
 impl DecimalDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_DECIMAL {
-			return Some(Self::Decimal);
-		}
-		if let Some(t) = IntegerDatatype::from_iri(iri) {
-			return Some(Self::Integer(t));
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"decimal" => Some(DecimalDatatype::Decimal),
+			"integer" => Some(DecimalDatatype::Integer(IntegerDatatype::Integer)),
+			"nonPositiveInteger" => Some(DecimalDatatype::Integer(
+				IntegerDatatype::NonPositiveInteger(NonPositiveIntegerDatatype::NonPositiveInteger),
+			)),
+			"negativeInteger" => Some(DecimalDatatype::Integer(
+				IntegerDatatype::NonPositiveInteger(NonPositiveIntegerDatatype::NegativeInteger),
+			)),
+			"nonNegativeInteger" => Some(DecimalDatatype::Integer(
+				IntegerDatatype::NonNegativeInteger(NonNegativeIntegerDatatype::NonNegativeInteger),
+			)),
+			"positiveInteger" => Some(DecimalDatatype::Integer(
+				IntegerDatatype::NonNegativeInteger(NonNegativeIntegerDatatype::PositiveInteger),
+			)),
+			"unsignedLong" => Some(DecimalDatatype::Integer(
+				IntegerDatatype::NonNegativeInteger(NonNegativeIntegerDatatype::UnsignedLong(
+					UnsignedLongDatatype::UnsignedLong,
+				)),
+			)),
+			"unsignedInt" => Some(DecimalDatatype::Integer(
+				IntegerDatatype::NonNegativeInteger(NonNegativeIntegerDatatype::UnsignedLong(
+					UnsignedLongDatatype::UnsignedInt(UnsignedIntDatatype::UnsignedInt),
+				)),
+			)),
+			"unsignedShort" => Some(DecimalDatatype::Integer(
+				IntegerDatatype::NonNegativeInteger(NonNegativeIntegerDatatype::UnsignedLong(
+					UnsignedLongDatatype::UnsignedInt(UnsignedIntDatatype::UnsignedShort(
+						UnsignedShortDatatype::UnsignedShort,
+					)),
+				)),
+			)),
+			"unsignedByte" => Some(DecimalDatatype::Integer(
+				IntegerDatatype::NonNegativeInteger(NonNegativeIntegerDatatype::UnsignedLong(
+					UnsignedLongDatatype::UnsignedInt(UnsignedIntDatatype::UnsignedShort(
+						UnsignedShortDatatype::UnsignedByte,
+					)),
+				)),
+			)),
+			"long" => Some(DecimalDatatype::Integer(IntegerDatatype::Long(
+				LongDatatype::Long,
+			))),
+			"int" => Some(DecimalDatatype::Integer(IntegerDatatype::Long(
+				LongDatatype::Int(IntDatatype::Int),
+			))),
+			"short" => Some(DecimalDatatype::Integer(IntegerDatatype::Long(
+				LongDatatype::Int(IntDatatype::Short(ShortDatatype::Short)),
+			))),
+			"byte" => Some(DecimalDatatype::Integer(IntegerDatatype::Long(
+				LongDatatype::Int(IntDatatype::Short(ShortDatatype::Byte)),
+			))),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -202,7 +348,7 @@ impl DecimalDatatype {
 		match self {
 			Self::Decimal => ParseXsd::parse_xsd(value)
 				.map(DecimalValue::Decimal)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::Integer(t) => t.parse(value).map(Into::into),
 		}
 	}
@@ -354,6 +500,9 @@ impl TryFrom<DecimalDatatype> for ShortDatatype {
 	}
 }
 /// [`str`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum StringDatatype {
 	String,
@@ -361,13 +510,44 @@ pub enum StringDatatype {
 }
 impl StringDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_STRING {
-			return Some(Self::String);
-		}
-		if let Some(t) = NormalizedStringDatatype::from_iri(iri) {
-			return Some(Self::NormalizedString(t));
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"string" => Some(StringDatatype::String),
+			"normalizedString" => Some(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::NormalizedString,
+			)),
+			"token" => Some(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::Token(TokenDatatype::Token),
+			)),
+			"language" => Some(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::Token(TokenDatatype::Language),
+			)),
+			"Name" => Some(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::Token(TokenDatatype::Name(NameDatatype::Name)),
+			)),
+			"NCName" => Some(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::Token(TokenDatatype::Name(NameDatatype::NCName(
+					NCNameDatatype::NCName,
+				))),
+			)),
+			"ID" => Some(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::Token(TokenDatatype::Name(NameDatatype::NCName(
+					NCNameDatatype::Id,
+				))),
+			)),
+			"IDREF" => Some(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::Token(TokenDatatype::Name(NameDatatype::NCName(
+					NCNameDatatype::IdRef,
+				))),
+			)),
+			"NMTOKEN" => Some(StringDatatype::NormalizedString(
+				NormalizedStringDatatype::Token(TokenDatatype::NMToken),
+			)),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -379,7 +559,7 @@ impl StringDatatype {
 		match self {
 			Self::String => ParseXsd::parse_xsd(value)
 				.map(StringValue::String)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::NormalizedString(t) => t.parse(value).map(Into::into),
 		}
 	}
@@ -447,6 +627,9 @@ impl TryFrom<StringDatatype> for NCNameDatatype {
 	}
 }
 /// [`Duration`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum DurationDatatype {
 	Duration,
@@ -455,16 +638,16 @@ pub enum DurationDatatype {
 }
 impl DurationDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_DURATION {
-			return Some(Self::Duration);
-		}
-		if iri == XSD_DAY_TIME_DURATION {
-			return Some(Self::DayTimeDuration);
-		}
-		if iri == XSD_YEAR_MONTH_DURATION {
-			return Some(Self::YearMonthDuration);
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"duration" => Some(DurationDatatype::Duration),
+			"dayTimeDuration" => Some(DurationDatatype::DayTimeDuration),
+			"yearMonthDuration" => Some(DurationDatatype::YearMonthDuration),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -477,17 +660,20 @@ impl DurationDatatype {
 		match self {
 			Self::Duration => ParseXsd::parse_xsd(value)
 				.map(DurationValue::Duration)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::DayTimeDuration => ParseXsd::parse_xsd(value)
 				.map(DurationValue::DayTimeDuration)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::YearMonthDuration => ParseXsd::parse_xsd(value)
 				.map(DurationValue::YearMonthDuration)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 		}
 	}
 }
 /// [`DateTime`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum DateTimeDatatype {
 	DateTime,
@@ -495,13 +681,15 @@ pub enum DateTimeDatatype {
 }
 impl DateTimeDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_DATE_TIME {
-			return Some(Self::DateTime);
-		}
-		if iri == XSD_DATE_TIME_STAMP {
-			return Some(Self::DateTimeStamp);
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"dateTime" => Some(DateTimeDatatype::DateTime),
+			"dateTimeStamp" => Some(DateTimeDatatype::DateTimeStamp),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -513,15 +701,15 @@ impl DateTimeDatatype {
 		match self {
 			Self::DateTime => ParseXsd::parse_xsd(value)
 				.map(DateTimeValue::DateTime)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::DateTimeStamp => ParseXsd::parse_xsd(value)
 				.map(DateTimeValue::DateTimeStamp)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 		}
 	}
 }
 /// Any XSD value.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub enum Value {
 	Boolean(Boolean),
 	Float(Float),
@@ -728,7 +916,7 @@ impl fmt::Display for Value {
 	}
 }
 /// Any XSD value reference.
-#[derive(Debug, Clone, Copy)]
+#[derive(Clone, Copy)]
 pub enum ValueRef<'a> {
 	Boolean(Boolean),
 	Float(Float),
@@ -1031,6 +1219,140 @@ impl<'a> XsdValue for ValueRef<'a> {
 		self.datatype()
 	}
 }
+/// Implements the conversion of the given types into their `Value` variant,
+/// as well as `FromValue`.
+///
+/// Each entry has the form `Variant(Type) => RefType`, where `RefType` is
+/// `FromValue::Ref<'a>`.
+macro_rules! value_conversions {
+	{ $( $variant:ident($ty:ty) => $ref_ty:ty ),* $(,)? } => {
+		$(
+			impl From<$ty> for Value {
+				fn from(value: $ty) -> Self {
+					Self::$variant(value)
+				}
+			}
+			impl sealed::Sealed for $ty {}
+			impl FromValue for $ty {
+				type Ref<'a> = $ref_ty;
+				fn from_value(value: Value) -> Result<Self, Value> {
+					match value {
+						Value::$variant(value) => Ok(value),
+						other => Err(other),
+					}
+				}
+				fn from_value_ref(value: ValueRef<'_>) -> Option<Self::Ref<'_>> {
+					match value {
+						ValueRef::$variant(value) => Some(value),
+						_ => None,
+					}
+				}
+			}
+		)*
+	};
+}
+/// Implements `TryFrom<Value>` for the given `FromValue` types.
+///
+/// `String` is excluded since it already implements `From<Value>`.
+macro_rules! try_from_value {
+	{ $( $ty:ty ),* $(,)? } => {
+		$(
+			impl TryFrom<Value> for $ty {
+				type Error = Value;
+				fn try_from(value: Value) -> Result<Self, Value> {
+					Self::from_value(value)
+				}
+			}
+		)*
+	};
+}
+value_conversions! {
+	Boolean(Boolean) => Boolean,
+	Float(Float) => Float,
+	Double(Double) => Double,
+	Decimal(Decimal) => &'a Decimal,
+	Integer(Integer) => &'a Integer,
+	NonPositiveInteger(NonPositiveInteger) => &'a NonPositiveInteger,
+	NegativeInteger(NegativeInteger) => &'a NegativeInteger,
+	NonNegativeInteger(NonNegativeInteger) => &'a NonNegativeInteger,
+	PositiveInteger(PositiveInteger) => &'a PositiveInteger,
+	UnsignedLong(UnsignedLong) => UnsignedLong,
+	UnsignedInt(UnsignedInt) => UnsignedInt,
+	UnsignedShort(UnsignedShort) => UnsignedShort,
+	UnsignedByte(UnsignedByte) => UnsignedByte,
+	Long(Long) => Long,
+	Int(Int) => Int,
+	Short(Short) => Short,
+	Byte(Byte) => Byte,
+	String(String) => &'a str,
+	NormalizedString(NormalizedString) => &'a NormalizedStr,
+	Token(TokenBuf) => &'a Token,
+	Language(LanguageBuf) => &'a Language,
+	Name(NameBuf) => &'a Name,
+	NCName(NCNameBuf) => &'a NCName,
+	Id(IdBuf) => &'a Id,
+	IdRef(IdRefBuf) => &'a IdRef,
+	NMToken(NMTokenBuf) => &'a NMToken,
+	Duration(Duration) => Duration,
+	DayTimeDuration(DayTimeDuration) => DayTimeDuration,
+	YearMonthDuration(YearMonthDuration) => YearMonthDuration,
+	DateTime(DateTime) => DateTime,
+	DateTimeStamp(DateTimeStamp) => DateTimeStamp,
+	Time(Time) => Time,
+	Date(Date) => Date,
+	GYearMonth(GYearMonth) => GYearMonth,
+	GYear(GYear) => GYear,
+	GMonthDay(GMonthDay) => GMonthDay,
+	GDay(GDay) => GDay,
+	GMonth(GMonth) => GMonth,
+	Base64Binary(Base64BinaryBuf) => &'a Base64Binary,
+	HexBinary(HexBinaryBuf) => &'a HexBinary,
+	AnyUri(AnyUriBuf) => &'a AnyUri,
+	QName(QNameBuf) => &'a QName,
+}
+try_from_value! {
+	Boolean,
+	Float,
+	Double,
+	Decimal,
+	Integer,
+	NonPositiveInteger,
+	NegativeInteger,
+	NonNegativeInteger,
+	PositiveInteger,
+	UnsignedLong,
+	UnsignedInt,
+	UnsignedShort,
+	UnsignedByte,
+	Long,
+	Int,
+	Short,
+	Byte,
+	NormalizedString,
+	TokenBuf,
+	LanguageBuf,
+	NameBuf,
+	NCNameBuf,
+	IdBuf,
+	IdRefBuf,
+	NMTokenBuf,
+	Duration,
+	DayTimeDuration,
+	YearMonthDuration,
+	DateTime,
+	DateTimeStamp,
+	Time,
+	Date,
+	GYearMonth,
+	GYear,
+	GMonthDay,
+	GDay,
+	GMonth,
+	Base64BinaryBuf,
+	HexBinaryBuf,
+	AnyUriBuf,
+	QNameBuf,
+}
 impl From<DecimalDatatype> for Datatype {
 	fn from(value: DecimalDatatype) -> Self {
 		Self::Decimal(value)
@@ -1879,6 +2201,9 @@ impl TryFrom<DecimalValue> for ShortValue {
 	}
 }
 /// [`Integer`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum IntegerDatatype {
 	Integer,
@@ -1888,19 +2213,52 @@ pub enum IntegerDatatype {
 }
 impl IntegerDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_INTEGER {
-			return Some(Self::Integer);
-		}
-		if let Some(t) = NonPositiveIntegerDatatype::from_iri(iri) {
-			return Some(Self::NonPositiveInteger(t));
-		}
-		if let Some(t) = NonNegativeIntegerDatatype::from_iri(iri) {
-			return Some(Self::NonNegativeInteger(t));
-		}
-		if let Some(t) = LongDatatype::from_iri(iri) {
-			return Some(Self::Long(t));
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"integer" => Some(IntegerDatatype::Integer),
+			"nonPositiveInteger" => Some(IntegerDatatype::NonPositiveInteger(
+				NonPositiveIntegerDatatype::NonPositiveInteger,
+			)),
+			"negativeInteger" => Some(IntegerDatatype::NonPositiveInteger(
+				NonPositiveIntegerDatatype::NegativeInteger,
+			)),
+			"nonNegativeInteger" => Some(IntegerDatatype::NonNegativeInteger(
+				NonNegativeIntegerDatatype::NonNegativeInteger,
+			)),
+			"positiveInteger" => Some(IntegerDatatype::NonNegativeInteger(
+				NonNegativeIntegerDatatype::PositiveInteger,
+			)),
+			"unsignedLong" => Some(IntegerDatatype::NonNegativeInteger(
+				NonNegativeIntegerDatatype::UnsignedLong(UnsignedLongDatatype::UnsignedLong),
+			)),
+			"unsignedInt" => Some(IntegerDatatype::NonNegativeInteger(
+				NonNegativeIntegerDatatype::UnsignedLong(UnsignedLongDatatype::UnsignedInt(
+					UnsignedIntDatatype::UnsignedInt,
+				)),
+			)),
+			"unsignedShort" => Some(IntegerDatatype::NonNegativeInteger(
+				NonNegativeIntegerDatatype::UnsignedLong(UnsignedLongDatatype::UnsignedInt(
+					UnsignedIntDatatype::UnsignedShort(UnsignedShortDatatype::UnsignedShort),
+				)),
+			)),
+			"unsignedByte" => Some(IntegerDatatype::NonNegativeInteger(
+				NonNegativeIntegerDatatype::UnsignedLong(UnsignedLongDatatype::UnsignedInt(
+					UnsignedIntDatatype::UnsignedShort(UnsignedShortDatatype::UnsignedByte),
+				)),
+			)),
+			"long" => Some(IntegerDatatype::Long(LongDatatype::Long)),
+			"int" => Some(IntegerDatatype::Long(LongDatatype::Int(IntDatatype::Int))),
+			"short" => Some(IntegerDatatype::Long(LongDatatype::Int(
+				IntDatatype::Short(ShortDatatype::Short),
+			))),
+			"byte" => Some(IntegerDatatype::Long(LongDatatype::Int(
+				IntDatatype::Short(ShortDatatype::Byte),
+			))),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -1914,7 +2272,7 @@ impl IntegerDatatype {
 		match self {
 			Self::Integer => ParseXsd::parse_xsd(value)
 				.map(IntegerValue::Integer)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::NonPositiveInteger(t) => t.parse(value).map(Into::into),
 			Self::NonNegativeInteger(t) => t.parse(value).map(Into::into),
 			Self::Long(t) => t.parse(value).map(Into::into),
@@ -2367,6 +2725,9 @@ impl TryFrom<IntegerValue> for NonPositiveIntegerValue {
 	}
 }
 /// [`NonPositiveInteger`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum NonPositiveIntegerDatatype {
 	NonPositiveInteger,
@@ -2374,13 +2735,15 @@ pub enum NonPositiveIntegerDatatype {
 }
 impl NonPositiveIntegerDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_NON_POSITIVE_INTEGER {
-			return Some(Self::NonPositiveInteger);
-		}
-		if iri == XSD_NEGATIVE_INTEGER {
-			return Some(Self::NegativeInteger);
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"nonPositiveInteger" => Some(NonPositiveIntegerDatatype::NonPositiveInteger),
+			"negativeInteger" => Some(NonPositiveIntegerDatatype::NegativeInteger),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -2392,10 +2755,10 @@ impl NonPositiveIntegerDatatype {
 		match self {
 			Self::NonPositiveInteger => ParseXsd::parse_xsd(value)
 				.map(NonPositiveIntegerValue::NonPositiveInteger)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::NegativeInteger => ParseXsd::parse_xsd(value)
 				.map(NonPositiveIntegerValue::NegativeInteger)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 		}
 	}
 }
@@ -2486,6 +2849,9 @@ impl TryFrom<IntegerValue> for UnsignedShortValue {
 	}
 }
 /// [`NonNegativeInteger`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum NonNegativeIntegerDatatype {
 	NonNegativeInteger,
@@ -2494,16 +2860,31 @@ pub enum NonNegativeIntegerDatatype {
 }
 impl NonNegativeIntegerDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_NON_NEGATIVE_INTEGER {
-			return Some(Self::NonNegativeInteger);
-		}
-		if iri == XSD_POSITIVE_INTEGER {
-			return Some(Self::PositiveInteger);
-		}
-		if let Some(t) = UnsignedLongDatatype::from_iri(iri) {
-			return Some(Self::UnsignedLong(t));
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"nonNegativeInteger" => Some(NonNegativeIntegerDatatype::NonNegativeInteger),
+			"positiveInteger" => Some(NonNegativeIntegerDatatype::PositiveInteger),
+			"unsignedLong" => Some(NonNegativeIntegerDatatype::UnsignedLong(
+				UnsignedLongDatatype::UnsignedLong,
+			)),
+			"unsignedInt" => Some(NonNegativeIntegerDatatype::UnsignedLong(
+				UnsignedLongDatatype::UnsignedInt(UnsignedIntDatatype::UnsignedInt),
+			)),
+			"unsignedShort" => Some(NonNegativeIntegerDatatype::UnsignedLong(
+				UnsignedLongDatatype::UnsignedInt(UnsignedIntDatatype::UnsignedShort(
+					UnsignedShortDatatype::UnsignedShort,
+				)),
+			)),
+			"unsignedByte" => Some(NonNegativeIntegerDatatype::UnsignedLong(
+				UnsignedLongDatatype::UnsignedInt(UnsignedIntDatatype::UnsignedShort(
+					UnsignedShortDatatype::UnsignedByte,
+				)),
+			)),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -2516,10 +2897,10 @@ impl NonNegativeIntegerDatatype {
 		match self {
 			Self::NonNegativeInteger => ParseXsd::parse_xsd(value)
 				.map(NonNegativeIntegerValue::NonNegativeInteger)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::PositiveInteger => ParseXsd::parse_xsd(value)
 				.map(NonNegativeIntegerValue::PositiveInteger)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::UnsignedLong(t) => t.parse(value).map(Into::into),
 		}
 	}
@@ -2633,6 +3014,9 @@ impl TryFrom<IntegerValue> for ShortValue {
 	}
 }
 /// [`Long`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum LongDatatype {
 	Long,
@@ -2640,13 +3024,17 @@ pub enum LongDatatype {
 }
 impl LongDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_LONG {
-			return Some(Self::Long);
-		}
-		if let Some(t) = IntDatatype::from_iri(iri) {
-			return Some(Self::Int(t));
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"long" => Some(LongDatatype::Long),
+			"int" => Some(LongDatatype::Int(IntDatatype::Int)),
+			"short" => Some(LongDatatype::Int(IntDatatype::Short(ShortDatatype::Short))),
+			"byte" => Some(LongDatatype::Int(IntDatatype::Short(ShortDatatype::Byte))),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -2658,7 +3046,7 @@ impl LongDatatype {
 		match self {
 			Self::Long => ParseXsd::parse_xsd(value)
 				.map(LongValue::Long)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::Int(t) => t.parse(value).map(Into::into),
 		}
 	}
@@ -3047,6 +3435,9 @@ impl TryFrom<NonNegativeIntegerValue> for UnsignedShortValue {
 	}
 }
 /// [`UnsignedLong`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum UnsignedLongDatatype {
 	UnsignedLong,
@@ -3054,13 +3445,23 @@ pub enum UnsignedLongDatatype {
 }
 impl UnsignedLongDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_UNSIGNED_LONG {
-			return Some(Self::UnsignedLong);
-		}
-		if let Some(t) = UnsignedIntDatatype::from_iri(iri) {
-			return Some(Self::UnsignedInt(t));
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"unsignedLong" => Some(UnsignedLongDatatype::UnsignedLong),
+			"unsignedInt" => Some(UnsignedLongDatatype::UnsignedInt(
+				UnsignedIntDatatype::UnsignedInt,
+			)),
+			"unsignedShort" => Some(UnsignedLongDatatype::UnsignedInt(
+				UnsignedIntDatatype::UnsignedShort(UnsignedShortDatatype::UnsignedShort),
+			)),
+			"unsignedByte" => Some(UnsignedLongDatatype::UnsignedInt(
+				UnsignedIntDatatype::UnsignedShort(UnsignedShortDatatype::UnsignedByte),
+			)),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -3072,7 +3473,7 @@ impl UnsignedLongDatatype {
 		match self {
 			Self::UnsignedLong => ParseXsd::parse_xsd(value)
 				.map(UnsignedLongValue::UnsignedLong)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::UnsignedInt(t) => t.parse(value).map(Into::into),
 		}
 	}
@@ -3264,6 +3665,9 @@ impl TryFrom<UnsignedLongValue> for UnsignedShortValue {
 	}
 }
 /// [`UnsignedInt`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum UnsignedIntDatatype {
 	UnsignedInt,
@@ -3271,13 +3675,20 @@ pub enum UnsignedIntDatatype {
 }
 impl UnsignedIntDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_UNSIGNED_INT {
-			return Some(Self::UnsignedInt);
-		}
-		if let Some(t) = UnsignedShortDatatype::from_iri(iri) {
-			return Some(Self::UnsignedShort(t));
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"unsignedInt" => Some(UnsignedIntDatatype::UnsignedInt),
+			"unsignedShort" => Some(UnsignedIntDatatype::UnsignedShort(
+				UnsignedShortDatatype::UnsignedShort,
+			)),
+			"unsignedByte" => Some(UnsignedIntDatatype::UnsignedShort(
+				UnsignedShortDatatype::UnsignedByte,
+			)),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -3289,7 +3700,7 @@ impl UnsignedIntDatatype {
 		match self {
 			Self::UnsignedInt => ParseXsd::parse_xsd(value)
 				.map(UnsignedIntValue::UnsignedInt)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::UnsignedShort(t) => t.parse(value).map(Into::into),
 		}
 	}
@@ -3361,6 +3772,9 @@ impl TryFrom<UnsignedIntValue> for UnsignedShortValue {
 	}
 }
 /// [`UnsignedShort`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum UnsignedShortDatatype {
 	UnsignedShort,
@@ -3368,13 +3782,15 @@ pub enum UnsignedShortDatatype {
 }
 impl UnsignedShortDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_UNSIGNED_SHORT {
-			return Some(Self::UnsignedShort);
-		}
-		if iri == XSD_UNSIGNED_BYTE {
-			return Some(Self::UnsignedByte);
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"unsignedShort" => Some(UnsignedShortDatatype::UnsignedShort),
+			"unsignedByte" => Some(UnsignedShortDatatype::UnsignedByte),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -3386,10 +3802,10 @@ impl UnsignedShortDatatype {
 		match self {
 			Self::UnsignedShort => ParseXsd::parse_xsd(value)
 				.map(UnsignedShortValue::UnsignedShort)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::UnsignedByte => ParseXsd::parse_xsd(value)
 				.map(UnsignedShortValue::UnsignedByte)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 		}
 	}
 }
@@ -3492,6 +3908,9 @@ impl TryFrom<LongValue> for ShortValue {
 	}
 }
 /// [`Int`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum IntDatatype {
 	Int,
@@ -3499,13 +3918,16 @@ pub enum IntDatatype {
 }
 impl IntDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_INT {
-			return Some(Self::Int);
-		}
-		if let Some(t) = ShortDatatype::from_iri(iri) {
-			return Some(Self::Short(t));
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"int" => Some(IntDatatype::Int),
+			"short" => Some(IntDatatype::Short(ShortDatatype::Short)),
+			"byte" => Some(IntDatatype::Short(ShortDatatype::Byte)),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -3517,7 +3939,7 @@ impl IntDatatype {
 		match self {
 			Self::Int => ParseXsd::parse_xsd(value)
 				.map(IntValue::Int)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::Short(t) => t.parse(value).map(Into::into),
 		}
 	}
@@ -3585,6 +4007,9 @@ impl TryFrom<IntValue> for ShortValue {
 	}
 }
 /// [`Short`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ShortDatatype {
 	Short,
@@ -3592,13 +4017,15 @@ pub enum ShortDatatype {
 }
 impl ShortDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_SHORT {
-			return Some(Self::Short);
-		}
-		if iri == XSD_BYTE {
-			return Some(Self::Byte);
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"short" => Some(ShortDatatype::Short),
+			"byte" => Some(ShortDatatype::Byte),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -3610,10 +4037,10 @@ impl ShortDatatype {
 		match self {
 			Self::Short => ParseXsd::parse_xsd(value)
 				.map(ShortValue::Short)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::Byte => ParseXsd::parse_xsd(value)
 				.map(ShortValue::Byte)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 		}
 	}
 }
@@ -4155,6 +4582,9 @@ impl TryFrom<StringValue> for NCNameValue {
 	}
 }
 /// [`NormalizedStr`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum NormalizedStringDatatype {
 	NormalizedString,
@@ -4162,13 +4592,29 @@ pub enum NormalizedStringDatatype {
 }
 impl NormalizedStringDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_NORMALIZED_STRING {
-			return Some(Self::NormalizedString);
-		}
-		if let Some(t) = TokenDatatype::from_iri(iri) {
-			return Some(Self::Token(t));
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"normalizedString" => Some(NormalizedStringDatatype::NormalizedString),
+			"token" => Some(NormalizedStringDatatype::Token(TokenDatatype::Token)),
+			"language" => Some(NormalizedStringDatatype::Token(TokenDatatype::Language)),
+			"Name" => Some(NormalizedStringDatatype::Token(TokenDatatype::Name(
+				NameDatatype::Name,
+			))),
+			"NCName" => Some(NormalizedStringDatatype::Token(TokenDatatype::Name(
+				NameDatatype::NCName(NCNameDatatype::NCName),
+			))),
+			"ID" => Some(NormalizedStringDatatype::Token(TokenDatatype::Name(
+				NameDatatype::NCName(NCNameDatatype::Id),
+			))),
+			"IDREF" => Some(NormalizedStringDatatype::Token(TokenDatatype::Name(
+				NameDatatype::NCName(NCNameDatatype::IdRef),
+			))),
+			"NMTOKEN" => Some(NormalizedStringDatatype::Token(TokenDatatype::NMToken)),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -4180,7 +4626,7 @@ impl NormalizedStringDatatype {
 		match self {
 			Self::NormalizedString => ParseXsd::parse_xsd(value)
 				.map(NormalizedStringValue::NormalizedString)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::Token(t) => t.parse(value).map(Into::into),
 		}
 	}
@@ -4545,6 +4991,9 @@ impl TryFrom<NormalizedStringValue> for NCNameValue {
 	}
 }
 /// [`Token`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TokenDatatype {
 	Token,
@@ -4554,19 +5003,26 @@ pub enum TokenDatatype {
 }
 impl TokenDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_TOKEN {
-			return Some(Self::Token);
-		}
-		if iri == XSD_LANGUAGE {
-			return Some(Self::Language);
-		}
-		if let Some(t) = NameDatatype::from_iri(iri) {
-			return Some(Self::Name(t));
-		}
-		if iri == XSD_NMTOKEN {
-			return Some(Self::NMToken);
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"token" => Some(TokenDatatype::Token),
+			"language" => Some(TokenDatatype::Language),
+			"Name" => Some(TokenDatatype::Name(NameDatatype::Name)),
+			"NCName" => Some(TokenDatatype::Name(NameDatatype::NCName(
+				NCNameDatatype::NCName,
+			))),
+			"ID" => Some(TokenDatatype::Name(NameDatatype::NCName(
+				NCNameDatatype::Id,
+			))),
+			"IDREF" => Some(TokenDatatype::Name(NameDatatype::NCName(
+				NCNameDatatype::IdRef,
+			))),
+			"NMTOKEN" => Some(TokenDatatype::NMToken),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -4580,14 +5036,14 @@ impl TokenDatatype {
 		match self {
 			Self::Token => ParseXsd::parse_xsd(value)
 				.map(TokenValue::Token)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::Language => ParseXsd::parse_xsd(value)
 				.map(TokenValue::Language)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::Name(t) => t.parse(value).map(Into::into),
 			Self::NMToken => ParseXsd::parse_xsd(value)
 				.map(TokenValue::NMToken)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 		}
 	}
 }
@@ -4855,6 +5311,9 @@ impl TryFrom<TokenValue> for NCNameValue {
 	}
 }
 /// [`Name`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum NameDatatype {
 	Name,
@@ -4862,13 +5321,17 @@ pub enum NameDatatype {
 }
 impl NameDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_NAME {
-			return Some(Self::Name);
-		}
-		if let Some(t) = NCNameDatatype::from_iri(iri) {
-			return Some(Self::NCName(t));
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"Name" => Some(NameDatatype::Name),
+			"NCName" => Some(NameDatatype::NCName(NCNameDatatype::NCName)),
+			"ID" => Some(NameDatatype::NCName(NCNameDatatype::Id)),
+			"IDREF" => Some(NameDatatype::NCName(NCNameDatatype::IdRef)),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -4880,7 +5343,7 @@ impl NameDatatype {
 		match self {
 			Self::Name => ParseXsd::parse_xsd(value)
 				.map(NameValue::Name)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::NCName(t) => t.parse(value).map(Into::into),
 		}
 	}
@@ -5061,6 +5524,9 @@ impl TryFrom<NameValue> for NCNameValue {
 	}
 }
 /// [`NCName`] datatype variants.
+///
+/// Variants are ordered following the datatype hierarchy.
+/// See [`Datatype`] for more details.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum NCNameDatatype {
 	NCName,
@@ -5069,16 +5535,16 @@ pub enum NCNameDatatype {
 }
 impl NCNameDatatype {
 	pub fn from_iri(iri: &Iri) -> Option<Self> {
-		if iri == XSD_NC_NAME {
-			return Some(Self::NCName);
-		}
-		if iri == XSD_ID {
-			return Some(Self::Id);
-		}
-		if iri == XSD_IDREF {
-			return Some(Self::IdRef);
+		datatype_from_iri(iri, Self::from_local_name, Self::iri)
+	}
+	/// Returns the datatype with the given local name in the XSD namespace.
+	pub fn from_local_name(name: &str) -> Option<Self> {
+		match name {
+			"NCName" => Some(NCNameDatatype::NCName),
+			"ID" => Some(NCNameDatatype::Id),
+			"IDREF" => Some(NCNameDatatype::IdRef),
+			_ => None,
 		}
-		None
 	}
 	pub fn iri(&self) -> &'static Iri {
 		match self {
@@ -5091,13 +5557,13 @@ impl NCNameDatatype {
 		match self {
 			Self::NCName => ParseXsd::parse_xsd(value)
 				.map(NCNameValue::NCName)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::Id => ParseXsd::parse_xsd(value)
 				.map(NCNameValue::Id)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 			Self::IdRef => ParseXsd::parse_xsd(value)
 				.map(NCNameValue::IdRef)
-				.map_err(|_| ParseError),
+				.map_err(|_| ParseError::invalid(value)),
 		}
 	}
 }
@@ -0,0 +1,107 @@
+use crate::{Datatype, ParseError, Value, XsdValue};
+
+/// XSD union datatype.
+///
+/// The value space of a union datatype is the union of the value spaces of
+/// its member datatypes.
+///
+/// See: <https://www.w3.org/TR/xmlschema11-2/#union-datatypes>
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnionDatatype {
+	members: Vec<Datatype>,
+}
+
+impl UnionDatatype {
+	/// Creates a new union datatype from its ordered list of member datatypes.
+	pub fn new(members: Vec<Datatype>) -> Self {
+		Self { members }
+	}
+
+	/// Returns the ordered list of member datatypes.
+	pub fn members(&self) -> &[Datatype] {
+		&self.members
+	}
+
+	/// Checks if the given datatype is a member of this union.
+	pub fn contains(&self, datatype: Datatype) -> bool {
+		self.members.contains(&datatype)
+	}
+
+	/// Parses the given lexical form.
+	///
+	/// Member datatypes are tried in order, and the first one accepting the
+	/// input lexical form is used to interpret it, as required by the
+	/// specification.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema11-2/#union-lexical-mapping>
+	pub fn parse(&self, value: &str) -> Result<UnionValue, ParseError> {
+		let mut error = None;
+
+		for (member, datatype) in self.members.iter().enumerate() {
+			match datatype.parse(value) {
+				Ok(value) => return Ok(UnionValue { member, value }),
+				Err(e) => error = Some(e),
+			}
+		}
+
+		Err(error.unwrap_or(if value.is_empty() {
+			ParseError::EmptyLexicalForm
+		} else {
+			ParseError::Invalid
+		}))
+	}
+}
+
+impl From<Vec<Datatype>> for UnionDatatype {
+	fn from(value: Vec<Datatype>) -> Self {
+		Self::new(value)
+	}
+}
+
+impl FromIterator<Datatype> for UnionDatatype {
+	fn from_iter<T: IntoIterator<Item = Datatype>>(iter: T) -> Self {
+		Self::new(iter.into_iter().collect())
+	}
+}
+
+/// Value of a union datatype.
+#[derive(Debug, Clone)]
+pub struct UnionValue {
+	/// Index of the member datatype that matched the lexical form.
+	pub member: usize,
+
+	/// Interpreted value.
+	pub value: Value,
+}
+
+impl UnionValue {
+	/// Returns the value.
+	pub fn into_value(self) -> Value {
+		self.value
+	}
+}
+
+impl XsdValue for UnionValue {
+	fn datatype(&self) -> Datatype {
+		self.value.datatype()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{XSD_BOOLEAN, XSD_INTEGER};
+
+	#[test]
+	fn parse() {
+		let union: UnionDatatype = [XSD_INTEGER, XSD_BOOLEAN]
+			.into_iter()
+			.map(|iri| Datatype::from_iri(iri).unwrap())
+			.collect();
+
+		assert_eq!(union.parse("12").unwrap().member, 0);
+		assert_eq!(union.parse("true").unwrap().member, 1);
+		assert_eq!(union.parse("1").unwrap().member, 0);
+		assert!(union.parse("foo").is_err())
+	}
+}
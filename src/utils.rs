@@ -1,8 +1,3 @@
-/// Returns the quotient and reminder of `a/b`.
-pub fn div_rem(a: u32, b: u32) -> (u32, u32) {
-	(a / b, a % b)
-}
-
 /// Find the earliest position of the given pattern `pattern` in `bytes` that is
 /// greater or equal to `offset`.
 pub fn byte_index_of(bytes: &[u8], mut offset: usize, pattern: impl BytePattern) -> Option<usize> {
@@ -38,3 +33,37 @@ impl<const N: usize> BytePattern for [u8; N] {
 		false
 	}
 }
+
+/// Converts bytes known to be valid UTF-8, such as lexical forms validated by
+/// a grammar, into a string slice.
+///
+/// With the `forbid-unsafe` feature, the UTF-8 encoding is checked again.
+#[inline(always)]
+pub fn str_from_validated_utf8(bytes: &[u8]) -> &str {
+	#[cfg(feature = "forbid-unsafe")]
+	{
+		std::str::from_utf8(bytes).expect("invalid UTF-8")
+	}
+
+	#[cfg(not(feature = "forbid-unsafe"))]
+	unsafe {
+		std::str::from_utf8_unchecked(bytes)
+	}
+}
+
+/// Converts bytes known to be valid UTF-8, such as lexical forms validated by
+/// a grammar, into a string.
+///
+/// With the `forbid-unsafe` feature, the UTF-8 encoding is checked again.
+#[inline(always)]
+pub fn string_from_validated_utf8(bytes: Vec<u8>) -> String {
+	#[cfg(feature = "forbid-unsafe")]
+	{
+		String::from_utf8(bytes).expect("invalid UTF-8")
+	}
+
+	#[cfg(not(feature = "forbid-unsafe"))]
+	unsafe {
+		String::from_utf8_unchecked(bytes)
+	}
+}
@@ -0,0 +1,353 @@
+use std::cmp::Ordering;
+
+use chrono::FixedOffset;
+
+use crate::{ConvertLossy, Datatype, Decimal, Double, Float, Integer, Value};
+
+/// Error raised when an aggregate function is given a value of unsupported
+/// type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("unsupported value type `{0:?}`")]
+pub struct TypeError(pub Datatype);
+
+/// Numeric value, after type promotion to one of the four numeric primitive
+/// types.
+///
+/// Variants are declared in type promotion order.
+#[derive(Debug, Clone)]
+enum Numeric {
+	Integer(Integer),
+	Decimal(Decimal),
+	Float(Float),
+	Double(Double),
+}
+
+impl Numeric {
+	fn from_value(value: &Value) -> Result<Self, TypeError> {
+		match value {
+			Value::Float(f) => Ok(Self::Float(*f)),
+			Value::Double(d) => Ok(Self::Double(*d)),
+			Value::Decimal(d) => Ok(Self::Decimal(d.clone())),
+			Value::Integer(i) => Ok(Self::Integer(i.clone())),
+			Value::NonPositiveInteger(i) => Ok(Self::Integer(i.clone().into_big_int().into())),
+			Value::NegativeInteger(i) => Ok(Self::Integer(i.clone().into_big_int().into())),
+			Value::NonNegativeInteger(i) => Ok(Self::Integer(i.clone().into_big_int().into())),
+			Value::PositiveInteger(i) => Ok(Self::Integer(i.clone().into_big_int().into())),
+			Value::UnsignedLong(i) => Ok(Self::Integer((*i).into())),
+			Value::UnsignedInt(i) => Ok(Self::Integer((*i).into())),
+			Value::UnsignedShort(i) => Ok(Self::Integer((*i).into())),
+			Value::UnsignedByte(i) => Ok(Self::Integer((*i).into())),
+			Value::Long(i) => Ok(Self::Integer((*i).into())),
+			Value::Int(i) => Ok(Self::Integer((*i).into())),
+			Value::Short(i) => Ok(Self::Integer((*i).into())),
+			Value::Byte(i) => Ok(Self::Integer((*i).into())),
+			other => Err(TypeError(other.datatype())),
+		}
+	}
+
+	/// Returns the position of this value's type in the type promotion order.
+	fn rank(&self) -> u8 {
+		match self {
+			Self::Integer(_) => 0,
+			Self::Decimal(_) => 1,
+			Self::Float(_) => 2,
+			Self::Double(_) => 3,
+		}
+	}
+
+	/// Promotes this value to the type of the given rank.
+	fn promote(self, rank: u8) -> Self {
+		if self.rank() >= rank {
+			return self;
+		}
+
+		match rank {
+			1 => Self::Decimal(self.to_decimal()),
+			2 => Self::Float(self.to_f32().into()),
+			_ => Self::Double(self.to_f64().into()),
+		}
+	}
+
+	fn is_nan(&self) -> bool {
+		match self {
+			Self::Float(f) => f.is_nan(),
+			Self::Double(d) => d.is_nan(),
+			_ => false,
+		}
+	}
+
+	/// Compares two values promoted to the same type, not `NaN`.
+	fn cmp_promoted(&self, other: &Self) -> Ordering {
+		match (self, other) {
+			(Self::Integer(a), Self::Integer(b)) => a.cmp(b),
+			(Self::Decimal(a), Self::Decimal(b)) => a.cmp(b),
+			(Self::Float(a), Self::Float(b)) => f32::from(*a)
+				.partial_cmp(&f32::from(*b))
+				.unwrap_or(Ordering::Equal),
+			(Self::Double(a), Self::Double(b)) => f64::from(*a)
+				.partial_cmp(&f64::from(*b))
+				.unwrap_or(Ordering::Equal),
+			_ => unreachable!(),
+		}
+	}
+
+	fn into_value(self) -> Value {
+		match self {
+			Self::Integer(i) => Value::Integer(i),
+			Self::Decimal(d) => Value::Decimal(d),
+			Self::Float(f) => Value::Float(f),
+			Self::Double(d) => Value::Double(d),
+		}
+	}
+
+	fn to_decimal(&self) -> Decimal {
+		match self {
+			Self::Integer(i) => i.clone().into(),
+			Self::Decimal(d) => d.clone(),
+			_ => unreachable!(),
+		}
+	}
+
+	fn to_f32(&self) -> f32 {
+		match self {
+			Self::Integer(i) => i.to_f32_lossy().0,
+			Self::Decimal(d) => d.to_f32_lossy().0,
+			Self::Float(f) => (*f).into(),
+			Self::Double(_) => unreachable!(),
+		}
+	}
+
+	fn to_f64(&self) -> f64 {
+		match self {
+			Self::Integer(i) => i.to_f64_lossy().0,
+			Self::Decimal(d) => d.to_f64_lossy().0,
+			Self::Float(f) => (*f).into(),
+			Self::Double(d) => (*d).into(),
+		}
+	}
+
+	/// Adds two numeric values, promoting them to a common type first.
+	fn add(self, other: Self) -> Self {
+		match (self, other) {
+			(Self::Integer(a), Self::Integer(b)) => Self::Integer(a + b),
+			(a @ Self::Double(_), b) | (a, b @ Self::Double(_)) => {
+				Self::Double((a.to_f64() + b.to_f64()).into())
+			}
+			(a @ Self::Float(_), b) | (a, b @ Self::Float(_)) => {
+				Self::Float((a.to_f32() + b.to_f32()).into())
+			}
+			(a, b) => Self::Decimal([a.to_decimal(), b.to_decimal()].into_iter().sum()),
+		}
+	}
+}
+
+/// Comparison key used to compute the minimum or maximum of a sequence of
+/// values.
+enum Key {
+	Numeric(Numeric),
+	String(std::string::String),
+	Boolean(bool),
+	DateTime(chrono::DateTime<FixedOffset>),
+}
+
+impl Key {
+	fn from_value(value: &Value) -> Result<Self, TypeError> {
+		let utc = FixedOffset::east_opt(0).unwrap();
+
+		match value {
+			Value::String(_)
+			| Value::NormalizedString(_)
+			| Value::Token(_)
+			| Value::Language(_)
+			| Value::Name(_)
+			| Value::NCName(_)
+			| Value::Id(_)
+			| Value::IdRef(_)
+			| Value::NMToken(_)
+			| Value::AnyUri(_) => Ok(Self::String(value.to_string())),
+			Value::Boolean(b) => Ok(Self::Boolean(b.0)),
+			Value::DateTime(d) => Ok(Self::DateTime(
+				d.date_time
+					.and_local_timezone(d.offset.unwrap_or(utc))
+					.unwrap(),
+			)),
+			Value::DateTimeStamp(d) => Ok(Self::DateTime(
+				d.date_time.and_local_timezone(d.offset).unwrap(),
+			)),
+			other => Numeric::from_value(other).map(Self::Numeric),
+		}
+	}
+
+	fn is_same_kind(&self, other: &Self) -> bool {
+		std::mem::discriminant(self) == std::mem::discriminant(other)
+	}
+
+	fn cmp(&self, other: &Self) -> Ordering {
+		match (self, other) {
+			(Self::Numeric(a), Self::Numeric(b)) => a.cmp_promoted(b),
+			(Self::String(a), Self::String(b)) => a.cmp(b),
+			(Self::Boolean(a), Self::Boolean(b)) => a.cmp(b),
+			(Self::DateTime(a), Self::DateTime(b)) => a.cmp(b),
+			_ => unreachable!(),
+		}
+	}
+}
+
+/// Finds the first value `v` such that no other value `w` is such that
+/// `w.cmp(v) == ordering`.
+fn extremum(
+	values: impl IntoIterator<Item = Value>,
+	ordering: Ordering,
+) -> Result<Option<Value>, TypeError> {
+	let mut entries = Vec::new();
+	for value in values {
+		let key = Key::from_value(&value)?;
+
+		if let Some((first, _)) = entries.first() {
+			if !key.is_same_kind(first) {
+				return Err(TypeError(value.datatype()));
+			}
+		}
+
+		entries.push((key, value))
+	}
+
+	// Numeric values are promoted to their least common type.
+	let rank = entries
+		.iter()
+		.filter_map(|(key, _)| match key {
+			Key::Numeric(n) => Some(n.rank()),
+			_ => None,
+		})
+		.max();
+
+	if let Some(rank) = rank {
+		for (key, value) in &mut entries {
+			if let Key::Numeric(n) = key {
+				let promoted = n.clone().promote(rank);
+
+				if promoted.is_nan() {
+					return Ok(Some(promoted.into_value()));
+				}
+
+				*value = promoted.clone().into_value();
+				*n = promoted;
+			}
+		}
+	}
+
+	let mut result: Option<(Key, Value)> = None;
+	for (key, value) in entries {
+		match &result {
+			Some((best, _)) if key.cmp(best) != ordering => (),
+			_ => result = Some((key, value)),
+		}
+	}
+
+	Ok(result.map(|(_, value)| value))
+}
+
+impl Value {
+	/// Returns the maximum of the given values.
+	///
+	/// Values are compared following the rules of the XPath
+	/// [`fn:max`](https://www.w3.org/TR/xpath-functions/#func-max) function:
+	/// - numeric values are promoted to their least common type (as in
+	///   [`Self::sum`]), and the result is `NaN` if any value is `NaN`;
+	/// - strings and URIs are compared by codepoint;
+	/// - date/times without timezone are compared as if in UTC.
+	///
+	/// Returns `None` if the input is empty, and an error if the values
+	/// cannot be compared with each other.
+	pub fn max_of(values: impl IntoIterator<Item = Value>) -> Result<Option<Value>, TypeError> {
+		extremum(values, Ordering::Greater)
+	}
+
+	/// Returns the minimum of the given values.
+	///
+	/// Values are compared following the rules of the XPath
+	/// [`fn:min`](https://www.w3.org/TR/xpath-functions/#func-min) function.
+	/// See [`Self::max_of`] for more details.
+	pub fn min_of(values: impl IntoIterator<Item = Value>) -> Result<Option<Value>, TypeError> {
+		extremum(values, Ordering::Less)
+	}
+
+	/// Computes the sum of the given numeric values.
+	///
+	/// Values are promoted to a common numeric type following the rules of
+	/// the XPath [`fn:sum`](https://www.w3.org/TR/xpath-functions/#func-sum)
+	/// function: integer types are promoted to `xsd:integer`, then to
+	/// `xsd:decimal`, `xsd:float` and `xsd:double` as needed.
+	/// The sum of an empty sequence is the integer `0`.
+	///
+	/// Returns an error if any of the values is not numeric.
+	pub fn sum(values: impl IntoIterator<Item = Value>) -> Result<Value, TypeError> {
+		let mut result = Numeric::Integer(Integer::zero());
+
+		for value in values {
+			result = result.add(Numeric::from_value(&value)?);
+		}
+
+		Ok(result.into_value())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn sum() {
+		let values = vec![
+			Value::Integer(1i32.into()),
+			Value::Int(2),
+			Value::Decimal("0.5".parse().unwrap()),
+		];
+
+		let sum = Value::sum(values).unwrap();
+		assert!(matches!(&sum, Value::Decimal(_)));
+		assert_eq!(sum.to_string(), "3.5");
+
+		let empty = Value::sum(Vec::new()).unwrap();
+		assert!(matches!(empty, Value::Integer(i) if i.is_zero()));
+
+		assert!(Value::sum(vec![Value::Boolean(true.into())]).is_err())
+	}
+
+	#[test]
+	fn min_max() {
+		let values = || {
+			vec![
+				Value::Integer(3i32.into()),
+				Value::Double(1.5f64.into()),
+				Value::Byte(-2),
+			]
+		};
+
+		let max = Value::max_of(values()).unwrap().unwrap();
+		assert!(matches!(max, Value::Double(d) if f64::from(d) == 3.0));
+
+		let min = Value::min_of(values()).unwrap().unwrap();
+		assert!(matches!(min, Value::Double(d) if f64::from(d) == -2.0));
+
+		let nan = Value::max_of(vec![Value::Int(1), Value::Double(f64::NAN.into())])
+			.unwrap()
+			.unwrap();
+		assert!(matches!(nan, Value::Double(d) if d.is_nan()));
+
+		let strings = vec![Value::String("b".to_owned()), Value::String("a".to_owned())];
+		assert_eq!(Value::min_of(strings).unwrap().unwrap().to_string(), "a");
+
+		assert!(Value::max_of(Vec::new()).unwrap().is_none());
+		assert!(Value::max_of(vec![Value::Int(1), Value::String("a".to_owned())]).is_err())
+	}
+
+	#[test]
+	fn integer_sum() {
+		let values: Vec<Integer> = vec![1i32.into(), 2i32.into(), 3i32.into()];
+		let sum: Integer = values.iter().sum();
+		let product: Integer = values.into_iter().product();
+		assert_eq!(sum, Integer::from(6i32));
+		assert_eq!(product, Integer::from(6i32))
+	}
+}
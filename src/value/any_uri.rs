@@ -1,6 +1,153 @@
+use core::fmt;
+use std::{
+	borrow::{Borrow, Cow},
+	fmt::Write as _,
+	ops::Deref,
+	str::FromStr,
+};
+
+use iref::{IriBuf, IriRef, IriRefBuf, UriBuf, UriRefBuf};
+
 use crate::{Datatype, ParseXsd, XsdValue};
 
-pub type AnyUri = iref::Uri;
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid anyURI `{0}`")]
+pub struct InvalidAnyUri<T = String>(pub T);
+
+/// XSD `anyURI` value.
+///
+/// The lexical space of `anyURI` is larger than the set of URI references:
+/// it also contains strings that only become URI references once some of
+/// their characters (such as spaces or non-ASCII characters) are escaped.
+/// An `AnyUri` is any string that maps to an IRI reference through this
+/// escaping procedure.
+///
+/// See: <https://www.w3.org/TR/xmlschema11-2/#anyURI>
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "forbid-unsafe", derive(ref_cast::RefCastCustom))]
+#[repr(transparent)]
+pub struct AnyUri(str);
+
+impl AnyUri {
+	pub fn new(value: &str) -> Result<&Self, InvalidAnyUri<&str>> {
+		if Self::validate(value) {
+			Ok(Self::cast(value))
+		} else {
+			Err(InvalidAnyUri(value))
+		}
+	}
+
+	fn validate(value: &str) -> bool {
+		IriRef::new(escape(value, false).as_ref()).is_ok()
+	}
+
+	unchecked_constructor! {
+		/// Creates a new `anyURI` value from the input `value` without
+		/// validation.
+		///
+		/// # Safety
+		///
+		/// The input `value` must be in the lexical space of `anyURI`.
+		pub unsafe fn new_unchecked(value: &str) -> &Self {
+			Self::cast(value)
+		}
+	}
+
+	transparent_cast! {
+		const fn cast(value: &str) -> &Self;
+	}
+
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+
+	/// Returns this value as an IRI reference, if it is one without any
+	/// escaping.
+	pub fn as_iri_ref(&self) -> Option<&IriRef> {
+		IriRef::new(&self.0).ok()
+	}
+
+	/// Maps this value to an IRI reference, percent-encoding every character
+	/// not allowed in IRIs (such as spaces).
+	pub fn to_iri_ref_escaped(&self) -> IriRefBuf {
+		IriRefBuf::new(escape(&self.0, false).into_owned()).unwrap()
+	}
+
+	/// Maps this value to an IRI, percent-encoding every character not
+	/// allowed in IRIs (such as spaces).
+	///
+	/// Returns `None` if this value is a relative reference.
+	pub fn to_iri_escaped(&self) -> Option<IriBuf> {
+		IriBuf::new(escape(&self.0, false).into_owned()).ok()
+	}
+
+	/// Maps this value to a URI reference, percent-encoding every character
+	/// not allowed in URIs, including non-ASCII characters.
+	///
+	/// This is the mapping defined by the specification to obtain the actual
+	/// URI designated by an `anyURI` value.
+	pub fn to_uri_ref_escaped(&self) -> UriRefBuf {
+		UriRefBuf::new(escape(&self.0, true).into_owned().into_bytes()).unwrap()
+	}
+}
+
+/// Percent-encodes the characters of `value` that are not allowed in IRI
+/// references, or in URI references if `uri` is `true`.
+///
+/// Each escaped character is replaced by the `%HH` encoding of every byte
+/// of its UTF-8 representation, as specified by RFC 3987 section 3.1.
+fn escape(value: &str, uri: bool) -> Cow<'_, str> {
+	let needs_escape = |c: char| {
+		if c.is_ascii() {
+			c.is_ascii_control()
+				|| matches!(
+					c,
+					' ' | '"' | '<' | '>' | '\\' | '^' | '`' | '{' | '|' | '}'
+				)
+		} else {
+			uri || !is_ucschar(c)
+		}
+	};
+
+	if !value.chars().any(needs_escape) {
+		return Cow::Borrowed(value);
+	}
+
+	let mut result = String::with_capacity(value.len());
+	for c in value.chars() {
+		if needs_escape(c) {
+			for b in c.encode_utf8(&mut [0; 4]).bytes() {
+				write!(result, "%{b:02X}").unwrap()
+			}
+		} else {
+			result.push(c)
+		}
+	}
+
+	Cow::Owned(result)
+}
+
+/// Checks if the given character is an RFC 3987 `ucschar`.
+fn is_ucschar(c: char) -> bool {
+	matches!(c as u32,
+		0xA0..=0xD7FF
+		| 0xF900..=0xFDCF
+		| 0xFDF0..=0xFFEF
+		| 0x10000..=0x1FFFD
+		| 0x20000..=0x2FFFD
+		| 0x30000..=0x3FFFD
+		| 0x40000..=0x4FFFD
+		| 0x50000..=0x5FFFD
+		| 0x60000..=0x6FFFD
+		| 0x70000..=0x7FFFD
+		| 0x80000..=0x8FFFD
+		| 0x90000..=0x9FFFD
+		| 0xA0000..=0xAFFFD
+		| 0xB0000..=0xBFFFD
+		| 0xC0000..=0xCFFFD
+		| 0xD0000..=0xDFFFD
+		| 0xE1000..=0xEFFFD)
+}
 
 impl XsdValue for AnyUri {
 	fn datatype(&self) -> Datatype {
@@ -8,7 +155,53 @@ impl XsdValue for AnyUri {
 	}
 }
 
-pub type AnyUriBuf = iref::UriBuf;
+impl fmt::Display for AnyUri {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl ToOwned for AnyUri {
+	type Owned = AnyUriBuf;
+
+	fn to_owned(&self) -> Self::Owned {
+		AnyUriBuf(self.0.to_owned())
+	}
+}
+
+/// Owned XSD `anyURI` value.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AnyUriBuf(String);
+
+impl AnyUriBuf {
+	pub fn new(value: String) -> Result<Self, InvalidAnyUri> {
+		if AnyUri::validate(&value) {
+			Ok(Self(value))
+		} else {
+			Err(InvalidAnyUri(value))
+		}
+	}
+
+	unchecked_constructor! {
+		/// Creates a new `anyURI` value from the input `value` without
+		/// validation.
+		///
+		/// # Safety
+		///
+		/// The input `value` must be in the lexical space of `anyURI`.
+		pub unsafe fn new_unchecked(value: String) -> Self {
+			Self(value)
+		}
+	}
+
+	pub fn as_any_uri(&self) -> &AnyUri {
+		AnyUri::cast(self.0.as_str())
+	}
+
+	pub fn into_string(self) -> String {
+		self.0
+	}
+}
 
 impl XsdValue for AnyUriBuf {
 	fn datatype(&self) -> Datatype {
@@ -16,6 +209,79 @@ impl XsdValue for AnyUriBuf {
 	}
 }
 
+impl fmt::Display for AnyUriBuf {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl Borrow<AnyUri> for AnyUriBuf {
+	fn borrow(&self) -> &AnyUri {
+		self.as_any_uri()
+	}
+}
+
+impl Deref for AnyUriBuf {
+	type Target = AnyUri;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_any_uri()
+	}
+}
+
+impl FromStr for AnyUriBuf {
+	type Err = InvalidAnyUri;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::new(s.to_owned())
+	}
+}
+
+impl From<IriRefBuf> for AnyUriBuf {
+	fn from(value: IriRefBuf) -> Self {
+		Self(value.as_str().to_owned())
+	}
+}
+
+impl From<IriBuf> for AnyUriBuf {
+	fn from(value: IriBuf) -> Self {
+		Self(value.as_str().to_owned())
+	}
+}
+
+impl From<UriBuf> for AnyUriBuf {
+	fn from(value: UriBuf) -> Self {
+		Self(value.as_str().to_owned())
+	}
+}
+
 impl ParseXsd for AnyUriBuf {
 	type LexicalForm = AnyUri;
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn escaping() {
+		let value = AnyUri::new("http://example.org/a b?q=<é>").unwrap();
+		assert!(value.as_iri_ref().is_none());
+		assert_eq!(
+			value.to_iri_escaped().unwrap().as_str(),
+			"http://example.org/a%20b?q=%3Cé%3E"
+		);
+		assert_eq!(
+			value.to_uri_ref_escaped().as_str(),
+			"http://example.org/a%20b?q=%3C%C3%A9%3E"
+		);
+
+		let value = AnyUri::new("../a|b").unwrap();
+		assert!(value.to_iri_escaped().is_none());
+		assert_eq!(value.to_iri_ref_escaped().as_str(), "../a%7Cb");
+
+		assert!(AnyUri::new("").is_ok());
+		assert!(AnyUri::new("http://example.org/%zz").is_err());
+		assert!(AnyUri::new("a#b#c").is_err())
+	}
+}
@@ -19,7 +19,7 @@ const CHARS: [char; 64] = [
 
 const PADDING: char = '=';
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 #[error("invalid base64")]
 pub struct InvalidBase64;
 
@@ -68,6 +68,21 @@ impl Base64BinaryBuf {
 		Ok(Self(bytes))
 	}
 
+	/// Decodes a base 64 string, ignoring any XML whitespace character
+	/// (space, tab, carriage return and line feed) in the input.
+	///
+	/// This accepts line-wrapped inputs such as the ones produced by
+	/// [`Base64Binary::write_wrapped`].
+	pub fn decode_lenient(input: impl AsRef<[u8]>) -> Result<Self, InvalidBase64> {
+		let filtered: Vec<u8> = input
+			.as_ref()
+			.iter()
+			.copied()
+			.filter(|c| !is_xml_whitespace(*c))
+			.collect();
+		Self::decode(filtered)
+	}
+
 	pub fn into_bytes(self) -> Vec<u8> {
 		self.0
 	}
@@ -85,6 +100,10 @@ impl Base64BinaryBuf {
 	}
 }
 
+fn is_xml_whitespace(c: u8) -> bool {
+	matches!(c, b' ' | b'\t' | b'\r' | b'\n')
+}
+
 fn decode_char(c: u8) -> Result<u8, InvalidBase64> {
 	match c {
 		b'A'..=b'Z' => Ok(c - b'A'),
@@ -167,15 +186,17 @@ impl LexicalFormOf<Base64BinaryBuf> for lexical::Base64Binary {
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "forbid-unsafe", derive(ref_cast::RefCastCustom))]
+#[repr(transparent)]
 pub struct Base64Binary([u8]);
 
 impl Base64Binary {
-	pub fn new(bytes: &[u8]) -> &Self {
-		unsafe { std::mem::transmute(bytes) }
+	transparent_cast! {
+		pub const fn new(bytes: &[u8]) -> &Self;
 	}
 
-	pub fn new_mut(bytes: &mut [u8]) -> &mut Self {
-		unsafe { std::mem::transmute(bytes) }
+	transparent_cast! {
+		pub fn new_mut(bytes: &mut [u8]) -> &mut Self;
 	}
 
 	pub fn chars(&self) -> Chars {
@@ -190,6 +211,38 @@ impl Base64Binary {
 	pub fn as_bytes(&self) -> &[u8] {
 		&self.0
 	}
+
+	/// Writes the base 64 encoding of the value into the given writer,
+	/// without allocating.
+	pub fn write(&self, w: &mut impl fmt::Write) -> fmt::Result {
+		for c in self.chars() {
+			w.write_char(c)?;
+		}
+
+		Ok(())
+	}
+
+	/// Writes the base 64 encoding of the value into the given writer,
+	/// inserting a line feed every `width` characters.
+	///
+	/// Use a `width` of 76 for [RFC 2045](https://www.rfc-editor.org/rfc/rfc2045#section-6.8)
+	/// style line wrapping. No line feed is written after the last line.
+	/// If `width` is `0`, no line wrapping is performed.
+	pub fn write_wrapped(&self, width: usize, w: &mut impl fmt::Write) -> fmt::Result {
+		if width == 0 {
+			return self.write(w);
+		}
+
+		for (i, c) in self.chars().enumerate() {
+			if i > 0 && i % width == 0 {
+				w.write_char('\n')?;
+			}
+
+			w.write_char(c)?;
+		}
+
+		Ok(())
+	}
 }
 
 impl<'a> From<&'a [u8]> for &'a Base64Binary {
@@ -314,4 +367,16 @@ mod tests {
 			assert_eq!(output.as_bytes(), expected)
 		}
 	}
+
+	#[test]
+	fn wrapped() {
+		let value = Base64Binary::new(b"Many hands make light work.");
+		let mut output = String::new();
+		value.write_wrapped(8, &mut output).unwrap();
+		assert_eq!(output, "TWFueSBo\nYW5kcyBt\nYWtlIGxp\nZ2h0IHdv\ncmsu");
+
+		let decoded = Base64BinaryBuf::decode_lenient(&output).unwrap();
+		assert_eq!(decoded.as_bytes(), value.as_bytes());
+		assert!(Base64BinaryBuf::decode("TWFu\nTWFu").is_err())
+	}
 }
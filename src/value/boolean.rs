@@ -1,13 +1,70 @@
 use core::fmt;
+use std::str::FromStr;
 
 use crate::{
 	lexical::{self, LexicalFormOf},
-	Datatype, ParseXsd, XsdValue,
+	Datatype, ParseXsd, Value, XsdValue,
 };
 
+/// Lexical subset accepted when parsing booleans.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BooleanSyntax {
+	/// Full `xsd:boolean` lexical space: `true`, `false`, `1` and `0`.
+	#[default]
+	Relaxed,
+
+	/// Only `true` and `false` are accepted.
+	///
+	/// Some profiles forbid the numeric forms.
+	Strict,
+}
+
+impl BooleanSyntax {
+	/// Checks if the given lexical form is accepted with this syntax.
+	pub fn accepts(&self, value: &lexical::Boolean) -> bool {
+		match self {
+			Self::Relaxed => true,
+			Self::Strict => !value.is_numeric(),
+		}
+	}
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Boolean(pub bool);
 
+impl Boolean {
+	/// Parses a boolean, accepting only the lexical forms of the given
+	/// syntax.
+	pub fn parse_with(s: &str, syntax: BooleanSyntax) -> Result<Self, lexical::InvalidBoolean> {
+		let value = lexical::Boolean::new(s)?;
+		if syntax.accepts(value) {
+			Ok(value.value())
+		} else {
+			Err(lexical::InvalidBoolean)
+		}
+	}
+
+	/// Parses a boolean, accepting only `true` and `false`.
+	///
+	/// ```
+	/// use xsd_types::Boolean;
+	///
+	/// assert_eq!(Boolean::parse_strict("true").unwrap(), Boolean(true));
+	/// assert!(Boolean::parse_strict("1").is_err());
+	/// ```
+	pub fn parse_strict(s: &str) -> Result<Self, lexical::InvalidBoolean> {
+		Self::parse_with(s, BooleanSyntax::Strict)
+	}
+}
+
+impl FromStr for Boolean {
+	type Err = lexical::InvalidBoolean;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::parse_with(s, BooleanSyntax::Relaxed)
+	}
+}
+
 impl From<bool> for Boolean {
 	fn from(value: bool) -> Self {
 		Self(value)
@@ -20,6 +77,25 @@ impl From<Boolean> for bool {
 	}
 }
 
+impl Value {
+	/// Converts an optional boolean into a value, using `missing` when there
+	/// is no boolean.
+	///
+	/// ```
+	/// use xsd_types::{Boolean, Value};
+	///
+	/// let missing = || Value::String("N/A".to_owned());
+	/// let value = Value::from_bool_or(Some(true), missing());
+	/// assert!(matches!(value, Value::Boolean(Boolean(true))));
+	///
+	/// let value = Value::from_bool_or(None, missing());
+	/// assert!(matches!(value, Value::String(s) if s == "N/A"));
+	/// ```
+	pub fn from_bool_or(value: Option<bool>, missing: Value) -> Self {
+		value.map(|b| Self::Boolean(Boolean(b))).unwrap_or(missing)
+	}
+}
+
 impl XsdValue for Boolean {
 	fn datatype(&self) -> Datatype {
 		Datatype::Boolean
@@ -47,3 +123,30 @@ impl fmt::Display for Boolean {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn syntax() {
+		for (input, relaxed, strict) in [
+			("true", true, true),
+			("false", true, true),
+			("1", true, false),
+			("0", true, false),
+			("yes", false, false),
+		] {
+			assert_eq!(
+				Boolean::parse_with(input, BooleanSyntax::Relaxed).is_ok(),
+				relaxed
+			);
+			assert_eq!(Boolean::parse_strict(input).is_ok(), strict)
+		}
+
+		assert_eq!(
+			Boolean::parse_with("0", BooleanSyntax::default()).unwrap(),
+			Boolean(false)
+		)
+	}
+}
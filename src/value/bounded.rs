@@ -0,0 +1,272 @@
+use core::fmt::{self, Write};
+use std::{borrow::Borrow, ops::Deref};
+
+use crate::{Boolean, Date, DateTime, DateTimeStamp, Double, Float, Time};
+
+/// Capacity of an [`InlineString`].
+pub const INLINE_STRING_CAPACITY: usize = 64;
+
+/// Value whose canonical representation has a bounded length.
+///
+/// Such values can be formatted into a fixed-size buffer, without allocating
+/// a `String` per value.
+pub trait BoundedDisplay: fmt::Display {
+	/// Maximum length, in bytes, of the canonical representation of a value
+	/// of this type.
+	const MAX_LEN: usize;
+
+	/// Writes the canonical representation of this value into the given
+	/// buffer, returning the number of bytes written.
+	///
+	/// # Panics
+	///
+	/// Panics if the buffer is too small. A buffer of at least
+	/// [`Self::MAX_LEN`] bytes is always large enough.
+	fn write_into(&self, buffer: &mut [u8]) -> usize {
+		let mut writer = SliceWriter { buffer, len: 0 };
+		write!(writer, "{self}").expect("buffer too small");
+		writer.len
+	}
+
+	/// Returns the canonical representation of this value as an inline
+	/// string.
+	fn to_inline_string(&self) -> InlineString {
+		let mut result = InlineString::new();
+		result.len = self.write_into(&mut result.bytes) as u8;
+		result
+	}
+}
+
+struct SliceWriter<'a> {
+	buffer: &'a mut [u8],
+	len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+	fn write_byte(&mut self, b: u8) {
+		self.buffer[self.len] = b;
+		self.len += 1
+	}
+}
+
+impl<'a> fmt::Write for SliceWriter<'a> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		let end = self.len + s.len();
+
+		if end > self.buffer.len() {
+			return Err(fmt::Error);
+		}
+
+		self.buffer[self.len..end].copy_from_slice(s.as_bytes());
+		self.len = end;
+		Ok(())
+	}
+}
+
+/// Formats the canonical representation of a finite floating-point value
+/// whose absolute value is `mantissa * 10^exponent`, where `mantissa` and
+/// `exponent` are the shortest decimal representation of the value.
+///
+/// The result is written into a stack buffer: no `String` is allocated.
+pub(crate) fn fmt_canonical_float(
+	f: &mut fmt::Formatter,
+	negative: bool,
+	mantissa: u64,
+	exponent: i32,
+) -> fmt::Result {
+	// `u64::MAX` has 20 digits.
+	let mut digits = [0u8; 20];
+	let mut start = digits.len();
+	let mut m = mantissa;
+	loop {
+		start -= 1;
+		digits[start] = b'0' + (m % 10) as u8;
+		m /= 10;
+		if m == 0 {
+			break;
+		}
+	}
+
+	let digits = &digits[start..];
+	let mut buffer = [0u8; 32];
+	let mut writer = SliceWriter {
+		buffer: &mut buffer,
+		len: 0,
+	};
+
+	if negative {
+		writer.write_byte(b'-');
+	}
+
+	writer.write_byte(digits[0]);
+	writer.write_byte(b'.');
+	if digits.len() == 1 {
+		writer.write_byte(b'0');
+	} else {
+		for &d in &digits[1..] {
+			writer.write_byte(d);
+		}
+	}
+
+	write!(writer, "E{}", exponent + digits.len() as i32 - 1)?;
+	let len = writer.len;
+	f.pad(crate::utils::str_from_validated_utf8(&buffer[..len]))
+}
+
+/// String stored inline, with a capacity of [`INLINE_STRING_CAPACITY`]
+/// bytes.
+///
+/// This is large enough to hold the canonical representation of any
+/// [`BoundedDisplay`] value.
+#[derive(Clone, Copy)]
+pub struct InlineString {
+	bytes: [u8; INLINE_STRING_CAPACITY],
+	len: u8,
+}
+
+impl InlineString {
+	fn new() -> Self {
+		Self {
+			bytes: [0; INLINE_STRING_CAPACITY],
+			len: 0,
+		}
+	}
+
+	pub fn as_str(&self) -> &str {
+		// SAFETY: the bytes are written from `&str` values.
+		crate::utils::str_from_validated_utf8(&self.bytes[..self.len as usize])
+	}
+}
+
+impl Deref for InlineString {
+	type Target = str;
+
+	fn deref(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl AsRef<str> for InlineString {
+	fn as_ref(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl Borrow<str> for InlineString {
+	fn borrow(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl PartialEq for InlineString {
+	fn eq(&self, other: &Self) -> bool {
+		self.as_str() == other.as_str()
+	}
+}
+
+impl Eq for InlineString {}
+
+impl fmt::Display for InlineString {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.as_str().fmt(f)
+	}
+}
+
+impl fmt::Debug for InlineString {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.as_str().fmt(f)
+	}
+}
+
+macro_rules! bounded_display {
+	{ $( $ty:ty: $len:expr ),* } => {
+		$(
+			impl BoundedDisplay for $ty {
+				const MAX_LEN: usize = $len;
+			}
+		)*
+	};
+}
+
+bounded_display! {
+	i8: 4,
+	i16: 6,
+	i32: 11,
+	i64: 20,
+	u8: 3,
+	u16: 5,
+	u32: 10,
+	u64: 20,
+	Boolean: 5,
+	Float: 16,
+	Double: 24,
+	// `-262143-12-31+14:00`
+	Date: 19,
+	// `23:59:59.999999999+14:00`
+	Time: 24,
+	DateTime: 38,
+	DateTimeStamp: 38
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn write_into() {
+		let mut buffer = [0; i32::MAX_LEN];
+		let len = i32::MIN.write_into(&mut buffer);
+		assert_eq!(&buffer[..len], b"-2147483648");
+
+		let value: DateTime = "-262143-12-31T23:59:59.999999999+14:00".parse().unwrap();
+		let s = value.to_inline_string();
+		assert_eq!(s.as_str(), value.to_string());
+		assert!(s.len() <= DateTime::MAX_LEN);
+
+		assert_eq!(Boolean(false).to_inline_string().as_str(), "false")
+	}
+
+	#[test]
+	fn canonical_floats() {
+		const CONFIG: pretty_dtoa::FmtFloatConfig = pretty_dtoa::FmtFloatConfig::default()
+			.force_e_notation()
+			.capitalize_e(true);
+
+		let mut seed = 0x2545_f491_4f6c_dd1du64;
+		let mut next = move || {
+			seed ^= seed << 13;
+			seed ^= seed >> 7;
+			seed ^= seed << 17;
+			seed
+		};
+
+		let doubles = [0.0, -0.0, 1.0, 0.5, 123.456, f64::MIN, f64::MAX, 5e-324];
+		let floats = [0.0, -0.0, 1.0, 0.5, 123.456, f32::MIN, f32::MAX, 1e-45];
+		let random_doubles = (0..100_000).map(|_| f64::from_bits(next()));
+
+		for value in doubles.into_iter().chain(random_doubles) {
+			if value.is_finite() {
+				let value = Double::from(value);
+				let s = value.to_inline_string();
+				assert_eq!(s.as_str(), pretty_dtoa::dtoa(value.into(), CONFIG));
+				assert!(s.len() <= Double::MAX_LEN)
+			}
+		}
+
+		let random_floats = (0..100_000).map(|_| f32::from_bits(next() as u32));
+		for value in floats.into_iter().chain(random_floats) {
+			if value.is_finite() {
+				let value = Float::from(value);
+				let s = value.to_inline_string();
+				assert_eq!(s.as_str(), pretty_dtoa::ftoa(value.into(), CONFIG));
+				assert!(s.len() <= Float::MAX_LEN)
+			}
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn buffer_too_small() {
+		i32::MIN.write_into(&mut [0; 4]);
+	}
+}
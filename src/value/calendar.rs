@@ -0,0 +1,154 @@
+//! Calendar utilities shared by date arithmetic.
+use chrono::FixedOffset;
+
+/// Maximum timezone offset allowed by XSD, in seconds.
+pub(crate) const MAX_TIMEZONE_OFFSET: i32 = 14 * 60 * 60;
+
+/// Invalid date or time component, returned by the `with_*` setters of
+/// date/time values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum InvalidComponent {
+	#[error("year {0} is out of range")]
+	Year(i32),
+
+	#[error("invalid month {0}")]
+	Month(u8),
+
+	#[error("invalid day {0}")]
+	Day(u8),
+
+	#[error("invalid hour {0}")]
+	Hour(u8),
+
+	#[error("invalid minute {0}")]
+	Minute(u8),
+
+	#[error("invalid second {0}")]
+	Second(u8),
+
+	#[error("invalid timezone offset {0}")]
+	Timezone(FixedOffset),
+}
+
+/// Checks that the given offset is a valid XSD timezone, between `-14:00`
+/// and `+14:00` with a whole number of minutes.
+pub(crate) fn check_timezone(
+	offset: Option<FixedOffset>,
+) -> Result<Option<FixedOffset>, InvalidComponent> {
+	match offset {
+		Some(o)
+			if o.local_minus_utc().abs() > MAX_TIMEZONE_OFFSET || o.local_minus_utc() % 60 != 0 =>
+		{
+			Err(InvalidComponent::Timezone(o))
+		}
+		_ => Ok(offset),
+	}
+}
+
+/// Checks if the given year is a leap year in the proleptic Gregorian
+/// calendar, using the XSD 1.1 year numbering (where year `0` is 1 BCE).
+pub fn is_leap_year(year: i32) -> bool {
+	year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days of the given month, or `None` if the month is
+/// not between 1 and 12.
+pub fn days_in_month(year: i32, month: u8) -> Option<u8> {
+	match month {
+		1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+		4 | 6 | 9 | 11 => Some(30),
+		2 if is_leap_year(year) => Some(29),
+		2 => Some(28),
+		_ => None,
+	}
+}
+
+/// Clamps the given day to the last day of the given month.
+///
+/// This is the day "pinning" used by XSD when adding durations to dates:
+/// adding one month to January 31st gives the last day of February. All the
+/// date arithmetic of this crate goes through this function, so that they
+/// agree on month-end edge cases.
+///
+/// Returns `None` if the month is not between 1 and 12, or if the day is
+/// zero.
+///
+/// ```
+/// use xsd_types::clamp_day_to_month;
+///
+/// assert_eq!(clamp_day_to_month(2023, 2, 31), Some(28));
+/// assert_eq!(clamp_day_to_month(2024, 2, 30), Some(29));
+/// assert_eq!(clamp_day_to_month(2024, 4, 15), Some(15));
+/// ```
+pub fn clamp_day_to_month(year: i32, month: u8, day: u8) -> Option<u8> {
+	if day == 0 {
+		return None;
+	}
+
+	days_in_month(year, month).map(|max| day.min(max))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::Datelike;
+
+	#[test]
+	fn month_ends() {
+		assert!(is_leap_year(2000) && is_leap_year(0) && is_leap_year(-4));
+		assert!(!is_leap_year(1900) && !is_leap_year(-1));
+
+		assert_eq!(clamp_day_to_month(1900, 2, 29), Some(28));
+		assert_eq!(clamp_day_to_month(2000, 2, 29), Some(29));
+		assert_eq!(clamp_day_to_month(2024, 6, 31), Some(30));
+		assert_eq!(clamp_day_to_month(2024, 13, 1), None);
+		assert_eq!(clamp_day_to_month(2024, 1, 0), None);
+
+		for year in [-401, -1, 0, 1900, 2023, 2024] {
+			for month in 1..=12 {
+				let last = chrono::NaiveDate::from_ymd_opt(year, month as u32, 1)
+					.unwrap()
+					.checked_add_months(chrono::Months::new(1))
+					.unwrap()
+					.pred_opt()
+					.unwrap();
+				assert_eq!(days_in_month(year, month), Some(last.day() as u8));
+			}
+		}
+	}
+
+	#[test]
+	fn consistent_pinning() {
+		use crate::{DateTime, Duration, GMonthDay};
+		use chrono::NaiveDate;
+
+		let date_time: DateTime = "2023-01-31T10:00:00".parse().unwrap();
+		let one_month: Duration = "P1M".parse().unwrap();
+		let thirteen_months: Duration = "P1Y1M".parse().unwrap();
+		assert_eq!(
+			date_time
+				.checked_add_duration(&one_month)
+				.unwrap()
+				.to_string(),
+			"2023-02-28T10:00:00"
+		);
+		assert_eq!(
+			date_time
+				.checked_add_duration(&thirteen_months)
+				.unwrap()
+				.to_string(),
+			"2024-02-29T10:00:00"
+		);
+
+		let leap_day = GMonthDay::new(2, 29, None).unwrap();
+		let from = NaiveDate::from_ymd_opt(2023, 3, 1).unwrap();
+		assert_eq!(
+			leap_day.next_occurrence(from),
+			NaiveDate::from_ymd_opt(2024, 2, 29)
+		);
+		assert_eq!(
+			leap_day.next_occurrence(NaiveDate::from_ymd_opt(2025, 2, 28).unwrap()),
+			NaiveDate::from_ymd_opt(2025, 2, 28)
+		);
+	}
+}
@@ -0,0 +1,236 @@
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+use crate::{
+	Base64BinaryBuf, Datatype, Date, DateTime, DateTimeDatatype, Decimal, Double, Float,
+	HexBinaryBuf, Integer, Time, Value,
+};
+
+/// Rust value from which an XSD value can be constructed.
+///
+/// See [`Value::try_new`].
+#[derive(Debug, Clone)]
+pub enum ValueSource {
+	Boolean(bool),
+	Integer(Integer),
+	Decimal(Decimal),
+	Float(f32),
+	Double(f64),
+
+	/// Lexical form, interpreted according to the target datatype.
+	String(String),
+
+	Bytes(Vec<u8>),
+	DateTime(chrono::DateTime<FixedOffset>),
+	NaiveDateTime(NaiveDateTime),
+	NaiveDate(NaiveDate),
+	NaiveTime(NaiveTime),
+}
+
+/// Error returned by [`Value::try_new`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ConstructError {
+	/// The source value has no interpretation in the target datatype.
+	#[error("incompatible value source for `{}`", .0.iri())]
+	Incompatible(Datatype),
+
+	/// The source value is outside of the value space of the target datatype.
+	#[error("invalid `{}` value", .0.iri())]
+	Invalid(Datatype),
+}
+
+impl Value {
+	/// Creates a new value of the given datatype from a Rust value.
+	///
+	/// The source value is checked against the constraints of the datatype
+	/// (range of bounded integers, lexical space of string types, etc.).
+	/// String sources are interpreted as lexical forms of the datatype.
+	///
+	/// Returns an error if the source cannot be interpreted as a value of the
+	/// datatype, or if it is outside of its value space.
+	pub fn try_new(
+		datatype: Datatype,
+		source: impl Into<ValueSource>,
+	) -> Result<Self, ConstructError> {
+		match (datatype, source.into()) {
+			(_, ValueSource::String(s)) => parse(datatype, &s),
+			(Datatype::Boolean, ValueSource::Boolean(b)) => Ok(Self::Boolean(b.into())),
+			(Datatype::Float, ValueSource::Float(f)) => Ok(Self::Float(f.into())),
+			(Datatype::Float, ValueSource::Double(d)) => {
+				let f = d as f32;
+				if d.is_nan() || f64::from(f) == d {
+					Ok(Self::Float(f.into()))
+				} else {
+					Err(ConstructError::Invalid(datatype))
+				}
+			}
+			(Datatype::Double, ValueSource::Float(f)) => Ok(Self::Double(f.into())),
+			(Datatype::Double, ValueSource::Double(d)) => Ok(Self::Double(d.into())),
+			(Datatype::Decimal(_), ValueSource::Integer(i)) => parse(datatype, &i.to_string()),
+			(Datatype::Decimal(_), ValueSource::Decimal(d)) => parse(datatype, &d.to_string()),
+			(Datatype::Decimal(_), ValueSource::Float(f)) => {
+				let d = Decimal::try_from(Float::from(f))
+					.map_err(|_| ConstructError::Invalid(datatype))?;
+				parse(datatype, &d.to_string())
+			}
+			(Datatype::Decimal(_), ValueSource::Double(f)) => {
+				let d = Decimal::try_from(Double::from(f))
+					.map_err(|_| ConstructError::Invalid(datatype))?;
+				parse(datatype, &d.to_string())
+			}
+			(Datatype::Base64Binary, ValueSource::Bytes(b)) => {
+				Ok(Self::Base64Binary(Base64BinaryBuf::from_bytes(b)))
+			}
+			(Datatype::HexBinary, ValueSource::Bytes(b)) => {
+				Ok(Self::HexBinary(HexBinaryBuf::from_bytes(b)))
+			}
+			(Datatype::DateTime(DateTimeDatatype::DateTime), ValueSource::DateTime(d)) => {
+				Ok(Self::DateTime(d.into()))
+			}
+			(Datatype::DateTime(DateTimeDatatype::DateTime), ValueSource::NaiveDateTime(d)) => {
+				Ok(Self::DateTime(DateTime::new(d, None)))
+			}
+			(Datatype::DateTime(DateTimeDatatype::DateTimeStamp), ValueSource::DateTime(d)) => {
+				Ok(Self::DateTimeStamp(d.into()))
+			}
+			(Datatype::Date, ValueSource::NaiveDate(d)) => Ok(Self::Date(Date::new(d, None))),
+			(Datatype::Time, ValueSource::NaiveTime(t)) => Ok(Self::Time(Time::new(t, None))),
+			_ => Err(ConstructError::Incompatible(datatype)),
+		}
+	}
+}
+
+fn parse(datatype: Datatype, lexical_form: &str) -> Result<Value, ConstructError> {
+	datatype
+		.parse(lexical_form)
+		.map_err(|_| ConstructError::Invalid(datatype))
+}
+
+impl From<bool> for ValueSource {
+	fn from(value: bool) -> Self {
+		Self::Boolean(value)
+	}
+}
+
+macro_rules! from_integer {
+	{ $( $ty:ty ),* } => {
+		$(
+			impl From<$ty> for ValueSource {
+				fn from(value: $ty) -> Self {
+					Self::Integer(value.into())
+				}
+			}
+		)*
+	};
+}
+
+from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, Integer);
+
+impl From<Decimal> for ValueSource {
+	fn from(value: Decimal) -> Self {
+		Self::Decimal(value)
+	}
+}
+
+impl From<f32> for ValueSource {
+	fn from(value: f32) -> Self {
+		Self::Float(value)
+	}
+}
+
+impl From<f64> for ValueSource {
+	fn from(value: f64) -> Self {
+		Self::Double(value)
+	}
+}
+
+impl<'a> From<&'a str> for ValueSource {
+	fn from(value: &'a str) -> Self {
+		Self::String(value.to_owned())
+	}
+}
+
+impl From<String> for ValueSource {
+	fn from(value: String) -> Self {
+		Self::String(value)
+	}
+}
+
+impl<'a> From<&'a [u8]> for ValueSource {
+	fn from(value: &'a [u8]) -> Self {
+		Self::Bytes(value.to_vec())
+	}
+}
+
+impl From<Vec<u8>> for ValueSource {
+	fn from(value: Vec<u8>) -> Self {
+		Self::Bytes(value)
+	}
+}
+
+impl From<chrono::DateTime<FixedOffset>> for ValueSource {
+	fn from(value: chrono::DateTime<FixedOffset>) -> Self {
+		Self::DateTime(value)
+	}
+}
+
+impl From<chrono::DateTime<Utc>> for ValueSource {
+	fn from(value: chrono::DateTime<Utc>) -> Self {
+		Self::DateTime(value.into())
+	}
+}
+
+impl From<NaiveDateTime> for ValueSource {
+	fn from(value: NaiveDateTime) -> Self {
+		Self::NaiveDateTime(value)
+	}
+}
+
+impl From<NaiveDate> for ValueSource {
+	fn from(value: NaiveDate) -> Self {
+		Self::NaiveDate(value)
+	}
+}
+
+impl From<NaiveTime> for ValueSource {
+	fn from(value: NaiveTime) -> Self {
+		Self::NaiveTime(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{XSD_BYTE, XSD_DECIMAL, XSD_HEX_BINARY, XSD_INTEGER, XSD_LANGUAGE};
+
+	#[test]
+	fn try_new() {
+		let byte = Datatype::from_iri(XSD_BYTE).unwrap();
+		assert!(matches!(Value::try_new(byte, 12u64), Ok(Value::Byte(12))));
+		assert!(matches!(
+			Value::try_new(byte, 300i32),
+			Err(ConstructError::Invalid(_))
+		));
+
+		let integer = Datatype::from_iri(XSD_INTEGER).unwrap();
+		assert!(matches!(
+			Value::try_new(integer, 1.5f64),
+			Err(ConstructError::Invalid(_))
+		));
+		assert!(matches!(
+			Value::try_new(integer, true),
+			Err(ConstructError::Incompatible(_))
+		));
+
+		let decimal = Datatype::from_iri(XSD_DECIMAL).unwrap();
+		let value = Value::try_new(decimal, 1.5f64).unwrap();
+		assert_eq!(value.to_string(), "1.5");
+
+		let language = Datatype::from_iri(XSD_LANGUAGE).unwrap();
+		assert!(Value::try_new(language, "en-GB").is_ok());
+		assert!(Value::try_new(language, "not a language").is_err());
+
+		let hex = Datatype::from_iri(XSD_HEX_BINARY).unwrap();
+		let value = Value::try_new(hex, vec![0xcau8, 0xfe]).unwrap();
+		assert_eq!(value.to_string(), "CAFE")
+	}
+}
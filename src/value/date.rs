@@ -1,14 +1,15 @@
 use chrono::{Datelike, FixedOffset, NaiveDate};
 
 use crate::{
-	format_timezone,
-	lexical::{InvalidDate, LexicalFormOf},
-	Datatype, DisplayYear, ParseXsd, XsdValue,
+	check_timezone, format_timezone,
+	lexical::{InvalidDate, Lexical, LexicalFormOf},
+	year_bce, Datatype, DisplayYear, GMonthDay, GYear, GYearMonth, InvalidComponent, ParseXsd,
+	XsdValue, YearConvention,
 };
 use core::fmt;
 use std::str::FromStr;
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 #[error("invalid date value")]
 pub struct InvalidDateValue;
 
@@ -22,9 +23,112 @@ impl Date {
 	pub fn new(date: NaiveDate, offset: Option<FixedOffset>) -> Self {
 		Self { date, offset }
 	}
+
+	/// Returns the date without timezone.
+	pub fn naive(&self) -> &NaiveDate {
+		&self.date
+	}
+
+	/// Returns the timezone offset, if any.
+	pub fn offset(&self) -> Option<FixedOffset> {
+		self.offset
+	}
+
+	/// Checks if this date has an explicit timezone.
+	pub fn has_timezone(&self) -> bool {
+		self.offset.is_some()
+	}
+
+	/// Parses a date from its lexical form, reading the year with the given
+	/// convention.
+	///
+	/// Parsing with [`FromStr`] uses the default XSD 1.1 convention.
+	pub fn parse_with(s: &str, convention: YearConvention) -> Result<Self, DateFromStrError> {
+		let lexical_value = <crate::lexical::Date as Lexical>::parse(s)?;
+		lexical_value
+			.parts()
+			.to_date_with(convention)
+			.map_err(Into::into)
+	}
+
+	/// Returns the year of this date in the given convention.
+	pub fn year_in(&self, convention: YearConvention) -> i32 {
+		convention.from_xsd11(self.date.year())
+	}
+
+	/// Returns the year before the common era (BCE) of this date, if any.
+	pub fn year_bce(&self) -> Option<u32> {
+		year_bce(self.date.year())
+	}
+
+	/// Truncates this date to its year and month, preserving the timezone.
+	pub fn year_month(&self) -> GYearMonth {
+		GYearMonth::new(self.date.year(), self.date.month() as u8, self.offset).unwrap()
+	}
+
+	/// Truncates this date to its year, preserving the timezone.
+	pub fn gyear(&self) -> GYear {
+		GYear::new(self.date.year(), self.offset)
+	}
+
+	/// Returns the recurring month and day of this date, preserving the
+	/// timezone.
+	pub fn gmonth_day(&self) -> GMonthDay {
+		GMonthDay::new(self.date.month() as u8, self.date.day() as u8, self.offset).unwrap()
+	}
+
+	/// Returns this date with the given year.
+	///
+	/// Fails if the resulting date does not exist (February 29th of a
+	/// non-leap year).
+	pub fn with_year(&self, year: i32) -> Result<Self, InvalidComponent> {
+		match self.date.with_year(year) {
+			Some(date) => Ok(Self::new(date, self.offset)),
+			None if NaiveDate::from_ymd_opt(year, 1, 1).is_none() => {
+				Err(InvalidComponent::Year(year))
+			}
+			None => Err(InvalidComponent::Day(self.date.day() as u8)),
+		}
+	}
+
+	/// Returns this date with the given month.
+	pub fn with_month(&self, month: u8) -> Result<Self, InvalidComponent> {
+		if !(1..=12).contains(&month) {
+			return Err(InvalidComponent::Month(month));
+		}
+
+		self.date
+			.with_month(month.into())
+			.map(|date| Self::new(date, self.offset))
+			.ok_or(InvalidComponent::Day(self.date.day() as u8))
+	}
+
+	/// Returns this date with the given day of the month.
+	///
+	/// ```
+	/// use xsd_types::Date;
+	///
+	/// let date: Date = "2024-02-17Z".parse().unwrap();
+	/// assert_eq!(date.with_day(1).unwrap().to_string(), "2024-02-01Z");
+	/// assert!(date.with_day(30).is_err());
+	/// ```
+	pub fn with_day(&self, day: u8) -> Result<Self, InvalidComponent> {
+		self.date
+			.with_day(day.into())
+			.map(|date| Self::new(date, self.offset))
+			.ok_or(InvalidComponent::Day(day))
+	}
+
+	/// Returns this date with the given timezone offset.
+	///
+	/// The date itself is left unchanged. Fails if the offset is not between
+	/// `-14:00` and `+14:00`, or is not a whole number of minutes.
+	pub fn with_timezone(&self, offset: Option<FixedOffset>) -> Result<Self, InvalidComponent> {
+		check_timezone(offset).map(|offset| Self::new(self.date, offset))
+	}
 }
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum DateFromStrError {
 	#[error("invalid date syntax")]
 	Syntax(#[from] InvalidDate<String>),
@@ -37,8 +141,7 @@ impl FromStr for Date {
 	type Err = DateFromStrError;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let lexical_value =
-			crate::lexical::Date::new(s).map_err(|InvalidDate(s)| InvalidDate(s.to_owned()))?;
+		let lexical_value = <crate::lexical::Date as Lexical>::parse(s)?;
 		lexical_value.try_as_value().map_err(Into::into)
 	}
 }
@@ -2,19 +2,29 @@ use chrono::{Datelike, FixedOffset, Timelike, Utc};
 use std::{cmp::Ordering, fmt, hash::Hash, str::FromStr};
 
 use crate::{
-	format_nanoseconds, format_timezone,
-	lexical::{date_time::InvalidDateTimeStamp, LexicalFormOf},
-	Datatype, DateTimeDatatype, DisplayYear, ParseXsd, XsdValue,
+	fmt_time_seconds, format_timezone,
+	lexical::{date_time::InvalidDateTimeStamp, Lexical, LexicalFormOf},
+	year_bce, Datatype, DateTimeDatatype, DisplayYear, InvalidDateTimeValue, ParseXsd, Seconds,
+	XsdValue, YearConvention,
 };
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 #[error("invalid timezone")]
 pub struct InvalidTimezone(chrono::NaiveDateTime, FixedOffset);
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 #[error("invalid datetimestamp value")]
 pub struct InvalidDateTimeStampValue;
 
+/// Error returned by [`DateTimeStamp::from_sortable_key`] when the input is
+/// not a sortable key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("invalid sortable key")]
+pub struct InvalidSortableKey;
+
+/// Length of a sortable key.
+const SORTABLE_KEY_LEN: usize = 33;
+
 #[derive(Debug, Clone, Copy)]
 pub struct DateTimeStamp {
 	pub date_time: chrono::NaiveDateTime,
@@ -26,14 +36,51 @@ impl DateTimeStamp {
 		Self { date_time, offset }
 	}
 
+	/// Returns the date and time without timezone.
+	pub fn naive(&self) -> &chrono::NaiveDateTime {
+		&self.date_time
+	}
+
+	/// Returns the timezone offset.
+	pub fn offset(&self) -> FixedOffset {
+		self.offset
+	}
+
+	/// Parses a date/time stamp from its lexical form, reading the year with the given
+	/// convention.
+	///
+	/// Parsing with [`FromStr`] uses the default XSD 1.1 convention.
+	pub fn parse_with(
+		s: &str,
+		convention: YearConvention,
+	) -> Result<Self, DateTimeStampFromStrError> {
+		let lexical_value = <crate::lexical::DateTimeStamp as Lexical>::parse(s)?;
+		lexical_value
+			.parts()
+			.to_datetime_with(convention)
+			.map_err(Into::into)
+	}
+
+	/// Returns the year of this date/time stamp in the given convention.
+	pub fn year_in(&self, convention: YearConvention) -> i32 {
+		convention.from_xsd11(self.date_time.year())
+	}
+
+	/// Returns the year before the common era (BCE) of this date/time stamp, if any.
+	pub fn year_bce(&self) -> Option<u32> {
+		year_bce(self.date_time.year())
+	}
+
 	/// Returns a `DateTimeStamp` which corresponds to the current time and
 	/// date.
+	#[cfg(feature = "clock")]
 	pub fn now() -> Self {
 		Utc::now().into()
 	}
 
 	/// Returns a `DateTimeStamp` which corresponds to the current time and
 	/// date, with millisecond precision (at most).
+	#[cfg(feature = "clock")]
 	pub fn now_ms() -> Self {
 		let now = Utc::now();
 		let ms = now.timestamp_subsec_millis();
@@ -45,10 +92,147 @@ impl DateTimeStamp {
 		self.to_string()
 	}
 
+	/// Returns the seconds component of this date/time.
+	pub fn seconds(&self) -> Seconds {
+		Seconds::of(&self.date_time)
+	}
+
 	/// Converts this `DateTimeStamp` to a `chrono::DateTime<FixedOffset>`.
 	pub fn to_chrono_date_time(&self) -> chrono::DateTime<FixedOffset> {
 		self.date_time.and_local_timezone(self.offset).unwrap()
 	}
+
+	/// Converts this `DateTimeStamp` to the local time of the given IANA
+	/// timezone, at the same instant.
+	#[cfg(feature = "chrono-tz")]
+	pub fn to_zone(&self, tz: chrono_tz::Tz) -> chrono::DateTime<chrono_tz::Tz> {
+		self.to_chrono_date_time().with_timezone(&tz)
+	}
+
+	/// Checks if the offset of this `DateTimeStamp` is the one of the given
+	/// IANA timezone at the same instant.
+	///
+	/// This can be used to recover the named timezone of a fixed offset, by
+	/// testing candidate zones.
+	///
+	/// ```
+	/// use xsd_types::DateTimeStamp;
+	///
+	/// let value: DateTimeStamp = "2024-01-15T09:00:00+01:00".parse().unwrap();
+	/// assert!(value.is_in_zone(chrono_tz::Europe::Paris));
+	/// assert!(!value.is_in_zone(chrono_tz::Europe::London));
+	/// ```
+	#[cfg(feature = "chrono-tz")]
+	pub fn is_in_zone(&self, tz: chrono_tz::Tz) -> bool {
+		use chrono::Offset;
+		self.to_zone(tz).offset().fix() == self.offset
+	}
+
+	/// Returns a fixed-width key, such that comparing the keys of two
+	/// date/time stamps as strings gives the same result as comparing the
+	/// date/time stamps.
+	///
+	/// The key has the form `YYYYYYY-MM-DDThh:mm:ss.fffffffffZ`: the time is
+	/// normalized to UTC, with nanosecond precision. Non-negative years are
+	/// written on 7 digits. A negative year `-n` is written as `-` followed by
+	/// `1000000 - n` on 6 digits, so that it sorts before the years that
+	/// follow it.
+	///
+	/// Equal date/time stamps in different timezones have the same key. Use
+	/// [`Self::from_sortable_key`] to parse it back. Fails if the UTC date/time
+	/// is out of the supported range.
+	///
+	/// ```
+	/// use xsd_types::DateTimeStamp;
+	///
+	/// let value: DateTimeStamp = "2024-05-17T12:00:00.5+02:00".parse().unwrap();
+	/// assert_eq!(value.to_sortable_key().unwrap(), "0002024-05-17T10:00:00.500000000Z");
+	/// ```
+	pub fn to_sortable_key(&self) -> Result<String, InvalidDateTimeValue> {
+		let utc = self
+			.date_time
+			.checked_sub_signed(chrono::Duration::seconds(
+				self.offset.local_minus_utc().into(),
+			))
+			.ok_or(InvalidDateTimeValue)?;
+
+		// Leap seconds are stored by chrono as an overflowing nanosecond.
+		let (second, nano_second) = if utc.nanosecond() >= 1_000_000_000 {
+			(utc.second() + 1, utc.nanosecond() - 1_000_000_000)
+		} else {
+			(utc.second(), utc.nanosecond())
+		};
+
+		let year = match utc.year() {
+			year if year < 0 => format!("-{:06}", 1_000_000 + year),
+			year => format!("{year:07}"),
+		};
+
+		Ok(format!(
+			"{year}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}Z",
+			utc.month(),
+			utc.day(),
+			utc.hour(),
+			utc.minute(),
+			second,
+			nano_second
+		))
+	}
+
+	/// Parses a key produced by [`Self::to_sortable_key`].
+	///
+	/// The returned date/time stamp is in UTC.
+	pub fn from_sortable_key(key: &str) -> Result<Self, InvalidSortableKey> {
+		fn number<T: FromStr>(s: &str) -> Result<T, InvalidSortableKey> {
+			if s.bytes().all(|c| c.is_ascii_digit()) {
+				s.parse().map_err(|_| InvalidSortableKey)
+			} else {
+				Err(InvalidSortableKey)
+			}
+		}
+
+		let bytes = key.as_bytes();
+		if bytes.len() != SORTABLE_KEY_LEN
+			|| !key.is_ascii()
+			|| bytes[7] != b'-'
+			|| bytes[10] != b'-'
+			|| bytes[13] != b'T'
+			|| bytes[16] != b':'
+			|| bytes[19] != b':'
+			|| bytes[22] != b'.'
+			|| bytes[32] != b'Z'
+		{
+			return Err(InvalidSortableKey);
+		}
+
+		let year = match key.strip_prefix('-') {
+			Some(complement) => match number::<i32>(&complement[..6])? {
+				0 => return Err(InvalidSortableKey),
+				n => n - 1_000_000,
+			},
+			None => number(&key[..7])?,
+		};
+
+		// Leap seconds are stored by chrono as an overflowing nanosecond.
+		let (second, nano_second) = match (number(&key[20..22])?, number::<u32>(&key[23..32])?) {
+			(60, nano_second) => (59, nano_second + 1_000_000_000),
+			other => other,
+		};
+
+		let date_time =
+			chrono::NaiveDate::from_ymd_opt(year, number(&key[8..10])?, number(&key[11..13])?)
+				.and_then(|date| {
+					date.and_hms_nano_opt(
+						number(&key[14..16]).ok()?,
+						number(&key[17..19]).ok()?,
+						second,
+						nano_second,
+					)
+				})
+				.ok_or(InvalidSortableKey)?;
+
+		Ok(Self::new(date_time, FixedOffset::east_opt(0).unwrap()))
+	}
 }
 
 impl PartialEq for DateTimeStamp {
@@ -116,21 +300,20 @@ impl fmt::Display for DateTimeStamp {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		write!(
 			f,
-			"{}-{:02}-{:02}T{:02}:{:02}:{:02}",
+			"{}-{:02}-{:02}T{:02}:{:02}:",
 			DisplayYear(self.date_time.year()),
 			self.date_time.month(),
 			self.date_time.day(),
 			self.date_time.hour(),
-			self.date_time.minute(),
-			self.date_time.second()
+			self.date_time.minute()
 		)?;
 
-		format_nanoseconds(self.date_time.nanosecond(), f)?;
+		fmt_time_seconds(&self.date_time, f)?;
 		format_timezone(Some(self.offset), f)
 	}
 }
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum DateTimeStampFromStrError {
 	#[error("invalid date syntax")]
 	Syntax(#[from] InvalidDateTimeStamp<String>),
@@ -143,8 +326,7 @@ impl FromStr for DateTimeStamp {
 	type Err = DateTimeStampFromStrError;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let lexical_value = crate::lexical::DateTimeStamp::new(s)
-			.map_err(|InvalidDateTimeStamp(s)| InvalidDateTimeStamp(s.to_owned()))?;
+		let lexical_value = <crate::lexical::DateTimeStamp as Lexical>::parse(s)?;
 		lexical_value.try_as_value().map_err(Into::into)
 	}
 }
@@ -165,6 +347,16 @@ impl From<chrono::DateTime<Utc>> for DateTimeStamp {
 	}
 }
 
+/// Keeps the local time and offset of the given date/time, dropping the
+/// timezone name.
+#[cfg(feature = "chrono-tz")]
+impl From<chrono::DateTime<chrono_tz::Tz>> for DateTimeStamp {
+	fn from(value: chrono::DateTime<chrono_tz::Tz>) -> Self {
+		use chrono::Offset;
+		Self::new(value.naive_local(), value.offset().fix())
+	}
+}
+
 #[cfg(feature = "time")]
 impl From<time::OffsetDateTime> for DateTimeStamp {
 	fn from(value: time::OffsetDateTime) -> Self {
@@ -243,6 +435,52 @@ impl<'de> serde::Deserialize<'de> for DateTimeStamp {
 
 #[cfg(test)]
 mod tests {
+	#[test]
+	fn sortable_keys() {
+		use super::{DateTimeStamp, InvalidDateTimeValue, InvalidSortableKey};
+
+		let mut values: Vec<DateTimeStamp> = [
+			"2024-05-17T10:00:00Z",
+			"2024-05-17T11:00:00.000000001+02:00",
+			"-0001-01-01T00:00:00Z",
+			"-1000-12-31T23:59:59Z",
+			"0000-06-01T00:00:00Z",
+			"12024-01-01T00:00:00-14:00",
+		]
+		.iter()
+		.map(|s| s.parse().unwrap())
+		.collect();
+
+		let mut keys: Vec<_> = values
+			.iter()
+			.map(|value| value.to_sortable_key().unwrap())
+			.collect();
+		assert!(keys.iter().all(|key| key.len() == 33));
+
+		values.sort();
+		keys.sort();
+		assert_eq!(
+			values
+				.iter()
+				.map(|value| value.to_sortable_key().unwrap())
+				.collect::<Vec<_>>(),
+			keys
+		);
+
+		for (value, key) in values.iter().zip(&keys) {
+			assert_eq!(DateTimeStamp::from_sortable_key(key), Ok(*value))
+		}
+
+		assert_eq!(keys[0], "-999000-12-31T23:59:59.000000000Z");
+		assert_eq!(
+			DateTimeStamp::from_sortable_key("2024-05-17T10:00:00Z"),
+			Err(InvalidSortableKey)
+		);
+
+		let value: DateTimeStamp = "-262143-01-01T01:00:00+14:00".parse().unwrap();
+		assert_eq!(value.to_sortable_key(), Err(InvalidDateTimeValue))
+	}
+
 	#[cfg(feature = "time")]
 	#[test]
 	fn chrono_time_roundtrip() {
@@ -260,4 +498,30 @@ mod tests {
 		let time: time::OffsetDateTime = xsd.into();
 		assert_eq!(time, expected_time);
 	}
+
+	#[cfg(feature = "chrono-tz")]
+	#[test]
+	fn named_timezones() {
+		use super::DateTimeStamp;
+		use crate::{DateTime, NonexistentLocalTime};
+		use chrono_tz::Europe::Paris;
+
+		// Skipped when moving to daylight saving time.
+		let value: DateTime = "2024-03-31T02:30:00".parse().unwrap();
+		assert_eq!(
+			value.in_zone(Paris),
+			Err(NonexistentLocalTime(value.date_time))
+		);
+
+		// Repeated when moving back from daylight saving time.
+		let value: DateTime = "2024-10-27T02:30:00".parse().unwrap();
+		let stamp = value.in_zone(Paris).unwrap();
+		assert_eq!(stamp.to_string(), "2024-10-27T02:30:00+02:00");
+		assert!(stamp.is_in_zone(Paris));
+
+		let winter: DateTimeStamp = "2024-10-27T02:30:00+01:00".parse().unwrap();
+		assert!(winter.is_in_zone(Paris));
+		assert_eq!(winter.to_zone(Paris).to_string(), "2024-10-27 02:30:00 CET");
+		assert_eq!(DateTimeStamp::from(winter.to_zone(Paris)), winter);
+	}
 }
@@ -1,24 +1,36 @@
-use chrono::{Datelike, FixedOffset, Timelike, Utc};
+use chrono::{Datelike, FixedOffset, NaiveDate, NaiveTime, Timelike, Utc};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::ToPrimitive;
 use std::{cmp::Ordering, fmt, hash::Hash, str::FromStr};
 
+use super::calendar::MAX_TIMEZONE_OFFSET;
 use crate::{
-	lexical::{InvalidDateTime, LexicalFormOf},
-	utils::div_rem,
-	Datatype, DateTimeDatatype, ParseXsd, XsdValue,
+	check_timezone, clamp_day_to_month, fmt_time_seconds,
+	lexical::{InvalidDateTime, Lexical, LexicalFormOf},
+	year_bce, Datatype, Date, DateTimeDatatype, Decimal, Duration, GMonthDay, GYear, GYearMonth,
+	InvalidComponent, ParseXsd, Seconds, XsdValue, YearConvention,
 };
 
+/// Number of days from 0001-01-01 (day 1 of the common era) to 1970-01-01.
+const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719_163;
+
+const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
+
+const SECONDS_PER_DAY: u32 = 86_400;
+
 mod date_time_stamp;
 pub use date_time_stamp::*;
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 #[error("missing timezone")]
 pub struct MissingTimezone;
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 #[error("invalid timezone")]
 pub struct InvalidTimezone(chrono::NaiveDateTime, FixedOffset);
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 pub enum TimezoneError {
 	#[error(transparent)]
 	Missing(#[from] MissingTimezone),
@@ -27,10 +39,16 @@ pub enum TimezoneError {
 	Invalid(#[from] InvalidTimezone),
 }
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 #[error("invalid datetime value")]
 pub struct InvalidDateTimeValue;
 
+/// Error returned when a local date/time is skipped by a timezone
+/// transition (e.g. when moving to daylight saving time).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("local time `{0}` does not exist in this timezone")]
+pub struct NonexistentLocalTime(pub chrono::NaiveDateTime);
+
 #[derive(Debug, Clone, Copy)]
 pub struct DateTime {
 	pub date_time: chrono::NaiveDateTime,
@@ -42,13 +60,141 @@ impl DateTime {
 		Self { date_time, offset }
 	}
 
+	/// Returns the date and time without timezone.
+	pub fn naive(&self) -> &chrono::NaiveDateTime {
+		&self.date_time
+	}
+
+	/// Returns the timezone offset, if any.
+	pub fn offset(&self) -> Option<FixedOffset> {
+		self.offset
+	}
+
+	/// Checks if this date/time has an explicit timezone.
+	///
+	/// Whether the timezone was written `Z` or `+00:00` is not kept in the
+	/// value space: see the `timezone_is_z` method of the lexical form.
+	pub fn has_timezone(&self) -> bool {
+		self.offset.is_some()
+	}
+
+	/// Parses a date/time from its lexical form, reading the year with the given
+	/// convention.
+	///
+	/// Parsing with [`FromStr`] uses the default XSD 1.1 convention.
+	pub fn parse_with(s: &str, convention: YearConvention) -> Result<Self, DateTimeFromStrError> {
+		let lexical_value = <crate::lexical::DateTime as Lexical>::parse(s)?;
+		lexical_value
+			.parts()
+			.to_datetime_with(convention)
+			.map_err(Into::into)
+	}
+
+	/// Parses a date/time from its lexical form and normalizes it to UTC.
+	///
+	/// Two normalized date/times are equal exactly when their date, time
+	/// and offset are, which makes comparing or hashing them trivial. See
+	/// [`Self::to_utc`].
+	///
+	/// ```
+	/// use xsd_types::DateTime;
+	///
+	/// let value = DateTime::parse_normalized("2024-05-17T12:00:00+02:00").unwrap();
+	/// assert_eq!(value.to_string(), "2024-05-17T10:00:00Z");
+	/// ```
+	pub fn parse_normalized(s: &str) -> Result<Self, DateTimeFromStrError> {
+		let value: Self = s.parse()?;
+		value.to_utc().map_err(Into::into)
+	}
+
+	/// Parses a date/time from its lexical form and normalizes it to UTC,
+	/// also returning the original timezone offset.
+	///
+	/// The original date/time can be recovered with [`Self::to_offset`].
+	pub fn parse_normalized_with_offset(
+		s: &str,
+	) -> Result<(Self, Option<FixedOffset>), DateTimeFromStrError> {
+		let value: Self = s.parse()?;
+		Ok((value.to_utc()?, value.offset))
+	}
+
+	/// Converts this date/time to UTC, at the same instant.
+	///
+	/// Date/times without timezone are returned unchanged, since they do not
+	/// designate a single instant. Fails if the UTC date/time is out of the
+	/// supported range.
+	pub fn to_utc(&self) -> Result<Self, InvalidDateTimeValue> {
+		self.to_offset(Some(FixedOffset::east_opt(0).unwrap()))
+	}
+
+	/// Converts this date/time to the given offset, at the same instant.
+	///
+	/// Date/times without timezone are returned unchanged. Fails if the
+	/// resulting date/time is out of the supported range.
+	pub fn to_offset(&self, offset: Option<FixedOffset>) -> Result<Self, InvalidDateTimeValue> {
+		match (self.offset, offset) {
+			(Some(current), Some(offset)) => self
+				.date_time
+				.checked_sub_signed(chrono::Duration::seconds(current.local_minus_utc().into()))
+				.and_then(|utc| {
+					utc.checked_add_signed(chrono::Duration::seconds(
+						offset.local_minus_utc().into(),
+					))
+				})
+				.map(|date_time| Self::new(date_time, Some(offset)))
+				.ok_or(InvalidDateTimeValue),
+			_ => Ok(*self),
+		}
+	}
+
+	/// Returns the year of this date/time in the given convention.
+	pub fn year_in(&self, convention: YearConvention) -> i32 {
+		convention.from_xsd11(self.date_time.year())
+	}
+
+	/// Returns the year before the common era (BCE) of this date/time, if any.
+	pub fn year_bce(&self) -> Option<u32> {
+		year_bce(self.date_time.year())
+	}
+
+	/// Truncates this date/time to its date, preserving the timezone.
+	pub fn date(&self) -> Date {
+		Date::new(self.date_time.date(), self.offset)
+	}
+
+	/// Truncates this date/time to its year and month, preserving the
+	/// timezone.
+	///
+	/// ```
+	/// use xsd_types::DateTime;
+	///
+	/// let value: DateTime = "2024-03-01T12:30:00+02:00".parse().unwrap();
+	/// assert_eq!(value.year_month().to_string(), "2024-03+02:00");
+	/// ```
+	pub fn year_month(&self) -> GYearMonth {
+		self.date().year_month()
+	}
+
+	/// Truncates this date/time to its year, preserving the timezone.
+	pub fn gyear(&self) -> GYear {
+		self.date().gyear()
+	}
+
+	/// Returns the recurring month and day of this date/time, preserving the
+	/// timezone.
+	pub fn gmonth_day(&self) -> GMonthDay {
+		self.date().gmonth_day()
+	}
+
 	/// Returns a `DateTime` which corresponds to the current time and date.
+	#[cfg(feature = "clock")]
 	pub fn now() -> Self {
 		Utc::now().into()
 	}
 
 	/// Returns a `DateTime` which corresponds to the current time and date,
 	/// with millisecond precision (at most).
+	#[cfg(feature = "clock")]
 	pub fn now_ms() -> Self {
 		let now = Utc::now();
 		let ms = now.timestamp_subsec_millis();
@@ -60,6 +206,128 @@ impl DateTime {
 		self.to_string()
 	}
 
+	/// Returns the seconds component of this date/time.
+	pub fn seconds(&self) -> Seconds {
+		Seconds::of(&self.date_time)
+	}
+
+	/// Returns this date/time with the given date, keeping the time and
+	/// timezone.
+	fn with_date(&self, date: Date) -> Self {
+		Self::new(date.date.and_time(self.date_time.time()), self.offset)
+	}
+
+	/// Returns this date/time with the given year.
+	///
+	/// Fails if the resulting date does not exist (February 29th of a
+	/// non-leap year).
+	pub fn with_year(&self, year: i32) -> Result<Self, InvalidComponent> {
+		self.date().with_year(year).map(|date| self.with_date(date))
+	}
+
+	/// Returns this date/time with the given month.
+	pub fn with_month(&self, month: u8) -> Result<Self, InvalidComponent> {
+		self.date()
+			.with_month(month)
+			.map(|date| self.with_date(date))
+	}
+
+	/// Returns this date/time with the given day of the month.
+	///
+	/// ```
+	/// use xsd_types::DateTime;
+	///
+	/// let value: DateTime = "2024-02-17T10:30:00Z".parse().unwrap();
+	/// assert_eq!(
+	///     value.with_day(1).unwrap().to_string(),
+	///     "2024-02-01T10:30:00Z"
+	/// );
+	/// ```
+	pub fn with_day(&self, day: u8) -> Result<Self, InvalidComponent> {
+		self.date().with_day(day).map(|date| self.with_date(date))
+	}
+
+	/// Returns this date/time with the given hour.
+	pub fn with_hour(&self, hour: u8) -> Result<Self, InvalidComponent> {
+		self.date_time
+			.with_hour(hour.into())
+			.map(|date_time| Self::new(date_time, self.offset))
+			.ok_or(InvalidComponent::Hour(hour))
+	}
+
+	/// Returns this date/time with the given minute.
+	pub fn with_minute(&self, minute: u8) -> Result<Self, InvalidComponent> {
+		self.date_time
+			.with_minute(minute.into())
+			.map(|date_time| Self::new(date_time, self.offset))
+			.ok_or(InvalidComponent::Minute(minute))
+	}
+
+	/// Returns this date/time with the given (whole) second, keeping the
+	/// fractional part of the seconds.
+	pub fn with_second(&self, second: u8) -> Result<Self, InvalidComponent> {
+		self.date_time
+			.with_second(second.into())
+			.map(|date_time| Self::new(date_time, self.offset))
+			.ok_or(InvalidComponent::Second(second))
+	}
+
+	/// Returns this date/time with the given timezone offset.
+	///
+	/// The local date and time are left unchanged, meaning that the result
+	/// represents a different instant. Fails if the offset is not between
+	/// `-14:00` and `+14:00`, or is not a whole number of minutes.
+	pub fn with_timezone(&self, offset: Option<FixedOffset>) -> Result<Self, InvalidComponent> {
+		check_timezone(offset).map(|offset| Self::new(self.date_time, offset))
+	}
+
+	/// Truncates this date/time to millisecond precision.
+	///
+	/// Returns the truncated date/time along with a boolean which is `true`
+	/// if sub-millisecond information was lost.
+	pub fn truncate_to_millis(&self) -> (Self, bool) {
+		let ns = self.date_time.nanosecond();
+		let truncated_ns = ns / 1_000_000 * 1_000_000;
+		let date_time = self.date_time.with_nanosecond(truncated_ns).unwrap();
+		(Self::new(date_time, self.offset), ns != truncated_ns)
+	}
+
+	/// Attaches the given IANA timezone to this date/time.
+	///
+	/// If this date/time has an offset, it is converted to the local time of
+	/// `tz` at the same instant. Otherwise, it is interpreted as a local time
+	/// of `tz`. When the local time is ambiguous (e.g. when moving back from
+	/// daylight saving time), the earliest instant is chosen.
+	///
+	/// ```
+	/// use xsd_types::DateTime;
+	///
+	/// let value: DateTime = "2024-07-01T12:00:00".parse().unwrap();
+	/// let stamp = value.in_zone(chrono_tz::Europe::Paris).unwrap();
+	/// assert_eq!(stamp.to_string(), "2024-07-01T12:00:00+02:00");
+	///
+	/// let value: DateTime = "2024-07-01T12:00:00Z".parse().unwrap();
+	/// let stamp = value.in_zone(chrono_tz::America::New_York).unwrap();
+	/// assert_eq!(stamp.to_string(), "2024-07-01T08:00:00-04:00");
+	/// ```
+	#[cfg(feature = "chrono-tz")]
+	pub fn in_zone(&self, tz: chrono_tz::Tz) -> Result<DateTimeStamp, NonexistentLocalTime> {
+		use chrono::TimeZone;
+		match self.offset {
+			Some(offset) => Ok(self
+				.date_time
+				.and_local_timezone(offset)
+				.unwrap()
+				.with_timezone(&tz)
+				.into()),
+			None => tz
+				.from_local_datetime(&self.date_time)
+				.earliest()
+				.map(Into::into)
+				.ok_or(NonexistentLocalTime(self.date_time)),
+		}
+	}
+
 	/// Returns the earliest date/time with offset represented by this
 	/// date/time.
 	pub fn earliest(&self) -> chrono::DateTime<FixedOffset> {
@@ -67,7 +335,7 @@ impl DateTime {
 			Some(offset) => self.date_time.and_local_timezone(offset).unwrap(),
 			None => self
 				.date_time
-				.and_local_timezone(FixedOffset::west_opt(14 * 60 * 60).unwrap())
+				.and_local_timezone(FixedOffset::west_opt(MAX_TIMEZONE_OFFSET).unwrap())
 				.unwrap(),
 		}
 	}
@@ -79,10 +347,142 @@ impl DateTime {
 			Some(offset) => self.date_time.and_local_timezone(offset).unwrap(),
 			None => self
 				.date_time
-				.and_local_timezone(FixedOffset::east_opt(14 * 60 * 60).unwrap())
+				.and_local_timezone(FixedOffset::east_opt(MAX_TIMEZONE_OFFSET).unwrap())
 				.unwrap(),
 		}
 	}
+
+	/// Returns the position of this date/time on the time line, as a number
+	/// of days since 1970-01-01 and the (exact) number of seconds elapsed
+	/// in that day, along with the timezone offset.
+	///
+	/// Following the `timeOnTimeline` function of XSD 1.1, the position is
+	/// computed in UTC: the date/time is first normalized using its offset.
+	/// A date/time without timezone is positioned as if it were in UTC.
+	/// Leap seconds are folded back into the last second of the day.
+	///
+	/// The original value can be reconstructed exactly with
+	/// [`Self::from_epoch_parts`].
+	///
+	/// ```
+	/// use xsd_types::DateTime;
+	///
+	/// let value: DateTime = "1970-01-02T03:00:00.5+02:00".parse().unwrap();
+	/// let (days, seconds, offset) = value.to_epoch_parts();
+	/// assert_eq!(days, 1);
+	/// assert_eq!(seconds.to_string(), "3600.5");
+	///
+	/// let back = DateTime::from_epoch_parts(days, seconds, offset).unwrap();
+	/// assert_eq!(back.to_string(), "1970-01-02T03:00:00.5+02:00");
+	/// ```
+	pub fn to_epoch_parts(&self) -> (i64, Decimal, Option<FixedOffset>) {
+		// Normalized by hand, since the UTC date/time may be out of the range
+		// supported by `chrono`.
+		let mut days = self.date_time.num_days_from_ce() as i64 - UNIX_EPOCH_DAYS_FROM_CE;
+		let mut secs = i64::from(self.date_time.num_seconds_from_midnight())
+			- self
+				.offset
+				.map_or(0, |offset| offset.local_minus_utc().into());
+		if secs < 0 {
+			days -= 1;
+			secs += i64::from(SECONDS_PER_DAY)
+		} else if secs >= i64::from(SECONDS_PER_DAY) {
+			days += 1;
+			secs -= i64::from(SECONDS_PER_DAY)
+		}
+
+		let ns = BigInt::from(secs) * NANOSECONDS_PER_SECOND
+			+ self.date_time.nanosecond() % NANOSECONDS_PER_SECOND;
+		let r = BigRational::new(ns, NANOSECONDS_PER_SECOND.into());
+		// SAFETY: the denominator is a power of ten.
+		let seconds = unchecked!(Decimal, r);
+
+		(days, seconds, self.offset)
+	}
+
+	/// Creates a date/time from its position on the time line, as returned
+	/// by [`Self::to_epoch_parts`].
+	///
+	/// `seconds` must be in the `[0, 86400)` range, with at most nanosecond
+	/// precision. The date/time is then expressed in the given `offset`.
+	pub fn from_epoch_parts(
+		days: i64,
+		seconds: Decimal,
+		offset: Option<FixedOffset>,
+	) -> Result<Self, InvalidDateTimeValue> {
+		let ns =
+			seconds.as_big_rational() * BigRational::from_integer(NANOSECONDS_PER_SECOND.into());
+		if !ns.is_integer() {
+			return Err(InvalidDateTimeValue);
+		}
+
+		let ns = ns.to_integer().to_u64().ok_or(InvalidDateTimeValue)?;
+		let secs = u32::try_from(ns / NANOSECONDS_PER_SECOND as u64)
+			.ok()
+			.filter(|s| *s < SECONDS_PER_DAY)
+			.ok_or(InvalidDateTimeValue)?;
+		let nanos = (ns % NANOSECONDS_PER_SECOND as u64) as u32;
+
+		let date = days
+			.checked_add(UNIX_EPOCH_DAYS_FROM_CE)
+			.and_then(|d| i32::try_from(d).ok())
+			.and_then(NaiveDate::from_num_days_from_ce_opt)
+			.ok_or(InvalidDateTimeValue)?;
+		let time = NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos)
+			.ok_or(InvalidDateTimeValue)?;
+		let utc = date.and_time(time);
+
+		let date_time = match offset {
+			Some(offset) => utc
+				.checked_add_signed(chrono::Duration::seconds(offset.local_minus_utc().into()))
+				.ok_or(InvalidDateTimeValue)?,
+			None => utc,
+		};
+
+		Ok(Self::new(date_time, offset))
+	}
+
+	/// Adds the given duration to this date/time, following the algorithm
+	/// of XSD 1.1 (Appendix E.3).
+	///
+	/// The months of the duration are added first, pinning the day to the
+	/// last day of the resulting month with [`clamp_day_to_month`] if
+	/// necessary, then the seconds. The timezone is preserved.
+	///
+	/// Returns `None` if the result is out of the supported range.
+	///
+	/// ```
+	/// use xsd_types::{DateTime, Duration};
+	///
+	/// let value: DateTime = "2000-01-31T12:00:00Z".parse().unwrap();
+	/// let duration: Duration = "P1MT13H".parse().unwrap();
+	/// let result = value.checked_add_duration(&duration).unwrap();
+	/// assert_eq!(result.to_string(), "2000-03-01T01:00:00Z");
+	/// ```
+	pub fn checked_add_duration(&self, duration: &Duration) -> Option<Self> {
+		let sign: i64 = if duration.is_negative() { -1 } else { 1 };
+		let months = self.date_time.year() as i64 * 12
+			+ self.date_time.month0() as i64
+			+ sign * i64::from(duration.months());
+
+		let year = i32::try_from(months.div_euclid(12)).ok()?;
+		let month = months.rem_euclid(12) as u8 + 1;
+		let day = clamp_day_to_month(year, month, self.date_time.day() as u8)?;
+		let date = NaiveDate::from_ymd_opt(year, month.into(), day.into())?;
+
+		let delta = chrono::Duration::seconds(duration.seconds().into())
+			+ chrono::Duration::nanoseconds(duration.nano_seconds().into());
+		let delta = if duration.is_negative() {
+			-delta
+		} else {
+			delta
+		};
+		let date_time = date
+			.and_time(self.date_time.time())
+			.checked_add_signed(delta)?;
+
+		Some(Self::new(date_time, self.offset))
+	}
 }
 
 impl PartialEq for DateTime {
@@ -128,53 +528,19 @@ impl fmt::Display for DateTime {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		write!(
 			f,
-			"{}-{:02}-{:02}T{:02}:{:02}:{:02}",
+			"{}-{:02}-{:02}T{:02}:{:02}:",
 			DisplayYear(self.date_time.year()),
 			self.date_time.month(),
 			self.date_time.day(),
 			self.date_time.hour(),
-			self.date_time.minute(),
-			self.date_time.second()
+			self.date_time.minute()
 		)?;
 
-		format_nanoseconds(self.date_time.nanosecond(), f)?;
+		fmt_time_seconds(&self.date_time, f)?;
 		format_timezone(self.offset, f)
 	}
 }
 
-pub(crate) fn format_nanoseconds(ns: u32, f: &mut fmt::Formatter) -> fmt::Result {
-	let mut nano = ns % 1_000_000_000;
-
-	if nano == 0 {
-		Ok(())
-	} else {
-		let mut buffer = *b".000000000";
-		let mut i = 10;
-		let mut trailing = true;
-		let mut end = 10;
-		while nano > 0 {
-			i -= 1;
-			let (rest, d) = div_rem(nano, 10);
-			nano = rest;
-
-			if trailing {
-				if d == 0 {
-					end = i;
-					continue;
-				} else {
-					trailing = false;
-				}
-			}
-
-			buffer[i] = b'0' + d as u8;
-		}
-
-		let string = unsafe { std::str::from_utf8_unchecked(&buffer[..end]) };
-
-		f.write_str(string)
-	}
-}
-
 pub(crate) fn format_timezone(tz: Option<FixedOffset>, f: &mut fmt::Formatter) -> fmt::Result {
 	match tz {
 		Some(tz) => {
@@ -204,14 +570,14 @@ pub(crate) struct DisplayYear(pub i32);
 impl fmt::Display for DisplayYear {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		if self.0.is_negative() {
-			write!(f, "-{:04}", -self.0)
+			write!(f, "-{:04}", self.0.unsigned_abs())
 		} else {
 			write!(f, "{:04}", self.0)
 		}
 	}
 }
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum DateTimeFromStrError {
 	#[error("invalid date syntax")]
 	Syntax(#[from] InvalidDateTime<String>),
@@ -224,8 +590,7 @@ impl FromStr for DateTime {
 	type Err = DateTimeFromStrError;
 
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
-		let lexical_value = crate::lexical::DateTime::new(s)
-			.map_err(|InvalidDateTime(s)| InvalidDateTime(s.to_owned()))?;
+		let lexical_value = <crate::lexical::DateTime as Lexical>::parse(s)?;
 		lexical_value.try_as_value().map_err(Into::into)
 	}
 }
@@ -340,6 +705,160 @@ impl<'de> serde::Deserialize<'de> for DateTime {
 
 #[cfg(test)]
 mod tests {
+	#[test]
+	fn utc_normalization() {
+		use super::DateTime;
+		use std::collections::HashSet;
+
+		let a = DateTime::parse_normalized("2024-05-17T12:00:00+02:00").unwrap();
+		let b = DateTime::parse_normalized("2024-05-17T05:00:00-05:00").unwrap();
+		assert_eq!((a.date_time, a.offset), (b.date_time, b.offset));
+		assert_eq!(HashSet::from([a, b]).len(), 1);
+
+		let local = DateTime::parse_normalized("2024-05-17T12:00:00").unwrap();
+		assert_eq!(local.to_string(), "2024-05-17T12:00:00");
+
+		let (value, offset) =
+			DateTime::parse_normalized_with_offset("2024-12-31T23:30:00-01:00").unwrap();
+		assert_eq!(value.to_string(), "2025-01-01T00:30:00Z");
+		assert_eq!(
+			value.to_offset(offset).unwrap().to_string(),
+			"2024-12-31T23:30:00-01:00"
+		);
+	}
+
+	#[test]
+	fn utc_out_of_range() {
+		use super::DateTime;
+
+		let value: DateTime = "-262143-01-01T01:00:00+14:00".parse().unwrap();
+		assert!(value.to_utc().is_err());
+		assert!(DateTime::parse_normalized("-262143-01-01T01:00:00+14:00").is_err());
+
+		let (days, seconds, offset) = value.to_epoch_parts();
+		assert_eq!(seconds.to_string(), "39600");
+		assert_eq!(
+			DateTime::from_epoch_parts(days + 1, seconds, offset)
+				.unwrap()
+				.to_string(),
+			"-262143-01-02T01:00:00+14:00"
+		);
+	}
+
+	#[test]
+	fn truncate_to_millis() {
+		use super::DateTime;
+		use chrono::Timelike;
+
+		let value: DateTime = "2024-01-01T00:00:00.1234Z".parse().unwrap();
+		let (truncated, lost) = value.truncate_to_millis();
+		assert_eq!(truncated.date_time.nanosecond(), 123_000_000);
+		assert!(lost);
+
+		let (_, lost) = truncated.truncate_to_millis();
+		assert!(!lost)
+	}
+
+	#[test]
+	fn set_components() {
+		use super::DateTime;
+		use crate::InvalidComponent;
+		use chrono::FixedOffset;
+
+		let value: DateTime = "2024-02-29T10:30:15.5+01:00".parse().unwrap();
+		assert_eq!(
+			value.with_month(3).unwrap().to_string(),
+			"2024-03-29T10:30:15.5+01:00"
+		);
+		assert_eq!(value.with_year(2023), Err(InvalidComponent::Day(29)));
+		assert_eq!(value.with_month(13), Err(InvalidComponent::Month(13)));
+		assert_eq!(value.with_hour(24), Err(InvalidComponent::Hour(24)));
+		assert_eq!(
+			value.with_second(0).unwrap().to_string(),
+			"2024-02-29T10:30:00.5+01:00"
+		);
+
+		assert_eq!(
+			value.with_timezone(None).unwrap().to_string(),
+			"2024-02-29T10:30:15.5"
+		);
+		let offset = FixedOffset::east_opt(15 * 60 * 60).unwrap();
+		assert_eq!(
+			value.with_timezone(Some(offset)),
+			Err(InvalidComponent::Timezone(offset))
+		);
+	}
+
+	#[test]
+	fn naive_accessors() {
+		use super::DateTime;
+
+		let value: DateTime = "2024-03-01T12:30:00+02:00".parse().unwrap();
+		assert_eq!(
+			value.naive().format("%d/%m/%Y %H:%M").to_string(),
+			"01/03/2024 12:30"
+		);
+		assert_eq!(value.offset().unwrap().local_minus_utc(), 7200);
+
+		let value: DateTime = "2024-03-01T12:30:00".parse().unwrap();
+		assert!(value.offset().is_none())
+	}
+
+	#[test]
+	fn epoch_parts() {
+		use super::DateTime;
+		use crate::Decimal;
+
+		for input in [
+			"2024-02-29T23:59:59.123456789-05:00",
+			"1969-12-31T23:00:00Z",
+			"-0044-03-15T12:00:00",
+		] {
+			let value: DateTime = input.parse().unwrap();
+			let (days, seconds, offset) = value.to_epoch_parts();
+			let back = DateTime::from_epoch_parts(days, seconds, offset).unwrap();
+			assert_eq!(back.to_string(), value.to_string())
+		}
+
+		let (days, seconds, _) = "1969-12-31T23:00:00Z"
+			.parse::<DateTime>()
+			.unwrap()
+			.to_epoch_parts();
+		assert_eq!((days, seconds.to_string().as_str()), (-1, "82800"));
+
+		let seconds: Decimal = "86400".parse().unwrap();
+		assert!(DateTime::from_epoch_parts(0, seconds, None).is_err());
+		let seconds: Decimal = "0.0000000001".parse().unwrap();
+		assert!(DateTime::from_epoch_parts(0, seconds, None).is_err())
+	}
+
+	#[test]
+	fn truncation() {
+		use super::DateTime;
+
+		let value: DateTime = "-0044-03-15T12:00:00Z".parse().unwrap();
+		assert_eq!(value.date().to_string(), "-0044-03-15Z");
+		assert_eq!(value.year_month().to_string(), "-0044-03Z");
+		assert_eq!(value.gyear().to_string(), "-0044Z");
+		assert_eq!(value.gmonth_day().to_string(), "--03-15Z");
+
+		let value: DateTime = "2024-12-31T23:59:59".parse().unwrap();
+		assert_eq!(value.year_month().to_string(), "2024-12")
+	}
+
+	#[test]
+	fn display_min_year() {
+		use crate::{GYear, GYearMonth, ParseXsd};
+
+		assert_eq!(super::DisplayYear(i32::MIN).to_string(), "-2147483648");
+
+		let year = GYear::parse_xsd("-2147483648").unwrap();
+		assert_eq!(year.to_string(), "-2147483648");
+
+		let year_month = GYearMonth::parse_xsd("-2147483648-01Z").unwrap();
+		assert_eq!(year_month.to_string(), "-2147483648-01Z")
+	}
+
 	#[cfg(feature = "time")]
 	#[test]
 	fn chrono_time_roundtrip() {
@@ -1,12 +1,13 @@
 use std::{
 	borrow::Borrow,
 	fmt,
-	ops::{Add, Div, Mul, Sub},
+	ops::{Add, Div, Mul, Neg, RangeInclusive, Rem, Sub},
 	str::FromStr,
 };
 
 use num_bigint::{BigInt, TryFromBigIntError};
-use num_traits::{Signed, Zero};
+use num_integer::Integer as _;
+use num_traits::{FromPrimitive, Num, One, Signed, ToPrimitive, Zero};
 
 use crate::{
 	lexical::{self, LexicalFormOf},
@@ -15,28 +16,29 @@ use crate::{
 	UnsignedShortDatatype, XsdValue,
 };
 
-use super::{Sign, I16_MIN, I32_MIN, I64_MIN, I8_MIN, U16_MAX, U32_MAX, U64_MAX, U8_MAX};
+use super::{
+	integer_lexical_bytes, Sign, I16_MIN, I32_MIN, I64_MIN, I8_MIN, U16_MAX, U32_MAX, U64_MAX,
+	U8_MAX,
+};
 
+mod bounded_int;
 mod non_negative_integer;
 mod non_positive_integer;
 
+pub use bounded_int::*;
 pub use non_negative_integer::*;
 pub use non_positive_integer::*;
 
 /// Integer number.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "forbid-unsafe", derive(ref_cast::RefCastCustom))]
 #[repr(transparent)]
 pub struct Integer(BigInt);
 
 impl Integer {
-	/// Converts a `BigInt` reference into an `Integer` reference.
-	#[inline(always)]
-	pub fn from_bigint_ref(n: &BigInt) -> &Self {
-		unsafe {
-			// This is safe because `Integer` is a transparent wrapper around
-			// `BigInt`.
-			std::mem::transmute(n)
-		}
+	transparent_cast! {
+		/// Converts a `BigInt` reference into an `Integer` reference.
+		pub const fn from_bigint_ref(n: &BigInt) -> &Self;
 	}
 
 	/// Converts a `BigInt` into an `Integer`.
@@ -114,11 +116,44 @@ impl Integer {
 	/// Returns a lexical representation of this integer.
 	#[inline(always)]
 	pub fn lexical_representation(&self) -> lexical::IntegerBuf {
-		unsafe {
-			// This is safe because the `Display::fmt` method matches the
-			// XSD lexical representation.
-			lexical::IntegerBuf::new_unchecked(format!("{}", self))
+		// This is safe because `integer_lexical_bytes` returns the
+		// canonical XSD lexical representation.
+		unchecked!(lexical::IntegerBuf, integer_lexical_bytes(&self.0))
+	}
+
+	/// Parses an integer from its representation in the given radix.
+	///
+	/// Digits greater than 9 are represented by ASCII letters (case
+	/// insensitive). The input may start with a `+` or `-` sign, but must
+	/// not include any radix prefix (such as `0x`) nor digit separator (such
+	/// as `_`).
+	///
+	/// This is not an XSD lexical form: the only lexical representation of
+	/// integers is the decimal one.
+	///
+	/// # Panics
+	///
+	/// Panics if `radix` is not in the range `2..=36`.
+	pub fn from_radix(value: &str, radix: u32) -> Result<Self, InvalidRadixInteger> {
+		assert!((2..=36).contains(&radix), "invalid radix {radix}");
+		if value.contains('_') {
+			// `BigInt::from_str_radix` accepts underscores between digits.
+			return Err(InvalidRadixInteger::new(value, radix));
 		}
+
+		BigInt::from_str_radix(value, radix)
+			.map(Self)
+			.map_err(|_| InvalidRadixInteger::new(value, radix))
+	}
+
+	/// Returns the representation of this integer in the given radix, using
+	/// lowercase ASCII letters for digits greater than 9.
+	///
+	/// # Panics
+	///
+	/// Panics if `radix` is not in the range `2..=36`.
+	pub fn to_radix_string(&self, radix: u32) -> String {
+		self.0.to_str_radix(radix)
 	}
 
 	pub fn to_bytes_be(&self) -> (Sign, Vec<u8>) {
@@ -136,6 +171,62 @@ impl Integer {
 	pub fn to_signed_bytes_le(&self) -> Vec<u8> {
 		self.0.to_signed_bytes_le()
 	}
+
+	/// Returns a reference to the underlying `BigInt`.
+	#[inline(always)]
+	pub fn as_bigint(&self) -> &BigInt {
+		&self.0
+	}
+
+	/// Applies the given function to the underlying `BigInt`.
+	pub fn map_bigint(self, f: impl FnOnce(BigInt) -> BigInt) -> Self {
+		Self(f(self.0))
+	}
+
+	/// Raises this integer to the power of `exp`.
+	pub fn pow(&self, exp: u32) -> Self {
+		Self(self.0.pow(exp))
+	}
+
+	/// Returns the greatest common divisor of this integer and `other`.
+	///
+	/// The result is always non negative, and is zero only if both integers
+	/// are zero.
+	pub fn gcd(&self, other: &Self) -> NonNegativeInteger {
+		// SAFETY: the GCD is non negative.
+		unchecked!(NonNegativeInteger, self.0.gcd(&other.0))
+	}
+
+	/// Returns the least common multiple of this integer and `other`.
+	///
+	/// The result is always non negative, and is zero if one of the integers
+	/// is zero.
+	pub fn lcm(&self, other: &Self) -> NonNegativeInteger {
+		// SAFETY: the LCM is non negative.
+		unchecked!(NonNegativeInteger, self.0.lcm(&other.0))
+	}
+
+	/// Checks if this integer divides `other`, meaning that `other` is a
+	/// multiple of this integer.
+	///
+	/// Zero only divides zero.
+	pub fn divides(&self, other: &Self) -> bool {
+		if self.0.is_zero() {
+			other.0.is_zero()
+		} else {
+			other.0.is_multiple_of(&self.0)
+		}
+	}
+
+	/// Computes the remainder of the floored division of this integer by
+	/// `other`, which has the same sign as `other`.
+	///
+	/// # Panics
+	///
+	/// Panics if `other` is zero.
+	pub fn mod_floor(&self, other: &Self) -> Self {
+		Self(self.0.mod_floor(&other.0))
+	}
 }
 
 impl XsdValue for Integer {
@@ -229,10 +320,67 @@ impl Borrow<BigInt> for Integer {
 	}
 }
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 #[error("integer out of supported bounds: {0}")]
 pub struct IntegerOutOfTargetBounds(pub Integer);
 
+/// Error raised when parsing an integer from an invalid radix representation.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid base {radix} integer `{value}`")]
+pub struct InvalidRadixInteger {
+	pub value: String,
+	pub radix: u32,
+}
+
+impl InvalidRadixInteger {
+	pub(crate) fn new(value: &str, radix: u32) -> Self {
+		Self {
+			value: value.to_owned(),
+			radix,
+		}
+	}
+}
+
+/// Error returned when an integer is outside of the value space of a bounded
+/// integer datatype (such as `xsd:byte`).
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("integer {value} is out of <{}> range ({}..={})", .datatype.iri(), .range.start(), .range.end())]
+pub struct IntegerOutOfRange {
+	value: Integer,
+	datatype: Datatype,
+	range: RangeInclusive<i128>,
+}
+
+impl IntegerOutOfRange {
+	pub(crate) fn new(value: Integer, datatype: Datatype, min: i128, max: i128) -> Self {
+		Self {
+			value,
+			datatype,
+			range: min..=max,
+		}
+	}
+
+	/// Returns the out of range integer.
+	pub fn value(&self) -> &Integer {
+		&self.value
+	}
+
+	/// Returns the out of range integer.
+	pub fn into_value(self) -> Integer {
+		self.value
+	}
+
+	/// Returns the bounded integer datatype.
+	pub fn datatype(&self) -> Datatype {
+		self.datatype
+	}
+
+	/// Returns the range of values allowed by the datatype.
+	pub fn expected_range(&self) -> RangeInclusive<i128> {
+		self.range.clone()
+	}
+}
+
 macro_rules! from {
 	{ $( $ty:ty ),* } => {
 		$(
@@ -302,10 +450,19 @@ impl ParseXsd for Long {
 }
 
 impl LexicalFormOf<Long> for lexical::Integer {
-	type ValueError = IntegerOutOfTargetBounds;
+	type ValueError = IntegerOutOfRange;
 
 	fn try_as_value(&self) -> Result<Long, Self::ValueError> {
-		self.value().try_into()
+		self.value()
+			.try_into()
+			.map_err(|e: IntegerOutOfTargetBounds| {
+				IntegerOutOfRange::new(
+					e.0,
+					LongDatatype::Long.into(),
+					Long::MIN as i128,
+					Long::MAX as i128,
+				)
+			})
 	}
 }
 
@@ -338,10 +495,19 @@ impl ParseXsd for Int {
 }
 
 impl LexicalFormOf<Int> for lexical::Integer {
-	type ValueError = IntegerOutOfTargetBounds;
+	type ValueError = IntegerOutOfRange;
 
 	fn try_as_value(&self) -> Result<Int, Self::ValueError> {
-		self.value().try_into()
+		self.value()
+			.try_into()
+			.map_err(|e: IntegerOutOfTargetBounds| {
+				IntegerOutOfRange::new(
+					e.0,
+					IntDatatype::Int.into(),
+					Int::MIN as i128,
+					Int::MAX as i128,
+				)
+			})
 	}
 }
 
@@ -372,10 +538,19 @@ impl ParseXsd for Short {
 }
 
 impl LexicalFormOf<Short> for lexical::Integer {
-	type ValueError = IntegerOutOfTargetBounds;
+	type ValueError = IntegerOutOfRange;
 
 	fn try_as_value(&self) -> Result<Short, Self::ValueError> {
-		self.value().try_into()
+		self.value()
+			.try_into()
+			.map_err(|e: IntegerOutOfTargetBounds| {
+				IntegerOutOfRange::new(
+					e.0,
+					ShortDatatype::Short.into(),
+					Short::MIN as i128,
+					Short::MAX as i128,
+				)
+			})
 	}
 }
 
@@ -392,10 +567,19 @@ impl ParseXsd for Byte {
 }
 
 impl LexicalFormOf<Byte> for lexical::Integer {
-	type ValueError = IntegerOutOfTargetBounds;
+	type ValueError = IntegerOutOfRange;
 
 	fn try_as_value(&self) -> Result<Byte, Self::ValueError> {
-		self.value().try_into()
+		self.value()
+			.try_into()
+			.map_err(|e: IntegerOutOfTargetBounds| {
+				IntegerOutOfRange::new(
+					e.0,
+					ShortDatatype::Byte.into(),
+					Byte::MIN as i128,
+					Byte::MAX as i128,
+				)
+			})
 	}
 }
 
@@ -420,6 +604,9 @@ macro_rules! impl_integer_arithmetic {
 				}
 			}
 
+			/// Panics if the difference is not in the value space of the
+			/// target type, as when subtracting a greater number from a non
+			/// negative integer.
 			impl Sub<$ty> for $target {
 				type Output = Self;
 
@@ -472,3 +659,271 @@ impl_integer_arithmetic! {
 		Integer [.0], i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
 	}
 }
+
+impl Rem for Integer {
+	type Output = Self;
+
+	fn rem(self, rhs: Self) -> Self::Output {
+		Self(self.0 % rhs.0)
+	}
+}
+
+impl Neg for Integer {
+	type Output = Self;
+
+	fn neg(self) -> Self::Output {
+		Self(-self.0)
+	}
+}
+
+impl Zero for Integer {
+	fn zero() -> Self {
+		Self(BigInt::zero())
+	}
+
+	fn is_zero(&self) -> bool {
+		self.0.is_zero()
+	}
+}
+
+impl One for Integer {
+	fn one() -> Self {
+		Self(BigInt::one())
+	}
+}
+
+impl Num for Integer {
+	type FromStrRadixErr = InvalidRadixInteger;
+
+	fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+		Self::from_radix(str, radix)
+	}
+}
+
+impl Signed for Integer {
+	fn abs(&self) -> Self {
+		Self(self.0.abs())
+	}
+
+	fn abs_sub(&self, other: &Self) -> Self {
+		Self(self.0.abs_sub(&other.0))
+	}
+
+	fn signum(&self) -> Self {
+		Self(self.0.signum())
+	}
+
+	fn is_positive(&self) -> bool {
+		self.0.is_positive()
+	}
+
+	fn is_negative(&self) -> bool {
+		self.0.is_negative()
+	}
+}
+
+impl ToPrimitive for Integer {
+	fn to_i64(&self) -> Option<i64> {
+		self.0.to_i64()
+	}
+
+	fn to_u64(&self) -> Option<u64> {
+		self.0.to_u64()
+	}
+
+	fn to_i128(&self) -> Option<i128> {
+		self.0.to_i128()
+	}
+
+	fn to_u128(&self) -> Option<u128> {
+		self.0.to_u128()
+	}
+
+	fn to_f64(&self) -> Option<f64> {
+		self.0.to_f64()
+	}
+}
+
+impl FromPrimitive for Integer {
+	fn from_i64(n: i64) -> Option<Self> {
+		Some(Self(n.into()))
+	}
+
+	fn from_u64(n: u64) -> Option<Self> {
+		Some(Self(n.into()))
+	}
+
+	fn from_i128(n: i128) -> Option<Self> {
+		Some(Self(n.into()))
+	}
+
+	fn from_u128(n: u128) -> Option<Self> {
+		Some(Self(n.into()))
+	}
+
+	/// Truncates the given float toward zero, returning `None` if it is not
+	/// finite.
+	fn from_f64(n: f64) -> Option<Self> {
+		BigInt::from_f64(n).map(Self)
+	}
+}
+
+/// Implements [`Sum`](std::iter::Sum) and [`Product`](std::iter::Product)
+/// for integer types closed under addition and multiplication.
+macro_rules! impl_integer_sum {
+	{ $( $target:ty ),* } => {
+		$(
+			impl std::iter::Sum for $target {
+				fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+					Self(iter.map(|i| i.0).sum())
+				}
+			}
+
+			impl<'a> std::iter::Sum<&'a $target> for $target {
+				fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+					Self(iter.map(|i| &i.0).sum())
+				}
+			}
+
+			impl std::iter::Product for $target {
+				fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+					Self(iter.map(|i| i.0).product())
+				}
+			}
+
+			impl<'a> std::iter::Product<&'a $target> for $target {
+				fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+					Self(iter.map(|i| &i.0).product())
+				}
+			}
+		)*
+	};
+}
+
+pub(crate) use impl_integer_sum;
+
+impl_integer_sum!(Integer);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn radix() {
+		let n = Integer::from_radix("-ff", 16).unwrap();
+		assert_eq!(n, Integer::from(-255i32));
+		assert_eq!(n.to_radix_string(2), "-11111111");
+		assert_eq!(Integer::from(8i32).to_radix_string(8), "10");
+		assert!(Integer::from_radix("0x1", 16).is_err());
+		assert!(Integer::from_radix("12", 2).is_err());
+		assert!(Integer::from_radix("1_000", 10).is_err());
+
+		let buf = lexical::IntegerBuf::from_radix("+1F", 16).unwrap();
+		assert_eq!(buf.as_str(), "31");
+
+		let id = NonNegativeInteger::from_hex("DEADBEEF").unwrap();
+		assert_eq!(id.to_string(), "3735928559");
+		assert!(NonNegativeInteger::from_hex("-1").is_err());
+		assert!(NonNegativeInteger::from_hex("dead_beef").is_err())
+	}
+
+	#[test]
+	fn lexical_round_trip() {
+		for (input, canonical) in [
+			("0", "0"),
+			("-0", "0"),
+			("+007", "7"),
+			("-120", "-120"),
+			(
+				"123456789012345678901234567890",
+				"123456789012345678901234567890",
+			),
+		] {
+			let value = lexical::Integer::new(input).unwrap().value();
+			assert_eq!(value.lexical_representation().as_str(), canonical);
+			assert_eq!(value.to_string(), canonical)
+		}
+
+		let n = lexical::NonPositiveInteger::new("-0042").unwrap().value();
+		assert_eq!(n.lexical_representation().as_str(), "-42");
+
+		let n = lexical::NonNegativeInteger::new("+0").unwrap().value();
+		assert_eq!(n.lexical_representation().as_str(), "0")
+	}
+
+	#[test]
+	fn number_theory() {
+		let a = Integer::from(-12i32);
+		let b = Integer::from(18i32);
+		assert_eq!(a.gcd(&b).to_string(), "6");
+		assert_eq!(a.lcm(&b).to_string(), "36");
+		assert!(Integer::from(6i32).divides(&b));
+		assert!(!b.divides(&a));
+		assert!(Integer::zero().divides(&Integer::zero()));
+		assert!(!Integer::zero().divides(&a));
+		assert_eq!(a.mod_floor(&Integer::from(5i32)), Integer::from(3i32));
+		assert_eq!(a.pow(3), Integer::from(-1728i32));
+
+		let n = NonNegativeInteger::from(2u32).pow(100);
+		assert_eq!(n.to_string(), "1267650600228229401496703205376");
+		assert!(NonNegativeInteger::from(3u32)
+			.map_bigint(|n| n - 4)
+			.is_err());
+
+		let n = lexical::NegativeInteger::new("-2").unwrap().value();
+		assert_eq!(n.checked_pow(3).unwrap().to_string(), "-8");
+		assert!(n.checked_pow(2).is_none());
+	}
+
+	#[test]
+	fn checked_assign() {
+		let mut counter = NonNegativeInteger::zero();
+		counter.add_assign_checked(1).unwrap();
+		counter.add_assign_checked(1u64).unwrap();
+		assert_eq!(counter.to_string(), "2");
+		assert!(counter.sub_assign_checked(3).is_err());
+		assert_eq!(counter.to_string(), "2");
+
+		let mut n = lexical::PositiveInteger::new("1").unwrap().value();
+		assert!(n.sub_assign_checked(1).is_err());
+		assert!(n.add_assign_checked(-1).is_err());
+		assert!(n.is_one());
+	}
+
+	#[test]
+	fn constrained_conversions() {
+		let zero = Integer::zero();
+		let one = Integer::from(1i32);
+		let minus_one = Integer::from(-1i32);
+
+		assert_eq!(
+			PositiveInteger::try_from(one.clone()).unwrap().to_string(),
+			"1"
+		);
+		assert_eq!(
+			PositiveInteger::try_from(zero.clone()),
+			Err(IntegerIsNotPositive(zero.clone()))
+		);
+		assert_eq!(
+			NegativeInteger::try_from(minus_one.clone())
+				.unwrap()
+				.to_string(),
+			"-1"
+		);
+		assert_eq!(
+			NegativeInteger::try_from(zero.clone()),
+			Err(IntegerIsNotNegative(zero.clone()))
+		);
+		assert!(NonPositiveInteger::try_from(zero.clone()).is_ok());
+		let e = NonPositiveInteger::try_from(one.clone()).unwrap_err();
+		assert_eq!(e.0, one);
+		assert_eq!(e.to_string(), "integer 1 is positive");
+
+		let d: crate::Decimal = "42.0".parse().unwrap();
+		assert_eq!(NonNegativeInteger::try_from(d).unwrap().to_string(), "42");
+		for input in ["1.5", "-3"] {
+			let d: crate::Decimal = input.parse().unwrap();
+			assert_eq!(NonNegativeInteger::try_from(d.clone()).unwrap_err().0, d)
+		}
+	}
+}
@@ -0,0 +1,197 @@
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+use crate::{
+	lexical::{self, LexicalFormOf},
+	Datatype, IntDatatype, LongDatatype, ParseXsd, ParseXsdError, ShortDatatype,
+	UnsignedIntDatatype, UnsignedLongDatatype, UnsignedShortDatatype, XsdValue,
+};
+
+use super::{Integer, IntegerOutOfRange};
+
+/// Integer restricted to the `MIN..=MAX` range.
+///
+/// This represents a datatype derived from a built-in integer datatype with
+/// the `minInclusive` and `maxInclusive` facets, such as a percentage:
+///
+/// ```
+/// use xsd_types::BoundedInt;
+///
+/// type Percentage = BoundedInt<0, 100>;
+///
+/// let p: Percentage = "42".parse().unwrap();
+/// assert_eq!(p.get(), 42);
+/// assert!("101".parse::<Percentage>().is_err());
+/// ```
+///
+/// The bounds are checked at compile time: `MIN` must not be greater than
+/// `MAX`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedInt<const MIN: i64, const MAX: i64>(i64);
+
+impl<const MIN: i64, const MAX: i64> BoundedInt<MIN, MAX> {
+	const VALID_RANGE: () = assert!(MIN <= MAX, "empty bounded integer range");
+
+	/// Creates a new bounded integer, if `value` is in range.
+	pub fn new(value: i64) -> Result<Self, IntegerOutOfRange> {
+		let () = Self::VALID_RANGE;
+
+		if Self::range().contains(&value) {
+			Ok(Self(value))
+		} else {
+			Err(Self::out_of_range(value.into()))
+		}
+	}
+
+	/// Returns the allowed range of values, `MIN..=MAX`.
+	pub fn range() -> RangeInclusive<i64> {
+		MIN..=MAX
+	}
+
+	/// Returns the facets restricting the base datatype.
+	pub fn facets() -> IntegerFacets {
+		IntegerFacets {
+			base: Self::base_datatype(),
+			min_inclusive: MIN,
+			max_inclusive: MAX,
+		}
+	}
+
+	/// Returns the smallest built-in integer datatype containing the
+	/// `MIN..=MAX` range.
+	///
+	/// Unsigned datatypes are preferred when `MIN` is not negative.
+	pub fn base_datatype() -> Datatype {
+		let fits = |min: i64, max: i64| min <= MIN && MAX <= max;
+
+		if MIN >= 0 {
+			if fits(0, u8::MAX as i64) {
+				UnsignedShortDatatype::UnsignedByte.into()
+			} else if fits(0, u16::MAX as i64) {
+				UnsignedShortDatatype::UnsignedShort.into()
+			} else if fits(0, u32::MAX as i64) {
+				UnsignedIntDatatype::UnsignedInt.into()
+			} else {
+				UnsignedLongDatatype::UnsignedLong.into()
+			}
+		} else if fits(i8::MIN as i64, i8::MAX as i64) {
+			ShortDatatype::Byte.into()
+		} else if fits(i16::MIN as i64, i16::MAX as i64) {
+			ShortDatatype::Short.into()
+		} else if fits(i32::MIN as i64, i32::MAX as i64) {
+			IntDatatype::Int.into()
+		} else {
+			LongDatatype::Long.into()
+		}
+	}
+
+	/// Returns the value.
+	pub fn get(&self) -> i64 {
+		self.0
+	}
+
+	fn out_of_range(value: Integer) -> IntegerOutOfRange {
+		IntegerOutOfRange::new(value, Self::base_datatype(), MIN as i128, MAX as i128)
+	}
+}
+
+/// Facets of an integer datatype restricted to a range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntegerFacets {
+	/// Built-in datatype the restriction applies to.
+	pub base: Datatype,
+
+	/// Value of the `minInclusive` facet.
+	pub min_inclusive: i64,
+
+	/// Value of the `maxInclusive` facet.
+	pub max_inclusive: i64,
+}
+
+impl<const MIN: i64, const MAX: i64> XsdValue for BoundedInt<MIN, MAX> {
+	fn datatype(&self) -> Datatype {
+		Self::base_datatype()
+	}
+}
+
+impl<const MIN: i64, const MAX: i64> ParseXsd for BoundedInt<MIN, MAX> {
+	type LexicalForm = lexical::Integer;
+}
+
+impl<const MIN: i64, const MAX: i64> LexicalFormOf<BoundedInt<MIN, MAX>> for lexical::Integer {
+	type ValueError = IntegerOutOfRange;
+
+	fn try_as_value(&self) -> Result<BoundedInt<MIN, MAX>, Self::ValueError> {
+		let value = self.value();
+		match i64::try_from(&value) {
+			Ok(i) => BoundedInt::new(i),
+			Err(_) => Err(BoundedInt::<MIN, MAX>::out_of_range(value)),
+		}
+	}
+}
+
+impl<const MIN: i64, const MAX: i64> FromStr for BoundedInt<MIN, MAX> {
+	type Err = ParseXsdError<lexical::InvalidInteger, IntegerOutOfRange>;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::parse_xsd(s)
+	}
+}
+
+impl<const MIN: i64, const MAX: i64> fmt::Display for BoundedInt<MIN, MAX> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl<const MIN: i64, const MAX: i64> TryFrom<i64> for BoundedInt<MIN, MAX> {
+	type Error = IntegerOutOfRange;
+
+	fn try_from(value: i64) -> Result<Self, Self::Error> {
+		Self::new(value)
+	}
+}
+
+impl<const MIN: i64, const MAX: i64> From<BoundedInt<MIN, MAX>> for i64 {
+	fn from(value: BoundedInt<MIN, MAX>) -> Self {
+		value.0
+	}
+}
+
+impl<const MIN: i64, const MAX: i64> From<BoundedInt<MIN, MAX>> for Integer {
+	fn from(value: BoundedInt<MIN, MAX>) -> Self {
+		value.0.into()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{XSD_INT, XSD_UNSIGNED_BYTE};
+
+	#[test]
+	fn bounds() {
+		type Percentage = BoundedInt<0, 100>;
+		assert_eq!(Percentage::new(100).unwrap().get(), 100);
+		assert!(Percentage::new(-1).is_err());
+		assert_eq!(
+			Percentage::facets(),
+			IntegerFacets {
+				base: Datatype::from_iri(XSD_UNSIGNED_BYTE).unwrap(),
+				min_inclusive: 0,
+				max_inclusive: 100
+			}
+		);
+
+		type Offset = BoundedInt<-50_000, 50_000>;
+		assert_eq!(
+			Offset::base_datatype(),
+			Datatype::from_iri(XSD_INT).unwrap()
+		);
+
+		match "99999999999999999999".parse::<Offset>() {
+			Err(ParseXsdError::InvalidValue(e)) => assert_eq!(e.expected_range(), -50_000..=50_000),
+			_ => panic!("expected a range error"),
+		}
+		assert_eq!("-0042".parse::<Offset>().unwrap().to_string(), "-42")
+	}
+}
@@ -1,19 +1,20 @@
 use std::{
 	borrow::Borrow,
 	fmt,
-	ops::{Add, Div, Mul, Sub},
+	ops::{Add, Div, Mul, Rem, Sub},
 	str::FromStr,
 };
 
 use num_bigint::{BigInt, TryFromBigIntError};
-use num_traits::{Signed, Zero};
+use num_integer::Integer as _;
+use num_traits::{FromPrimitive, Num, One, Signed, ToPrimitive, Zero};
 
 use crate::{
-	impl_integer_arithmetic,
+	impl_integer_arithmetic, impl_integer_sum,
 	lexical::{self, LexicalFormOf},
-	value::decimal::{U16_MAX, U32_MAX, U64_MAX, U8_MAX},
-	Datatype, Integer, NonNegativeIntegerDatatype, ParseXsd, UnsignedIntDatatype,
-	UnsignedLongDatatype, UnsignedShortDatatype, XsdValue,
+	value::decimal::{integer_lexical_bytes, U16_MAX, U32_MAX, U64_MAX, U8_MAX},
+	Datatype, Decimal, Integer, IntegerOutOfRange, InvalidRadixInteger, NonNegativeIntegerDatatype,
+	ParseXsd, UnsignedIntDatatype, UnsignedLongDatatype, UnsignedShortDatatype, XsdValue,
 };
 
 use super::Sign;
@@ -22,13 +23,25 @@ use super::Sign;
 pub struct NonNegativeInteger(BigInt);
 
 impl NonNegativeInteger {
-	/// Create a new non negative integer from a `BigInt`.
-	///
-	/// # Safety
-	///
-	/// The input number must be non negative.
-	pub unsafe fn new_unchecked(n: BigInt) -> Self {
-		Self(n)
+	/// Creates a new non negative integer from a `BigInt`, if it is non
+	/// negative.
+	pub fn new(n: BigInt) -> Result<Self, IntegerIsNegative> {
+		if n.is_negative() {
+			Err(IntegerIsNegative(n.into()))
+		} else {
+			Ok(Self(n))
+		}
+	}
+
+	unchecked_constructor! {
+		/// Create a new non negative integer from a `BigInt`.
+		///
+		/// # Safety
+		///
+		/// The input number must be non negative.
+		pub unsafe fn new_unchecked(n: BigInt) -> Self {
+			Self(n)
+		}
 	}
 
 	/// Creates a non negative integer from its unsigned big endian bytes
@@ -43,24 +56,40 @@ impl NonNegativeInteger {
 		Self(BigInt::from_bytes_le(Sign::Plus, bytes))
 	}
 
-	/// Creates a non negative integer from its signed big endian bytes
-	/// representation.
-	///
-	/// # Safety
-	///
-	/// The represented number must be non negative.
-	pub unsafe fn from_signed_bytes_be_unchecked(bytes: &[u8]) -> Self {
-		Self(BigInt::from_signed_bytes_be(bytes))
+	unchecked_constructor! {
+		/// Creates a non negative integer from its signed big endian bytes
+		/// representation.
+		///
+		/// # Safety
+		///
+		/// The represented number must be non negative.
+		pub unsafe fn from_signed_bytes_be_unchecked(bytes: &[u8]) -> Self {
+			Self(BigInt::from_signed_bytes_be(bytes))
+		}
 	}
 
-	/// Creates a non negative integer from its signed little endian bytes
-	/// representation.
-	///
-	/// # Safety
+	unchecked_constructor! {
+		/// Creates a non negative integer from its signed little endian bytes
+		/// representation.
+		///
+		/// # Safety
+		///
+		/// The represented number must be non negative.
+		pub unsafe fn from_signed_bytes_le_unchecked(bytes: &[u8]) -> Self {
+			Self(BigInt::from_signed_bytes_le(bytes))
+		}
+	}
+
+	/// Parses a non negative integer from its hexadecimal representation.
 	///
-	/// The represented number must be non negative.
-	pub unsafe fn from_signed_bytes_le_unchecked(bytes: &[u8]) -> Self {
-		Self(BigInt::from_signed_bytes_le(bytes))
+	/// Both uppercase and lowercase digits are accepted, but no sign, prefix
+	/// (such as `0x`) nor digit separator (such as `_`).
+	pub fn from_hex(value: &str) -> Result<Self, InvalidRadixInteger> {
+		if value.starts_with(['+', '-']) {
+			return Err(InvalidRadixInteger::new(value, 16));
+		}
+
+		Integer::from_radix(value, 16).map(|n| Self(n.into()))
 	}
 
 	pub fn from_signed_bytes_be(bytes: &[u8]) -> Result<Self, IntegerIsNegative> {
@@ -76,6 +105,87 @@ impl NonNegativeInteger {
 		self.0
 	}
 
+	/// Returns a reference to the underlying `BigInt`.
+	#[inline(always)]
+	pub fn as_bigint(&self) -> &BigInt {
+		&self.0
+	}
+
+	/// Applies the given function to the underlying `BigInt`, failing if the
+	/// result is negative.
+	pub fn map_bigint(self, f: impl FnOnce(BigInt) -> BigInt) -> Result<Self, IntegerIsNegative> {
+		Integer::from_bigint(f(self.0)).try_into()
+	}
+
+	/// Raises this integer to the power of `exp`.
+	pub fn pow(&self, exp: u32) -> Self {
+		Self(self.0.pow(exp))
+	}
+
+	/// Returns the greatest common divisor of this integer and `other`.
+	pub fn gcd(&self, other: &Self) -> Self {
+		Self(self.0.gcd(&other.0))
+	}
+
+	/// Returns the least common multiple of this integer and `other`.
+	pub fn lcm(&self, other: &Self) -> Self {
+		Self(self.0.lcm(&other.0))
+	}
+
+	/// Checks if this integer divides `other`, meaning that `other` is a
+	/// multiple of this integer.
+	///
+	/// Zero only divides zero.
+	pub fn divides(&self, other: &Self) -> bool {
+		Integer::from_bigint_ref(&self.0).divides(Integer::from_bigint_ref(&other.0))
+	}
+
+	/// Computes the remainder of the division of this integer by `other`.
+	///
+	/// Since both integers are non negative, this is the same as the
+	/// truncated remainder.
+	///
+	/// # Panics
+	///
+	/// Panics if `other` is zero.
+	pub fn mod_floor(&self, other: &Self) -> Self {
+		Self(self.0.mod_floor(&other.0))
+	}
+
+	/// Adds `rhs` to this integer in place.
+	///
+	/// Fails if the result would be negative, in which case this integer is
+	/// left unchanged.
+	pub fn add_assign_checked(&mut self, rhs: impl Into<BigInt>) -> Result<(), IntegerIsNegative> {
+		let rhs = rhs.into();
+		self.0 += &rhs;
+
+		if self.0.is_negative() {
+			let invalid = self.0.clone();
+			self.0 -= rhs;
+			return Err(IntegerIsNegative(invalid.into()));
+		}
+
+		Ok(())
+	}
+
+	/// Subtracts `rhs` from this integer in place.
+	///
+	/// Fails if the result would be negative, in which case this integer is
+	/// left unchanged.
+	pub fn sub_assign_checked(&mut self, rhs: impl Into<BigInt>) -> Result<(), IntegerIsNegative> {
+		let rhs = rhs.into();
+		self.0 -= &rhs;
+
+		if self.0.is_negative() {
+			let invalid = self.0.clone();
+			self.0 += rhs;
+			return Err(IntegerIsNegative(invalid.into()));
+		}
+
+		Ok(())
+	}
+
 	#[inline(always)]
 	pub fn zero() -> Self {
 		Self(BigInt::zero())
@@ -107,11 +217,12 @@ impl NonNegativeInteger {
 	/// Returns a lexical representation of this non negative integer.
 	#[inline(always)]
 	pub fn lexical_representation(&self) -> lexical::NonNegativeIntegerBuf {
-		unsafe {
-			// This is safe because the `Display::fmt` method matches the
-			// XSD lexical representation.
-			lexical::NonNegativeIntegerBuf::new_unchecked(format!("{}", self))
-		}
+		// This is safe because `integer_lexical_bytes` returns the
+		// canonical XSD lexical representation.
+		unchecked!(
+			lexical::NonNegativeIntegerBuf,
+			integer_lexical_bytes(&self.0)
+		)
 	}
 
 	pub fn to_bytes_be(&self) -> (Sign, Vec<u8>) {
@@ -199,9 +310,9 @@ impl Borrow<BigInt> for NonNegativeInteger {
 	}
 }
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 #[error("integer {0} is negative")]
-pub struct IntegerIsNegative(Integer);
+pub struct IntegerIsNegative(pub Integer);
 
 impl TryFrom<Integer> for NonNegativeInteger {
 	type Error = IntegerIsNegative;
@@ -229,7 +340,38 @@ macro_rules! from {
 
 from!(u8, u16, u32, u64, usize);
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("decimal {0} is not a non negative integer")]
+pub struct DecimalIsNotNonNegativeInteger(pub Decimal);
+
+impl TryFrom<Decimal> for NonNegativeInteger {
+	type Error = DecimalIsNotNonNegativeInteger;
+
+	fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+		match value.as_integer() {
+			Some(i) if !i.is_negative() => Ok(Self(i.as_bigint().clone())),
+			_ => Err(DecimalIsNotNonNegativeInteger(value)),
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("integer {0} is not positive")]
+pub struct IntegerIsNotPositive(pub Integer);
+
+impl TryFrom<Integer> for PositiveInteger {
+	type Error = IntegerIsNotPositive;
+
+	fn try_from(value: Integer) -> Result<Self, Self::Error> {
+		if value.is_positive() {
+			Ok(Self(value.into()))
+		} else {
+			Err(IntegerIsNotPositive(value))
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 #[error("integer out of supported bounds: {0}")]
 pub struct NonNegativeIntegerOutOfTargetBounds(pub NonNegativeInteger);
 
@@ -249,6 +391,8 @@ macro_rules! try_into {
 
 try_into!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
 
+impl_integer_sum!(NonNegativeInteger);
+
 impl_integer_arithmetic!(
 	for NonNegativeInteger where r ( !r.is_negative() ) {
 		Integer [.0],
@@ -269,6 +413,88 @@ impl_integer_arithmetic!(
 	}
 );
 
+impl Rem for NonNegativeInteger {
+	type Output = Self;
+
+	fn rem(self, rhs: Self) -> Self::Output {
+		Self(self.0 % rhs.0)
+	}
+}
+
+impl Zero for NonNegativeInteger {
+	fn zero() -> Self {
+		Self(BigInt::zero())
+	}
+
+	fn is_zero(&self) -> bool {
+		self.0.is_zero()
+	}
+}
+
+impl One for NonNegativeInteger {
+	fn one() -> Self {
+		Self(BigInt::one())
+	}
+}
+
+impl Num for NonNegativeInteger {
+	type FromStrRadixErr = InvalidRadixInteger;
+
+	fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+		match Integer::from_radix(str, radix) {
+			Ok(n) if !n.is_negative() => Ok(Self(n.into())),
+			_ => Err(InvalidRadixInteger::new(str, radix)),
+		}
+	}
+}
+
+impl ToPrimitive for NonNegativeInteger {
+	fn to_i64(&self) -> Option<i64> {
+		self.0.to_i64()
+	}
+
+	fn to_u64(&self) -> Option<u64> {
+		self.0.to_u64()
+	}
+
+	fn to_i128(&self) -> Option<i128> {
+		self.0.to_i128()
+	}
+
+	fn to_u128(&self) -> Option<u128> {
+		self.0.to_u128()
+	}
+
+	fn to_f64(&self) -> Option<f64> {
+		self.0.to_f64()
+	}
+}
+
+/// Negative inputs are rejected.
+impl FromPrimitive for NonNegativeInteger {
+	fn from_i64(n: i64) -> Option<Self> {
+		(n >= 0).then(|| Self(n.into()))
+	}
+
+	fn from_u64(n: u64) -> Option<Self> {
+		Some(Self(n.into()))
+	}
+
+	fn from_i128(n: i128) -> Option<Self> {
+		(n >= 0).then(|| Self(n.into()))
+	}
+
+	fn from_u128(n: u128) -> Option<Self> {
+		Some(Self(n.into()))
+	}
+
+	/// Truncates the given float toward zero, returning `None` if it is not
+	/// finite or is not greater than `-1`.
+	fn from_f64(n: f64) -> Option<Self> {
+		BigInt::from_f64(n).filter(|n| !n.is_negative()).map(Self)
+	}
+}
+
 pub type UnsignedLong = u64;
 
 pub trait XsdUnsignedLong {
@@ -300,10 +526,19 @@ impl ParseXsd for UnsignedLong {
 }
 
 impl LexicalFormOf<UnsignedLong> for lexical::NonNegativeInteger {
-	type ValueError = NonNegativeIntegerOutOfTargetBounds;
+	type ValueError = IntegerOutOfRange;
 
 	fn try_as_value(&self) -> Result<UnsignedLong, Self::ValueError> {
-		self.value().try_into()
+		self.value()
+			.try_into()
+			.map_err(|e: NonNegativeIntegerOutOfTargetBounds| {
+				IntegerOutOfRange::new(
+					e.0.into(),
+					UnsignedLongDatatype::UnsignedLong.into(),
+					0,
+					UnsignedLong::MAX as i128,
+				)
+			})
 	}
 }
 
@@ -336,10 +571,19 @@ impl ParseXsd for UnsignedInt {
 }
 
 impl LexicalFormOf<UnsignedInt> for lexical::NonNegativeInteger {
-	type ValueError = NonNegativeIntegerOutOfTargetBounds;
+	type ValueError = IntegerOutOfRange;
 
 	fn try_as_value(&self) -> Result<UnsignedInt, Self::ValueError> {
-		self.value().try_into()
+		self.value()
+			.try_into()
+			.map_err(|e: NonNegativeIntegerOutOfTargetBounds| {
+				IntegerOutOfRange::new(
+					e.0.into(),
+					UnsignedIntDatatype::UnsignedInt.into(),
+					0,
+					UnsignedInt::MAX as i128,
+				)
+			})
 	}
 }
 
@@ -370,10 +614,19 @@ impl ParseXsd for UnsignedShort {
 }
 
 impl LexicalFormOf<UnsignedShort> for lexical::NonNegativeInteger {
-	type ValueError = NonNegativeIntegerOutOfTargetBounds;
+	type ValueError = IntegerOutOfRange;
 
 	fn try_as_value(&self) -> Result<UnsignedShort, Self::ValueError> {
-		self.value().try_into()
+		self.value()
+			.try_into()
+			.map_err(|e: NonNegativeIntegerOutOfTargetBounds| {
+				IntegerOutOfRange::new(
+					e.0.into(),
+					UnsignedShortDatatype::UnsignedShort.into(),
+					0,
+					UnsignedShort::MAX as i128,
+				)
+			})
 	}
 }
 
@@ -390,10 +643,19 @@ impl ParseXsd for UnsignedByte {
 }
 
 impl LexicalFormOf<UnsignedByte> for lexical::NonNegativeInteger {
-	type ValueError = NonNegativeIntegerOutOfTargetBounds;
+	type ValueError = IntegerOutOfRange;
 
 	fn try_as_value(&self) -> Result<UnsignedByte, Self::ValueError> {
-		self.value().try_into()
+		self.value()
+			.try_into()
+			.map_err(|e: NonNegativeIntegerOutOfTargetBounds| {
+				IntegerOutOfRange::new(
+					e.0.into(),
+					UnsignedShortDatatype::UnsignedByte.into(),
+					0,
+					UnsignedByte::MAX as i128,
+				)
+			})
 	}
 }
 
@@ -401,13 +663,24 @@ impl LexicalFormOf<UnsignedByte> for lexical::NonNegativeInteger {
 pub struct PositiveInteger(BigInt);
 
 impl PositiveInteger {
-	/// Creates a new positive integer from the given `BigInt`.
-	///
-	/// # Safety
-	///
-	/// The input value *must* but a positive integer.
-	pub unsafe fn new_unchecked(n: BigInt) -> Self {
-		Self(n)
+	/// Creates a new positive integer from a `BigInt`, if it is positive.
+	pub fn new(n: BigInt) -> Result<Self, IntegerIsNotPositive> {
+		if n.is_positive() {
+			Ok(Self(n))
+		} else {
+			Err(IntegerIsNotPositive(n.into()))
+		}
+	}
+
+	unchecked_constructor! {
+		/// Creates a new positive integer from the given `BigInt`.
+		///
+		/// # Safety
+		///
+		/// The input value *must* but a positive integer.
+		pub unsafe fn new_unchecked(n: BigInt) -> Self {
+			Self(n)
+		}
 	}
 
 	/// Creates a positive integer from its unsigned big endian bytes
@@ -422,30 +695,98 @@ impl PositiveInteger {
 		Self(BigInt::from_bytes_le(Sign::Plus, bytes))
 	}
 
-	/// Creates a positive integer from its unsigned big endian bytes
-	/// representation.
-	///
-	/// # Safety
-	///
-	/// The represented number must be positive.
-	pub unsafe fn from_signed_bytes_be_unchecked(bytes: &[u8]) -> Self {
-		Self(BigInt::from_signed_bytes_be(bytes))
+	unchecked_constructor! {
+		/// Creates a positive integer from its unsigned big endian bytes
+		/// representation.
+		///
+		/// # Safety
+		///
+		/// The represented number must be positive.
+		pub unsafe fn from_signed_bytes_be_unchecked(bytes: &[u8]) -> Self {
+			Self(BigInt::from_signed_bytes_be(bytes))
+		}
 	}
 
-	/// Creates a positive integer from its unsigned little endian bytes
-	/// representation.
-	///
-	/// # Safety
-	///
-	/// The represented number must be positive.
-	pub unsafe fn from_signed_bytes_le_unchecked(bytes: &[u8]) -> Self {
-		Self(BigInt::from_signed_bytes_le(bytes))
+	unchecked_constructor! {
+		/// Creates a positive integer from its unsigned little endian bytes
+		/// representation.
+		///
+		/// # Safety
+		///
+		/// The represented number must be positive.
+		pub unsafe fn from_signed_bytes_le_unchecked(bytes: &[u8]) -> Self {
+			Self(BigInt::from_signed_bytes_le(bytes))
+		}
 	}
 
 	pub fn into_big_int(self) -> BigInt {
 		self.0
 	}
 
+	/// Returns a reference to the underlying `BigInt`.
+	pub fn as_bigint(&self) -> &BigInt {
+		&self.0
+	}
+
+	/// Applies the given function to the underlying `BigInt`, failing if the
+	/// result is not positive.
+	pub fn map_bigint(
+		self,
+		f: impl FnOnce(BigInt) -> BigInt,
+	) -> Result<Self, IntegerIsNotPositive> {
+		let n = f(self.0);
+		if n.is_positive() {
+			Ok(Self(n))
+		} else {
+			Err(IntegerIsNotPositive(n.into()))
+		}
+	}
+
+	/// Raises this integer to the power of `exp`.
+	pub fn pow(&self, exp: u32) -> Self {
+		Self(self.0.pow(exp))
+	}
+
+	/// Adds `rhs` to this integer in place.
+	///
+	/// Fails if the result would be zero or negative, in which case this integer is
+	/// left unchanged.
+	pub fn add_assign_checked(
+		&mut self,
+		rhs: impl Into<BigInt>,
+	) -> Result<(), IntegerIsNotPositive> {
+		let rhs = rhs.into();
+		self.0 += &rhs;
+
+		if !self.0.is_positive() {
+			let invalid = self.0.clone();
+			self.0 -= rhs;
+			return Err(IntegerIsNotPositive(invalid.into()));
+		}
+
+		Ok(())
+	}
+
+	/// Subtracts `rhs` from this integer in place.
+	///
+	/// Fails if the result would be zero or negative, in which case this integer is
+	/// left unchanged.
+	pub fn sub_assign_checked(
+		&mut self,
+		rhs: impl Into<BigInt>,
+	) -> Result<(), IntegerIsNotPositive> {
+		let rhs = rhs.into();
+		self.0 -= &rhs;
+
+		if !self.0.is_positive() {
+			let invalid = self.0.clone();
+			self.0 += rhs;
+			return Err(IntegerIsNotPositive(invalid.into()));
+		}
+
+		Ok(())
+	}
+
 	pub fn is_one(&self) -> bool {
 		matches!(u8::try_from(&self.0), Ok(1))
 	}
@@ -485,6 +826,16 @@ impl LexicalFormOf<PositiveInteger> for lexical::PositiveInteger {
 	}
 }
 
+impl FromStr for PositiveInteger {
+	type Err = lexical::InvalidPositiveInteger;
+
+	#[inline(always)]
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let l = lexical::PositiveInteger::new(s)?;
+		Ok(l.value())
+	}
+}
+
 impl fmt::Display for PositiveInteger {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		self.0.fmt(f)
@@ -11,6 +11,7 @@ use num_traits::{Signed, Zero};
 use crate::{
 	impl_integer_arithmetic,
 	lexical::{self, LexicalFormOf},
+	value::decimal::integer_lexical_bytes,
 	Datatype, Integer, NonPositiveIntegerDatatype, ParseXsd, XsdValue,
 };
 
@@ -20,13 +21,25 @@ use super::Sign;
 pub struct NonPositiveInteger(BigInt);
 
 impl NonPositiveInteger {
-	/// Create a new non positive integer from a `BigInt`.
-	///
-	/// # Safety
-	///
-	/// The input number must be non positive.
-	pub unsafe fn new_unchecked(n: BigInt) -> Self {
-		Self(n)
+	/// Creates a new non positive integer from a `BigInt`, if it is non
+	/// positive.
+	pub fn new(n: BigInt) -> Result<Self, IntegerIsPositive> {
+		if n.is_positive() {
+			Err(IntegerIsPositive(n.into()))
+		} else {
+			Ok(Self(n))
+		}
+	}
+
+	unchecked_constructor! {
+		/// Create a new non positive integer from a `BigInt`.
+		///
+		/// # Safety
+		///
+		/// The input number must be non positive.
+		pub unsafe fn new_unchecked(n: BigInt) -> Self {
+			Self(n)
+		}
 	}
 
 	/// Creates a non positive integer from its unsigned big endian bytes
@@ -41,24 +54,28 @@ impl NonPositiveInteger {
 		Self(BigInt::from_bytes_le(Sign::Minus, bytes))
 	}
 
-	/// Creates a non positive integer from its signed big endian bytes
-	/// representation.
-	///
-	/// # Safety
-	///
-	/// The represented number must be non positive.
-	pub unsafe fn from_signed_bytes_be_unchecked(bytes: &[u8]) -> Self {
-		Self(BigInt::from_signed_bytes_be(bytes))
+	unchecked_constructor! {
+		/// Creates a non positive integer from its signed big endian bytes
+		/// representation.
+		///
+		/// # Safety
+		///
+		/// The represented number must be non positive.
+		pub unsafe fn from_signed_bytes_be_unchecked(bytes: &[u8]) -> Self {
+			Self(BigInt::from_signed_bytes_be(bytes))
+		}
 	}
 
-	/// Creates a non positive integer from its signed little endian bytes
-	/// representation.
-	///
-	/// # Safety
-	///
-	/// The represented number must be non positive.
-	pub unsafe fn from_signed_bytes_le_unchecked(bytes: &[u8]) -> Self {
-		Self(BigInt::from_signed_bytes_le(bytes))
+	unchecked_constructor! {
+		/// Creates a non positive integer from its signed little endian bytes
+		/// representation.
+		///
+		/// # Safety
+		///
+		/// The represented number must be non positive.
+		pub unsafe fn from_signed_bytes_le_unchecked(bytes: &[u8]) -> Self {
+			Self(BigInt::from_signed_bytes_le(bytes))
+		}
 	}
 
 	pub fn from_signed_bytes_be(bytes: &[u8]) -> Result<Self, IntegerIsPositive> {
@@ -74,6 +91,25 @@ impl NonPositiveInteger {
 		self.0
 	}
 
+	/// Returns a reference to the underlying `BigInt`.
+	#[inline(always)]
+	pub fn as_bigint(&self) -> &BigInt {
+		&self.0
+	}
+
+	/// Applies the given function to the underlying `BigInt`, failing if the
+	/// result is positive.
+	pub fn map_bigint(self, f: impl FnOnce(BigInt) -> BigInt) -> Result<Self, IntegerIsPositive> {
+		Integer::from_bigint(f(self.0)).try_into()
+	}
+
+	/// Raises this integer to the power of `exp`, returning `None` if the
+	/// result is positive.
+	pub fn checked_pow(&self, exp: u32) -> Option<Self> {
+		let n = self.0.pow(exp);
+		(!n.is_positive()).then_some(Self(n))
+	}
+
 	#[inline(always)]
 	pub fn zero() -> Self {
 		Self(BigInt::zero())
@@ -96,11 +132,12 @@ impl NonPositiveInteger {
 	/// Returns a lexical representation of this non positive integer.
 	#[inline(always)]
 	pub fn lexical_representation(&self) -> lexical::NonPositiveIntegerBuf {
-		unsafe {
-			// This is safe because the `Display::fmt` method matches the
-			// XSD lexical representation.
-			lexical::NonPositiveIntegerBuf::new_unchecked(format!("{}", self))
-		}
+		// This is safe because `integer_lexical_bytes` returns the
+		// canonical XSD lexical representation.
+		unchecked!(
+			lexical::NonPositiveIntegerBuf,
+			integer_lexical_bytes(&self.0)
+		)
 	}
 
 	pub fn to_bytes_be(&self) -> (Sign, Vec<u8>) {
@@ -209,7 +246,7 @@ impl_integer_arithmetic!(
 	}
 );
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 #[error("integer out of supported bounds: {0}")]
 pub struct NonPositiveIntegerOutOfTargetBounds(pub NonPositiveInteger);
 
@@ -229,9 +266,9 @@ macro_rules! try_into {
 
 try_into!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
 
-#[derive(Debug, thiserror::Error)]
-#[error("integer {0} is negative")]
-pub struct IntegerIsPositive(Integer);
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("integer {0} is positive")]
+pub struct IntegerIsPositive(pub Integer);
 
 impl TryFrom<Integer> for NonPositiveInteger {
 	type Error = IntegerIsPositive;
@@ -245,63 +282,124 @@ impl TryFrom<Integer> for NonPositiveInteger {
 	}
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("integer {0} is not negative")]
+pub struct IntegerIsNotNegative(pub Integer);
+
+impl TryFrom<Integer> for NegativeInteger {
+	type Error = IntegerIsNotNegative;
+
+	fn try_from(value: Integer) -> Result<Self, Self::Error> {
+		if value.is_negative() {
+			Ok(Self(value.into()))
+		} else {
+			Err(IntegerIsNotNegative(value))
+		}
+	}
+}
+
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct NegativeInteger(BigInt);
 
 impl NegativeInteger {
-	/// Creates a new negative integer from the given `BigInt`.
-	///
-	/// # Safety
-	///
-	/// The input value *must* but a negative integer.
-	pub unsafe fn new_unchecked(n: BigInt) -> Self {
-		Self(n)
+	/// Creates a new negative integer from a `BigInt`, if it is negative.
+	pub fn new(n: BigInt) -> Result<Self, IntegerIsNotNegative> {
+		if n.is_negative() {
+			Ok(Self(n))
+		} else {
+			Err(IntegerIsNotNegative(n.into()))
+		}
 	}
 
-	/// Creates a negative integer from its unsigned big endian bytes
-	/// representation.
-	///
-	/// # Safety
-	///
-	/// The represented number must be negative.
-	pub unsafe fn from_bytes_be_unchecked(bytes: &[u8]) -> Self {
-		Self(BigInt::from_bytes_be(Sign::Minus, bytes))
+	unchecked_constructor! {
+		/// Creates a new negative integer from the given `BigInt`.
+		///
+		/// # Safety
+		///
+		/// The input value *must* but a negative integer.
+		pub unsafe fn new_unchecked(n: BigInt) -> Self {
+			Self(n)
+		}
 	}
 
-	/// Creates a negative integer from its unsigned little endian bytes
-	/// representation.
-	///
-	/// # Safety
-	///
-	/// The represented number must be negative.
-	pub unsafe fn from_bytes_le_unchecked(bytes: &[u8]) -> Self {
-		Self(BigInt::from_bytes_le(Sign::Minus, bytes))
+	unchecked_constructor! {
+		/// Creates a negative integer from its unsigned big endian bytes
+		/// representation.
+		///
+		/// # Safety
+		///
+		/// The represented number must be negative.
+		pub unsafe fn from_bytes_be_unchecked(bytes: &[u8]) -> Self {
+			Self(BigInt::from_bytes_be(Sign::Minus, bytes))
+		}
 	}
 
-	/// Creates a negative integer from its signed big endian bytes
-	/// representation.
-	///
-	/// # Safety
-	///
-	/// The represented number must be negative.
-	pub unsafe fn from_signed_bytes_be_unchecked(bytes: &[u8]) -> Self {
-		Self(BigInt::from_signed_bytes_be(bytes))
+	unchecked_constructor! {
+		/// Creates a negative integer from its unsigned little endian bytes
+		/// representation.
+		///
+		/// # Safety
+		///
+		/// The represented number must be negative.
+		pub unsafe fn from_bytes_le_unchecked(bytes: &[u8]) -> Self {
+			Self(BigInt::from_bytes_le(Sign::Minus, bytes))
+		}
 	}
 
-	/// Creates a negative integer from its signed little endian bytes
-	/// representation.
-	///
-	/// # Safety
-	///
-	/// The represented number must be negative.
-	pub unsafe fn from_signed_bytes_le_unchecked(bytes: &[u8]) -> Self {
-		Self(BigInt::from_signed_bytes_le(bytes))
+	unchecked_constructor! {
+		/// Creates a negative integer from its signed big endian bytes
+		/// representation.
+		///
+		/// # Safety
+		///
+		/// The represented number must be negative.
+		pub unsafe fn from_signed_bytes_be_unchecked(bytes: &[u8]) -> Self {
+			Self(BigInt::from_signed_bytes_be(bytes))
+		}
+	}
+
+	unchecked_constructor! {
+		/// Creates a negative integer from its signed little endian bytes
+		/// representation.
+		///
+		/// # Safety
+		///
+		/// The represented number must be negative.
+		pub unsafe fn from_signed_bytes_le_unchecked(bytes: &[u8]) -> Self {
+			Self(BigInt::from_signed_bytes_le(bytes))
+		}
 	}
 
 	pub fn into_big_int(self) -> BigInt {
 		self.0
 	}
 
+	/// Returns a reference to the underlying `BigInt`.
+	pub fn as_bigint(&self) -> &BigInt {
+		&self.0
+	}
+
+	/// Applies the given function to the underlying `BigInt`, failing if the
+	/// result is not negative.
+	pub fn map_bigint(
+		self,
+		f: impl FnOnce(BigInt) -> BigInt,
+	) -> Result<Self, IntegerIsNotNegative> {
+		let n = f(self.0);
+		if n.is_negative() {
+			Ok(Self(n))
+		} else {
+			Err(IntegerIsNotNegative(n.into()))
+		}
+	}
+
+	/// Raises this integer to the power of `exp`, returning `None` if the
+	/// result is not negative (when `exp` is even).
+	pub fn checked_pow(&self, exp: u32) -> Option<Self> {
+		let n = self.0.pow(exp);
+		n.is_negative().then_some(Self(n))
+	}
+
 	pub fn is_minus_one(&self) -> bool {
 		matches!(i8::try_from(&self.0), Ok(-1))
 	}
@@ -341,6 +439,16 @@ impl LexicalFormOf<NegativeInteger> for lexical::NegativeInteger {
 	}
 }
 
+impl FromStr for NegativeInteger {
+	type Err = lexical::InvalidNegativeInteger;
+
+	#[inline(always)]
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let l = lexical::NegativeInteger::new(s)?;
+		Ok(l.value())
+	}
+}
+
 impl fmt::Display for NegativeInteger {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		self.0.fmt(f)
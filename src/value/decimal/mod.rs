@@ -1,14 +1,14 @@
 use std::fmt;
 use std::hash::Hash;
-use std::ops::Deref;
+use std::ops::{Add, Deref, Div, Mul, Neg, Rem, Sub};
 use std::str::FromStr;
 use std::{borrow::Borrow, collections::HashSet};
 
 use lazy_static::lazy_static;
 use num_bigint::BigInt;
 use num_rational::BigRational;
-use num_traits::{Signed, ToPrimitive, Zero};
-use once_cell::unsync::OnceCell;
+use num_traits::{FromPrimitive, Num, One, Signed, ToPrimitive, Zero};
+use once_cell::sync::OnceCell;
 
 use crate::lexical::LexicalFormOf;
 use crate::{
@@ -43,6 +43,12 @@ lazy_static! {
 	static ref TEN: BigInt = 10u32.into();
 }
 
+/// Number of fractional digits of the result of a division between decimal
+/// numbers, when it has no finite decimal representation.
+///
+/// This is the minimum precision required by XPath for `xs:decimal`.
+pub const DECIMAL_DIVISION_FRACTION_DIGITS: u32 = 18;
+
 /// Decimal number.
 ///
 /// Internally a decimal number is represented as a `BigRational` with a finite
@@ -50,6 +56,10 @@ lazy_static! {
 #[derive(Clone)]
 pub struct Decimal {
 	data: BigRational,
+
+	/// Lazily computed canonical lexical representation.
+	///
+	/// This is a thread-safe cell so that `Decimal` stays `Sync`.
 	lexical: OnceCell<lexical::DecimalBuf>,
 }
 
@@ -122,12 +132,32 @@ pub fn is_decimal(r: &BigRational) -> bool {
 	c.is_decimal(r)
 }
 
+/// Appends the ASCII decimal digits of the absolute value of `n` to `output`.
+///
+/// This is a digit-level conversion that does not go through the formatting
+/// machinery.
+pub(crate) fn push_ascii_digits(output: &mut Vec<u8>, n: &BigInt) {
+	let (_, digits) = n.to_radix_be(10);
+	output.extend(digits.into_iter().map(|d| b'0' + d))
+}
+
+/// Returns the canonical lexical representation of the given integer, as
+/// ASCII bytes.
+pub(crate) fn integer_lexical_bytes(n: &BigInt) -> Vec<u8> {
+	let mut output = Vec::new();
+
+	if n.is_negative() {
+		output.push(b'-')
+	}
+
+	push_ascii_digits(&mut output, n);
+	output
+}
+
 /// Returns the decimal lexical representation of the given rational number, if
 /// any.
 pub fn decimal_lexical_representation(r: &BigRational) -> Option<lexical::DecimalBuf> {
-	use std::fmt::Write;
-
-	let mut fraction = String::new();
+	let mut fraction = Vec::new();
 	let mut map = std::collections::HashMap::new();
 
 	let mut rem = if r.is_negative() {
@@ -140,39 +170,55 @@ pub fn decimal_lexical_representation(r: &BigRational) -> Option<lexical::Decima
 	while !rem.is_zero() && !map.contains_key(&rem) {
 		map.insert(rem.clone(), fraction.len());
 		rem *= TEN.clone();
-		fraction.push_str(&(rem.clone() / r.denom()).to_string());
+		fraction.push(b'0' + (rem.clone() / r.denom()).to_u8().unwrap());
 		rem %= r.denom();
 	}
 
-	let mut output = if r.is_negative() {
-		"-".to_owned()
-	} else {
-		String::new()
-	};
+	let mut output = Vec::new();
+
+	if r.is_negative() {
+		output.push(b'-')
+	}
 
-	output.push_str(&(r.numer() / r.denom()).to_string());
+	push_ascii_digits(&mut output, &(r.numer() / r.denom()));
 
 	if rem.is_zero() {
 		if !fraction.is_empty() {
-			write!(output, ".{}", &fraction).unwrap();
+			output.push(b'.');
+			output.extend(fraction)
 		}
 
-		Some(unsafe { lexical::DecimalBuf::new_unchecked(output) })
+		Some(unchecked!(lexical::DecimalBuf, output))
 	} else {
 		None
 	}
 }
 
 impl Decimal {
-	/// Creates a new decimal number from a rational number.
-	///
-	/// # Safety
-	///
-	/// The input rational number must have a finite decimal representation.
-	pub unsafe fn new_unchecked(r: BigRational) -> Self {
-		Self {
-			data: r,
-			lexical: OnceCell::new(),
+	/// Creates a new decimal number from a rational number, if it has a
+	/// finite decimal representation.
+	pub fn new(r: BigRational) -> Result<Self, NoDecimalRepresentation> {
+		if is_decimal(&r) {
+			Ok(Self {
+				data: r,
+				lexical: OnceCell::new(),
+			})
+		} else {
+			Err(NoDecimalRepresentation(r))
+		}
+	}
+
+	unchecked_constructor! {
+		/// Creates a new decimal number from a rational number.
+		///
+		/// # Safety
+		///
+		/// The input rational number must have a finite decimal representation.
+		pub unsafe fn new_unchecked(r: BigRational) -> Self {
+			Self {
+				data: r,
+				lexical: OnceCell::new(),
+			}
 		}
 	}
 
@@ -209,6 +255,23 @@ impl Decimal {
 		self.data.is_negative()
 	}
 
+	/// Returns `false`.
+	///
+	/// Unlike floating point numbers, decimal numbers have a single zero:
+	/// the lexical form `-0` maps to the same value as `0`.
+	#[inline(always)]
+	pub fn is_negative_zero(&self) -> bool {
+		false
+	}
+
+	/// Returns `-1`, `0` or `1` depending on the sign of `self`.
+	pub fn signum(&self) -> Self {
+		Self {
+			data: self.data.signum(),
+			lexical: OnceCell::new(),
+		}
+	}
+
 	pub fn as_integer(&self) -> Option<&Integer> {
 		if self.data.is_integer() {
 			Some(Integer::from_bigint_ref(self.data.numer()))
@@ -259,12 +322,51 @@ impl Decimal {
 		}
 	}
 
+	/// Returns the canonical lexical representation of this number.
+	///
+	/// The lexical space of `xsd:decimal` has no exponent notation: the
+	/// representation is always a plain sequence of digits, with an optional
+	/// leading `-` and fractional part, however small or large the number
+	/// is. Parsing it back gives the same number.
 	#[inline(always)]
 	pub fn lexical_representation(&self) -> &lexical::DecimalBuf {
 		self.lexical
 			.get_or_init(|| decimal_lexical_representation(&self.data).unwrap())
 	}
 
+	/// Rounds this number to the given number of fractional digits.
+	///
+	/// Halfway cases are rounded away from zero.
+	pub fn round(&self, fraction_digits: u32) -> Self {
+		let scale = BigRational::from_integer(TEN.pow(fraction_digits));
+		let r = (&self.data * &scale).round() / scale;
+
+		// SAFETY: `r` has at most `fraction_digits` fractional digits.
+		unchecked!(Self, r)
+	}
+
+	/// Formats this number without exponent notation, with at most
+	/// `max_fraction_digits` fractional digits.
+	///
+	/// The number is first rounded with [`Self::round`], and the result is
+	/// its canonical lexical representation, which is always a valid
+	/// `xsd:decimal` lexical form.
+	///
+	/// ```
+	/// use xsd_types::Decimal;
+	///
+	/// let d: Decimal = "0.000000000000000000012345".parse().unwrap();
+	/// assert_eq!(d.to_string(), "0.000000000000000000012345");
+	/// assert_eq!(d.to_plain_string(22), "0.0000000000000000000123");
+	/// assert_eq!(d.to_plain_string(2), "0");
+	///
+	/// let d: Decimal = "-2.675".parse().unwrap();
+	/// assert_eq!(d.to_plain_string(2), "-2.68");
+	/// ```
+	pub fn to_plain_string(&self, max_fraction_digits: u32) -> String {
+		self.round(max_fraction_digits).to_string()
+	}
+
 	pub fn as_f64(&self) -> Option<f64> {
 		self.data.to_f64()
 	}
@@ -282,6 +384,46 @@ impl Decimal {
 	}
 }
 
+impl std::iter::Sum for Decimal {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		let r = iter.fold(BigRational::zero(), |acc, d| acc + d.data);
+
+		// SAFETY: the sum of decimal numbers is a decimal number.
+		unchecked!(Self, r)
+	}
+}
+
+impl<'a> std::iter::Sum<&'a Decimal> for Decimal {
+	fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+		let r = iter.fold(BigRational::zero(), |acc, d| acc + &d.data);
+
+		// SAFETY: the sum of decimal numbers is a decimal number.
+		unchecked!(Self, r)
+	}
+}
+
+impl std::iter::Product for Decimal {
+	fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+		let r = iter.fold(BigRational::one(), |acc, d| acc * d.data);
+
+		// SAFETY: the product of decimal numbers is a decimal number.
+		unchecked!(Self, r)
+	}
+}
+
+impl<'a> std::iter::Product<&'a Decimal> for Decimal {
+	fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+		let r = iter.fold(BigRational::one(), |acc, d| acc * &d.data);
+
+		// SAFETY: the product of decimal numbers is a decimal number.
+		unchecked!(Self, r)
+	}
+}
+
+/// Writes the canonical lexical representation of the number.
+///
+/// The output never uses exponent notation, and always round-trips through
+/// [`FromStr`].
 impl fmt::Display for Decimal {
 	#[inline(always)]
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -299,23 +441,30 @@ impl<'a> From<&'a lexical::Decimal> for Decimal {
 impl From<lexical::DecimalBuf> for Decimal {
 	#[inline(always)]
 	fn from(value: lexical::DecimalBuf) -> Self {
-		let integer_part: BigInt = value.integer_part().as_str().parse().unwrap();
-		let data = match value.fractional_part() {
-			Some(fract) => {
-				let numer = fract.as_str().parse().unwrap();
-				let mut denom = BigInt::new(Sign::Plus, vec![1u32]);
-				for _ in 0..fract.as_str().len() {
-					denom *= 10
-				}
-
-				BigRational::from(integer_part) + BigRational::new(numer, denom)
-			}
-			None => integer_part.into(),
+		let (negative, digits) = match value.as_bytes()[0] {
+			b'-' => (true, &value.as_str()[1..]),
+			b'+' => (false, &value.as_str()[1..]),
+			_ => (false, value.as_str()),
 		};
 
+		// The integer or fractional part may be empty (`.5` or `5.`).
+		let (integer_part, fractional_part) = digits.split_once('.').unwrap_or((digits, ""));
+
+		let digits: Vec<u8> = integer_part
+			.bytes()
+			.chain(fractional_part.bytes())
+			.map(|d| d - b'0')
+			.collect();
+		let sign = if negative { Sign::Minus } else { Sign::Plus };
+		let numer = BigInt::from_radix_be(sign, &digits, 10).unwrap();
+
+		let denom = BigInt::from(10u32).pow(fractional_part.len() as u32);
+		let data = BigRational::new(numer, denom);
+
+		// The canonical lexical representation is computed lazily.
 		Self {
 			data,
-			lexical: value.into(),
+			lexical: OnceCell::new(),
 		}
 	}
 }
@@ -379,7 +528,7 @@ macro_rules! try_into_int {
 	};
 }
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 #[error("decimal number conversion failed")]
 pub struct FromDecimalError;
 
@@ -417,6 +566,192 @@ impl TryFrom<Decimal> for Double {
 	}
 }
 
+impl Add for Decimal {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self::Output {
+		// SAFETY: decimal numbers are closed under addition.
+		unchecked!(Self, self.data + rhs.data)
+	}
+}
+
+impl Sub for Decimal {
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self::Output {
+		// SAFETY: decimal numbers are closed under subtraction.
+		unchecked!(Self, self.data - rhs.data)
+	}
+}
+
+impl Mul for Decimal {
+	type Output = Self;
+
+	fn mul(self, rhs: Self) -> Self::Output {
+		// SAFETY: decimal numbers are closed under multiplication.
+		unchecked!(Self, self.data * rhs.data)
+	}
+}
+
+/// Divides two decimal numbers.
+///
+/// If the quotient has no finite decimal representation, it is rounded to
+/// [`DECIMAL_DIVISION_FRACTION_DIGITS`] fractional digits, halfway cases
+/// being rounded away from zero.
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero.
+impl Div for Decimal {
+	type Output = Self;
+
+	fn div(self, rhs: Self) -> Self::Output {
+		let r = self.data / rhs.data;
+
+		if is_decimal(&r) {
+			// SAFETY: `r` has a finite decimal representation.
+			unchecked!(Self, r)
+		} else {
+			let scale = BigRational::from_integer(TEN.pow(DECIMAL_DIVISION_FRACTION_DIGITS));
+			// SAFETY: the result has a finite number of fractional digits.
+			unchecked!(Self, (r * &scale).round() / scale)
+		}
+	}
+}
+
+/// Computes the remainder of the truncated division, which has the sign of
+/// `self`.
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero.
+impl Rem for Decimal {
+	type Output = Self;
+
+	fn rem(self, rhs: Self) -> Self::Output {
+		// SAFETY: `self - rhs * n` is a decimal number for any integer
+		// `n`.
+		unchecked!(Self, self.data % rhs.data)
+	}
+}
+
+impl Neg for Decimal {
+	type Output = Self;
+
+	fn neg(self) -> Self::Output {
+		// SAFETY: decimal numbers are closed under negation.
+		unchecked!(Self, -self.data)
+	}
+}
+
+impl Zero for Decimal {
+	fn zero() -> Self {
+		Self::from(BigInt::zero())
+	}
+
+	fn is_zero(&self) -> bool {
+		self.data.is_zero()
+	}
+}
+
+impl One for Decimal {
+	fn one() -> Self {
+		Self::from(BigInt::one())
+	}
+}
+
+/// Only the decimal radix is supported, using the `xsd:decimal` lexical
+/// space.
+impl Num for Decimal {
+	type FromStrRadixErr = lexical::InvalidDecimal;
+
+	fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+		if radix == 10 {
+			str.parse()
+		} else {
+			Err(lexical::InvalidDecimal)
+		}
+	}
+}
+
+impl Signed for Decimal {
+	fn abs(&self) -> Self {
+		// SAFETY: decimal numbers are closed under absolute value.
+		unchecked!(Self, self.data.abs())
+	}
+
+	fn abs_sub(&self, other: &Self) -> Self {
+		if self <= other {
+			Self::from(BigInt::zero())
+		} else {
+			self.clone() - other.clone()
+		}
+	}
+
+	fn signum(&self) -> Self {
+		Decimal::signum(self)
+	}
+
+	fn is_positive(&self) -> bool {
+		self.data.is_positive()
+	}
+
+	fn is_negative(&self) -> bool {
+		self.data.is_negative()
+	}
+}
+
+/// Conversions to integers truncate toward zero.
+impl ToPrimitive for Decimal {
+	fn to_i64(&self) -> Option<i64> {
+		self.data.to_i64()
+	}
+
+	fn to_u64(&self) -> Option<u64> {
+		self.data.to_u64()
+	}
+
+	fn to_i128(&self) -> Option<i128> {
+		self.data.to_i128()
+	}
+
+	fn to_u128(&self) -> Option<u128> {
+		self.data.to_u128()
+	}
+
+	fn to_f64(&self) -> Option<f64> {
+		self.data.to_f64()
+	}
+}
+
+impl FromPrimitive for Decimal {
+	fn from_i64(n: i64) -> Option<Self> {
+		Some(Self::from(BigInt::from(n)))
+	}
+
+	fn from_u64(n: u64) -> Option<Self> {
+		Some(Self::from(BigInt::from(n)))
+	}
+
+	fn from_i128(n: i128) -> Option<Self> {
+		Some(Self::from(BigInt::from(n)))
+	}
+
+	fn from_u128(n: u128) -> Option<Self> {
+		Some(Self::from(BigInt::from(n)))
+	}
+
+	/// Converts the given float exactly, returning `None` if it is not
+	/// finite.
+	fn from_f64(n: f64) -> Option<Self> {
+		BigRational::from_float(n).map(|r| {
+			// SAFETY: finite floats are dyadic rationals, which all have a
+			// finite decimal representation.
+			unchecked!(Self, r)
+		})
+	}
+}
+
 impl From<BigInt> for Decimal {
 	#[inline(always)]
 	fn from(value: BigInt) -> Self {
@@ -460,7 +795,7 @@ impl Deref for Decimal {
 
 /// Error raised when trying to create a decimal value from a rational without
 /// finite decimal representation.
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 #[error("no decimal representation for rational number {0}")]
 pub struct NoDecimalRepresentation(pub BigRational);
 
@@ -469,11 +804,7 @@ impl TryFrom<BigRational> for Decimal {
 
 	#[inline(always)]
 	fn try_from(value: BigRational) -> Result<Self, Self::Error> {
-		if is_decimal(&value) {
-			Ok(unsafe { Self::new_unchecked(value) })
-		} else {
-			Err(NoDecimalRepresentation(value))
-		}
+		Self::new(value)
 	}
 }
 
@@ -503,7 +834,7 @@ impl LexicalFormOf<Decimal> for lexical::Decimal {
 	}
 }
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 pub enum NonDecimalFloat {
 	#[error("float is NaN")]
 	Nan,
@@ -553,3 +884,151 @@ impl TryFrom<Double> for Decimal {
 		}
 	}
 }
+
+#[cfg(feature = "nightly")]
+impl TryFrom<f16> for Decimal {
+	type Error = NonDecimalFloat;
+
+	fn try_from(value: f16) -> Result<Self, Self::Error> {
+		Float::from(value).try_into()
+	}
+}
+
+/// Converts the given `f128` value exactly.
+///
+/// Since `xsd:double` only has the precision of `f64`, this is the way to
+/// write `f128` values in RDF without losing precision: the lexical form of
+/// the resulting decimal is the exact decimal expansion of the value.
+#[cfg(feature = "nightly")]
+impl TryFrom<f128> for Decimal {
+	type Error = NonDecimalFloat;
+
+	fn try_from(value: f128) -> Result<Self, Self::Error> {
+		const MANTISSA_BITS: u32 = 112;
+		const EXPONENT_BIAS: i64 = 16383;
+
+		if value.is_nan() {
+			Err(NonDecimalFloat::Nan)
+		} else if value.is_infinite() {
+			if value.is_sign_positive() {
+				Err(NonDecimalFloat::PositiveInfinity)
+			} else {
+				Err(NonDecimalFloat::NegativeInfinity)
+			}
+		} else {
+			// `BigRational::from_float` does not support `f128`, so the value
+			// is decoded by hand.
+			let bits = value.to_bits();
+			let biased_exponent = ((bits >> MANTISSA_BITS) & 0x7fff) as i64;
+			let mut mantissa = BigInt::from(bits & ((1 << MANTISSA_BITS) - 1));
+
+			let exponent = if biased_exponent == 0 {
+				// Subnormal number.
+				1 - EXPONENT_BIAS - MANTISSA_BITS as i64
+			} else {
+				mantissa += BigInt::one() << MANTISSA_BITS;
+				biased_exponent - EXPONENT_BIAS - MANTISSA_BITS as i64
+			};
+
+			if value.is_sign_negative() {
+				mantissa = -mantissa
+			}
+
+			let r = if exponent >= 0 {
+				BigRational::from_integer(mantissa << exponent as usize)
+			} else {
+				BigRational::new(mantissa, BigInt::one() << (-exponent) as usize)
+			};
+
+			// SAFETY: `r` is a dyadic rational, which has a finite decimal
+			// representation.
+			Ok(unchecked!(Self, r))
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn format_negative() {
+		let value = Decimal::try_from(BigRational::new((-3).into(), 2.into())).unwrap();
+		assert_eq!(value.to_string(), "-1.5")
+	}
+
+	#[test]
+	fn plain_round_trip() {
+		for exp in 0..40u32 {
+			let scale = TEN.pow(exp);
+			for n in [1i64, -1, 7, -123456789, i64::MAX, i64::MIN] {
+				for r in [
+					BigRational::new(n.into(), scale.clone()),
+					BigRational::from_integer(BigInt::from(n) * &scale),
+				] {
+					let d = Decimal::try_from(r).unwrap();
+					let s = d.to_string();
+					assert!(!s.contains(['e', 'E']), "{s}");
+					assert_eq!(s.parse::<Decimal>().unwrap(), d);
+
+					let plain = d.to_plain_string(exp / 2);
+					assert!(!plain.contains(['e', 'E']), "{plain}");
+					assert_eq!(plain.parse::<Decimal>().unwrap(), d.round(exp / 2))
+				}
+			}
+		}
+
+		let d: Decimal = "1.005".parse().unwrap();
+		assert_eq!(d.to_plain_string(2), "1.01");
+		assert_eq!(d.to_plain_string(0), "1");
+		assert_eq!(d.to_plain_string(5), "1.005");
+
+		let d: Decimal = "-0.004".parse().unwrap();
+		assert_eq!(d.to_plain_string(2), "0")
+	}
+
+	#[test]
+	fn arithmetic() {
+		let d = |s: &str| s.parse::<Decimal>().unwrap();
+
+		assert_eq!(d("1.5") + d("0.25"), d("1.75"));
+		assert_eq!(d("1.5") - d("2"), d("-0.5"));
+		assert_eq!(d("1.5") * d("-0.2"), d("-0.3"));
+		assert_eq!(d("1") / d("8"), d("0.125"));
+		assert_eq!(d("2") / d("3"), d("0.666666666666666667"));
+		assert_eq!(d("-7.5") % d("2"), d("-1.5"));
+		assert_eq!(-d("1.5"), d("-1.5"));
+	}
+
+	#[cfg(feature = "nightly")]
+	#[test]
+	fn extended_precision() {
+		// 1 + 2^-60, which is not representable as `f64`.
+		let value = f128::from_bits((16383 << 112) | (1 << 52));
+		assert_eq!(Double::from_f128(value), Double::new(1.0));
+		assert_eq!(
+			Decimal::try_from(value).unwrap().to_string(),
+			"1.000000000000000000867361737988403547205962240695953369140625"
+		);
+
+		assert_eq!(Decimal::try_from(-0.375f16).unwrap().to_string(), "-0.375");
+		assert_eq!(
+			Decimal::try_from(f128::INFINITY),
+			Err(NonDecimalFloat::PositiveInfinity)
+		)
+	}
+
+	#[test]
+	fn num_traits() {
+		let d = |s: &str| s.parse::<Decimal>().unwrap();
+
+		assert!(Decimal::zero().is_zero());
+		assert_eq!(Decimal::from_str_radix("-2.5", 10), Ok(d("-2.5")));
+		assert!(Decimal::from_str_radix("10", 2).is_err());
+		assert_eq!(Signed::abs(&d("-2.5")), d("2.5"));
+		assert_eq!(d("1").abs_sub(&d("2.5")), Decimal::zero());
+		assert_eq!(d("-2.9").to_i64(), Some(-2));
+		assert_eq!(Decimal::from_f64(0.375), Some(d("0.375")));
+		assert_eq!(Decimal::from_f64(f64::NAN), None);
+	}
+}
@@ -1,10 +1,9 @@
 use crate::{
-	format_nanoseconds,
 	lexical::{duration::InvalidDayTimeDuration, LexicalFormOf},
-	Datatype, DurationDatatype, ParseXsd, XsdValue,
+	Datatype, DurationDatatype, DurationOverflow, ParseXsd, SecondsParts, XsdValue,
 };
 use core::fmt;
-use std::str::FromStr;
+use std::{ops::Mul, str::FromStr};
 
 #[derive(Debug, Clone, Copy)]
 pub struct DayTimeDuration {
@@ -29,14 +28,63 @@ impl DayTimeDuration {
 		}
 	}
 
+	/// Checks if this duration is zero.
+	pub fn is_zero(&self) -> bool {
+		self.seconds == 0 && self.nano_seconds == 0
+	}
+
+	/// Checks if this duration is negative.
+	pub fn is_negative(&self) -> bool {
+		self.is_negative
+	}
+
+	/// Returns the number of whole seconds of this duration, without sign.
+	pub fn seconds(&self) -> u32 {
+		self.seconds
+	}
+
+	/// Returns the fractional part of the seconds of this duration, in
+	/// nanoseconds, without sign.
+	pub fn nano_seconds(&self) -> u32 {
+		self.nano_seconds
+	}
+
+	/// Multiplies this duration by `n`, failing if the result overflows.
+	pub fn checked_mul(&self, n: u32) -> Result<Self, DurationOverflow> {
+		let nano_seconds = (self.seconds as u64 * 1_000_000_000 + self.nano_seconds as u64)
+			.checked_mul(n as u64)
+			.ok_or(DurationOverflow)?;
+
+		let seconds = u32::try_from(nano_seconds / 1_000_000_000).map_err(|_| DurationOverflow)?;
+		Ok(Self::new(
+			self.is_negative,
+			seconds,
+			(nano_seconds % 1_000_000_000) as u32,
+		))
+	}
+
 	pub fn into_string(self) -> String {
 		self.to_string()
 	}
 }
 
+/// Multiplies a duration by an integer.
+///
+/// # Panics
+///
+/// Panics if the result overflows. See [`DayTimeDuration::checked_mul`] for
+/// the non-panicking version.
+impl Mul<u32> for DayTimeDuration {
+	type Output = Self;
+
+	fn mul(self, rhs: u32) -> Self::Output {
+		self.checked_mul(rhs).expect("duration overflow")
+	}
+}
+
 impl XsdValue for DayTimeDuration {
 	fn datatype(&self) -> Datatype {
-		Datatype::Duration(DurationDatatype::Duration)
+		Datatype::Duration(DurationDatatype::DayTimeDuration)
 	}
 }
 
@@ -50,14 +98,23 @@ impl FromStr for DayTimeDuration {
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
 		let lexical_value = crate::lexical::DayTimeDuration::new(s)
 			.map_err(|InvalidDayTimeDuration(s)| InvalidDayTimeDuration(s.to_owned()))?;
-		Ok(lexical_value.as_value())
+		lexical_value
+			.try_as_value()
+			.map_err(|_| InvalidDayTimeDuration(s.to_owned()))
 	}
 }
 
 impl fmt::Display for DayTimeDuration {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.is_zero() {
+			return write!(f, "PT0S");
+		}
+
 		let mut minute = self.seconds / 60;
-		let second = self.seconds - minute * 60;
+		let seconds = SecondsParts {
+			seconds: self.seconds - minute * 60,
+			nanoseconds: self.nano_seconds,
+		};
 
 		let mut hour = minute / 60;
 		minute -= hour * 60;
@@ -75,7 +132,7 @@ impl fmt::Display for DayTimeDuration {
 			write!(f, "{day}D")?;
 		}
 
-		if hour > 0 || minute > 0 || second > 0 || self.nano_seconds > 0 {
+		if hour > 0 || minute > 0 || !seconds.is_zero() {
 			write!(f, "T")?;
 
 			if hour > 0 {
@@ -86,13 +143,8 @@ impl fmt::Display for DayTimeDuration {
 				write!(f, "{minute}M")?;
 			}
 
-			if second > 0 || self.nano_seconds > 0 {
-				if second > 0 {
-					second.fmt(f)?;
-				}
-
-				format_nanoseconds(self.nano_seconds, f)?;
-				write!(f, "S")?;
+			if !seconds.is_zero() {
+				write!(f, "{seconds}S")?;
 			}
 		}
 
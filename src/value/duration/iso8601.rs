@@ -0,0 +1,261 @@
+//! ISO 8601 durations interoperability.
+//!
+//! The XSD `duration` lexical space is a restriction of the ISO 8601
+//! duration format. ISO 8601 also allows week durations (`P1W`) and decimal
+//! fractions in any component (`P0.5Y`, `P1.5DT2H`), frequently found in
+//! data coming from JSON APIs.
+use super::Duration;
+
+/// Error returned by [`Duration::from_iso8601`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum Iso8601DurationError {
+	/// The input is not an ISO 8601 duration.
+	#[error("invalid ISO 8601 duration")]
+	Invalid,
+
+	/// The duration cannot be represented exactly as an XSD duration, for
+	/// instance because it has a fractional number of months, or a precision
+	/// finer than the nanosecond.
+	#[error("ISO 8601 duration cannot be represented exactly")]
+	Inexact,
+
+	/// The duration is too large.
+	#[error("ISO 8601 duration out of range")]
+	OutOfRange,
+}
+
+const NANOSECONDS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Unit of an ISO 8601 duration component.
+#[derive(Clone, Copy)]
+enum Unit {
+	/// Number of months.
+	Months(u128),
+
+	/// Number of nanoseconds.
+	Nanoseconds(u128),
+}
+
+/// Date components designators, in order, with their unit.
+const DATE_DESIGNATORS: [(u8, Unit); 4] = [
+	(b'Y', Unit::Months(12)),
+	(b'M', Unit::Months(1)),
+	(b'W', Unit::Nanoseconds(7 * 86400 * NANOSECONDS_PER_SECOND)),
+	(b'D', Unit::Nanoseconds(86400 * NANOSECONDS_PER_SECOND)),
+];
+
+/// Time components designators, in order, with their unit.
+const TIME_DESIGNATORS: [(u8, Unit); 3] = [
+	(b'H', Unit::Nanoseconds(3600 * NANOSECONDS_PER_SECOND)),
+	(b'M', Unit::Nanoseconds(60 * NANOSECONDS_PER_SECOND)),
+	(b'S', Unit::Nanoseconds(NANOSECONDS_PER_SECOND)),
+];
+
+/// Maximum number of significant fractional digits.
+const MAX_FRACTION_DIGITS: usize = 20;
+
+impl Duration {
+	/// Parses an ISO 8601 duration, converting it into an XSD duration.
+	///
+	/// On top of the XSD lexical space, this accepts week components (`P2W`),
+	/// decimal fractions in any component, and a comma as decimal separator.
+	/// Fractions are converted exactly into the smaller units: `P1.5D`
+	/// becomes `P1DT12H` and `P0.5Y` becomes `P6M`. Fractional months are
+	/// rejected since months have no fixed length.
+	///
+	/// ```
+	/// use xsd_types::Duration;
+	///
+	/// let d = Duration::from_iso8601("P2W").unwrap();
+	/// assert_eq!(d.to_string(), "P14D");
+	///
+	/// let d = Duration::from_iso8601("P1.5DT0,5H").unwrap();
+	/// assert_eq!(d.to_string(), "P1DT12H30M");
+	/// ```
+	pub fn from_iso8601(s: &str) -> Result<Self, Iso8601DurationError> {
+		let (is_negative, s) = match s.strip_prefix('-') {
+			Some(s) => (true, s),
+			None => (false, s),
+		};
+
+		let s = s.strip_prefix('P').ok_or(Iso8601DurationError::Invalid)?;
+		let (date, time) = match s.split_once('T') {
+			Some((_, "")) => return Err(Iso8601DurationError::Invalid),
+			Some((date, time)) => (date, time),
+			None => (s, ""),
+		};
+
+		let mut months = 0u128;
+		let mut nanoseconds = 0u128;
+		let mut count = 0;
+
+		for (components, designators) in [(date, &DATE_DESIGNATORS[..]), (time, &TIME_DESIGNATORS)]
+		{
+			let mut designators = designators.iter();
+			let mut rest = components.as_bytes();
+
+			while !rest.is_empty() {
+				let end = rest
+					.iter()
+					.position(u8::is_ascii_alphabetic)
+					.ok_or(Iso8601DurationError::Invalid)?;
+				let (number, designator) = (&rest[..end], rest[end]);
+				rest = &rest[(end + 1)..];
+
+				let (_, unit) = designators
+					.find(|(d, _)| *d == designator)
+					.ok_or(Iso8601DurationError::Invalid)?;
+
+				match *unit {
+					Unit::Months(n) => months = add(months, scale(number, n)?)?,
+					Unit::Nanoseconds(n) => nanoseconds = add(nanoseconds, scale(number, n)?)?,
+				}
+
+				count += 1
+			}
+		}
+
+		if count == 0 {
+			return Err(Iso8601DurationError::Invalid);
+		}
+
+		let months = u32::try_from(months).map_err(|_| Iso8601DurationError::OutOfRange)?;
+		let seconds = u32::try_from(nanoseconds / NANOSECONDS_PER_SECOND)
+			.map_err(|_| Iso8601DurationError::OutOfRange)?;
+		let nano_seconds = (nanoseconds % NANOSECONDS_PER_SECOND) as u32;
+
+		Ok(Self::new(is_negative, months, seconds, nano_seconds))
+	}
+
+	/// Formats this duration as an ISO 8601 number of weeks (`P2W`), if it
+	/// is exactly a whole number of weeks.
+	///
+	/// ```
+	/// use xsd_types::Duration;
+	///
+	/// let d: Duration = "P14D".parse().unwrap();
+	/// assert_eq!(d.to_iso8601_weeks().unwrap(), "P2W");
+	///
+	/// let d: Duration = "P1M".parse().unwrap();
+	/// assert!(d.to_iso8601_weeks().is_none());
+	/// ```
+	pub fn to_iso8601_weeks(&self) -> Option<String> {
+		const SECONDS_PER_WEEK: u32 = 7 * 86400;
+
+		if self.months != 0 || self.nano_seconds != 0 || self.seconds % SECONDS_PER_WEEK != 0 {
+			return None;
+		}
+
+		let weeks = self.seconds / SECONDS_PER_WEEK;
+		let sign = if self.is_negative && weeks != 0 {
+			"-"
+		} else {
+			""
+		};
+
+		Some(format!("{sign}P{weeks}W"))
+	}
+}
+
+fn add(a: u128, b: u128) -> Result<u128, Iso8601DurationError> {
+	a.checked_add(b).ok_or(Iso8601DurationError::OutOfRange)
+}
+
+/// Multiplies the given decimal number by `unit`, failing if the result is
+/// not an integer.
+fn scale(number: &[u8], unit: u128) -> Result<u128, Iso8601DurationError> {
+	let (integer, fraction) = match number.iter().position(|&b| b == b'.' || b == b',') {
+		Some(i) => (&number[..i], &number[(i + 1)..]),
+		None => (number, &[][..]),
+	};
+
+	if (integer.is_empty() && fraction.is_empty())
+		|| !integer.iter().chain(fraction).all(u8::is_ascii_digit)
+	{
+		return Err(Iso8601DurationError::Invalid);
+	}
+
+	let mut result = 0u128;
+	for &d in integer {
+		result = result
+			.checked_mul(10)
+			.and_then(|r| r.checked_add((d - b'0') as u128))
+			.ok_or(Iso8601DurationError::OutOfRange)?
+	}
+
+	result = result
+		.checked_mul(unit)
+		.ok_or(Iso8601DurationError::OutOfRange)?;
+
+	let len = fraction
+		.iter()
+		.rposition(|&d| d != b'0')
+		.map(|i| i + 1)
+		.unwrap_or(0);
+	if len > MAX_FRACTION_DIGITS {
+		return Err(Iso8601DurationError::Inexact);
+	}
+
+	let mut numerator = 0u128;
+	for &d in &fraction[..len] {
+		numerator = numerator * 10 + (d - b'0') as u128
+	}
+
+	let numerator = numerator * unit;
+	let denominator = 10u128.pow(len as u32);
+	if numerator % denominator != 0 {
+		return Err(Iso8601DurationError::Inexact);
+	}
+
+	add(result, numerator / denominator)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn iso8601() {
+		for (input, expected) in [
+			("P1W", "P7D"),
+			("-P1.5W", "-P10DT12H"),
+			("P0.5Y", "P6M"),
+			("P1Y2M3DT4H5M6.5S", "P1Y2M3DT4H5M6.5S"),
+			("PT0,25M", "PT15S"),
+			("PT1.5H", "PT1H30M"),
+			("P0D", "PT0S"),
+		] {
+			assert_eq!(Duration::from_iso8601(input).unwrap().to_string(), expected)
+		}
+
+		for input in [
+			"", "P", "PT", "1D", "P1DT", "P1H", "PD", "P1D2Y", "P.D", "P1.2.3D",
+		] {
+			assert_eq!(
+				Duration::from_iso8601(input).unwrap_err(),
+				Iso8601DurationError::Invalid,
+				"{input}"
+			)
+		}
+
+		assert_eq!(
+			Duration::from_iso8601("P1.5M").unwrap_err(),
+			Iso8601DurationError::Inexact
+		);
+		assert_eq!(
+			Duration::from_iso8601("PT0.0000000001S").unwrap_err(),
+			Iso8601DurationError::Inexact
+		);
+		assert_eq!(
+			Duration::from_iso8601("P99999999999D").unwrap_err(),
+			Iso8601DurationError::OutOfRange
+		);
+
+		let d = Duration::from_iso8601("-P3W").unwrap();
+		assert_eq!(d.to_iso8601_weeks().unwrap(), "-P3W");
+		assert!(Duration::from_iso8601("P1DT1S")
+			.unwrap()
+			.to_iso8601_weeks()
+			.is_none())
+	}
+}
@@ -1,7 +1,6 @@
 use crate::{
-	format_nanoseconds,
 	lexical::{InvalidDuration, LexicalFormOf},
-	Datatype, DurationDatatype, ParseXsd, XsdValue,
+	Datatype, DurationDatatype, ParseXsd, SecondsParts, XsdValue,
 };
 use core::fmt;
 use std::str::FromStr;
@@ -9,9 +8,18 @@ use std::str::FromStr;
 pub mod day_time_duration;
 pub use day_time_duration::*;
 
+mod iso8601;
+pub use iso8601::*;
+
 pub mod year_month_duration;
 pub use year_month_duration::*;
 
+/// Error returned when the result of an operation on durations does not fit
+/// in the duration representation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("duration overflow")]
+pub struct DurationOverflow;
+
 #[derive(Debug, Clone, Copy)]
 pub struct Duration {
 	is_negative: bool,
@@ -37,6 +45,32 @@ impl Duration {
 		}
 	}
 
+	/// Checks if this duration is zero.
+	pub fn is_zero(&self) -> bool {
+		self.months == 0 && self.seconds == 0 && self.nano_seconds == 0
+	}
+
+	/// Checks if this duration is negative.
+	pub fn is_negative(&self) -> bool {
+		self.is_negative
+	}
+
+	/// Returns the number of months of this duration, without sign.
+	pub fn months(&self) -> u32 {
+		self.months
+	}
+
+	/// Returns the number of whole seconds of this duration, without sign.
+	pub fn seconds(&self) -> u32 {
+		self.seconds
+	}
+
+	/// Returns the fractional part of the seconds of this duration, in
+	/// nanoseconds, without sign.
+	pub fn nano_seconds(&self) -> u32 {
+		self.nano_seconds
+	}
+
 	pub fn into_string(self) -> String {
 		self.to_string()
 	}
@@ -58,17 +92,28 @@ impl FromStr for Duration {
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
 		let lexical_value = crate::lexical::Duration::new(s)
 			.map_err(|InvalidDuration(s)| InvalidDuration(s.to_owned()))?;
-		Ok(lexical_value.as_value())
+		lexical_value
+			.try_as_value()
+			.map_err(|_| InvalidDuration(s.to_owned()))
 	}
 }
 
 impl fmt::Display for Duration {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.is_zero() {
+			// The canonical representation of a zero duration is unsigned
+			// and has a single zero seconds component.
+			return write!(f, "PT0S");
+		}
+
 		let year = self.months / 12;
 		let month = self.months - year * 12;
 
 		let mut minute = self.seconds / 60;
-		let second = self.seconds - minute * 60;
+		let seconds = SecondsParts {
+			seconds: self.seconds - minute * 60,
+			nanoseconds: self.nano_seconds,
+		};
 
 		let mut hour = minute / 60;
 		minute -= hour * 60;
@@ -94,7 +139,7 @@ impl fmt::Display for Duration {
 			write!(f, "{day}D")?;
 		}
 
-		if hour > 0 || minute > 0 || second > 0 || self.nano_seconds > 0 {
+		if hour > 0 || minute > 0 || !seconds.is_zero() {
 			write!(f, "T")?;
 
 			if hour > 0 {
@@ -105,13 +150,8 @@ impl fmt::Display for Duration {
 				write!(f, "{minute}M")?;
 			}
 
-			if second > 0 || self.nano_seconds > 0 {
-				if second > 0 {
-					second.fmt(f)?;
-				}
-
-				format_nanoseconds(self.nano_seconds, f)?;
-				write!(f, "S")?;
+			if !seconds.is_zero() {
+				write!(f, "{seconds}S")?;
 			}
 		}
 
@@ -155,3 +195,45 @@ impl<'de> serde::Deserialize<'de> for Duration {
 		deserializer.deserialize_str(Visitor)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn canonical_zero() {
+		for input in ["P0D", "-PT0S", "P0Y0M0DT0H0M0.0S"] {
+			let value: Duration = input.parse().unwrap();
+			assert!(value.is_zero());
+			assert_eq!(value.to_string(), "PT0S")
+		}
+
+		let value: DayTimeDuration = "-P0D".parse().unwrap();
+		assert!(value.is_zero());
+		assert_eq!(value.to_string(), "PT0S");
+
+		let value: YearMonthDuration = "-P0Y".parse().unwrap();
+		assert!(value.is_zero());
+		assert_eq!(value.to_string(), "P0M");
+
+		let value: Duration = "PT0.5S".parse().unwrap();
+		assert!(!value.is_zero());
+		assert_eq!(value.to_string(), "PT0.5S")
+	}
+
+	#[test]
+	fn scaling() {
+		let value: DayTimeDuration = "PT1H30M0.5S".parse().unwrap();
+		assert_eq!((value * 3).to_string(), "PT4H30M1.5S");
+		assert_eq!(value.checked_mul(0).unwrap().to_string(), "PT0S");
+		assert_eq!(value.checked_mul(u32::MAX).unwrap_err(), DurationOverflow);
+
+		let value: YearMonthDuration = "P3M".parse().unwrap();
+		assert_eq!((value * 10).to_string(), "P2Y6M");
+		assert_eq!((value * -2).to_string(), "-P6M");
+
+		let value: YearMonthDuration = "-P1Y".parse().unwrap();
+		assert_eq!((value * -1).to_string(), "P1Y");
+		assert_eq!(value.checked_mul(i32::MIN).unwrap_err(), DurationOverflow)
+	}
+}
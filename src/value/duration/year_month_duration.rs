@@ -1,9 +1,9 @@
 use crate::{
 	lexical::{duration::InvalidYearMonthDuration, LexicalFormOf},
-	Datatype, DurationDatatype, ParseXsd, XsdValue,
+	Datatype, DurationDatatype, DurationOverflow, ParseXsd, XsdValue,
 };
 use core::fmt;
-use std::str::FromStr;
+use std::{ops::Mul, str::FromStr};
 
 #[derive(Debug, Clone, Copy)]
 pub struct YearMonthDuration {
@@ -19,14 +19,44 @@ impl YearMonthDuration {
 		}
 	}
 
+	/// Checks if this duration is zero.
+	pub fn is_zero(&self) -> bool {
+		self.months == 0
+	}
+
+	/// Multiplies this duration by `n`, failing if the result overflows.
+	///
+	/// A negative `n` flips the sign of the duration.
+	pub fn checked_mul(&self, n: i32) -> Result<Self, DurationOverflow> {
+		let months = self
+			.months
+			.checked_mul(n.unsigned_abs())
+			.ok_or(DurationOverflow)?;
+		Ok(Self::new(self.is_negative != (n < 0), months))
+	}
+
 	pub fn into_string(self) -> String {
 		self.to_string()
 	}
 }
 
+/// Multiplies a duration by an integer.
+///
+/// # Panics
+///
+/// Panics if the result overflows. See [`YearMonthDuration::checked_mul`]
+/// for the non-panicking version.
+impl Mul<i32> for YearMonthDuration {
+	type Output = Self;
+
+	fn mul(self, rhs: i32) -> Self::Output {
+		self.checked_mul(rhs).expect("duration overflow")
+	}
+}
+
 impl XsdValue for YearMonthDuration {
 	fn datatype(&self) -> Datatype {
-		Datatype::Duration(DurationDatatype::Duration)
+		Datatype::Duration(DurationDatatype::YearMonthDuration)
 	}
 }
 
@@ -40,12 +70,18 @@ impl FromStr for YearMonthDuration {
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
 		let lexical_value = crate::lexical::YearMonthDuration::new(s)
 			.map_err(|InvalidYearMonthDuration(s)| InvalidYearMonthDuration(s.to_owned()))?;
-		Ok(lexical_value.as_value())
+		lexical_value
+			.try_as_value()
+			.map_err(|_| InvalidYearMonthDuration(s.to_owned()))
 	}
 }
 
 impl fmt::Display for YearMonthDuration {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.is_zero() {
+			return write!(f, "P0M");
+		}
+
 		let year = self.months / 12;
 		let month = self.months - year * 12;
 
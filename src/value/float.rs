@@ -1,6 +1,7 @@
 use std::{
 	borrow::Borrow,
 	fmt,
+	hash::{Hash, Hasher},
 	ops::{Add, Div, Mul, Sub},
 	str::FromStr,
 };
@@ -12,6 +13,13 @@ use crate::{
 	Datatype, ParseXsd, XsdValue,
 };
 
+/// XSD `float` value.
+///
+/// The [`PartialEq`], [`Eq`], [`Ord`] and [`Hash`] implementations define a
+/// total equivalence where every `NaN` is equal to itself and where positive
+/// and negative zero are equal. Use [`Self::xsd_eq`] for XSD equality,
+/// [`Self::is_identical`] for XSD identity, and [`HashableFloat`] to
+/// distinguish values by bit pattern.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct Float(OrderedFloat<f32>);
@@ -76,21 +84,158 @@ impl Float {
 		self.0 .0.is_sign_negative()
 	}
 
+	/// Returns `true` if this value is negative zero (`-0`).
+	///
+	/// Negative and positive zero are distinct values, but they are equal
+	/// (see [`Self::xsd_eq`]). Some operations, like division, distinguish
+	/// them.
+	#[inline(always)]
+	pub fn is_negative_zero(&self) -> bool {
+		self.0 .0 == 0.0 && self.0 .0.is_sign_negative()
+	}
+
+	/// Returns a number representing the sign of `self`:
+	/// - `1` if the number is greater than zero,
+	/// - `-1` if the number is less than zero,
+	/// - the number itself if it is a (positive or negative) zero or NaN.
+	///
+	/// This differs from [`f32::signum`], which returns `1` for `+0` and `-1`
+	/// for `-0`.
+	#[inline(always)]
+	pub fn signum(&self) -> Self {
+		if self.0 .0 == 0.0 || self.is_nan() {
+			*self
+		} else {
+			Self::new(self.0 .0.signum())
+		}
+	}
+
+	/// Checks if `self` and `other` are equal, following the XSD
+	/// definition of equality: `NaN` is not equal to itself, and positive
+	/// and negative zero are equal.
+	///
+	/// Note that the [`PartialEq`] implementation defines a total
+	/// equivalence where `NaN` is equal to itself, so that values can be
+	/// used as keys.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema11-2/#float>
+	#[inline(always)]
+	pub fn xsd_eq(&self, other: &Self) -> bool {
+		self.0 .0 == other.0 .0
+	}
+
+	/// Checks if `self` and `other` are identical, following the XSD
+	/// definition of identity: `NaN` is identical to itself, but positive
+	/// and negative zero are distinct.
+	///
+	/// See: <https://www.w3.org/TR/xmlschema11-2/#float>
+	#[inline(always)]
+	pub fn is_identical(&self, other: &Self) -> bool {
+		if self.is_nan() {
+			other.is_nan()
+		} else {
+			self.xsd_eq(other) && self.is_negative() == other.is_negative()
+		}
+	}
+
 	/// Converts this value into a `f32`.
 	#[inline(always)]
 	pub const fn into_f32(self) -> f32 {
 		self.0 .0
 	}
+
+	/// Returns the raw IEEE 754 bit pattern of this value.
+	#[inline(always)]
+	pub fn bits(&self) -> u32 {
+		self.0 .0.to_bits()
+	}
+
+	/// Creates a value from its raw IEEE 754 bit pattern.
+	#[inline(always)]
+	pub fn from_bits(bits: u32) -> Self {
+		Self::new(f32::from_bits(bits))
+	}
 }
 
-// <https://www.w3.org/TR/xmlschema11-2/#f-doubleLexmap>
-const XSD_CANONICAL_FLOAT: pretty_dtoa::FmtFloatConfig = pretty_dtoa::FmtFloatConfig::default()
-	.force_e_notation()
-	.capitalize_e(true);
+/// `float` value compared and hashed by bit pattern.
+///
+/// Unlike [`Float`], positive and negative zero are distinct, and so are
+/// `NaN` values with different payloads. This makes it suitable as a key when
+/// every distinct value must be kept apart, as in a dictionary encoding.
+///
+/// ```
+/// use std::collections::HashSet;
+/// use xsd_types::{Float, HashableFloat};
+///
+/// let zeros: HashSet<_> = [0.0, -0.0].into_iter().map(Float::new).collect();
+/// assert_eq!(zeros.len(), 1);
+///
+/// let zeros: HashSet<_> = [0.0, -0.0]
+///     .into_iter()
+///     .map(|f| HashableFloat(Float::new(f)))
+///     .collect();
+/// assert_eq!(zeros.len(), 2);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+pub struct HashableFloat(pub Float);
+
+impl PartialEq for HashableFloat {
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool {
+		self.0.bits() == other.0.bits()
+	}
+}
+
+impl Eq for HashableFloat {}
+
+impl Hash for HashableFloat {
+	#[inline(always)]
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.0.bits().hash(state)
+	}
+}
+
+impl From<Float> for HashableFloat {
+	#[inline(always)]
+	fn from(value: Float) -> Self {
+		Self(value)
+	}
+}
+
+impl From<HashableFloat> for Float {
+	#[inline(always)]
+	fn from(value: HashableFloat) -> Self {
+		value.0
+	}
+}
 
 impl fmt::Display for Float {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		pretty_dtoa::ftoa(self.0 .0, XSD_CANONICAL_FLOAT).fmt(f)
+		let value = self.0 .0;
+		if value.is_nan() {
+			f.write_str("NaN")
+		} else if value.is_infinite() {
+			f.write_str(if value.is_sign_negative() {
+				"-INF"
+			} else {
+				"INF"
+			})
+		} else {
+			// <https://www.w3.org/TR/xmlschema11-2/#f-doubleCanmap>
+			let negative = value.is_sign_negative();
+			if value == 0.0 {
+				crate::value::bounded::fmt_canonical_float(f, negative, 0, 0)
+			} else {
+				let decimal = ryu_floating_decimal::f2d(value);
+				crate::value::bounded::fmt_canonical_float(
+					f,
+					negative,
+					decimal.mantissa.into(),
+					decimal.exponent,
+				)
+			}
+		}
 	}
 }
 
@@ -155,6 +300,32 @@ impl From<Float> for f64 {
 	}
 }
 
+#[cfg(feature = "nightly")]
+impl From<f16> for Float {
+	#[inline(always)]
+	fn from(value: f16) -> Self {
+		Self(OrderedFloat(value as f32))
+	}
+}
+
+#[cfg(feature = "nightly")]
+impl From<Float> for f128 {
+	#[inline(always)]
+	fn from(value: Float) -> Self {
+		value.0 .0 as f128
+	}
+}
+
+#[cfg(feature = "nightly")]
+impl Float {
+	/// Converts this value to `f16`, rounding to the nearest representable
+	/// value.
+	#[inline(always)]
+	pub fn to_f16(&self) -> f16 {
+		self.0 .0 as f16
+	}
+}
+
 impl AsRef<f32> for Float {
 	#[inline(always)]
 	fn as_ref(&self) -> &f32 {
@@ -204,3 +375,22 @@ impl Div for Float {
 		Self(OrderedFloat(*self.0 / *rhs.0))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn display_special() {
+		for (lexical, canonical) in [
+			("INF", "INF"),
+			("-INF", "-INF"),
+			("NaN", "NaN"),
+			("1e309", "INF"),
+		] {
+			let value = Float::parse_xsd(lexical).unwrap();
+			assert_eq!(value.to_string(), canonical);
+			assert_eq!(Float::parse_xsd(canonical).unwrap().bits(), value.bits())
+		}
+	}
+}
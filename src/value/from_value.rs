@@ -0,0 +1,78 @@
+use crate::{Value, ValueRef};
+
+pub(crate) mod sealed {
+	pub trait Sealed {}
+}
+
+/// Payload type of a [`Value`] variant.
+///
+/// This trait is sealed: it is implemented for the payload type of every
+/// [`Value`] variant, and cannot be implemented outside of this crate. It
+/// drives the type-directed extraction methods [`Value::try_into_typed`],
+/// [`Value::as_typed`] and [`ValueRef::as_typed`].
+pub trait FromValue: Sized + sealed::Sealed {
+	/// Payload type of the corresponding [`ValueRef`] variant.
+	type Ref<'a>;
+
+	/// Extracts the payload from the given value, or returns the value
+	/// unchanged if it is not of this type.
+	fn from_value(value: Value) -> Result<Self, Value>;
+
+	/// Extracts the payload from the given value reference, if it is of this
+	/// type.
+	fn from_value_ref(value: ValueRef<'_>) -> Option<Self::Ref<'_>>;
+}
+
+impl Value {
+	/// Extracts the payload of this value if it is of type `T`, or returns
+	/// the value unchanged.
+	///
+	/// ```
+	/// use xsd_types::{Datatype, UnsignedInt, Value, XSD_UNSIGNED_INT};
+	///
+	/// let value = Datatype::from_iri(XSD_UNSIGNED_INT).unwrap().parse("12").unwrap();
+	/// let n: UnsignedInt = value.try_into_typed().unwrap();
+	/// assert_eq!(n, 12);
+	/// ```
+	pub fn try_into_typed<T: FromValue>(self) -> Result<T, Value> {
+		T::from_value(self)
+	}
+
+	/// Returns a reference to the payload of this value, if it is of type `T`.
+	pub fn as_typed<T: FromValue>(&self) -> Option<T::Ref<'_>> {
+		self.as_ref().as_typed::<T>()
+	}
+}
+
+impl<'a> ValueRef<'a> {
+	/// Returns the payload of this value reference, if it is of type `T`.
+	pub fn as_typed<T: FromValue>(self) -> Option<T::Ref<'a>> {
+		T::from_value_ref(self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Boolean, Datatype, Decimal, Integer, String, Value, XSD_DECIMAL, XSD_STRING};
+
+	#[test]
+	fn typed_extraction() {
+		let value = Datatype::from_iri(XSD_DECIMAL)
+			.unwrap()
+			.parse("1.5")
+			.unwrap();
+		assert_eq!(value.as_typed::<Decimal>().unwrap().to_string(), "1.5");
+		assert!(value.as_typed::<Integer>().is_none());
+
+		let value = value.try_into_typed::<Integer>().unwrap_err();
+		assert!(value.try_into_typed::<Decimal>().is_ok());
+
+		let value = Datatype::from_iri(XSD_STRING)
+			.unwrap()
+			.parse("foo")
+			.unwrap();
+		assert_eq!(value.as_typed::<String>(), Some("foo"));
+		assert!(matches!(value.try_into_typed::<String>(), Ok(s) if s == "foo"));
+		assert!(Value::from(Boolean(true)).as_typed::<u8>().is_none())
+	}
+}
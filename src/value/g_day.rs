@@ -17,6 +17,16 @@ impl GDay {
 			None
 		}
 	}
+
+	/// Returns the day of the month, between 1 and 31.
+	pub fn day(&self) -> u8 {
+		self.day
+	}
+
+	/// Returns the timezone offset, if any.
+	pub fn offset(&self) -> Option<FixedOffset> {
+		self.offset
+	}
 }
 
 impl XsdValue for GDay {
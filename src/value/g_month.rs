@@ -17,6 +17,16 @@ impl GMonth {
 			None
 		}
 	}
+
+	/// Returns the month, between 1 and 12.
+	pub fn month(&self) -> u8 {
+		self.month
+	}
+
+	/// Returns the timezone offset, if any.
+	pub fn offset(&self) -> Option<FixedOffset> {
+		self.offset
+	}
 }
 
 impl XsdValue for GMonth {
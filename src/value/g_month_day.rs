@@ -1,6 +1,6 @@
-use chrono::FixedOffset;
+use chrono::{Datelike, FixedOffset, NaiveDate};
 
-use crate::{format_timezone, Datatype, ParseXsd, XsdValue};
+use crate::{clamp_day_to_month, format_timezone, Datatype, ParseXsd, XsdValue};
 use core::fmt;
 
 const MONTH_MAX_LEN: [u8; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
@@ -25,6 +25,50 @@ impl GMonthDay {
 			None
 		}
 	}
+
+	/// Returns the month, between 1 and 12.
+	pub fn month(&self) -> u8 {
+		self.month
+	}
+
+	/// Returns the day of the month, between 1 and 31.
+	pub fn day(&self) -> u8 {
+		self.day
+	}
+
+	/// Returns the timezone offset, if any.
+	pub fn offset(&self) -> Option<FixedOffset> {
+		self.offset
+	}
+
+	/// Returns the first date on or after `from` with this month and day.
+	///
+	/// In years where the day does not exist (February 29th outside of leap
+	/// years), it is clamped to the last day of the month with
+	/// [`clamp_day_to_month`], as done when adding durations to dates.
+	///
+	/// Returns `None` if the result is out of the range supported by
+	/// [`NaiveDate`].
+	///
+	/// ```
+	/// use chrono::NaiveDate;
+	/// use xsd_types::GMonthDay;
+	///
+	/// let leap_day = GMonthDay::new(2, 29, None).unwrap();
+	/// let from = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+	/// assert_eq!(leap_day.next_occurrence(from), NaiveDate::from_ymd_opt(2023, 2, 28));
+	/// ```
+	pub fn next_occurrence(&self, from: NaiveDate) -> Option<NaiveDate> {
+		let in_year = |year: i32| {
+			let day = clamp_day_to_month(year, self.month, self.day)?;
+			NaiveDate::from_ymd_opt(year, self.month.into(), day.into())
+		};
+
+		match in_year(from.year())? {
+			date if date >= from => Some(date),
+			_ => in_year(from.year().checked_add(1)?),
+		}
+	}
 }
 
 impl XsdValue for GMonthDay {
@@ -1,6 +1,9 @@
 use chrono::FixedOffset;
 
-use crate::{format_timezone, Datatype, DisplayYear, ParseXsd, XsdValue};
+use crate::{
+	format_timezone, lexical::Lexical, Datatype, DisplayYear, ParseXsd, ParseXsdError,
+	ParseXsdResult, XsdValue, YearConvention,
+};
 use core::fmt;
 
 #[derive(Debug, Clone, Copy)]
@@ -22,6 +25,42 @@ impl GYearMonth {
 			None
 		}
 	}
+
+	/// Returns the year.
+	pub fn year(&self) -> i32 {
+		self.year
+	}
+
+	/// Returns the month, from 1 to 12.
+	pub fn month(&self) -> u8 {
+		self.month
+	}
+
+	/// Returns the timezone offset, if any.
+	pub fn offset(&self) -> Option<FixedOffset> {
+		self.offset
+	}
+
+	/// Parses a year-month from its lexical form, reading the year with the
+	/// given convention.
+	///
+	/// Year `0000` is out of range in the XSD 1.0 convention. Parsing with
+	/// [`ParseXsd`] uses the default XSD 1.1 convention.
+	pub fn parse_with(
+		s: &str,
+		convention: YearConvention,
+	) -> ParseXsdResult<Self, crate::lexical::GYearMonth> {
+		crate::lexical::GYearMonth::parse(s)
+			.map_err(ParseXsdError::InvalidLexicalForm)?
+			.parts()
+			.to_g_year_month_with(convention)
+			.map_err(ParseXsdError::InvalidValue)
+	}
+
+	/// Returns the year in the given convention.
+	pub fn year_in(&self, convention: YearConvention) -> i32 {
+		convention.from_xsd11(self.year)
+	}
 }
 
 impl XsdValue for GYearMonth {
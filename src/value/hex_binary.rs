@@ -14,7 +14,7 @@ const CHARS: [char; 16] = [
 	'0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
 ];
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 #[error("invalid hexadecimal")]
 pub struct InvalidHex;
 
@@ -147,15 +147,17 @@ impl LexicalFormOf<HexBinaryBuf> for lexical::HexBinary {
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "forbid-unsafe", derive(ref_cast::RefCastCustom))]
+#[repr(transparent)]
 pub struct HexBinary([u8]);
 
 impl HexBinary {
-	pub fn new(bytes: &[u8]) -> &Self {
-		unsafe { std::mem::transmute(bytes) }
+	transparent_cast! {
+		pub const fn new(bytes: &[u8]) -> &Self;
 	}
 
-	pub fn new_mut(bytes: &mut [u8]) -> &mut Self {
-		unsafe { std::mem::transmute(bytes) }
+	transparent_cast! {
+		pub fn new_mut(bytes: &mut [u8]) -> &mut Self;
 	}
 
 	pub fn chars(&self) -> Chars {
@@ -168,6 +170,37 @@ impl HexBinary {
 	pub fn as_bytes(&self) -> &[u8] {
 		&self.0
 	}
+
+	/// Writes the hexadecimal encoding of the value into the given writer,
+	/// without allocating.
+	pub fn write(&self, w: &mut impl fmt::Write) -> fmt::Result {
+		for c in self.chars() {
+			w.write_char(c)?;
+		}
+
+		Ok(())
+	}
+
+	/// Writes the hexadecimal encoding of the value into the given writer,
+	/// inserting a line feed every `width` characters.
+	///
+	/// No line feed is written after the last line. If `width` is `0`, no
+	/// line wrapping is performed.
+	pub fn write_wrapped(&self, width: usize, w: &mut impl fmt::Write) -> fmt::Result {
+		if width == 0 {
+			return self.write(w);
+		}
+
+		for (i, c) in self.chars().enumerate() {
+			if i > 0 && i % width == 0 {
+				w.write_char('\n')?;
+			}
+
+			w.write_char(c)?;
+		}
+
+		Ok(())
+	}
 }
 
 impl<'a> From<&'a [u8]> for &'a HexBinary {
@@ -267,4 +300,13 @@ mod tests {
 			assert_eq!(output.as_bytes(), expected)
 		}
 	}
+
+	#[test]
+	fn wrapped() {
+		let mut output = String::new();
+		HexBinary::new(b"Man")
+			.write_wrapped(4, &mut output)
+			.unwrap();
+		assert_eq!(output, "4D61\n6E")
+	}
 }
@@ -0,0 +1,294 @@
+use core::fmt;
+use std::{cmp::Ordering, str::FromStr};
+
+use crate::{DateTime, Duration, Temporal};
+
+/// Error returned when the result of a comparison between date/times is
+/// undefined.
+///
+/// A date/time without timezone can be in any timezone between `-14:00` and
+/// `+14:00`. It is thus incomparable with a date/time with timezone that
+/// is less than 14 hours apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("indeterminate comparison")]
+pub struct Indeterminate;
+
+/// Invalid interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum InvalidInterval {
+	#[error("interval ends before it starts")]
+	EndBeforeStart,
+
+	#[error("interval bounds are not comparable")]
+	Indeterminate(#[from] Indeterminate),
+
+	#[error("interval end is out of range")]
+	Overflow,
+
+	#[error("invalid interval syntax")]
+	Syntax,
+}
+
+/// Compares two date/times, following the order relation of XSD 1.1.
+///
+/// Date/times that both have, or both lack, a timezone are always
+/// comparable. The comparison is delegated to [`Temporal`], whose
+/// positions on the time line cannot overflow.
+fn compare(a: &DateTime, b: &DateTime) -> Result<Ordering, Indeterminate> {
+	Temporal::DateTime(*a)
+		.partial_cmp(&Temporal::DateTime(*b))
+		.ok_or(Indeterminate)
+}
+
+/// Three-valued conjunction: a determinate `false` wins over an
+/// indeterminate operand.
+fn and(
+	a: Result<bool, Indeterminate>,
+	b: Result<bool, Indeterminate>,
+) -> Result<bool, Indeterminate> {
+	match (a, b) {
+		(Ok(false), _) | (_, Ok(false)) => Ok(false),
+		(Ok(true), Ok(true)) => Ok(true),
+		_ => Err(Indeterminate),
+	}
+}
+
+/// Half-open time interval `[start, end)` between two date/times.
+///
+/// This is not an XSD datatype, but is commonly needed by temporal data
+/// (e.g. OWL-Time). Since date/times without timezone are only partially
+/// ordered with respect to date/times with timezone, operations on intervals
+/// return [`Indeterminate`] when their result depends on the unknown
+/// timezone.
+///
+/// ```
+/// use xsd_types::{DateTime, Interval};
+///
+/// let morning: Interval = "2024-05-17T08:00:00Z/PT4H".parse().unwrap();
+/// let lunch: Interval = "2024-05-17T11:30:00Z/2024-05-17T13:00:00Z".parse().unwrap();
+///
+/// assert_eq!(morning.overlaps(&lunch), Ok(true));
+/// assert_eq!(
+///     morning.intersection(&lunch).unwrap().unwrap().to_string(),
+///     "2024-05-17T11:30:00Z/2024-05-17T12:00:00Z"
+/// );
+///
+/// // Without timezone, the date/time may be anywhere in a 28 hours range.
+/// let t: DateTime = "2024-05-17T10:00:00".parse().unwrap();
+/// assert!(morning.contains(&t).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+	start: DateTime,
+	end: DateTime,
+}
+
+impl Interval {
+	/// Creates a new interval from its bounds.
+	///
+	/// Fails if `end` is before `start`, or if this cannot be determined.
+	pub fn new(start: DateTime, end: DateTime) -> Result<Self, InvalidInterval> {
+		match compare(&start, &end)? {
+			Ordering::Greater => Err(InvalidInterval::EndBeforeStart),
+			_ => Ok(Self { start, end }),
+		}
+	}
+
+	/// Creates a new interval from its start and duration.
+	///
+	/// The end of the interval is computed with
+	/// [`DateTime::checked_add_duration`].
+	pub fn from_duration(start: DateTime, duration: &Duration) -> Result<Self, InvalidInterval> {
+		let end = start
+			.checked_add_duration(duration)
+			.ok_or(InvalidInterval::Overflow)?;
+		Self::new(start, end)
+	}
+
+	/// Returns the (inclusive) start of the interval.
+	pub fn start(&self) -> &DateTime {
+		&self.start
+	}
+
+	/// Returns the (exclusive) end of the interval.
+	pub fn end(&self) -> &DateTime {
+		&self.end
+	}
+
+	/// Checks if the interval is empty, meaning that its start and end are
+	/// equal.
+	pub fn is_empty(&self) -> Result<bool, Indeterminate> {
+		compare(&self.start, &self.end).map(Ordering::is_eq)
+	}
+
+	/// Checks if the given date/time is in this interval.
+	pub fn contains(&self, value: &DateTime) -> Result<bool, Indeterminate> {
+		and(
+			compare(&self.start, value).map(Ordering::is_le),
+			compare(value, &self.end).map(Ordering::is_lt),
+		)
+	}
+
+	/// Checks if the given interval is included in this interval.
+	pub fn contains_interval(&self, other: &Self) -> Result<bool, Indeterminate> {
+		and(
+			compare(&self.start, &other.start).map(Ordering::is_le),
+			compare(&other.end, &self.end).map(Ordering::is_le),
+		)
+	}
+
+	/// Checks if this interval and `other` share at least one instant.
+	pub fn overlaps(&self, other: &Self) -> Result<bool, Indeterminate> {
+		and(
+			compare(&self.start, &other.end).map(Ordering::is_lt),
+			compare(&other.start, &self.end).map(Ordering::is_lt),
+		)
+	}
+
+	/// Computes the intersection of this interval and `other`.
+	///
+	/// Returns `None` if the intervals do not overlap.
+	pub fn intersection(&self, other: &Self) -> Result<Option<Self>, Indeterminate> {
+		if !self.overlaps(other)? {
+			return Ok(None);
+		}
+
+		let start = match compare(&self.start, &other.start)? {
+			Ordering::Less => other.start,
+			_ => self.start,
+		};
+
+		let end = match compare(&self.end, &other.end)? {
+			Ordering::Greater => other.end,
+			_ => self.end,
+		};
+
+		Ok(Some(Self { start, end }))
+	}
+}
+
+/// Displays the interval as an ISO 8601 `start/end` interval.
+impl fmt::Display for Interval {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}/{}", self.start, self.end)
+	}
+}
+
+/// Parses an ISO 8601 interval of the form `start/end` or `start/duration`,
+/// where `start` and `end` are `xsd:dateTime` lexical forms and `duration`
+/// is an `xsd:duration` lexical form.
+impl FromStr for Interval {
+	type Err = InvalidInterval;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (start, end) = s.split_once('/').ok_or(InvalidInterval::Syntax)?;
+		let start: DateTime = start.parse().map_err(|_| InvalidInterval::Syntax)?;
+
+		if end.starts_with('P') || end.starts_with("-P") {
+			let duration: Duration = end.parse().map_err(|_| InvalidInterval::Syntax)?;
+			Self::from_duration(start, &duration)
+		} else {
+			let end: DateTime = end.parse().map_err(|_| InvalidInterval::Syntax)?;
+			Self::new(start, end)
+		}
+	}
+}
+
+impl From<Interval> for (DateTime, DateTime) {
+	fn from(value: Interval) -> Self {
+		(value.start, value.end)
+	}
+}
+
+impl TryFrom<(DateTime, DateTime)> for Interval {
+	type Error = InvalidInterval;
+
+	fn try_from((start, end): (DateTime, DateTime)) -> Result<Self, Self::Error> {
+		Self::new(start, end)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn interval(s: &str) -> Interval {
+		s.parse().unwrap()
+	}
+
+	fn date_time(s: &str) -> DateTime {
+		s.parse().unwrap()
+	}
+
+	#[test]
+	fn intervals() {
+		let a = interval("2024-01-01T00:00:00Z/P1D");
+		assert_eq!(a.end().to_string(), "2024-01-02T00:00:00Z");
+		assert_eq!(
+			a.contains(&date_time("2024-01-01T23:00:00-02:00")),
+			Ok(false)
+		);
+		assert_eq!(
+			a.contains(&date_time("2024-01-01T23:00:00+02:00")),
+			Ok(true)
+		);
+		assert_eq!(a.contains(a.end()), Ok(false));
+
+		let b = interval("2024-01-01T12:00:00Z/2024-01-03T00:00:00Z");
+		assert_eq!(a.overlaps(&b), Ok(true));
+		assert_eq!(a.contains_interval(&b), Ok(false));
+		assert_eq!(
+			a.intersection(&b).unwrap().unwrap().to_string(),
+			"2024-01-01T12:00:00Z/2024-01-02T00:00:00Z"
+		);
+
+		let c = interval("2024-01-02T00:00:00Z/PT1H");
+		assert_eq!(a.overlaps(&c), Ok(false));
+		assert_eq!(a.intersection(&c), Ok(None));
+
+		assert_eq!(
+			"2024-01-02T00:00:00Z/2024-01-01T00:00:00Z".parse::<Interval>(),
+			Err(InvalidInterval::EndBeforeStart)
+		);
+		assert_eq!(
+			"2024-01-02T00:00:00Z/-P1D".parse::<Interval>(),
+			Err(InvalidInterval::EndBeforeStart)
+		);
+	}
+
+	#[test]
+	fn indeterminate() {
+		let a = interval("2024-01-01T00:00:00Z/P3D");
+
+		// Without timezone, comparisons are only defined more than 14 hours
+		// away from the bounds.
+		assert_eq!(a.contains(&date_time("2024-01-02T12:00:00")), Ok(true));
+		assert_eq!(
+			a.contains(&date_time("2024-01-01T06:00:00")),
+			Err(Indeterminate)
+		);
+		assert_eq!(a.contains(&date_time("2023-12-31T09:00:00")), Ok(false));
+
+		// A determinate `false` wins.
+		let b = interval("2024-01-05T00:00:00/P1D");
+		assert_eq!(a.overlaps(&b), Ok(false));
+
+		let c = interval("2024-01-04T05:00:00/P1D");
+		assert_eq!(a.overlaps(&c), Err(Indeterminate));
+		assert_eq!(a.intersection(&c), Err(Indeterminate));
+
+		assert_eq!(
+			"2024-01-01T00:00:00Z/2024-01-01T10:00:00".parse::<Interval>(),
+			Err(InvalidInterval::Indeterminate(Indeterminate))
+		);
+	}
+
+	#[test]
+	fn range_bounds() {
+		let a = interval("262142-12-31T20:00:00/PT1H");
+		assert_eq!(a.contains(&date_time("262142-12-31T20:30:00")), Ok(true));
+
+		let b = interval("-262143-01-01T01:00:00/PT1H");
+		assert_eq!(b.is_empty(), Ok(false));
+	}
+}
@@ -0,0 +1,183 @@
+use crate::{
+	AnyUri, AnyUriBuf, Base64Binary, Base64BinaryBuf, HexBinary, HexBinaryBuf, Id, IdBuf, IdRef,
+	IdRefBuf, IdRefsBuf, Language, LanguageBuf, NCName, NCNameBuf, NMToken, NMTokenBuf,
+	NMTokensBuf, Name, NameBuf, NormalizedStr, NormalizedString, Token, TokenBuf, Value, ValueRef,
+};
+
+/// Value measured by the `length`, `minLength` and `maxLength` facets.
+///
+/// The unit of length depends on the datatype:
+/// - string datatypes (and `xsd:anyURI`) are measured in characters (Unicode
+///   code points, not bytes);
+/// - binary datatypes (`xsd:hexBinary` and `xsd:base64Binary`) are measured
+///   in octets of binary data, not characters of their lexical form;
+/// - list datatypes are measured in items.
+///
+/// ```
+/// use xsd_types::{FacetLength, HexBinaryBuf};
+///
+/// assert_eq!("héllo".facet_length(), 5);
+///
+/// let bytes: HexBinaryBuf = "0FB7".parse().unwrap();
+/// assert_eq!(bytes.facet_length(), 2);
+/// ```
+pub trait FacetLength {
+	/// Returns the length of this value, in the unit of its datatype.
+	fn facet_length(&self) -> usize;
+
+	/// Checks if this value satisfies the `length` facet.
+	fn has_length(&self, length: usize) -> bool {
+		self.facet_length() == length
+	}
+
+	/// Checks if this value satisfies the `minLength` facet.
+	fn has_min_length(&self, min_length: usize) -> bool {
+		self.facet_length() >= min_length
+	}
+
+	/// Checks if this value satisfies the `maxLength` facet.
+	fn has_max_length(&self, max_length: usize) -> bool {
+		self.facet_length() <= max_length
+	}
+}
+
+impl<T: ?Sized + FacetLength> FacetLength for &T {
+	fn facet_length(&self) -> usize {
+		T::facet_length(*self)
+	}
+}
+
+impl FacetLength for str {
+	fn facet_length(&self) -> usize {
+		self.chars().count()
+	}
+}
+
+impl FacetLength for String {
+	fn facet_length(&self) -> usize {
+		self.as_str().facet_length()
+	}
+}
+
+/// Implements `FacetLength` for string types, counting the characters of
+/// their lexical form.
+macro_rules! char_length {
+	($($ty:ty),*) => {
+		$(
+			impl FacetLength for $ty {
+				fn facet_length(&self) -> usize {
+					self.as_str().facet_length()
+				}
+			}
+		)*
+	};
+}
+
+char_length!(
+	NormalizedStr,
+	NormalizedString,
+	Token,
+	TokenBuf,
+	Language,
+	LanguageBuf,
+	Name,
+	NameBuf,
+	NCName,
+	NCNameBuf,
+	Id,
+	IdBuf,
+	IdRef,
+	IdRefBuf,
+	NMToken,
+	NMTokenBuf,
+	AnyUri,
+	AnyUriBuf
+);
+
+/// Implements `FacetLength` for binary types, counting octets.
+macro_rules! octet_length {
+	($($ty:ty),*) => {
+		$(
+			impl FacetLength for $ty {
+				fn facet_length(&self) -> usize {
+					self.as_bytes().len()
+				}
+			}
+		)*
+	};
+}
+
+octet_length!(HexBinary, HexBinaryBuf, Base64Binary, Base64BinaryBuf);
+
+/// Implements `FacetLength` for list types, counting items.
+macro_rules! item_length {
+	($($ty:ty),*) => {
+		$(
+			impl FacetLength for $ty {
+				fn facet_length(&self) -> usize {
+					self.len()
+				}
+			}
+		)*
+	};
+}
+
+item_length!(NMTokensBuf, IdRefsBuf);
+
+impl<'a> ValueRef<'a> {
+	/// Returns the length of the value as measured by the `length`,
+	/// `minLength` and `maxLength` facets, if these facets apply to its
+	/// datatype.
+	///
+	/// See [`FacetLength`] for the unit of length of each datatype.
+	pub fn facet_length(&self) -> Option<usize> {
+		match self {
+			Self::String(v) => Some(v.facet_length()),
+			Self::NormalizedString(v) => Some(v.facet_length()),
+			Self::Token(v) => Some(v.facet_length()),
+			Self::Language(v) => Some(v.facet_length()),
+			Self::Name(v) => Some(v.facet_length()),
+			Self::NCName(v) => Some(v.facet_length()),
+			Self::Id(v) => Some(v.facet_length()),
+			Self::IdRef(v) => Some(v.facet_length()),
+			Self::NMToken(v) => Some(v.facet_length()),
+			Self::Base64Binary(v) => Some(v.facet_length()),
+			Self::HexBinary(v) => Some(v.facet_length()),
+			Self::AnyUri(v) => Some(v.facet_length()),
+			_ => None,
+		}
+	}
+}
+
+impl Value {
+	/// Returns the length of the value as measured by the `length`,
+	/// `minLength` and `maxLength` facets, if these facets apply to its
+	/// datatype.
+	///
+	/// See [`FacetLength`] for the unit of length of each datatype.
+	pub fn facet_length(&self) -> Option<usize> {
+		self.as_ref().facet_length()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn units() {
+		assert_eq!("日本語".facet_length(), 3);
+		assert!(Token::new("a b").unwrap().has_length(3));
+
+		let hex: HexBinaryBuf = "DEADBEEF".parse().unwrap();
+		assert_eq!(hex.facet_length(), 4);
+		assert!(hex.has_max_length(4) && !hex.has_min_length(5));
+
+		let value = Value::HexBinary(hex);
+		assert_eq!(value.facet_length(), Some(4));
+		assert_eq!(Value::Boolean(true.into()).facet_length(), None);
+
+		let tokens: NMTokensBuf = "a bb ccc".parse().unwrap();
+		assert_eq!(tokens.facet_length(), 3)
+	}
+}
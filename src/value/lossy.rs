@@ -0,0 +1,84 @@
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive};
+
+use crate::{Decimal, Integer};
+
+/// Narrowing conversions reporting information loss.
+///
+/// Each method returns the converted value along with a boolean which is
+/// `true` if the conversion lost information (the converted value does not
+/// represent exactly the original value).
+pub trait ConvertLossy {
+	/// Converts the value into a `f32`.
+	///
+	/// Values too large to be represented are converted into an infinity.
+	fn to_f32_lossy(&self) -> (f32, bool);
+
+	/// Converts the value into a `f64`.
+	///
+	/// Values too large to be represented are converted into an infinity.
+	fn to_f64_lossy(&self) -> (f64, bool);
+}
+
+fn rational_to_f32_lossy(r: &BigRational) -> (f32, bool) {
+	match r.to_f32() {
+		Some(f) if f.is_finite() => (f, BigRational::from_float(f).as_ref() != Some(r)),
+		_ if r.is_negative() => (f32::NEG_INFINITY, true),
+		_ => (f32::INFINITY, true),
+	}
+}
+
+fn rational_to_f64_lossy(r: &BigRational) -> (f64, bool) {
+	match r.to_f64() {
+		Some(f) if f.is_finite() => (f, BigRational::from_float(f).as_ref() != Some(r)),
+		_ if r.is_negative() => (f64::NEG_INFINITY, true),
+		_ => (f64::INFINITY, true),
+	}
+}
+
+impl ConvertLossy for Decimal {
+	fn to_f32_lossy(&self) -> (f32, bool) {
+		rational_to_f32_lossy(self.as_big_rational())
+	}
+
+	fn to_f64_lossy(&self) -> (f64, bool) {
+		rational_to_f64_lossy(self.as_big_rational())
+	}
+}
+
+impl ConvertLossy for Integer {
+	fn to_f32_lossy(&self) -> (f32, bool) {
+		let n: &BigInt = self.as_ref();
+		rational_to_f32_lossy(&BigRational::from_integer(n.clone()))
+	}
+
+	fn to_f64_lossy(&self) -> (f64, bool) {
+		let n: &BigInt = self.as_ref();
+		rational_to_f64_lossy(&BigRational::from_integer(n.clone()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn decimal_to_f64() {
+		let exact: Decimal = "0.5".parse().unwrap();
+		assert_eq!(exact.to_f64_lossy(), (0.5, false));
+
+		let inexact: Decimal = "0.1".parse().unwrap();
+		assert_eq!(inexact.to_f64_lossy(), (0.1, true))
+	}
+
+	#[test]
+	fn integer_to_f64() {
+		let exact = Integer::from(1i64 << 53);
+		assert!(!exact.to_f64_lossy().1);
+
+		let inexact = Integer::from((1i64 << 53) + 1);
+		assert!(inexact.to_f64_lossy().1);
+		assert!(Integer::from(16_777_217i32).to_f32_lossy().1)
+	}
+}
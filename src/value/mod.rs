@@ -1,54 +1,186 @@
+mod aggregate;
 mod any_uri;
 pub mod base64_binary;
 mod boolean;
+mod bounded;
+mod calendar;
+mod construct;
 mod date;
 mod date_time;
 mod decimal;
 mod double;
 mod duration;
 mod float;
+mod from_value;
 mod g_day;
 mod g_month;
 mod g_month_day;
 mod g_year;
 mod g_year_month;
 pub mod hex_binary;
+mod interval;
+mod length;
+mod lossy;
+#[cfg(feature = "oxsdatatypes")]
+mod oxsdatatypes;
+mod partial_date;
+#[cfg(feature = "prost")]
+mod prost;
 mod q_name;
+mod seconds;
 mod string;
+mod temporal;
 mod time;
+mod typed;
+mod year;
 
+#[cfg(feature = "oxsdatatypes")]
+pub use self::oxsdatatypes::*;
+#[cfg(feature = "prost")]
+pub use self::prost::*;
+pub use aggregate::*;
 pub use any_uri::*;
 pub use base64_binary::{Base64Binary, Base64BinaryBuf, InvalidBase64};
 pub use boolean::*;
+pub use bounded::*;
+pub use calendar::*;
+pub use construct::*;
 pub use date::*;
 pub use date_time::*;
 pub use decimal::*;
 pub use double::*;
 pub use duration::*;
 pub use float::*;
+pub use from_value::*;
 pub use g_day::*;
 pub use g_month::*;
 pub use g_month_day::*;
 pub use g_year::*;
 pub use g_year_month::*;
 pub use hex_binary::{HexBinary, HexBinaryBuf, InvalidHex};
+pub use interval::*;
+pub use length::*;
+pub use lossy::*;
+pub use partial_date::*;
 pub use q_name::*;
+pub use seconds::*;
 pub use string::*;
+pub use temporal::*;
 pub use time::*;
+pub use typed::*;
+pub use year::*;
 
-use crate::{Datatype, Value, ValueRef};
+use std::{cmp::Ordering, fmt};
+
+use crate::{Datatype, Value, ValueRef, XSD_NAMESPACE};
+
+/// Implements [`FromStr`](std::str::FromStr) for the given value types
+/// through their [`ParseXsd`](crate::ParseXsd) implementation.
+macro_rules! from_str_via_parse_xsd {
+	($($ty:ty),*) => {
+		$(
+			impl std::str::FromStr for $ty {
+				type Err = crate::ParseXsdError<
+					<<$ty as crate::ParseXsd>::LexicalForm as crate::Lexical>::Error,
+					<<$ty as crate::ParseXsd>::LexicalForm as crate::lexical::LexicalFormOf<$ty>>::ValueError,
+				>;
+
+				fn from_str(s: &str) -> Result<Self, Self::Err> {
+					<$ty as crate::ParseXsd>::parse_xsd(s)
+				}
+			}
+		)*
+	};
+}
+
+from_str_via_parse_xsd!(Time, GYearMonth, GYear, GMonthDay, GDay, GMonth);
 
 pub trait XsdValue {
 	/// Returns the XSD datatype that best describes the value.
 	fn datatype(&self) -> Datatype;
 }
 
+impl Value {
+	/// Compares two values using a canonical total order.
+	///
+	/// Values are first ordered by datatype (see [`Datatype`] for how
+	/// datatypes are ordered), then by value. Values of datatypes without a
+	/// total order (durations, dates and times) are ordered by their
+	/// canonical lexical representation.
+	///
+	/// This order is deterministic and stable across versions, which makes
+	/// it suitable to build indexes over values.
+	pub fn canonical_cmp(&self, other: &Self) -> Ordering {
+		self.datatype()
+			.cmp(&other.datatype())
+			.then_with(|| match (self, other) {
+				(Self::Boolean(a), Self::Boolean(b)) => a.cmp(b),
+				(Self::Float(a), Self::Float(b)) => a.cmp(b),
+				(Self::Double(a), Self::Double(b)) => a.cmp(b),
+				(Self::Decimal(a), Self::Decimal(b)) => a.cmp(b),
+				(Self::Integer(a), Self::Integer(b)) => a.cmp(b),
+				(Self::NonPositiveInteger(a), Self::NonPositiveInteger(b)) => a.cmp(b),
+				(Self::NegativeInteger(a), Self::NegativeInteger(b)) => a.cmp(b),
+				(Self::NonNegativeInteger(a), Self::NonNegativeInteger(b)) => a.cmp(b),
+				(Self::PositiveInteger(a), Self::PositiveInteger(b)) => a.cmp(b),
+				(Self::UnsignedLong(a), Self::UnsignedLong(b)) => a.cmp(b),
+				(Self::UnsignedInt(a), Self::UnsignedInt(b)) => a.cmp(b),
+				(Self::UnsignedShort(a), Self::UnsignedShort(b)) => a.cmp(b),
+				(Self::UnsignedByte(a), Self::UnsignedByte(b)) => a.cmp(b),
+				(Self::Long(a), Self::Long(b)) => a.cmp(b),
+				(Self::Int(a), Self::Int(b)) => a.cmp(b),
+				(Self::Short(a), Self::Short(b)) => a.cmp(b),
+				(Self::Byte(a), Self::Byte(b)) => a.cmp(b),
+				(Self::String(a), Self::String(b)) => a.cmp(b),
+				(Self::NormalizedString(a), Self::NormalizedString(b)) => a.cmp(b),
+				(Self::Token(a), Self::Token(b)) => a.cmp(b),
+				(Self::Language(a), Self::Language(b)) => a.cmp(b),
+				(Self::Name(a), Self::Name(b)) => a.cmp(b),
+				(Self::NCName(a), Self::NCName(b)) => a.cmp(b),
+				(Self::Id(a), Self::Id(b)) => a.cmp(b),
+				(Self::IdRef(a), Self::IdRef(b)) => a.cmp(b),
+				(Self::NMToken(a), Self::NMToken(b)) => a.cmp(b),
+				(Self::DateTimeStamp(a), Self::DateTimeStamp(b)) => a.cmp(b),
+				(Self::Base64Binary(a), Self::Base64Binary(b)) => a.cmp(b),
+				(Self::HexBinary(a), Self::HexBinary(b)) => a.cmp(b),
+				(Self::AnyUri(a), Self::AnyUri(b)) => a.as_str().cmp(b.as_str()),
+				(Self::QName(a), Self::QName(b)) => a.as_str().cmp(b.as_str()),
+				_ => self.to_string().cmp(&other.to_string()),
+			})
+	}
+}
+
+/// Values are equal if they are equal according to
+/// [`Value::canonical_cmp`]: they must have the same datatype.
+impl PartialEq for Value {
+	fn eq(&self, other: &Self) -> bool {
+		self.canonical_cmp(other).is_eq()
+	}
+}
+
+impl Eq for Value {}
+
 impl From<Value> for std::string::String {
 	fn from(value: Value) -> Self {
 		value.to_string()
 	}
 }
 
+impl fmt::Debug for Value {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.as_ref().fmt(f)
+	}
+}
+
+/// Formats the value as `xsd:<datatype>("<canonical form>")`.
+impl<'a> fmt::Debug for ValueRef<'a> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let iri = self.datatype().iri().as_str();
+		let name = iri.strip_prefix(XSD_NAMESPACE).unwrap_or(iri);
+		write!(f, "xsd:{name}({:?})", self.to_string())
+	}
+}
+
 pub enum CowValue<'a> {
 	Borrowed(ValueRef<'a>),
 	Owned(Value),
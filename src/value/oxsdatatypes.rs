@@ -0,0 +1,123 @@
+//! Conversions from and to [`oxsdatatypes`] value types.
+use crate::{
+	Boolean, Date, DateTime, DayTimeDuration, Decimal, Double, Duration, Float, GDay, GMonth,
+	GMonthDay, GYear, GYearMonth, Integer, ParseXsd, Time, YearMonthDuration,
+};
+
+/// Error returned when a value cannot be converted from or into an
+/// [`oxsdatatypes`] value.
+///
+/// This happens when the value is outside of the value space supported by
+/// the target type (for instance, `oxsdatatypes` integers are 64-bit).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("value out of range")]
+pub struct OxsdatatypesConversionError;
+
+impl From<Boolean> for ::oxsdatatypes::Boolean {
+	fn from(value: Boolean) -> Self {
+		value.0.into()
+	}
+}
+
+impl From<::oxsdatatypes::Boolean> for Boolean {
+	fn from(value: ::oxsdatatypes::Boolean) -> Self {
+		Self(value.into())
+	}
+}
+
+impl From<Float> for ::oxsdatatypes::Float {
+	fn from(value: Float) -> Self {
+		f32::from(value).into()
+	}
+}
+
+impl From<::oxsdatatypes::Float> for Float {
+	fn from(value: ::oxsdatatypes::Float) -> Self {
+		f32::from(value).into()
+	}
+}
+
+impl From<Double> for ::oxsdatatypes::Double {
+	fn from(value: Double) -> Self {
+		f64::from(value).into()
+	}
+}
+
+impl From<::oxsdatatypes::Double> for Double {
+	fn from(value: ::oxsdatatypes::Double) -> Self {
+		f64::from(value).into()
+	}
+}
+
+/// Implements conversions through the canonical lexical representation of
+/// the values.
+macro_rules! lexical_conversion {
+	($($ty:ident),*) => {
+		$(
+			impl<'a> TryFrom<&'a $ty> for ::oxsdatatypes::$ty {
+				type Error = OxsdatatypesConversionError;
+
+				fn try_from(value: &'a $ty) -> Result<Self, Self::Error> {
+					value
+						.to_string()
+						.parse()
+						.map_err(|_| OxsdatatypesConversionError)
+				}
+			}
+
+			impl TryFrom<$ty> for ::oxsdatatypes::$ty {
+				type Error = OxsdatatypesConversionError;
+
+				fn try_from(value: $ty) -> Result<Self, Self::Error> {
+					(&value).try_into()
+				}
+			}
+
+			impl TryFrom<::oxsdatatypes::$ty> for $ty {
+				type Error = OxsdatatypesConversionError;
+
+				fn try_from(value: ::oxsdatatypes::$ty) -> Result<Self, Self::Error> {
+					$ty::parse_xsd(&value.to_string()).map_err(|_| OxsdatatypesConversionError)
+				}
+			}
+		)*
+	};
+}
+
+lexical_conversion!(
+	Decimal,
+	Integer,
+	DateTime,
+	Time,
+	Date,
+	GYearMonth,
+	GYear,
+	GMonthDay,
+	GDay,
+	GMonth,
+	Duration,
+	YearMonthDuration,
+	DayTimeDuration
+);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn integer_roundtrip() {
+		let value: Integer = "-42".parse().unwrap();
+		let ox: ::oxsdatatypes::Integer = (&value).try_into().unwrap();
+		assert_eq!(Integer::try_from(ox).unwrap(), value);
+
+		let large: Integer = "99999999999999999999".parse().unwrap();
+		assert!(::oxsdatatypes::Integer::try_from(large).is_err())
+	}
+
+	#[test]
+	fn date_time_roundtrip() {
+		let value: DateTime = "2002-05-31T13:07:12+01:00".parse().unwrap();
+		let ox: ::oxsdatatypes::DateTime = (&value).try_into().unwrap();
+		assert_eq!(DateTime::try_from(ox).unwrap(), value)
+	}
+}
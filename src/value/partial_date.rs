@@ -0,0 +1,262 @@
+use chrono::{Datelike, NaiveDate};
+use core::fmt;
+use std::str::FromStr;
+
+use crate::{days_in_month, Date, DisplayYear, GYear, GYearMonth};
+
+/// Precision of a [`PartialDate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DatePrecision {
+	Year,
+	Month,
+	Day,
+}
+
+/// Invalid partial date.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid partial date `{0}`")]
+pub struct InvalidPartialDate(pub String);
+
+/// Date with reduced precision: a year, optionally followed by a month,
+/// optionally followed by a day.
+///
+/// This is not an XSD datatype. It allows `xsd:gYear`, `xsd:gYearMonth` and
+/// `xsd:date` values (such as `2024`, `2024-05` and `2024-05-17`) to be
+/// handled uniformly. Timezones are ignored.
+///
+/// Partial dates are ordered chronologically by their first day, and a
+/// partial date comes before the more precise dates it contains.
+///
+/// ```
+/// use xsd_types::PartialDate;
+///
+/// let year: PartialDate = "2024".parse().unwrap();
+/// let month: PartialDate = "2024-05".parse().unwrap();
+/// let day: PartialDate = "2024-05-17".parse().unwrap();
+///
+/// assert!(year.contains(&day) && month.contains(&day));
+/// assert!(!day.contains(&month));
+/// assert!(year < month && month < day);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PartialDate {
+	year: i32,
+	month: Option<u8>,
+	day: Option<u8>,
+}
+
+impl PartialDate {
+	/// Creates a partial date with year precision.
+	pub fn from_year(year: i32) -> Self {
+		Self {
+			year,
+			month: None,
+			day: None,
+		}
+	}
+
+	/// Creates a partial date with month precision.
+	///
+	/// Returns `None` if the month is not between 1 and 12.
+	pub fn from_year_month(year: i32, month: u8) -> Option<Self> {
+		(1..=12).contains(&month).then_some(Self {
+			year,
+			month: Some(month),
+			day: None,
+		})
+	}
+
+	/// Creates a partial date with day precision.
+	///
+	/// Returns `None` if the date does not exist.
+	pub fn from_ymd(year: i32, month: u8, day: u8) -> Option<Self> {
+		NaiveDate::from_ymd_opt(year, month as u32, day as u32).map(Self::from)
+	}
+
+	/// Returns the year.
+	pub fn year(&self) -> i32 {
+		self.year
+	}
+
+	/// Returns the month, from 1 to 12, if the precision is at least the
+	/// month.
+	pub fn month(&self) -> Option<u8> {
+		self.month
+	}
+
+	/// Returns the day of the month, starting from 1, if the precision is
+	/// the day.
+	pub fn day(&self) -> Option<u8> {
+		self.day
+	}
+
+	/// Returns the precision of this partial date.
+	pub fn precision(&self) -> DatePrecision {
+		match (self.month, self.day) {
+			(None, _) => DatePrecision::Year,
+			(Some(_), None) => DatePrecision::Month,
+			(Some(_), Some(_)) => DatePrecision::Day,
+		}
+	}
+
+	/// Returns the first day covered by this partial date.
+	///
+	/// Returns `None` if the year is out of the range supported by
+	/// [`NaiveDate`].
+	pub fn first_day(&self) -> Option<NaiveDate> {
+		NaiveDate::from_ymd_opt(
+			self.year,
+			self.month.unwrap_or(1) as u32,
+			self.day.unwrap_or(1) as u32,
+		)
+	}
+
+	/// Returns the last day covered by this partial date.
+	///
+	/// Returns `None` if the year is out of the range supported by
+	/// [`NaiveDate`].
+	pub fn last_day(&self) -> Option<NaiveDate> {
+		match (self.month, self.day) {
+			(None, _) => NaiveDate::from_ymd_opt(self.year, 12, 31),
+			(Some(month), None) => NaiveDate::from_ymd_opt(
+				self.year,
+				month.into(),
+				days_in_month(self.year, month)?.into(),
+			),
+			(Some(_), Some(_)) => self.first_day(),
+		}
+	}
+
+	/// Checks if every day covered by `other` is covered by this partial
+	/// date.
+	pub fn contains(&self, other: &Self) -> bool {
+		self.year == other.year
+			&& match (self.month, other.month) {
+				(None, _) => true,
+				(Some(a), Some(b)) if a == b => match (self.day, other.day) {
+					(None, _) => true,
+					(Some(a), Some(b)) => a == b,
+					(Some(_), None) => false,
+				},
+				_ => false,
+			}
+	}
+
+	/// Checks if this partial date and `other` cover at least one common
+	/// day.
+	///
+	/// Since partial dates cover whole years, months or days, this is the
+	/// case if and only if one contains the other.
+	pub fn intersects(&self, other: &Self) -> bool {
+		self.contains(other) || other.contains(self)
+	}
+
+	/// Truncates this partial date to the given precision, if it is not
+	/// already less precise.
+	pub fn truncate(&self, precision: DatePrecision) -> Self {
+		match precision {
+			DatePrecision::Year => Self::from_year(self.year),
+			DatePrecision::Month => Self { day: None, ..*self },
+			DatePrecision::Day => *self,
+		}
+	}
+}
+
+impl fmt::Display for PartialDate {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		DisplayYear(self.year).fmt(f)?;
+
+		if let Some(month) = self.month {
+			write!(f, "-{month:02}")?;
+		}
+
+		if let Some(day) = self.day {
+			write!(f, "-{day:02}")?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Parses an `xsd:date`, `xsd:gYearMonth` or `xsd:gYear` lexical form,
+/// ignoring its timezone.
+impl FromStr for PartialDate {
+	type Err = InvalidPartialDate;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		if let Ok(date) = s.parse::<Date>() {
+			return Ok(date.into());
+		}
+
+		if let Ok(year_month) = s.parse::<GYearMonth>() {
+			return Ok(year_month.into());
+		}
+
+		s.parse::<GYear>()
+			.map(Into::into)
+			.map_err(|_| InvalidPartialDate(s.to_owned()))
+	}
+}
+
+impl From<NaiveDate> for PartialDate {
+	fn from(value: NaiveDate) -> Self {
+		Self {
+			year: value.year(),
+			month: Some(value.month() as u8),
+			day: Some(value.day() as u8),
+		}
+	}
+}
+
+impl From<Date> for PartialDate {
+	fn from(value: Date) -> Self {
+		(*value.naive()).into()
+	}
+}
+
+impl From<GYearMonth> for PartialDate {
+	fn from(value: GYearMonth) -> Self {
+		Self {
+			year: value.year(),
+			month: Some(value.month()),
+			day: None,
+		}
+	}
+}
+
+impl From<GYear> for PartialDate {
+	fn from(value: GYear) -> Self {
+		Self::from_year(value.year())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn partial_dates() {
+		let dates: Vec<PartialDate> = ["2024-05-17", "2023", "2024-05", "2024", "2024-04-30"]
+			.into_iter()
+			.map(|s| s.parse().unwrap())
+			.collect();
+
+		let mut sorted = dates.clone();
+		sorted.sort();
+		assert_eq!(
+			sorted.iter().map(ToString::to_string).collect::<Vec<_>>(),
+			["2023", "2024", "2024-04-30", "2024-05", "2024-05-17"]
+		);
+
+		assert!(dates[2].intersects(&dates[0]) && dates[0].intersects(&dates[2]));
+		assert!(!dates[2].intersects(&dates[4]));
+		assert!(!dates[1].intersects(&dates[3]));
+
+		let february = PartialDate::from_year_month(2024, 2).unwrap();
+		assert_eq!(february.last_day(), NaiveDate::from_ymd_opt(2024, 2, 29));
+		assert_eq!(dates[0].truncate(DatePrecision::Month), dates[2]);
+
+		assert!(PartialDate::from_ymd(2023, 2, 29).is_none());
+		assert!("2024-05-17T00:00:00".parse::<PartialDate>().is_err())
+	}
+}
@@ -0,0 +1,327 @@
+//! Conversions from and to [`prost_types`] well-known types.
+use chrono::NaiveDate;
+
+use crate::{
+	ConvertLossy, DateTime, DateTimeStamp, DayTimeDuration, Double, Duration, Integer, Value,
+};
+
+/// Smallest `google.protobuf.Timestamp` seconds, `0001-01-01T00:00:00Z`.
+const MIN_TIMESTAMP_SECONDS: i64 = -62_135_596_800;
+
+/// Largest `google.protobuf.Timestamp` seconds, `9999-12-31T23:59:59Z`.
+const MAX_TIMESTAMP_SECONDS: i64 = 253_402_300_799;
+
+const NANOSECONDS_PER_SECOND: i32 = 1_000_000_000;
+
+/// Error returned when a value cannot be converted from or into a
+/// [`prost_types`] well-known type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ProstConversionError {
+	/// The value is outside of the range supported by the target type.
+	#[error("value out of range")]
+	OutOfRange,
+
+	/// A timestamp is converted from a date/time without timezone.
+	#[error("missing timezone")]
+	MissingTimezone,
+
+	/// A protobuf duration is converted from a duration with months, which
+	/// have no fixed length.
+	#[error("calendar duration")]
+	CalendarDuration,
+
+	/// The input value is not valid, or has no counterpart in the target
+	/// type.
+	#[error("invalid value")]
+	Invalid,
+}
+
+impl TryFrom<DateTimeStamp> for ::prost_types::Timestamp {
+	type Error = ProstConversionError;
+
+	fn try_from(value: DateTimeStamp) -> Result<Self, Self::Error> {
+		let date_time = value.to_chrono_date_time();
+		let seconds = date_time.timestamp();
+
+		if !(MIN_TIMESTAMP_SECONDS..=MAX_TIMESTAMP_SECONDS).contains(&seconds) {
+			return Err(ProstConversionError::OutOfRange);
+		}
+
+		Ok(Self {
+			seconds,
+			nanos: date_time.timestamp_subsec_nanos() as i32,
+		})
+	}
+}
+
+/// Fails with [`ProstConversionError::MissingTimezone`] if the date/time has
+/// no timezone.
+impl TryFrom<DateTime> for ::prost_types::Timestamp {
+	type Error = ProstConversionError;
+
+	fn try_from(value: DateTime) -> Result<Self, Self::Error> {
+		let offset = value
+			.offset()
+			.ok_or(ProstConversionError::MissingTimezone)?;
+		DateTimeStamp::new(*value.naive(), offset).try_into()
+	}
+}
+
+/// Converts a timestamp into a date/time stamp in UTC.
+impl TryFrom<::prost_types::Timestamp> for DateTimeStamp {
+	type Error = ProstConversionError;
+
+	fn try_from(value: ::prost_types::Timestamp) -> Result<Self, Self::Error> {
+		if !(MIN_TIMESTAMP_SECONDS..=MAX_TIMESTAMP_SECONDS).contains(&value.seconds) {
+			return Err(ProstConversionError::OutOfRange);
+		}
+
+		if !(0..NANOSECONDS_PER_SECOND).contains(&value.nanos) {
+			return Err(ProstConversionError::Invalid);
+		}
+
+		let date_time = NaiveDate::from_ymd_opt(1970, 1, 1)
+			.unwrap()
+			.and_hms_opt(0, 0, 0)
+			.unwrap()
+			.checked_add_signed(chrono::Duration::seconds(value.seconds))
+			.and_then(|t| t.checked_add_signed(chrono::Duration::nanoseconds(value.nanos.into())))
+			.ok_or(ProstConversionError::OutOfRange)?;
+
+		Ok(Self::new(
+			date_time,
+			chrono::FixedOffset::east_opt(0).unwrap(),
+		))
+	}
+}
+
+/// Converts a timestamp into a date/time in UTC.
+impl TryFrom<::prost_types::Timestamp> for DateTime {
+	type Error = ProstConversionError;
+
+	fn try_from(value: ::prost_types::Timestamp) -> Result<Self, Self::Error> {
+		let value = DateTimeStamp::try_from(value)?;
+		Ok(Self::new(*value.naive(), Some(value.offset())))
+	}
+}
+
+fn to_prost_duration(
+	is_negative: bool,
+	seconds: u32,
+	nano_seconds: u32,
+) -> ::prost_types::Duration {
+	let seconds = seconds as i64;
+	let nanos = nano_seconds as i32;
+
+	if is_negative {
+		::prost_types::Duration {
+			seconds: -seconds,
+			nanos: -nanos,
+		}
+	} else {
+		::prost_types::Duration { seconds, nanos }
+	}
+}
+
+/// Splits a protobuf duration into its sign, seconds and nanoseconds.
+fn from_prost_duration(
+	value: &::prost_types::Duration,
+) -> Result<(bool, u32, u32), ProstConversionError> {
+	if value.nanos <= -NANOSECONDS_PER_SECOND
+		|| value.nanos >= NANOSECONDS_PER_SECOND
+		|| (value.seconds < 0 && value.nanos > 0)
+		|| (value.seconds > 0 && value.nanos < 0)
+	{
+		return Err(ProstConversionError::Invalid);
+	}
+
+	let is_negative = value.seconds < 0 || value.nanos < 0;
+	let seconds = u32::try_from(value.seconds.unsigned_abs())
+		.map_err(|_| ProstConversionError::OutOfRange)?;
+
+	Ok((is_negative, seconds, value.nanos.unsigned_abs()))
+}
+
+impl From<DayTimeDuration> for ::prost_types::Duration {
+	fn from(value: DayTimeDuration) -> Self {
+		to_prost_duration(value.is_negative(), value.seconds(), value.nano_seconds())
+	}
+}
+
+/// Fails with [`ProstConversionError::CalendarDuration`] if the duration has
+/// months.
+impl TryFrom<Duration> for ::prost_types::Duration {
+	type Error = ProstConversionError;
+
+	fn try_from(value: Duration) -> Result<Self, Self::Error> {
+		if value.months() != 0 {
+			return Err(ProstConversionError::CalendarDuration);
+		}
+
+		Ok(to_prost_duration(
+			value.is_negative(),
+			value.seconds(),
+			value.nano_seconds(),
+		))
+	}
+}
+
+impl TryFrom<::prost_types::Duration> for DayTimeDuration {
+	type Error = ProstConversionError;
+
+	fn try_from(value: ::prost_types::Duration) -> Result<Self, Self::Error> {
+		let (is_negative, seconds, nano_seconds) = from_prost_duration(&value)?;
+		Ok(Self::new(is_negative, seconds, nano_seconds))
+	}
+}
+
+impl TryFrom<::prost_types::Duration> for Duration {
+	type Error = ProstConversionError;
+
+	fn try_from(value: ::prost_types::Duration) -> Result<Self, Self::Error> {
+		let (is_negative, seconds, nano_seconds) = from_prost_duration(&value)?;
+		Ok(Self::new(is_negative, 0, seconds, nano_seconds))
+	}
+}
+
+/// Converts an integer into a protobuf number, if it is exactly
+/// representable.
+fn integer_to_number(value: Integer) -> Result<f64, ProstConversionError> {
+	match value.to_f64_lossy() {
+		(f, false) => Ok(f),
+		(_, true) => Err(ProstConversionError::OutOfRange),
+	}
+}
+
+/// Maps booleans and numeric values to `google.protobuf.Value`.
+///
+/// Protobuf numbers are 64-bit floating point numbers: integers and decimals
+/// are only converted if they are exactly representable, otherwise
+/// [`ProstConversionError::OutOfRange`] is returned. Other values fail with
+/// [`ProstConversionError::Invalid`].
+impl<'a> TryFrom<&'a Value> for ::prost_types::Value {
+	type Error = ProstConversionError;
+
+	fn try_from(value: &'a Value) -> Result<Self, Self::Error> {
+		use ::prost_types::value::Kind;
+
+		let number = match value {
+			Value::Boolean(b) => {
+				return Ok(Self {
+					kind: Some(Kind::BoolValue(b.0)),
+				})
+			}
+			Value::Float(f) => f32::from(*f) as f64,
+			Value::Double(d) => f64::from(*d),
+			Value::Decimal(d) => match d.to_f64_lossy() {
+				(f, false) => f,
+				(_, true) => return Err(ProstConversionError::OutOfRange),
+			},
+			Value::Integer(i) => integer_to_number(i.clone())?,
+			Value::NonPositiveInteger(i) => integer_to_number(i.clone().into_big_int().into())?,
+			Value::NegativeInteger(i) => integer_to_number(i.clone().into_big_int().into())?,
+			Value::NonNegativeInteger(i) => integer_to_number(i.clone().into_big_int().into())?,
+			Value::PositiveInteger(i) => integer_to_number(i.clone().into_big_int().into())?,
+			Value::UnsignedLong(i) => integer_to_number((*i).into())?,
+			Value::UnsignedInt(i) => *i as f64,
+			Value::UnsignedShort(i) => *i as f64,
+			Value::UnsignedByte(i) => *i as f64,
+			Value::Long(i) => integer_to_number((*i).into())?,
+			Value::Int(i) => *i as f64,
+			Value::Short(i) => *i as f64,
+			Value::Byte(i) => *i as f64,
+			_ => return Err(ProstConversionError::Invalid),
+		};
+
+		Ok(Self {
+			kind: Some(Kind::NumberValue(number)),
+		})
+	}
+}
+
+/// Maps `google.protobuf.Value` booleans, numbers and strings to
+/// `xsd:boolean`, `xsd:double` and `xsd:string` values. Other kinds fail with
+/// [`ProstConversionError::Invalid`].
+impl TryFrom<::prost_types::Value> for Value {
+	type Error = ProstConversionError;
+
+	fn try_from(value: ::prost_types::Value) -> Result<Self, Self::Error> {
+		use ::prost_types::value::Kind;
+
+		match value.kind {
+			Some(Kind::BoolValue(b)) => Ok(Self::Boolean(b.into())),
+			Some(Kind::NumberValue(n)) => Ok(Self::Double(Double::from(n))),
+			Some(Kind::StringValue(s)) => Ok(Self::String(s)),
+			_ => Err(ProstConversionError::Invalid),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn timestamps() {
+		let value: DateTime = "2024-05-17T10:00:00.25+02:00".parse().unwrap();
+		let timestamp = ::prost_types::Timestamp::try_from(value).unwrap();
+		assert_eq!(timestamp.seconds, 1_715_932_800);
+		assert_eq!(timestamp.nanos, 250_000_000);
+
+		let back = DateTime::try_from(timestamp).unwrap();
+		assert_eq!(back.to_string(), "2024-05-17T08:00:00.25Z");
+
+		let value: DateTime = "2024-05-17T10:00:00".parse().unwrap();
+		assert_eq!(
+			::prost_types::Timestamp::try_from(value),
+			Err(ProstConversionError::MissingTimezone)
+		);
+
+		let value: DateTime = "10000-01-01T00:00:00Z".parse().unwrap();
+		assert_eq!(
+			::prost_types::Timestamp::try_from(value),
+			Err(ProstConversionError::OutOfRange)
+		);
+	}
+
+	#[test]
+	fn durations() {
+		let value: Duration = "-PT1M30.5S".parse().unwrap();
+		let duration = ::prost_types::Duration::try_from(value).unwrap();
+		assert_eq!((duration.seconds, duration.nanos), (-90, -500_000_000));
+		assert_eq!(
+			Duration::try_from(duration).unwrap().to_string(),
+			"-PT1M30.5S"
+		);
+
+		let value: Duration = "P1M".parse().unwrap();
+		assert_eq!(
+			::prost_types::Duration::try_from(value),
+			Err(ProstConversionError::CalendarDuration)
+		);
+
+		let invalid = ::prost_types::Duration {
+			seconds: 1,
+			nanos: -1,
+		};
+		assert_eq!(
+			DayTimeDuration::try_from(invalid).map(|d| d.to_string()),
+			Err(ProstConversionError::Invalid)
+		);
+	}
+
+	#[test]
+	fn numbers() {
+		use ::prost_types::value::Kind;
+
+		let value = Value::Int(42);
+		let number = ::prost_types::Value::try_from(&value).unwrap();
+		assert_eq!(number.kind, Some(Kind::NumberValue(42.0)));
+
+		let value = Value::Long(i64::MAX);
+		assert_eq!(
+			::prost_types::Value::try_from(&value),
+			Err(ProstConversionError::OutOfRange)
+		);
+	}
+}
@@ -1,6 +1,133 @@
+use iref::{Iri, IriBuf};
+use std::{
+	collections::{BTreeMap, HashMap},
+	hash::BuildHasher,
+};
+
 pub use crate::lexical::{QName, QNameBuf};
-use crate::ParseXsd;
+use crate::{Datatype, ParseError, ParseXsd, Value};
 
 impl ParseXsd for QNameBuf {
 	type LexicalForm = QName;
 }
+
+/// Namespace context used to resolve the prefix of `xsd:QName` values.
+///
+/// Without such context, `xsd:QName` lexical forms can only be validated
+/// against the QName grammar (see [`Datatype::parse`]).
+pub trait QNameResolver {
+	/// Returns the namespace IRI bound to the given prefix, or the default
+	/// namespace if `prefix` is `None`.
+	fn resolve_prefix(&self, prefix: Option<&str>) -> Option<&Iri>;
+}
+
+impl<R: ?Sized + QNameResolver> QNameResolver for &R {
+	fn resolve_prefix(&self, prefix: Option<&str>) -> Option<&Iri> {
+		(**self).resolve_prefix(prefix)
+	}
+}
+
+/// Maps prefixes to namespaces, the default namespace being bound to the
+/// empty prefix.
+impl<S: BuildHasher> QNameResolver for HashMap<String, IriBuf, S> {
+	fn resolve_prefix(&self, prefix: Option<&str>) -> Option<&Iri> {
+		self.get(prefix.unwrap_or_default()).map(IriBuf::as_iri)
+	}
+}
+
+/// Maps prefixes to namespaces, the default namespace being bound to the
+/// empty prefix.
+impl QNameResolver for BTreeMap<String, IriBuf> {
+	fn resolve_prefix(&self, prefix: Option<&str>) -> Option<&Iri> {
+		self.get(prefix.unwrap_or_default()).map(IriBuf::as_iri)
+	}
+}
+
+impl QName {
+	/// Resolves this qualified name into an IRI, by concatenating the
+	/// namespace bound to its prefix with its local part, as is customary in
+	/// RDF.
+	///
+	/// Returns `None` if the prefix (or default namespace) is not bound.
+	///
+	/// ```
+	/// use std::collections::HashMap;
+	/// use iref::IriBuf;
+	/// use xsd_types::QName;
+	///
+	/// let namespaces = HashMap::from([(
+	///     "xsd".to_owned(),
+	///     IriBuf::new("http://www.w3.org/2001/XMLSchema#".to_owned()).unwrap(),
+	/// )]);
+	///
+	/// let name = QName::new("xsd:integer").unwrap();
+	/// assert_eq!(
+	///     name.resolve(&namespaces).unwrap().as_str(),
+	///     "http://www.w3.org/2001/XMLSchema#integer"
+	/// );
+	/// assert!(QName::new("integer").unwrap().resolve(&namespaces).is_none());
+	/// ```
+	pub fn resolve(&self, resolver: &impl QNameResolver) -> Option<IriBuf> {
+		let namespace = resolver.resolve_prefix(self.prefix())?;
+		IriBuf::new(format!("{namespace}{}", self.local_part())).ok()
+	}
+}
+
+impl Datatype {
+	/// Parses the given lexical form, using the given namespace context to
+	/// check that `xsd:QName` values can be resolved.
+	///
+	/// This is the same as [`Self::parse`] if `resolver` is `None`, in which
+	/// case `xsd:QName` lexical forms are only checked against the QName
+	/// grammar. Other datatypes ignore the resolver.
+	pub fn parse_resolving(
+		&self,
+		value: &str,
+		resolver: Option<&dyn QNameResolver>,
+	) -> Result<Value, ParseError> {
+		let result = self.parse(value)?;
+
+		if let (Value::QName(name), Some(resolver)) = (&result, resolver) {
+			name.resolve(&resolver).ok_or(ParseError::Invalid)?;
+		}
+
+		Ok(result)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::XSD_Q_NAME;
+
+	#[test]
+	fn resolution() {
+		let datatype = Datatype::from_iri(XSD_Q_NAME).unwrap();
+		let value = datatype.parse("ex:name").unwrap();
+		let Value::QName(name) = &value else {
+			panic!("expected a QName")
+		};
+		assert_eq!(name.prefix(), Some("ex"));
+		assert_eq!(name.local_part(), "name");
+		assert!(matches!(datatype.parse("ex:"), Err(ParseError::Invalid)));
+		assert!(matches!(datatype.parse("1ex:a"), Err(ParseError::Invalid)));
+
+		let mut namespaces = BTreeMap::new();
+		namespaces.insert(
+			"ex".to_owned(),
+			IriBuf::new("http://example.org/".to_owned()).unwrap(),
+		);
+
+		let resolver: &dyn QNameResolver = &namespaces;
+		assert!(datatype.parse_resolving("ex:name", Some(resolver)).is_ok());
+		assert!(matches!(
+			datatype.parse_resolving("other:name", Some(resolver)),
+			Err(ParseError::Invalid)
+		));
+		assert!(datatype.parse_resolving("other:name", None).is_ok());
+		assert_eq!(
+			name.resolve(&namespaces).unwrap().as_str(),
+			"http://example.org/name"
+		);
+	}
+}
@@ -0,0 +1,298 @@
+use core::fmt;
+
+use chrono::Timelike;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{ToPrimitive, Zero};
+
+use crate::Decimal;
+
+const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
+
+/// Seconds value out of the `[0, 60)` range.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("seconds value out of range: {0}")]
+pub struct SecondsOutOfRange(pub Decimal);
+
+/// Seconds component of a time, date/time or duration value.
+///
+/// This is a decimal number in the range `[0, 60)`, with arbitrary
+/// precision. Arithmetic on seconds is exact.
+///
+/// Time, date/time and duration values still store their seconds with
+/// nanosecond precision: the seconds returned by their `seconds` accessors
+/// have at most nine fractional digits.
+///
+/// Formatting a seconds value writes its canonical decimal representation
+/// (without leading zero padding).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Seconds(Decimal);
+
+impl Seconds {
+	/// Creates a new seconds value from a decimal number.
+	///
+	/// Returns an error if the number is not in the range `[0, 60)`.
+	pub fn new(value: Decimal) -> Result<Self, SecondsOutOfRange> {
+		if value.is_negative() || *value.as_big_rational() >= sixty() {
+			Err(SecondsOutOfRange(value))
+		} else {
+			Ok(Self(value))
+		}
+	}
+
+	/// Creates a new seconds value from a number of whole seconds and
+	/// nanoseconds.
+	///
+	/// Returns an error if the resulting number is not in the range `[0, 60)`.
+	pub fn from_parts(seconds: u32, nanoseconds: u32) -> Result<Self, SecondsOutOfRange> {
+		let n = BigInt::from(seconds) * NANOSECONDS_PER_SECOND + nanoseconds;
+		let r = BigRational::new(n, NANOSECONDS_PER_SECOND.into());
+		// SAFETY: the denominator is a power of ten.
+		Self::new(unchecked!(Decimal, r))
+	}
+
+	/// Returns the seconds component of the given time.
+	///
+	/// Leap seconds are folded back into the `[0, 60)` range.
+	pub(crate) fn of(time: &impl Timelike) -> Self {
+		Self::from_parts(time.second(), time.nanosecond() % NANOSECONDS_PER_SECOND).unwrap()
+	}
+
+	/// Zero seconds.
+	pub fn zero() -> Self {
+		Self(Decimal::zero())
+	}
+
+	/// Checks if this value is zero.
+	pub fn is_zero(&self) -> bool {
+		self.0.is_zero()
+	}
+
+	/// Returns the number of whole seconds.
+	pub fn whole_seconds(&self) -> u32 {
+		self.0.as_big_rational().trunc().to_u32().unwrap()
+	}
+
+	/// Returns the fractional part of the seconds, truncated to nanoseconds.
+	pub fn nanoseconds(&self) -> u32 {
+		(self.0.as_big_rational().fract() * BigInt::from(NANOSECONDS_PER_SECOND))
+			.trunc()
+			.to_u32()
+			.unwrap()
+	}
+
+	/// Returns the seconds as a decimal number.
+	pub fn as_decimal(&self) -> &Decimal {
+		&self.0
+	}
+
+	/// Turns this value into a decimal number.
+	pub fn into_decimal(self) -> Decimal {
+		self.0
+	}
+
+	/// Adds the given seconds, wrapping around at 60.
+	///
+	/// Returns the result along with a boolean indicating whether the
+	/// addition overflowed into the next minute.
+	pub fn overflowing_add(&self, other: &Self) -> (Self, bool) {
+		let mut r = self.0.as_big_rational() + other.0.as_big_rational();
+
+		let overflow = r >= sixty();
+		if overflow {
+			r -= sixty()
+		}
+
+		// SAFETY: the sum of two decimal numbers is a decimal number.
+		(Self(unchecked!(Decimal, r)), overflow)
+	}
+
+	/// Subtracts the given seconds, wrapping around at 0.
+	///
+	/// Returns the result along with a boolean indicating whether the
+	/// subtraction borrowed from the previous minute.
+	pub fn overflowing_sub(&self, other: &Self) -> (Self, bool) {
+		let mut r = self.0.as_big_rational() - other.0.as_big_rational();
+
+		let overflow = r < BigRational::zero();
+		if overflow {
+			r += sixty()
+		}
+
+		// SAFETY: the difference of two decimal numbers is a decimal number.
+		(Self(unchecked!(Decimal, r)), overflow)
+	}
+
+	/// Adds the given seconds, returning `None` if the result is not less than
+	/// 60.
+	pub fn checked_add(&self, other: &Self) -> Option<Self> {
+		match self.overflowing_add(other) {
+			(r, false) => Some(r),
+			_ => None,
+		}
+	}
+
+	/// Subtracts the given seconds, returning `None` if the result is
+	/// negative.
+	pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+		match self.overflowing_sub(other) {
+			(r, false) => Some(r),
+			_ => None,
+		}
+	}
+}
+
+/// Writes the seconds component of the given time, padded with a leading
+/// zero to two integer digits as required by the time lexical forms (e.g.
+/// `05` or `05.25`).
+///
+/// This is equivalent to formatting [`Seconds::of`] the time, but does not
+/// allocate.
+pub(crate) fn fmt_time_seconds(time: &impl Timelike, f: &mut impl fmt::Write) -> fmt::Result {
+	write!(f, "{:02}", time.second())?;
+
+	let nanoseconds = time.nanosecond() % NANOSECONDS_PER_SECOND;
+	if nanoseconds > 0 {
+		let mut buffer = *b".000000000";
+		let mut n = nanoseconds;
+		for d in buffer[1..].iter_mut().rev() {
+			*d = b'0' + (n % 10) as u8;
+			n /= 10;
+		}
+
+		let end = buffer.iter().rposition(|&d| d != b'0').unwrap() + 1;
+
+		// SAFETY: the buffer only contains ASCII characters.
+		f.write_str(crate::utils::str_from_validated_utf8(&buffer[..end]))?;
+	}
+
+	Ok(())
+}
+
+fn sixty() -> BigRational {
+	BigRational::from_integer(BigInt::from(60u32))
+}
+
+impl fmt::Display for Seconds {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(&self.0, f)
+	}
+}
+
+/// Seconds given by their whole seconds and nanoseconds.
+///
+/// Formats like the equivalent [`Seconds`] value, without allocating.
+pub(crate) struct SecondsParts {
+	pub seconds: u32,
+	pub nanoseconds: u32,
+}
+
+impl SecondsParts {
+	pub fn is_zero(&self) -> bool {
+		self.seconds == 0 && self.nanoseconds == 0
+	}
+}
+
+impl fmt::Display for SecondsParts {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.seconds)?;
+
+		if self.nanoseconds > 0 {
+			let mut fraction = self.nanoseconds;
+			let mut width = 9;
+			while fraction % 10 == 0 {
+				fraction /= 10;
+				width -= 1
+			}
+
+			write!(f, ".{fraction:0width$}")?;
+		}
+
+		Ok(())
+	}
+}
+
+impl TryFrom<Decimal> for Seconds {
+	type Error = SecondsOutOfRange;
+
+	fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+		Self::new(value)
+	}
+}
+
+impl From<Seconds> for Decimal {
+	fn from(value: Seconds) -> Self {
+		value.0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn arithmetic() {
+		let a = Seconds::new("59.75".parse().unwrap()).unwrap();
+		let b = Seconds::from_parts(0, 500_000_000).unwrap();
+
+		let (sum, overflow) = a.overflowing_add(&b);
+		assert!(overflow);
+		assert_eq!(sum.to_string(), "0.25");
+		assert!(a.checked_add(&b).is_none());
+
+		let (diff, overflow) = b.overflowing_sub(&a);
+		assert!(overflow);
+		assert_eq!(diff.to_string(), "0.75");
+		assert_eq!(a.checked_sub(&b).unwrap().to_string(), "59.25");
+
+		assert_eq!(a.whole_seconds(), 59);
+		assert_eq!(a.nanoseconds(), 750_000_000);
+		assert!(Seconds::new("60".parse().unwrap()).is_err())
+	}
+
+	#[test]
+	fn format_parts() {
+		for (seconds, nanoseconds) in [(0, 0), (7, 0), (59, 999_999_999), (1, 500_000_000), (0, 1)]
+		{
+			assert_eq!(
+				SecondsParts {
+					seconds,
+					nanoseconds
+				}
+				.to_string(),
+				Seconds::from_parts(seconds, nanoseconds)
+					.unwrap()
+					.to_string()
+			)
+		}
+	}
+
+	#[test]
+	fn arbitrary_precision() {
+		let s = Seconds::new("1.0000000001".parse().unwrap()).unwrap();
+		assert_eq!(s.to_string(), "1.0000000001");
+		assert_eq!(s.nanoseconds(), 0)
+	}
+
+	#[test]
+	fn date_time_seconds() {
+		let value: crate::DateTime = "2002-05-31T13:07:05.25Z".parse().unwrap();
+		assert_eq!(value.seconds().to_string(), "5.25");
+		assert_eq!(value.to_string(), "2002-05-31T13:07:05.25Z")
+	}
+
+	#[test]
+	fn time_seconds() {
+		for (seconds, nanoseconds, expected) in [
+			(0, 0, "00"),
+			(5, 250_000_000, "05.25"),
+			(59, 1, "59.000000001"),
+			(10, 999_999_999, "10.999999999"),
+		] {
+			let time = chrono::NaiveTime::from_hms_nano_opt(0, 0, seconds, nanoseconds).unwrap();
+			let mut result = String::new();
+			fmt_time_seconds(&time, &mut result).unwrap();
+			assert_eq!(result, expected)
+		}
+	}
+}
@@ -0,0 +1,124 @@
+use std::cmp::Ordering;
+
+use super::{Id, IdRef, Language, NCName, NMToken, Name, NormalizedStr, Token};
+
+/// String collation.
+///
+/// A collation defines how strings are compared, as used by the XPath
+/// [`fn:compare`](https://www.w3.org/TR/xpath-functions/#func-compare)
+/// function.
+pub trait Collation {
+	/// Compares the two input strings.
+	fn compare(&self, a: &str, b: &str) -> Ordering;
+}
+
+/// Unicode codepoint collation.
+///
+/// Compares strings codepoint by codepoint.
+///
+/// See: <http://www.w3.org/2005/xpath-functions/collation/codepoint>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CodepointCollation;
+
+impl Collation for CodepointCollation {
+	fn compare(&self, a: &str, b: &str) -> Ordering {
+		a.cmp(b)
+	}
+}
+
+/// ASCII case-insensitive collation.
+///
+/// Compares strings codepoint by codepoint after mapping ASCII uppercase
+/// letters to lowercase. Other characters are left untouched.
+///
+/// See: <http://www.w3.org/2005/xpath-functions/collation/html-ascii-case-insensitive>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AsciiCaseInsensitiveCollation;
+
+impl Collation for AsciiCaseInsensitiveCollation {
+	fn compare(&self, a: &str, b: &str) -> Ordering {
+		a.bytes()
+			.map(|c| c.to_ascii_lowercase())
+			.cmp(b.bytes().map(|c| c.to_ascii_lowercase()))
+	}
+}
+
+/// Unicode case-insensitive collation.
+///
+/// Compares strings codepoint by codepoint after applying the Unicode
+/// lowercase mapping on each character. This mapping does not depend on the
+/// current locale.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CaseInsensitiveCollation;
+
+impl Collation for CaseInsensitiveCollation {
+	fn compare(&self, a: &str, b: &str) -> Ordering {
+		a.chars()
+			.flat_map(char::to_lowercase)
+			.cmp(b.chars().flat_map(char::to_lowercase))
+	}
+}
+
+macro_rules! string_comparison {
+	($($ty:ident),*) => {
+		$(
+			impl $ty {
+				/// Checks that two values are equal, ignoring ASCII case.
+				pub fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
+					self.as_str().eq_ignore_ascii_case(other.as_str())
+				}
+
+				/// Checks that two values are equal, ignoring case.
+				///
+				/// Case is ignored using the locale-independent Unicode
+				/// lowercase mapping.
+				pub fn eq_ignore_case(&self, other: &Self) -> bool {
+					CaseInsensitiveCollation
+						.compare(self.as_str(), other.as_str())
+						.is_eq()
+				}
+
+				/// Compares two values using the given collation.
+				pub fn compare_with(&self, other: &Self, collation: &dyn Collation) -> Ordering {
+					collation.compare(self.as_str(), other.as_str())
+				}
+			}
+		)*
+	};
+}
+
+string_comparison!(
+	NormalizedStr,
+	Token,
+	Language,
+	Name,
+	NCName,
+	Id,
+	IdRef,
+	NMToken
+);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ascii_case_insensitive() {
+		let a = Token::new("Hello World").unwrap();
+		let b = Token::new("hello world").unwrap();
+		assert!(a.eq_ignore_ascii_case(b));
+		assert_eq!(
+			a.compare_with(b, &AsciiCaseInsensitiveCollation),
+			Ordering::Equal
+		);
+		assert_eq!(a.compare_with(b, &CodepointCollation), Ordering::Less)
+	}
+
+	#[test]
+	fn case_insensitive() {
+		let a = NCName::new("Élan").unwrap();
+		let b = NCName::new("élan").unwrap();
+		assert!(!a.eq_ignore_ascii_case(b));
+		assert!(a.eq_ignore_case(b))
+	}
+}
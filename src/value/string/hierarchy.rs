@@ -0,0 +1,172 @@
+//! Conversions along the string datatype hierarchy.
+//!
+//! ```text
+//! xsd:string
+//! └── xsd:normalizedString
+//!     └── xsd:token
+//!         ├── xsd:language
+//!         ├── xsd:NMTOKEN
+//!         └── xsd:Name
+//!             └── xsd:NCName
+//!                 ├── xsd:ID
+//!                 └── xsd:IDREF
+//! ```
+//!
+//! The lexical space of each datatype is a subset of the lexical space of
+//! the datatype it derives from, so a reference to a derived string can be
+//! freely converted into a reference to any of its ancestors. Each type also
+//! dereferences to its parent, so that `&Id` coerces to `&NCName`, `&Name`,
+//! `&Token`, `&NormalizedStr` and finally `&str`.
+use std::ops::Deref;
+
+use super::{Id, IdRef, Language, NCName, NMToken, Name, NormalizedStr, Token};
+
+impl Deref for NormalizedStr {
+	type Target = str;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_str()
+	}
+}
+
+impl AsRef<str> for NormalizedStr {
+	fn as_ref(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl Token {
+	pub fn as_normalized_str(&self) -> &NormalizedStr {
+		// SAFETY: a token is a normalized string.
+		unchecked!(NormalizedStr, self.as_str())
+	}
+}
+
+impl Deref for Token {
+	type Target = NormalizedStr;
+
+	fn deref(&self) -> &Self::Target {
+		self.as_normalized_str()
+	}
+}
+
+impl AsRef<str> for Token {
+	fn as_ref(&self) -> &str {
+		self.as_str()
+	}
+}
+
+impl AsRef<NormalizedStr> for Token {
+	fn as_ref(&self) -> &NormalizedStr {
+		self.as_normalized_str()
+	}
+}
+
+impl<'a> From<&'a Token> for &'a NormalizedStr {
+	fn from(value: &'a Token) -> Self {
+		value.as_normalized_str()
+	}
+}
+
+/// Implements the conversions from a derived string type to each of its
+/// ancestors in the string datatype hierarchy, above `xsd:normalizedString`.
+///
+/// The first ancestor is the parent datatype, which the type dereferences
+/// to.
+macro_rules! ancestors {
+	($($ty:ident: $parent:ident as $parent_method:ident $(, $ancestor:ident as $method:ident)*;)*) => {
+		$(
+			impl Deref for $ty {
+				type Target = $parent;
+
+				fn deref(&self) -> &Self::Target {
+					self.$parent_method()
+				}
+			}
+
+			ancestors!(@conversions $ty: $parent as $parent_method $(, $ancestor as $method)*);
+		)*
+	};
+	(@conversions $ty:ident: $($ancestor:ident as $method:ident),*) => {
+		impl $ty {
+			$(
+				pub fn $method(&self) -> &$ancestor {
+					// SAFETY: the lexical space of this datatype is a
+					//         subset of the ancestor lexical space.
+					unchecked!($ancestor, self.as_str())
+				}
+			)*
+
+			pub fn as_normalized_str(&self) -> &NormalizedStr {
+				// SAFETY: every derived datatype is a normalized string.
+				unchecked!(NormalizedStr, self.as_str())
+			}
+		}
+
+		$(
+			impl AsRef<$ancestor> for $ty {
+				fn as_ref(&self) -> &$ancestor {
+					self.$method()
+				}
+			}
+
+			impl<'a> From<&'a $ty> for &'a $ancestor {
+				fn from(value: &'a $ty) -> Self {
+					value.$method()
+				}
+			}
+		)*
+
+		impl AsRef<NormalizedStr> for $ty {
+			fn as_ref(&self) -> &NormalizedStr {
+				self.as_normalized_str()
+			}
+		}
+
+		impl<'a> From<&'a $ty> for &'a NormalizedStr {
+			fn from(value: &'a $ty) -> Self {
+				value.as_normalized_str()
+			}
+		}
+	};
+}
+
+ancestors! {
+	Language: Token as as_token;
+	NMToken: Token as as_token;
+	Name: Token as as_token;
+	NCName: Name as as_name, Token as as_token;
+	Id: NCName as as_ncname, Name as as_name, Token as as_token;
+	IdRef: NCName as as_ncname, Name as as_name, Token as as_token;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn local_name(name: &NCName) -> &str {
+		name.as_str()
+	}
+
+	#[test]
+	fn upcasts() {
+		let id = Id::new("foo").unwrap();
+		assert_eq!(local_name(id.as_ncname()), "foo");
+		assert_eq!(local_name(id.into()), "foo");
+
+		let token: &Token = AsRef::<Token>::as_ref(id);
+		assert_eq!(token.as_str(), "foo");
+		assert_eq!(token.len(), 3);
+
+		let name: &Name = id.into();
+		assert_eq!(name.as_normalized_str().as_str(), "foo");
+
+		// Dereferencing follows the hierarchy.
+		assert_eq!(local_name(id), "foo");
+		let normalized: &NormalizedStr = id;
+		assert_eq!(normalized.as_str(), "foo");
+		let language = Language::new("en-US").unwrap();
+		let token: &Token = language;
+		assert_eq!(token.as_str(), "en-US")
+	}
+}
@@ -19,7 +19,8 @@ use crate::ParseXsd;
 #[derive(RegularGrammar, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[grammar(
 	sized(IdBuf, derive(Debug, Display, PartialEq, Eq, PartialOrd, Ord, Hash)),
-	cache = "automata/ncname.automaton.cbor"
+	cache = "automata/ncname.automaton.cbor",
+	no_deref
 )]
 pub struct Id(str);
 
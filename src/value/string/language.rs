@@ -9,12 +9,54 @@ use crate::ParseXsd;
 /// ```
 ///
 #[derive(RegularGrammar, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[grammar(sized(
-	LanguageBuf,
-	derive(Debug, Display, PartialEq, Eq, PartialOrd, Ord, Hash)
-))]
+#[grammar(
+	sized(
+		LanguageBuf,
+		derive(Debug, Display, PartialEq, Eq, PartialOrd, Ord, Hash)
+	),
+	no_deref
+)]
 pub struct Language(str);
 
+impl Language {
+	/// Returns this language tag with ASCII uppercase letters mapped to
+	/// lowercase.
+	///
+	/// Language tags are case-insensitive (BCP 47). Since they only contain
+	/// ASCII letters and digits, the mapping does not depend on the locale
+	/// (in particular, `I` is always mapped to `i`, never to the Turkish
+	/// dotless `ı`).
+	///
+	/// ```
+	/// use xsd_types::Language;
+	///
+	/// let tag = Language::new("TR-Latn-TR").unwrap();
+	/// assert_eq!(tag.to_ascii_lowercase_canonical().as_str(), "tr-latn-tr");
+	/// ```
+	pub fn to_ascii_lowercase_canonical(&self) -> LanguageBuf {
+		LanguageBuf::new(self.as_str().to_ascii_lowercase()).unwrap()
+	}
+}
+
 impl ParseXsd for LanguageBuf {
 	type LexicalForm = crate::lexical::Language;
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ascii_case_folding() {
+		let a = Language::new("AZ-Latn-IQ").unwrap();
+		let b = Language::new("az-latn-iq").unwrap();
+		assert!(a != b);
+		assert!(a.eq_ignore_ascii_case(b));
+		assert_eq!(a.to_ascii_lowercase_canonical().as_str(), b.as_str());
+
+		// Non-ASCII letters, such as the Turkish dotted `İ` whose lowercase
+		// mapping is `i̇`, are not allowed in language tags.
+		assert!(Language::new("\u{130}T").is_err());
+		assert!(Language::new("\u{131}t").is_err());
+	}
+}
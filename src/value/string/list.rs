@@ -0,0 +1,147 @@
+use std::{fmt, str::FromStr};
+
+use crate::{IdRef, IdRefBuf, InvalidIdRef, InvalidNMToken, NMToken, NMTokenBuf};
+
+macro_rules! list {
+	($(#[$meta:meta])* $ty:ident: $item:ident, $item_buf:ident, $error:ident) => {
+		$(#[$meta])*
+		///
+		/// Items can never contain whitespace, so the lexical form of the list
+		/// is the space-separated sequence of its items.
+		#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+		pub struct $ty(Vec<$item_buf>);
+
+		impl $ty {
+			/// Creates a new empty list.
+			pub fn new() -> Self {
+				Self::default()
+			}
+
+			pub fn len(&self) -> usize {
+				self.0.len()
+			}
+
+			pub fn is_empty(&self) -> bool {
+				self.0.is_empty()
+			}
+
+			/// Appends an item at the end of the list.
+			pub fn push(&mut self, item: $item_buf) {
+				self.0.push(item)
+			}
+
+			/// Returns an iterator over the items of the list.
+			pub fn iter(
+				&self,
+			) -> impl '_ + DoubleEndedIterator<Item = &$item> + ExactSizeIterator {
+				self.0.iter().map(|item| &**item)
+			}
+
+			/// Returns the items of the list.
+			pub fn into_vec(self) -> Vec<$item_buf> {
+				self.0
+			}
+		}
+
+		impl fmt::Display for $ty {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				for (i, item) in self.iter().enumerate() {
+					if i > 0 {
+						f.write_str(" ")?;
+					}
+
+					f.write_str(item.as_str())?;
+				}
+
+				Ok(())
+			}
+		}
+
+		/// Parses a whitespace-separated list of items.
+		impl FromStr for $ty {
+			type Err = $error<String>;
+
+			fn from_str(s: &str) -> Result<Self, Self::Err> {
+				s.split([' ', '\t', '\r', '\n'])
+					.filter(|item| !item.is_empty())
+					.map(|item| {
+						$item::new(item)
+							.map(ToOwned::to_owned)
+							.map_err(|_| $error(item.to_owned()))
+					})
+					.collect()
+			}
+		}
+
+		impl From<Vec<$item_buf>> for $ty {
+			fn from(value: Vec<$item_buf>) -> Self {
+				Self(value)
+			}
+		}
+
+		impl FromIterator<$item_buf> for $ty {
+			fn from_iter<I: IntoIterator<Item = $item_buf>>(iter: I) -> Self {
+				Self(iter.into_iter().collect())
+			}
+		}
+
+		impl<'a> FromIterator<&'a $item> for $ty {
+			fn from_iter<I: IntoIterator<Item = &'a $item>>(iter: I) -> Self {
+				Self(iter.into_iter().map(ToOwned::to_owned).collect())
+			}
+		}
+
+		impl Extend<$item_buf> for $ty {
+			fn extend<I: IntoIterator<Item = $item_buf>>(&mut self, iter: I) {
+				self.0.extend(iter)
+			}
+		}
+
+		impl<'a> Extend<&'a $item> for $ty {
+			fn extend<I: IntoIterator<Item = &'a $item>>(&mut self, iter: I) {
+				self.0.extend(iter.into_iter().map(ToOwned::to_owned))
+			}
+		}
+
+		impl IntoIterator for $ty {
+			type Item = $item_buf;
+			type IntoIter = std::vec::IntoIter<$item_buf>;
+
+			fn into_iter(self) -> Self::IntoIter {
+				self.0.into_iter()
+			}
+		}
+	};
+}
+
+list! {
+	/// `NMTOKENS` list value.
+	NMTokensBuf: NMToken, NMTokenBuf, InvalidNMToken
+}
+
+list! {
+	/// `IDREFS` list value.
+	IdRefsBuf: IdRef, IdRefBuf, InvalidIdRef
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn collect_and_display() {
+		let mut tokens: NMTokensBuf = ["a", "b-c"]
+			.into_iter()
+			.map(|t| NMToken::new(t).unwrap())
+			.collect();
+		tokens.extend([NMToken::new("1.2").unwrap().to_owned()]);
+		assert_eq!(tokens.len(), 3);
+		assert_eq!(tokens.to_string(), "a b-c 1.2");
+		assert_eq!(tokens.iter().last().unwrap().as_str(), "1.2");
+
+		let refs: IdRefsBuf = " x\t y\n".parse().unwrap();
+		assert_eq!(refs.to_string(), "x y");
+		assert!("x 1y".parse::<IdRefsBuf>().is_err());
+		assert!(IdRefsBuf::new().to_string().is_empty())
+	}
+}
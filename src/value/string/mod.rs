@@ -1,23 +1,44 @@
-use crate::{Datatype, ParseXsd, XsdValue};
+use crate::{
+	lexical::Lexical, CowValue, Datatype, NCNameDatatype, NameDatatype, NormalizedStringDatatype,
+	ParseError, ParseXsd, StringDatatype, TokenDatatype, Value, ValueRef, XsdValue,
+};
 
+mod collation;
+mod hierarchy;
 mod id;
 mod idref;
 mod language;
+mod list;
+mod literal;
 mod name;
+mod name_char;
 mod ncname;
 mod nmtoken;
 mod normalized;
 mod token;
 
+pub use collation::*;
 pub use id::*;
 pub use idref::*;
 pub use language::*;
+pub use list::*;
+pub use literal::*;
 pub use name::*;
+pub use name_char::*;
 pub use ncname::*;
 pub use nmtoken::*;
 pub use normalized::*;
 pub use token::*;
 
+grammar_errors!(
+	InvalidLanguage,
+	InvalidName,
+	InvalidNCName,
+	InvalidId,
+	InvalidIdRef,
+	InvalidNMToken
+);
+
 pub type String = std::string::String;
 
 impl XsdValue for String {
@@ -29,3 +50,198 @@ impl XsdValue for String {
 impl ParseXsd for String {
 	type LexicalForm = str;
 }
+
+impl Value {
+	/// Returns the value as a string slice, if it belongs to the string
+	/// datatype family (`xsd:string` and all its derived datatypes).
+	pub fn as_str(&self) -> Option<&str> {
+		self.as_ref().as_str()
+	}
+
+	/// Returns the value as a token, if it is an `xsd:token` or any of its
+	/// derived datatypes (`xsd:language`, `xsd:Name`, `xsd:NCName`, etc.).
+	pub fn as_token(&self) -> Option<&Token> {
+		self.as_ref().as_token()
+	}
+
+	/// Returns the value as a language tag, if it is an `xsd:language`.
+	pub fn as_language(&self) -> Option<&Language> {
+		self.as_ref().as_language()
+	}
+
+	/// Returns the value as a non-colonized name, if it is an `xsd:NCName`
+	/// or any of its derived datatypes (`xsd:ID`, `xsd:IDREF`).
+	pub fn as_ncname(&self) -> Option<&NCName> {
+		self.as_ref().as_ncname()
+	}
+}
+
+impl<'a> ValueRef<'a> {
+	/// Returns the value as a string slice, if it belongs to the string
+	/// datatype family (`xsd:string` and all its derived datatypes).
+	pub fn as_str(&self) -> Option<&'a str> {
+		match *self {
+			Self::String(s) => Some(s),
+			Self::NormalizedString(s) => Some(s.as_str()),
+			Self::Token(s) => Some(s.as_str()),
+			Self::Language(s) => Some(s.as_str()),
+			Self::Name(s) => Some(s.as_str()),
+			Self::NCName(s) => Some(s.as_str()),
+			Self::Id(s) => Some(s.as_str()),
+			Self::IdRef(s) => Some(s.as_str()),
+			Self::NMToken(s) => Some(s.as_str()),
+			_ => None,
+		}
+	}
+
+	/// Returns the value as a token, if it is an `xsd:token` or any of its
+	/// derived datatypes (`xsd:language`, `xsd:Name`, `xsd:NCName`, etc.).
+	pub fn as_token(&self) -> Option<&'a Token> {
+		match *self {
+			Self::Token(t) => Some(t),
+			Self::Language(l) => Some(l.as_token()),
+			Self::Name(n) => Some(n.as_token()),
+			Self::NCName(n) => Some(n.as_token()),
+			Self::Id(n) => Some(n.as_token()),
+			Self::IdRef(n) => Some(n.as_token()),
+			Self::NMToken(n) => Some(n.as_token()),
+			_ => None,
+		}
+	}
+
+	/// Returns the value as a language tag, if it is an `xsd:language`.
+	pub fn as_language(&self) -> Option<&'a Language> {
+		match *self {
+			Self::Language(l) => Some(l),
+			_ => None,
+		}
+	}
+
+	/// Returns the value as a non-colonized name, if it is an `xsd:NCName`
+	/// or any of its derived datatypes (`xsd:ID`, `xsd:IDREF`).
+	pub fn as_ncname(&self) -> Option<&'a NCName> {
+		match *self {
+			Self::NCName(n) => Some(n),
+			Self::Id(n) => Some(n.as_ncname()),
+			Self::IdRef(n) => Some(n.as_ncname()),
+			_ => None,
+		}
+	}
+}
+
+impl Datatype {
+	/// Parses a value of this datatype, borrowing the input lexical form when
+	/// possible.
+	///
+	/// Values of `xsd:string` and its derived datatypes are their own lexical
+	/// form: they are only validated, and returned as a reference to the
+	/// input without allocation (see [`StringDatatype::parse_ref`]). Values
+	/// of other datatypes are parsed with [`Datatype::parse`].
+	pub fn parse_cow<'a>(&self, value: &'a str) -> Result<CowValue<'a>, ParseError> {
+		match self {
+			Self::String(t) => t.parse_ref(value).map(CowValue::Borrowed),
+			_ => self.parse(value).map(CowValue::Owned),
+		}
+	}
+}
+
+impl StringDatatype {
+	/// Parses a value of this datatype as a reference to the input lexical
+	/// form, without allocation.
+	///
+	/// ```
+	/// use xsd_types::{StringDatatype, ValueRef};
+	///
+	/// let input = "foo".to_owned();
+	/// let value = StringDatatype::String.parse_ref(&input).unwrap();
+	/// assert!(matches!(value, ValueRef::String(s) if s.as_ptr() == input.as_ptr()));
+	/// ```
+	pub fn parse_ref<'a>(&self, value: &'a str) -> Result<ValueRef<'a>, ParseError> {
+		fn parse<'a, T: ?Sized + Lexical + 'a>(
+			value: &'a str,
+			f: impl FnOnce(&'a T) -> ValueRef<'a>,
+		) -> Result<ValueRef<'a>, ParseError> {
+			T::parse(value)
+				.map(f)
+				.map_err(|_| ParseError::invalid(value))
+		}
+
+		match self {
+			Self::String => Ok(ValueRef::String(value)),
+			Self::NormalizedString(t) => match t {
+				NormalizedStringDatatype::NormalizedString => {
+					parse(value, ValueRef::NormalizedString)
+				}
+				NormalizedStringDatatype::Token(t) => match t {
+					TokenDatatype::Token => parse(value, ValueRef::Token),
+					TokenDatatype::Language => parse(value, ValueRef::Language),
+					TokenDatatype::NMToken => parse(value, ValueRef::NMToken),
+					TokenDatatype::Name(NameDatatype::Name) => parse(value, ValueRef::Name),
+					TokenDatatype::Name(NameDatatype::NCName(t)) => match t {
+						NCNameDatatype::NCName => parse(value, ValueRef::NCName),
+						NCNameDatatype::Id => parse(value, ValueRef::Id),
+						NCNameDatatype::IdRef => parse(value, ValueRef::IdRef),
+					},
+				},
+			},
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn string_accessors() {
+		let value = Value::Language(LanguageBuf::new("en-GB".to_owned()).unwrap());
+		assert_eq!(value.as_str(), Some("en-GB"));
+		assert_eq!(value.as_token().map(Token::as_str), Some("en-GB"));
+		assert_eq!(value.as_language().map(Language::as_str), Some("en-GB"));
+		assert!(value.as_ncname().is_none());
+
+		let value = Value::Id(IdBuf::new("foo".to_owned()).unwrap());
+		assert_eq!(value.as_ncname().map(NCName::as_str), Some("foo"));
+		assert!(value.as_language().is_none());
+
+		let value = Value::String("foo bar".to_owned());
+		assert_eq!(value.as_str(), Some("foo bar"));
+		assert!(value.as_token().is_none());
+
+		assert!(Value::Boolean(true.into()).as_str().is_none())
+	}
+
+	#[test]
+	fn whitespace_transformations() {
+		let text = "\t first  line\r\n second line\n";
+
+		let normalized = NormalizedString::from_text_replacing(text);
+		assert!(NormalizedStr::new(normalized.as_str()).is_ok());
+		assert_eq!(normalized.as_str(), "  first  line   second line ");
+
+		let token = TokenBuf::from_text_collapsing(text);
+		assert!(Token::new(token.as_str()).is_ok());
+		assert_eq!(token.as_str(), "first line second line");
+
+		assert!(TokenBuf::from_text_collapsing(" \t\n").as_str().is_empty());
+	}
+
+	#[test]
+	fn borrowed_parsing() {
+		let id = Datatype::from_iri(crate::XSD_ID).unwrap();
+		let value = id.parse_cow("foo").unwrap();
+		assert!(matches!(value, CowValue::Borrowed(ValueRef::Id(_))));
+		assert_eq!(value.datatype(), id);
+		assert!(matches!(
+			id.parse_cow(""),
+			Err(ParseError::EmptyLexicalForm)
+		));
+		assert!(matches!(id.parse_cow("a:b"), Err(ParseError::Invalid)));
+
+		let integer = Datatype::from_iri(crate::XSD_INTEGER).unwrap();
+		assert!(matches!(
+			integer.parse_cow("12").unwrap(),
+			CowValue::Owned(Value::Integer(_))
+		))
+	}
+}
@@ -19,7 +19,8 @@ use crate::ParseXsd;
 #[derive(RegularGrammar, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[grammar(
 	sized(NameBuf, derive(Debug, Display, PartialEq, Eq, PartialOrd, Ord, Hash)),
-	cache = "automata/name.automaton.cbor"
+	cache = "automata/name.automaton.cbor",
+	no_deref
 )]
 pub struct Name(str);
 
@@ -0,0 +1,189 @@
+use core::fmt;
+
+use super::{NCName, NCNameBuf, NMToken, NMTokenBuf, Name, NameBuf};
+
+/// Position of a character in a name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NameCharPosition {
+	/// First character of the name.
+	Start,
+
+	/// Any character following the first one.
+	Continuation,
+}
+
+impl fmt::Display for NameCharPosition {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Start => write!(f, "start"),
+			Self::Continuation => write!(f, "continuation"),
+		}
+	}
+}
+
+/// Detailed name validation error.
+///
+/// Returned by the `check_chars` function of [`Name`], [`NCName`] and
+/// [`NMToken`] to explain why a string is not a valid name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum InvalidNameChar {
+	#[error("empty name")]
+	Empty,
+
+	#[error("invalid {position} character {char:?} at byte index {index}")]
+	Char {
+		/// Byte index of the invalid character.
+		index: usize,
+
+		/// Invalid character.
+		char: char,
+
+		/// Position of the character in the name.
+		position: NameCharPosition,
+	},
+}
+
+/// Returns `true` if `prefix` followed by `c` is accepted by `validate`.
+fn probe(prefix: &str, c: char, validate: impl FnOnce(&str) -> bool) -> bool {
+	let mut buffer = [0; 8];
+	buffer[..prefix.len()].copy_from_slice(prefix.as_bytes());
+	let len = prefix.len() + c.encode_utf8(&mut buffer[prefix.len()..]).len();
+	validate(crate::utils::str_from_validated_utf8(&buffer[..len]))
+}
+
+macro_rules! name_chars {
+	($($ty:ident, $buffer_ty:ident, $prefix:literal, $start_position:ident),*) => {
+		$(
+			impl $ty {
+				/// Checks if the given character can start a name.
+				pub fn is_start_char(c: char) -> bool {
+					probe("", c, |s| Self::new(s).is_ok())
+				}
+
+				/// Checks if the given character can appear in a name after
+				/// the first character.
+				pub fn is_char(c: char) -> bool {
+					probe($prefix, c, |s| Self::new(s).is_ok())
+				}
+
+				/// Checks each character of the input string, returning the
+				/// first invalid character (if any) along with its index and
+				/// position in the name.
+				pub fn check_chars(input: &str) -> Result<(), InvalidNameChar> {
+					let mut chars = input.char_indices();
+					match chars.next() {
+						Some((index, char)) => {
+							if !Self::is_start_char(char) {
+								return Err(InvalidNameChar::Char {
+									index,
+									char,
+									position: NameCharPosition::$start_position,
+								});
+							}
+						}
+						None => return Err(InvalidNameChar::Empty),
+					}
+
+					for (index, char) in chars {
+						if !Self::is_char(char) {
+							return Err(InvalidNameChar::Char {
+								index,
+								char,
+								position: NameCharPosition::Continuation,
+							});
+						}
+					}
+
+					Ok(())
+				}
+			}
+
+			impl $buffer_ty {
+				/// Creates a valid name from an arbitrary string, replacing
+				/// each invalid character with `_`.
+				///
+				/// See [`Self::sanitize_with`].
+				pub fn sanitize(input: &str) -> Self {
+					Self::sanitize_with(input, '_')
+				}
+
+				/// Creates a valid name from an arbitrary string, replacing
+				/// each invalid character with `substitute`.
+				///
+				/// If the result would be empty or start with a character
+				/// that cannot start a name, it is prefixed with `_`.
+				///
+				/// # Panics
+				///
+				/// Panics if `substitute` is not a valid name character.
+				pub fn sanitize_with(input: &str, substitute: char) -> Self {
+					assert!(
+						<$ty>::is_char(substitute),
+						"invalid substitute character {substitute:?}"
+					);
+
+					let mut result = String::with_capacity(input.len() + 1);
+					for c in input.chars() {
+						if <$ty>::is_char(c) {
+							result.push(c)
+						} else {
+							result.push(substitute)
+						}
+					}
+
+					if !result.chars().next().is_some_and(<$ty>::is_start_char) {
+						result.insert(0, '_')
+					}
+
+					// SAFETY: every character is a valid name character
+					//         and the first one is a valid start character.
+					unchecked!(Self, result)
+				}
+			}
+		)*
+	};
+}
+
+name_chars! {
+	Name, NameBuf, "_", Start,
+	NCName, NCNameBuf, "_", Start,
+	NMToken, NMTokenBuf, "", Continuation
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn check_chars() {
+		assert_eq!(NCName::check_chars(""), Err(InvalidNameChar::Empty));
+		assert_eq!(NCName::check_chars("foo"), Ok(()));
+		assert_eq!(
+			NCName::check_chars("1foo"),
+			Err(InvalidNameChar::Char {
+				index: 0,
+				char: '1',
+				position: NameCharPosition::Start
+			})
+		);
+		assert_eq!(
+			NCName::check_chars("foo:bar"),
+			Err(InvalidNameChar::Char {
+				index: 3,
+				char: ':',
+				position: NameCharPosition::Continuation
+			})
+		);
+		assert_eq!(Name::check_chars("foo:bar"), Ok(()));
+		assert_eq!(NMToken::check_chars("1foo"), Ok(()))
+	}
+
+	#[test]
+	fn sanitize() {
+		assert_eq!(NCNameBuf::sanitize("foo bar").as_str(), "foo_bar");
+		assert_eq!(NCNameBuf::sanitize("1foo").as_str(), "_1foo");
+		assert_eq!(NCNameBuf::sanitize("").as_str(), "_");
+		assert_eq!(NCNameBuf::sanitize_with("a:b", '-').as_str(), "a-b");
+		assert_eq!(NMTokenBuf::sanitize("1 2").as_str(), "1_2")
+	}
+}
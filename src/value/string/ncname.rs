@@ -22,7 +22,8 @@ use crate::ParseXsd;
 		NCNameBuf,
 		derive(Debug, Display, PartialEq, Eq, PartialOrd, Ord, Hash)
 	),
-	cache = "automata/ncname.automaton.cbor"
+	cache = "automata/ncname.automaton.cbor",
+	no_deref
 )]
 pub struct NCName(str);
 
@@ -22,10 +22,33 @@ use crate::ParseXsd;
 		NMTokenBuf,
 		derive(Debug, Display, PartialEq, Eq, PartialOrd, Ord, Hash)
 	),
-	cache = "automata/nmtoken.automaton.cbor"
+	cache = "automata/nmtoken.automaton.cbor",
+	no_deref
 )]
 pub struct NMToken(str);
 
+impl NMTokenBuf {
+	/// Appends the given string to this token, if the result is a valid
+	/// token.
+	///
+	/// Since any sequence of name characters is a valid token, this
+	/// succeeds if and only if `s` is itself empty or a valid token. The token
+	/// is left unchanged otherwise.
+	pub fn try_push_str<'a>(&mut self, s: &'a str) -> Result<(), InvalidNMToken<&'a str>> {
+		if !s.is_empty() {
+			NMToken::new(s).map_err(|_| InvalidNMToken(s))?;
+			self.0.push_str(s)
+		}
+
+		Ok(())
+	}
+
+	/// Appends the given token to this token.
+	pub fn push_nmtoken(&mut self, token: &NMToken) {
+		self.0.push_str(token.as_str())
+	}
+}
+
 impl ParseXsd for NMTokenBuf {
 	type LexicalForm = crate::lexical::NMToken;
 }
@@ -3,18 +3,19 @@ use std::{borrow::Borrow, ops::Deref, str::FromStr};
 
 use crate::ParseXsd;
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 #[error("invalid normalized string `{0}`")]
 pub struct InvalidNormalizedStr<T = String>(pub T);
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "forbid-unsafe", derive(ref_cast::RefCastCustom))]
 #[repr(transparent)]
 pub struct NormalizedStr(str);
 
 impl NormalizedStr {
 	pub fn new(value: &str) -> Result<&Self, InvalidNormalizedStr<&str>> {
 		if Self::validate(value) {
-			Ok(unsafe { Self::new_unchecked(value) })
+			Ok(Self::cast(value))
 		} else {
 			Err(InvalidNormalizedStr(value))
 		}
@@ -24,14 +25,20 @@ impl NormalizedStr {
 		value.chars().all(|c| !matches!(c, '\t' | '\n' | '\r'))
 	}
 
-	/// Creates a new normalized string from the input `value` without
-	/// validation.
-	///
-	/// # Safety
-	///
-	/// The input `value` must be an XSD normalized string.
-	pub unsafe fn new_unchecked(value: &str) -> &Self {
-		std::mem::transmute(value)
+	unchecked_constructor! {
+		/// Creates a new normalized string from the input `value` without
+		/// validation.
+		///
+		/// # Safety
+		///
+		/// The input `value` must be an XSD normalized string.
+		pub unsafe fn new_unchecked(value: &str) -> &Self {
+			Self::cast(value)
+		}
+	}
+
+	transparent_cast! {
+		const fn cast(value: &str) -> &Self;
 	}
 
 	pub fn as_str(&self) -> &str {
@@ -65,18 +72,56 @@ impl NormalizedString {
 		}
 	}
 
-	/// Creates a new normalized string from the input `value` without
-	/// validation.
+	unchecked_constructor! {
+		/// Creates a new normalized string from the input `value` without
+		/// validation.
+		///
+		/// # Safety
+		///
+		/// The input `value` must be an XSD normalized string.
+		pub unsafe fn new_unchecked(value: String) -> Self {
+			Self(value)
+		}
+	}
+
+	/// Creates a new normalized string from any input string, replacing
+	/// each tab, line feed and carriage return with a space.
 	///
-	/// # Safety
+	/// This corresponds to the XSD `replace` whitespace normalization, also
+	/// available as [`Self::from_text_replacing`].
+	pub fn from_str_normalizing(value: &str) -> Self {
+		Self(value.chars().map(normalize_char).collect())
+	}
+
+	/// Alias of [`Self::from_str_normalizing`], named after the XSD
+	/// `whiteSpace=replace` transformation it performs.
 	///
-	/// The input `value` must be an XSD normalized string.
-	pub unsafe fn new_unchecked(value: String) -> Self {
-		Self(value)
+	/// ```
+	/// use xsd_types::NormalizedString;
+	///
+	/// let value = NormalizedString::from_text_replacing("a\tb\r\n");
+	/// assert_eq!(value.as_str(), "a b  ");
+	/// ```
+	///
+	/// See: <https://www.w3.org/TR/xmlschema11-2/#rf-whiteSpace>
+	#[inline(always)]
+	pub fn from_text_replacing(value: &str) -> Self {
+		Self::from_str_normalizing(value)
+	}
+
+	/// Appends the given normalized string at the end of this one.
+	pub fn push_normalized_str(&mut self, value: &NormalizedStr) {
+		self.0.push_str(value.as_str())
+	}
+
+	/// Appends the given string at the end of this one, replacing each tab,
+	/// line feed and carriage return with a space.
+	pub fn push_str_normalizing(&mut self, value: &str) {
+		self.0.extend(value.chars().map(normalize_char))
 	}
 
 	pub fn as_normalized_str(&self) -> &NormalizedStr {
-		unsafe { NormalizedStr::new_unchecked(self.0.as_str()) }
+		NormalizedStr::cast(self.0.as_str())
 	}
 
 	pub fn into_string(self) -> String {
@@ -84,6 +129,15 @@ impl NormalizedString {
 	}
 }
 
+/// Replaces tabs, line feeds and carriage returns with a space.
+fn normalize_char(c: char) -> char {
+	if matches!(c, '\t' | '\n' | '\r') {
+		' '
+	} else {
+		c
+	}
+}
+
 impl fmt::Display for NormalizedString {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		self.0.fmt(f)
@@ -3,18 +3,19 @@ use std::{borrow::Borrow, ops::Deref, str::FromStr};
 
 use crate::ParseXsd;
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 #[error("invalid token `{0}`")]
 pub struct InvalidToken<T = String>(pub T);
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "forbid-unsafe", derive(ref_cast::RefCastCustom))]
 #[repr(transparent)]
 pub struct Token(str);
 
 impl Token {
 	pub fn new(value: &str) -> Result<&Self, InvalidToken<&str>> {
 		if Self::validate(value) {
-			Ok(unsafe { Self::new_unchecked(value) })
+			Ok(Self::cast(value))
 		} else {
 			Err(InvalidToken(value))
 		}
@@ -45,13 +46,19 @@ impl Token {
 		!space
 	}
 
-	/// Creates a new token string from the input `value` without validation.
-	///
-	/// # Safety
-	///
-	/// The input `value` must be an XSD token string.
-	pub unsafe fn new_unchecked(value: &str) -> &Self {
-		std::mem::transmute(value)
+	unchecked_constructor! {
+		/// Creates a new token string from the input `value` without validation.
+		///
+		/// # Safety
+		///
+		/// The input `value` must be an XSD token string.
+		pub unsafe fn new_unchecked(value: &str) -> &Self {
+			Self::cast(value)
+		}
+	}
+
+	transparent_cast! {
+		const fn cast(value: &str) -> &Self;
 	}
 
 	pub fn as_str(&self) -> &str {
@@ -85,22 +92,75 @@ impl TokenBuf {
 		}
 	}
 
-	/// Creates a new token string from the input `value` without validation.
+	unchecked_constructor! {
+		/// Creates a new token string from the input `value` without validation.
+		///
+		/// # Safety
+		///
+		/// The input `value` must be an XSD token string.
+		pub unsafe fn new_unchecked(value: String) -> Self {
+			Self(value)
+		}
+	}
+
+	/// Creates a new token string from arbitrary text, applying the
+	/// `whiteSpace=collapse` transformation: each sequence of spaces, tabs,
+	/// line feeds and carriage returns is replaced with a single space, and
+	/// leading and trailing whitespace is removed.
+	///
+	/// ```
+	/// use xsd_types::TokenBuf;
 	///
-	/// # Safety
+	/// let value = TokenBuf::from_text_collapsing("\n  hello\t\tworld \r\n");
+	/// assert_eq!(value.as_str(), "hello world");
+	/// ```
 	///
-	/// The input `value` must be an XSD token string.
-	pub unsafe fn new_unchecked(value: String) -> Self {
-		Self(value)
+	/// See: <https://www.w3.org/TR/xmlschema11-2/#rf-whiteSpace>
+	pub fn from_text_collapsing(value: &str) -> Self {
+		let mut result = String::with_capacity(value.len());
+
+		for word in value
+			.split([' ', '\t', '\n', '\r'])
+			.filter(|word| !word.is_empty())
+		{
+			if !result.is_empty() {
+				result.push(' ')
+			}
+
+			result.push_str(word)
+		}
+
+		Self(result)
 	}
 
 	pub fn as_token(&self) -> &Token {
-		unsafe { Token::new_unchecked(self.0.as_str()) }
+		Token::cast(self.0.as_str())
 	}
 
 	pub fn into_string(self) -> String {
 		self.0
 	}
+
+	/// Appends the given token at the end of this token.
+	///
+	/// The concatenation of two tokens is always a valid token. No separator
+	/// is inserted between the two: use [`Self::push_token_separated`] to
+	/// insert a space.
+	pub fn push_token(&mut self, token: &Token) {
+		self.0.push_str(token.as_str())
+	}
+
+	/// Appends the given token at the end of this token, separated by a
+	/// single space.
+	///
+	/// No space is inserted if either token is empty.
+	pub fn push_token_separated(&mut self, token: &Token) {
+		if !self.0.is_empty() && !token.0.is_empty() {
+			self.0.push(' ')
+		}
+
+		self.0.push_str(token.as_str())
+	}
 }
 
 impl fmt::Display for TokenBuf {
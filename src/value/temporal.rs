@@ -0,0 +1,462 @@
+//! Date/time values of any datatype.
+use chrono::{Datelike, FixedOffset, Timelike};
+use std::{
+	cmp::Ordering,
+	hash::{Hash, Hasher},
+};
+
+use super::calendar::MAX_TIMEZONE_OFFSET;
+use crate::{
+	days_in_month, Datatype, Date, DateTime, DateTimeStamp, GDay, GMonth, GMonthDay, GYear,
+	GYearMonth, Time, Value, XsdValue,
+};
+
+/// Year used to position values without year on the time line. It is a leap
+/// year, so that `--02-29` is a valid date.
+const REFERENCE_YEAR: i32 = 1972;
+
+/// Default month of values without month.
+const REFERENCE_MONTH: u8 = 12;
+
+/// Value of the date/time family: `xsd:dateTime` (including
+/// `xsd:dateTimeStamp`), `xsd:time`, `xsd:date` and the Gregorian datatypes.
+///
+/// # Comparison
+///
+/// [`PartialOrd`] and [`PartialEq`] follow the order relation of XSD 1.1:
+/// values of different primitive datatypes are incomparable, and a value
+/// without timezone is incomparable with a value with timezone that is less
+/// than 14 hours apart. Values lacking some fields (such as `xsd:time` or
+/// `xsd:gYear`) are positioned on the time line by filling the missing
+/// fields with a reference date in 1972, as the `timeOnTimeline` function
+/// does.
+///
+/// [`Self::total_cmp`] extends this order into a total order, so that values
+/// of mixed datatypes can be sorted in a single index:
+///
+/// 1. values are first ordered by datatype, following the order of
+///    [`Datatype`] (`xsd:dateTimeStamp` values are ordered with
+///    `xsd:dateTime` values);
+/// 2. then by position on the time line, values without timezone being
+///    positioned as if they were in UTC;
+/// 3. then values without timezone come before values with timezone.
+///
+/// Values that are equal according to [`PartialEq`] are also equal
+/// according to [`Self::total_cmp`].
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use xsd_types::{Date, DateTime, Temporal};
+///
+/// let noon: DateTime = "2024-05-17T12:00:00+02:00".parse().unwrap();
+/// let date: Date = "2024-05-17".parse().unwrap();
+/// let a = Temporal::from(noon);
+/// let b = Temporal::from(date);
+///
+/// assert_eq!(a.partial_cmp(&b), None);
+/// assert_eq!(a.total_cmp(&b), Ordering::Less);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub enum Temporal {
+	DateTime(DateTime),
+	DateTimeStamp(DateTimeStamp),
+	Time(Time),
+	Date(Date),
+	GYearMonth(GYearMonth),
+	GYear(GYear),
+	GMonthDay(GMonthDay),
+	GDay(GDay),
+	GMonth(GMonth),
+}
+
+/// Position on the time line, as a number of seconds since 0000-01-01 and a
+/// number of nanoseconds.
+///
+/// Leap seconds are kept as an overflowing number of nanoseconds.
+type Position = (i64, u32);
+
+impl Temporal {
+	/// Returns the timezone offset, if any.
+	pub fn offset(&self) -> Option<FixedOffset> {
+		match self {
+			Self::DateTime(v) => v.offset(),
+			Self::DateTimeStamp(v) => Some(v.offset()),
+			Self::Time(v) => v.offset(),
+			Self::Date(v) => v.offset(),
+			Self::GYearMonth(v) => v.offset(),
+			Self::GYear(v) => v.offset(),
+			Self::GMonthDay(v) => v.offset(),
+			Self::GDay(v) => v.offset(),
+			Self::GMonth(v) => v.offset(),
+		}
+	}
+
+	/// Checks if this value has an explicit timezone.
+	pub fn has_timezone(&self) -> bool {
+		self.offset().is_some()
+	}
+
+	/// Rank of the primitive datatype, following the order of [`Datatype`].
+	fn rank(&self) -> u8 {
+		match self {
+			Self::DateTime(_) | Self::DateTimeStamp(_) => 0,
+			Self::Time(_) => 1,
+			Self::Date(_) => 2,
+			Self::GYearMonth(_) => 3,
+			Self::GYear(_) => 4,
+			Self::GMonthDay(_) => 5,
+			Self::GDay(_) => 6,
+			Self::GMonth(_) => 7,
+		}
+	}
+
+	/// Returns the local position of this value on the time line, ignoring
+	/// the timezone.
+	fn local_position(&self) -> Position {
+		let (year, month, day, time) = match self {
+			Self::DateTime(v) => (
+				v.naive().year(),
+				v.naive().month() as u8,
+				v.naive().day() as u8,
+				Some(v.naive().time()),
+			),
+			Self::DateTimeStamp(v) => (
+				v.naive().year(),
+				v.naive().month() as u8,
+				v.naive().day() as u8,
+				Some(v.naive().time()),
+			),
+			Self::Time(v) => (REFERENCE_YEAR, REFERENCE_MONTH, 31, Some(*v.naive())),
+			Self::Date(v) => (
+				v.naive().year(),
+				v.naive().month() as u8,
+				v.naive().day() as u8,
+				None,
+			),
+			Self::GYearMonth(v) => (v.year(), v.month(), last_day(v.year(), v.month()), None),
+			Self::GYear(v) => (v.year(), REFERENCE_MONTH, 31, None),
+			Self::GMonthDay(v) => (REFERENCE_YEAR, v.month(), v.day(), None),
+			Self::GDay(v) => (REFERENCE_YEAR, REFERENCE_MONTH, v.day(), None),
+			Self::GMonth(v) => (
+				REFERENCE_YEAR,
+				v.month(),
+				last_day(REFERENCE_YEAR, v.month()),
+				None,
+			),
+		};
+
+		let (seconds, nano_seconds) = match time {
+			Some(time) => (time.num_seconds_from_midnight() as i64, time.nanosecond()),
+			None => (0, 0),
+		};
+
+		(
+			days_from_year_zero(year, month, day) * 86_400 + seconds,
+			nano_seconds,
+		)
+	}
+
+	/// Returns the range of positions, in UTC, that this value may
+	/// represent.
+	fn utc_range(&self) -> (Position, Position) {
+		let (seconds, nano_seconds) = self.local_position();
+		match self.offset() {
+			Some(offset) => {
+				let utc = (seconds - offset.local_minus_utc() as i64, nano_seconds);
+				(utc, utc)
+			}
+			None => (
+				(seconds - MAX_TIMEZONE_OFFSET as i64, nano_seconds),
+				(seconds + MAX_TIMEZONE_OFFSET as i64, nano_seconds),
+			),
+		}
+	}
+
+	/// Returns the position of this value on the time line, in UTC, values
+	/// without timezone being positioned as if they were in UTC.
+	fn utc_position(&self) -> Position {
+		match self.offset() {
+			Some(_) => self.utc_range().0,
+			None => self.local_position(),
+		}
+	}
+
+	/// Compares two values with a total order, consistent with the order
+	/// relation of XSD 1.1.
+	///
+	/// See [`Temporal`] for a description of the order.
+	pub fn total_cmp(&self, other: &Self) -> Ordering {
+		self.rank()
+			.cmp(&other.rank())
+			.then_with(|| self.utc_position().cmp(&other.utc_position()))
+			.then_with(|| self.has_timezone().cmp(&other.has_timezone()))
+	}
+}
+
+/// Returns the last day of the given month.
+fn last_day(year: i32, month: u8) -> u8 {
+	days_in_month(year, month).unwrap_or(31)
+}
+
+/// Returns the number of days from 0000-01-01 to the given date of the
+/// proleptic Gregorian calendar, for any year.
+fn days_from_year_zero(year: i32, month: u8, day: u8) -> i64 {
+	// Years are counted from March, so that the leap day is the last day of
+	// the year.
+	let year = year as i64 - if month <= 2 { 1 } else { 0 };
+	let era = year.div_euclid(400);
+	let year_of_era = year.rem_euclid(400);
+	let month = month as i64;
+	let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+	era * 146_097 + day_of_era + 60
+}
+
+impl PartialEq for Temporal {
+	fn eq(&self, other: &Self) -> bool {
+		self.partial_cmp(other) == Some(Ordering::Equal)
+	}
+}
+
+impl PartialOrd for Temporal {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		if self.rank() != other.rank() {
+			return None;
+		}
+
+		if self.has_timezone() == other.has_timezone() {
+			return Some(self.utc_position().cmp(&other.utc_position()));
+		}
+
+		let (a_min, a_max) = self.utc_range();
+		let (b_min, b_max) = other.utc_range();
+
+		if a_max < b_min {
+			Some(Ordering::Less)
+		} else if a_min > b_max {
+			Some(Ordering::Greater)
+		} else {
+			None
+		}
+	}
+}
+
+/// [`Temporal`] value ordered by [`Temporal::total_cmp`].
+///
+/// Implements [`Ord`] and [`Hash`], so that values of mixed date/time
+/// datatypes can be used as keys of ordered or hashed collections.
+///
+/// ```
+/// use std::collections::BTreeSet;
+/// use xsd_types::{Date, DateTime, OrderedTemporal, Temporal};
+///
+/// let noon: DateTime = "2024-05-17T12:00:00+02:00".parse().unwrap();
+/// let date: Date = "2024-05-17".parse().unwrap();
+///
+/// let set: BTreeSet<_> = [Temporal::from(date), Temporal::from(noon)]
+///     .into_iter()
+///     .map(OrderedTemporal)
+///     .collect();
+/// assert!(matches!(set.first().unwrap().0, Temporal::DateTime(_)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+pub struct OrderedTemporal(pub Temporal);
+
+impl PartialEq for OrderedTemporal {
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool {
+		self.0.total_cmp(&other.0).is_eq()
+	}
+}
+
+impl Eq for OrderedTemporal {}
+
+impl PartialOrd for OrderedTemporal {
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for OrderedTemporal {
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.0.total_cmp(&other.0)
+	}
+}
+
+impl Hash for OrderedTemporal {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.0.rank().hash(state);
+		self.0.utc_position().hash(state);
+		self.0.has_timezone().hash(state);
+	}
+}
+
+impl From<Temporal> for OrderedTemporal {
+	#[inline(always)]
+	fn from(value: Temporal) -> Self {
+		Self(value)
+	}
+}
+
+impl From<OrderedTemporal> for Temporal {
+	#[inline(always)]
+	fn from(value: OrderedTemporal) -> Self {
+		value.0
+	}
+}
+
+impl XsdValue for Temporal {
+	fn datatype(&self) -> Datatype {
+		match self {
+			Self::DateTime(v) => v.datatype(),
+			Self::DateTimeStamp(v) => v.datatype(),
+			Self::Time(v) => v.datatype(),
+			Self::Date(v) => v.datatype(),
+			Self::GYearMonth(v) => v.datatype(),
+			Self::GYear(v) => v.datatype(),
+			Self::GMonthDay(v) => v.datatype(),
+			Self::GDay(v) => v.datatype(),
+			Self::GMonth(v) => v.datatype(),
+		}
+	}
+}
+
+macro_rules! temporal_conversions {
+	($($ty:ident),*) => {
+		$(
+			impl From<$ty> for Temporal {
+				fn from(value: $ty) -> Self {
+					Self::$ty(value)
+				}
+			}
+		)*
+
+		impl From<Temporal> for Value {
+			fn from(value: Temporal) -> Self {
+				match value {
+					$(Temporal::$ty(v) => Self::$ty(v)),*
+				}
+			}
+		}
+
+		/// Fails with the input value if it is not of the date/time family.
+		impl TryFrom<Value> for Temporal {
+			type Error = Value;
+
+			fn try_from(value: Value) -> Result<Self, Value> {
+				match value {
+					$(Value::$ty(v) => Ok(Self::$ty(v)),)*
+					other => Err(other),
+				}
+			}
+		}
+	};
+}
+
+temporal_conversions!(
+	DateTime,
+	DateTimeStamp,
+	Time,
+	Date,
+	GYearMonth,
+	GYear,
+	GMonthDay,
+	GDay,
+	GMonth
+);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse(datatype: &str, lexical: &str) -> Temporal {
+		Datatype::from_local_name(datatype)
+			.unwrap()
+			.parse(lexical)
+			.unwrap()
+			.try_into()
+			.ok()
+			.unwrap()
+	}
+
+	#[test]
+	fn spec_order() {
+		let a = parse("dateTime", "2024-05-17T12:00:00+02:00");
+		let b = parse("dateTimeStamp", "2024-05-17T10:00:00Z");
+		assert!(a == b);
+
+		let local = parse("dateTime", "2024-05-17T10:00:00");
+		assert_eq!(a.partial_cmp(&local), None);
+		assert_eq!(
+			parse("dateTime", "2024-05-18T10:00:00").partial_cmp(&a),
+			Some(Ordering::Greater)
+		);
+
+		assert_eq!(
+			parse("gYear", "2024").partial_cmp(&parse("gYear", "-0001")),
+			Some(Ordering::Greater)
+		);
+		assert_eq!(
+			parse("gMonthDay", "--02-29").partial_cmp(&parse("gMonthDay", "--03-01")),
+			Some(Ordering::Less)
+		);
+		assert_eq!(
+			parse("time", "23:00:00-02:00").partial_cmp(&parse("time", "00:00:00Z")),
+			Some(Ordering::Greater)
+		);
+		assert_eq!(
+			parse("gYear", "2024").partial_cmp(&parse("date", "2024-12-31")),
+			None
+		);
+	}
+
+	#[test]
+	fn total_order() {
+		let mut values = [
+			parse("gMonth", "--05"),
+			parse("date", "2024-05-17"),
+			parse("dateTime", "2024-05-17T10:00:00Z"),
+			parse("dateTime", "2024-05-17T10:00:00"),
+			parse("time", "10:00:00"),
+			parse("dateTimeStamp", "2024-05-17T09:00:00Z"),
+		];
+
+		values.sort_by(Temporal::total_cmp);
+		let sorted: Vec<_> = values.iter().map(|v| Value::from(*v).to_string()).collect();
+		assert_eq!(
+			sorted,
+			[
+				"2024-05-17T09:00:00Z",
+				"2024-05-17T10:00:00",
+				"2024-05-17T10:00:00Z",
+				"10:00:00",
+				"2024-05-17",
+				"--05"
+			]
+		);
+
+		assert_eq!(days_from_year_zero(0, 1, 1), 0);
+		assert_eq!(days_from_year_zero(1970, 1, 1), 719_528);
+		assert_eq!(days_from_year_zero(-1, 12, 31), -1);
+	}
+
+	#[test]
+	fn ordered() {
+		use std::collections::{BTreeSet, HashSet};
+
+		let values = [
+			parse("dateTime", "2024-05-17T12:00:00+02:00"),
+			parse("dateTimeStamp", "2024-05-17T10:00:00Z"),
+			parse("dateTime", "2024-05-17T10:00:00"),
+		];
+
+		let ordered: BTreeSet<_> = values.into_iter().map(OrderedTemporal).collect();
+		assert_eq!(ordered.len(), 2);
+
+		let hashed: HashSet<_> = values.into_iter().map(OrderedTemporal).collect();
+		assert_eq!(hashed.len(), 2);
+	}
+}
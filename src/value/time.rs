@@ -1,9 +1,9 @@
 use chrono::{FixedOffset, NaiveTime, Timelike};
 
-use crate::{format_nanoseconds, format_timezone, Datatype, ParseXsd, XsdValue};
+use crate::{fmt_time_seconds, format_timezone, Datatype, ParseXsd, Seconds, XsdValue};
 use core::fmt;
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
 #[error("invalid time value")]
 pub struct InvalidTimeValue;
 
@@ -17,6 +17,26 @@ impl Time {
 	pub fn new(time: NaiveTime, offset: Option<FixedOffset>) -> Self {
 		Self { time, offset }
 	}
+
+	/// Returns the time without timezone.
+	pub fn naive(&self) -> &NaiveTime {
+		&self.time
+	}
+
+	/// Returns the timezone offset, if any.
+	pub fn offset(&self) -> Option<FixedOffset> {
+		self.offset
+	}
+
+	/// Checks if this time has an explicit timezone.
+	pub fn has_timezone(&self) -> bool {
+		self.offset.is_some()
+	}
+
+	/// Returns the seconds component of this time.
+	pub fn seconds(&self) -> Seconds {
+		Seconds::of(&self.time)
+	}
 }
 
 impl XsdValue for Time {
@@ -31,15 +51,9 @@ impl ParseXsd for Time {
 
 impl fmt::Display for Time {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(
-			f,
-			"{:02}:{:02}:{:02}",
-			self.time.hour(),
-			self.time.minute(),
-			self.time.second()
-		)?;
-
-		format_nanoseconds(self.time.nanosecond(), f)?;
+		write!(f, "{:02}:{:02}:", self.time.hour(), self.time.minute())?;
+
+		fmt_time_seconds(&self.time, f)?;
 		format_timezone(self.offset, f)
 	}
 }
@@ -0,0 +1,99 @@
+use std::fmt;
+
+use crate::{Datatype, ParseError, Value, XsdValue};
+
+/// XSD value along with the lexical form it was parsed from.
+///
+/// Many lexical forms can represent the same value (for instance `1`, `01`
+/// and `+1` are all lexical forms of the same integer). This type retains
+/// the original lexical form so it can be reproduced exactly, while still
+/// exposing the interpreted value.
+///
+/// Formatting a typed value writes its original lexical form.
+#[derive(Debug, Clone)]
+pub struct TypedValue {
+	value: Value,
+	lexical_form: String,
+}
+
+impl TypedValue {
+	/// Creates a new typed value from its interpreted value and original
+	/// lexical form.
+	///
+	/// It is up to the caller to make sure that the lexical form is a valid
+	/// representation of the value.
+	pub fn new(value: Value, lexical_form: String) -> Self {
+		Self {
+			value,
+			lexical_form,
+		}
+	}
+
+	/// Returns the interpreted value.
+	pub fn value(&self) -> &Value {
+		&self.value
+	}
+
+	/// Returns the original lexical form.
+	pub fn lexical_form(&self) -> &str {
+		&self.lexical_form
+	}
+
+	/// Checks if the original lexical form is the canonical lexical form of
+	/// the value.
+	pub fn is_canonical(&self) -> bool {
+		self.value.to_string() == self.lexical_form
+	}
+
+	/// Returns the interpreted value, dropping the original lexical form.
+	pub fn into_value(self) -> Value {
+		self.value
+	}
+
+	/// Returns the interpreted value and original lexical form.
+	pub fn into_parts(self) -> (Value, String) {
+		(self.value, self.lexical_form)
+	}
+}
+
+impl Datatype {
+	/// Parses the given lexical form, retaining it alongside the parsed value.
+	pub fn parse_typed(&self, value: &str) -> Result<TypedValue, ParseError> {
+		self.parse(value)
+			.map(|v| TypedValue::new(v, value.to_owned()))
+	}
+}
+
+impl XsdValue for TypedValue {
+	fn datatype(&self) -> Datatype {
+		self.value.datatype()
+	}
+}
+
+impl fmt::Display for TypedValue {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.lexical_form.fmt(f)
+	}
+}
+
+impl From<TypedValue> for Value {
+	fn from(value: TypedValue) -> Self {
+		value.value
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Datatype, XSD_INTEGER};
+
+	#[test]
+	fn retain_lexical_form() {
+		let dt = Datatype::from_iri(XSD_INTEGER).unwrap();
+		let value = dt.parse_typed("+012").unwrap();
+		assert_eq!(value.lexical_form(), "+012");
+		assert_eq!(value.to_string(), "+012");
+		assert_eq!(value.value().to_string(), "12");
+		assert!(!value.is_canonical());
+		assert!(dt.parse_typed("12").unwrap().is_canonical())
+	}
+}
@@ -0,0 +1,156 @@
+use crate::{
+	Datatype, Date, DateTime, DateTimeDatatype, DateTimeStamp, GYear, GYearMonth, ParseError, Value,
+};
+
+/// Error returned when the year of a lexical form is out of the range
+/// supported by values.
+///
+/// The lexical space of date/time datatypes allows arbitrarily large years,
+/// but values store years in an `i32`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("year out of range")]
+pub struct YearOutOfRange;
+
+/// Year numbering convention of date/time lexical forms.
+///
+/// XSD 1.0 has no year zero: `-0001` is the year 1 BCE, and `0000` is not a
+/// valid year. XSD 1.1 follows ISO 8601 and uses astronomical year numbering:
+/// `0000` is the year 1 BCE, and `-0001` the year 2 BCE.
+///
+/// Values always store years using the XSD 1.1 convention, which is also
+/// the one used by [`chrono`]. The convention only matters when reading a
+/// lexical form (see for instance [`Date::parse_with`](crate::Date::parse_with))
+/// or when interpreting a year (see [`Date::year_in`](crate::Date::year_in)).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum YearConvention {
+	/// XSD 1.0 convention, without year zero.
+	Xsd10,
+
+	/// XSD 1.1 convention, with year zero.
+	#[default]
+	Xsd11,
+}
+
+impl YearConvention {
+	/// Converts a year written in this convention into the XSD 1.1
+	/// convention.
+	///
+	/// Returns `None` if the year does not exist in this convention (year
+	/// zero in XSD 1.0).
+	pub fn to_xsd11(self, year: i32) -> Option<i32> {
+		match self {
+			Self::Xsd10 if year == 0 => None,
+			Self::Xsd10 if year < 0 => Some(year + 1),
+			_ => Some(year),
+		}
+	}
+
+	/// Converts a year written in the XSD 1.1 convention into this
+	/// convention.
+	pub fn from_xsd11(self, year: i32) -> i32 {
+		match self {
+			Self::Xsd10 if year <= 0 => year - 1,
+			_ => year,
+		}
+	}
+}
+
+impl Datatype {
+	/// Parses a value of this datatype from its lexical form, reading the
+	/// year, if any, with the given convention.
+	///
+	/// Datatypes without year are parsed as with [`Self::parse`].
+	pub fn parse_with(&self, value: &str, convention: YearConvention) -> Result<Value, ParseError> {
+		match self {
+			Self::DateTime(DateTimeDatatype::DateTime) => DateTime::parse_with(value, convention)
+				.map(Into::into)
+				.map_err(|_| ParseError::invalid(value)),
+			Self::DateTime(DateTimeDatatype::DateTimeStamp) => {
+				DateTimeStamp::parse_with(value, convention)
+					.map(Into::into)
+					.map_err(|_| ParseError::invalid(value))
+			}
+			Self::Date => Date::parse_with(value, convention)
+				.map(Value::Date)
+				.map_err(|_| ParseError::invalid(value)),
+			Self::GYearMonth => GYearMonth::parse_with(value, convention)
+				.map(Value::GYearMonth)
+				.map_err(|_| ParseError::invalid(value)),
+			Self::GYear => GYear::parse_with(value, convention)
+				.map(Value::GYear)
+				.map_err(|_| ParseError::invalid(value)),
+			_ => self.parse(value),
+		}
+	}
+}
+
+/// Returns the year before the common era (BCE) corresponding to the given
+/// XSD 1.1 year, if any.
+pub(crate) fn year_bce(year: i32) -> Option<u32> {
+	(year <= 0).then(|| (1 - year as i64) as u32)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn conventions() {
+		assert_eq!(YearConvention::Xsd10.to_xsd11(-1), Some(0));
+		assert_eq!(YearConvention::Xsd10.to_xsd11(0), None);
+		assert_eq!(YearConvention::Xsd11.to_xsd11(-1), Some(-1));
+		assert_eq!(YearConvention::Xsd10.from_xsd11(0), -1);
+		assert_eq!(YearConvention::Xsd10.from_xsd11(2000), 2000)
+	}
+
+	#[test]
+	fn parse_with() {
+		let date: Date = "0000-01-01".parse().unwrap();
+		assert_eq!(date.year_bce(), Some(1));
+
+		let date = Date::parse_with("-0001-01-01", YearConvention::Xsd10).unwrap();
+		assert_eq!(date.year_bce(), Some(1));
+		assert_eq!(date.year_in(YearConvention::Xsd10), -1);
+		assert_eq!(date.to_string(), "0000-01-01");
+
+		let date = Date::parse_with("-0001-01-01", YearConvention::Xsd11).unwrap();
+		assert_eq!(date.year_bce(), Some(2));
+
+		assert!(Date::parse_with("0000-01-01", YearConvention::Xsd10).is_err());
+
+		// 1 BCE is a leap year.
+		assert!(Date::parse_with("-0001-02-29", YearConvention::Xsd10).is_ok());
+		assert!(Date::parse_with("-0001-02-29", YearConvention::Xsd11).is_err());
+
+		let date_time =
+			DateTime::parse_with("-0044-03-15T12:00:00Z", YearConvention::Xsd10).unwrap();
+		assert_eq!(date_time.year_bce(), Some(44));
+		assert_eq!(
+			"2002-05-31T13:07:12Z"
+				.parse::<DateTime>()
+				.unwrap()
+				.year_bce(),
+			None
+		);
+
+		let year = GYear::parse_with("-0001", YearConvention::Xsd10).unwrap();
+		assert_eq!(year.year(), 0);
+		assert_eq!(year.year_in(YearConvention::Xsd10), -1);
+		assert!(GYear::parse_with("0000Z", YearConvention::Xsd10).is_err());
+
+		let year_month = GYearMonth::parse_with("-0001-03", YearConvention::Xsd10).unwrap();
+		assert_eq!(year_month.year(), 0);
+		assert!(GYearMonth::parse_with("0000-03", YearConvention::Xsd10).is_err());
+
+		let value = Datatype::GYear
+			.parse_with("-0001", YearConvention::Xsd10)
+			.unwrap();
+		assert_eq!(value.to_string(), "0000");
+		assert!(Datatype::Date
+			.parse_with("0000-01-01", YearConvention::Xsd10)
+			.is_err());
+		assert!(Datatype::Boolean
+			.parse_with("true", YearConvention::Xsd10)
+			.is_ok())
+	}
+}
@@ -0,0 +1,70 @@
+//! JavaScript bindings, generated with [`wasm-bindgen`].
+//!
+//! ```js
+//! import { Datatype, canonicalize } from "xsd-types";
+//!
+//! const decimal = Datatype.fromIri("http://www.w3.org/2001/XMLSchema#decimal");
+//! decimal.parse("+01.50").toString(); // "1.5"
+//! canonicalize("http://www.w3.org/2001/XMLSchema#decimal", "+01.50"); // "1.5"
+//! ```
+//!
+//! [`wasm-bindgen`]: <https://rustwasm.github.io/docs/wasm-bindgen/>
+use iref::Iri;
+use wasm_bindgen::prelude::*;
+
+/// XSD datatype.
+#[wasm_bindgen(js_name = Datatype)]
+pub struct JsDatatype(crate::Datatype);
+
+#[wasm_bindgen(js_class = Datatype)]
+impl JsDatatype {
+	/// Returns the datatype identified by the given IRI.
+	#[wasm_bindgen(js_name = fromIri)]
+	pub fn from_iri(iri: &str) -> Result<JsDatatype, JsError> {
+		Ok(Self(datatype(iri)?))
+	}
+
+	/// Returns the IRI of the datatype.
+	#[wasm_bindgen(getter)]
+	pub fn iri(&self) -> String {
+		self.0.iri().to_string()
+	}
+
+	/// Parses a value of this datatype from its lexical form.
+	pub fn parse(&self, lexical_value: &str) -> Result<JsXsdValue, JsError> {
+		Ok(JsXsdValue(self.0.parse(lexical_value)?))
+	}
+}
+
+/// XSD value.
+#[wasm_bindgen(js_name = Value)]
+pub struct JsXsdValue(crate::Value);
+
+#[wasm_bindgen(js_class = Value)]
+impl JsXsdValue {
+	/// Returns the datatype of the value.
+	#[wasm_bindgen(getter)]
+	pub fn datatype(&self) -> JsDatatype {
+		JsDatatype(self.0.datatype())
+	}
+
+	/// Returns the canonical lexical form of the value.
+	#[wasm_bindgen(js_name = toString)]
+	pub fn to_js_string(&self) -> String {
+		self.0.to_string()
+	}
+}
+
+/// Returns the canonical form of the given lexical form of the datatype
+/// identified by `datatype_iri`.
+///
+/// See [`crate::canonicalize`].
+#[wasm_bindgen]
+pub fn canonicalize(datatype_iri: &str, lexical_value: &str) -> Result<String, JsError> {
+	Ok(datatype(datatype_iri)?.parse(lexical_value)?.to_string())
+}
+
+fn datatype(iri: &str) -> Result<crate::Datatype, JsError> {
+	let iri = Iri::new(iri).map_err(|_| JsError::new("invalid IRI"))?;
+	crate::Datatype::from_iri(iri).ok_or_else(|| JsError::from(crate::ParseError::UnknownDatatype))
+}
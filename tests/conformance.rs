@@ -0,0 +1,168 @@
+//! Lexical space conformance test runner.
+//!
+//! Runs every test case of the `tests/conformance/*.tsv` corpus files (see
+//! `tests/conformance/lexical.tsv` for the format) and checks that each
+//! lexical form is accepted or rejected as expected.
+//!
+//! The conformance matrix is printed on the standard output:
+//!
+//! ```text
+//! cargo test --test conformance -- --nocapture
+//! ```
+use std::{
+	collections::BTreeMap,
+	fs,
+	panic::{self, AssertUnwindSafe},
+	path::Path,
+};
+
+use xsd_types::Datatype;
+
+struct Case {
+	file: String,
+	line: usize,
+	datatype: String,
+	valid: bool,
+	known_deviation: bool,
+	lexical_form: String,
+}
+
+#[derive(Default)]
+struct Row {
+	conform: usize,
+	known_deviations: usize,
+	failures: usize,
+}
+
+enum Outcome {
+	Accepted,
+	Rejected,
+	Panicked,
+}
+
+fn load_cases() -> Vec<Case> {
+	let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/conformance");
+	let mut paths: Vec<_> = fs::read_dir(dir)
+		.unwrap()
+		.map(|entry| entry.unwrap().path())
+		.filter(|path| path.extension().is_some_and(|ext| ext == "tsv"))
+		.collect();
+	paths.sort();
+
+	let mut cases = Vec::new();
+	for path in paths {
+		let file = path.file_name().unwrap().to_string_lossy().into_owned();
+		let content = fs::read_to_string(&path).unwrap();
+
+		for (i, line) in content.lines().enumerate() {
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+
+			let mut fields = line.splitn(3, '\t');
+			let (Some(datatype), Some(expectation), Some(lexical_form)) =
+				(fields.next(), fields.next(), fields.next())
+			else {
+				panic!("{file}:{}: malformed test case", i + 1)
+			};
+
+			let (expectation, known_deviation) = match expectation.strip_suffix('!') {
+				Some(e) => (e, true),
+				None => (expectation, false),
+			};
+
+			let valid = match expectation {
+				"valid" => true,
+				"invalid" => false,
+				other => panic!("{file}:{}: unknown expectation `{other}`", i + 1),
+			};
+
+			cases.push(Case {
+				file: file.clone(),
+				line: i + 1,
+				datatype: datatype.to_owned(),
+				valid,
+				known_deviation,
+				lexical_form: lexical_form.to_owned(),
+			})
+		}
+	}
+
+	cases
+}
+
+fn run(datatype: Datatype, lexical_form: &str) -> Outcome {
+	match panic::catch_unwind(AssertUnwindSafe(|| datatype.parse(lexical_form).is_ok())) {
+		Ok(true) => Outcome::Accepted,
+		Ok(false) => Outcome::Rejected,
+		Err(_) => Outcome::Panicked,
+	}
+}
+
+#[test]
+fn lexical_space() {
+	let cases = load_cases();
+	assert!(!cases.is_empty());
+
+	let mut matrix: BTreeMap<&str, Row> = BTreeMap::new();
+	let mut failures = Vec::new();
+
+	for case in &cases {
+		let datatype = Datatype::from_local_name(&case.datatype).unwrap_or_else(|| {
+			panic!(
+				"{}:{}: unknown datatype `{}`",
+				case.file, case.line, case.datatype
+			)
+		});
+
+		let row = matrix.entry(&case.datatype).or_default();
+		let conform = match run(datatype, &case.lexical_form) {
+			Outcome::Accepted => case.valid,
+			Outcome::Rejected => !case.valid,
+			Outcome::Panicked => {
+				row.failures += 1;
+				failures.push(format!(
+					"{}:{}: `{}` panicked on {:?}",
+					case.file, case.line, case.datatype, case.lexical_form
+				));
+				continue;
+			}
+		};
+
+		match (conform, case.known_deviation) {
+			(true, false) => row.conform += 1,
+			(false, true) => row.known_deviations += 1,
+			(false, false) => {
+				row.failures += 1;
+				failures.push(format!(
+					"{}:{}: `{}` should {} {:?}",
+					case.file,
+					case.line,
+					case.datatype,
+					if case.valid { "accept" } else { "reject" },
+					case.lexical_form
+				))
+			}
+			(true, true) => {
+				row.failures += 1;
+				failures.push(format!(
+					"{}:{}: `{}` now conforms on {:?}, remove the `!` mark",
+					case.file, case.line, case.datatype, case.lexical_form
+				))
+			}
+		}
+	}
+
+	println!(
+		"{:<20} {:>8} {:>10} {:>10}",
+		"datatype", "conform", "deviation", "failure"
+	);
+	for (datatype, row) in &matrix {
+		println!(
+			"{:<20} {:>8} {:>10} {:>10}",
+			datatype, row.conform, row.known_deviations, row.failures
+		);
+	}
+
+	assert!(failures.is_empty(), "\n{}", failures.join("\n"))
+}
@@ -8,6 +8,11 @@ def variant(name, pattern)
 	end
 end
 
+# Local names of the XSD datatypes, indexed by IRI constant name.
+LOCAL_NAMES = File.read(File.join(__dir__, "../src/lib.rs"))
+	.scan(/pub const (XSD_\w+): &Iri =\s*iri!\("http:\/\/www\.w3\.org\/2001\/XMLSchema#(\w+)"\)/)
+	.to_h
+
 class Datatype
 	attr_reader :name, :owned_name, :ref_name
 	attr_reader :iri
@@ -33,8 +38,28 @@ class Datatype
 		@copy
 	end
 
+	def local_name
+		LOCAL_NAMES.fetch(@iri)
+	end
+
+	# Generates the `from_local_name` match cases for this datatype and its
+	# subclasses, `f` wrapping a `#{@name}Datatype` value into the target type.
+	def generate_local_name_cases(f)
+		puts "\t\t\t\"#{self.local_name}\" => Some(#{f.call("#{@name}Datatype::#{@name}")}),"
+		@subclasses.each do |c|
+			if c.subclasses.empty? then
+				puts "\t\t\t\"#{c.local_name}\" => Some(#{f.call("#{@name}Datatype::#{c.name}")}),"
+			else
+				c.generate_local_name_cases(lambda { |value| f.call("#{@name}Datatype::#{c.name}(#{value})") })
+			end
+		end
+	end
+
 	def generate_datatype_enum
 		puts "/// [`#{@ref_name}`] datatype variants."
+		puts "///"
+		puts "/// Variants are ordered following the datatype hierarchy."
+		puts "/// See [`Datatype`] for more details."
 		puts "#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]"
 		puts "pub enum #{@name}Datatype {"
 
@@ -52,21 +77,14 @@ class Datatype
 
 		puts "impl #{@name}Datatype {"
 		puts "\tpub fn from_iri(iri: &Iri) -> Option<Self> {"
-		puts "\t\tif iri == #{@iri} {"
-		puts "\t\t\treturn Some(Self::#{@name})"
+		puts "\t\tdatatype_from_iri(iri, Self::from_local_name, Self::iri)"
+		puts "\t}"
+		puts "\t/// Returns the datatype with the given local name in the XSD namespace."
+		puts "\tpub fn from_local_name(name: &str) -> Option<Self> {"
+		puts "\t\tmatch name {"
+		self.generate_local_name_cases(lambda { |value| value })
+		puts "\t\t\t_ => None,"
 		puts "\t\t}"
-		@subclasses.each do |c|
-			if c.subclasses.empty? then
-				puts "\t\tif iri == #{c.iri} {"
-				puts "\t\t\treturn Some(Self::#{c.name})"
-				puts "\t\t}"
-			else
-				puts "\t\tif let Some(t) = #{c.name}Datatype::from_iri(iri) {"
-				puts "\t\t\treturn Some(Self::#{c.name}(t))"
-				puts "\t\t}"
-			end
-		end
-		puts "\t\tNone"
 		puts "\t}"
 		puts "\tpub fn iri(&self) -> &'static Iri {"
 		puts "\t\tmatch self {"
@@ -82,10 +100,10 @@ class Datatype
 		puts "\t}"
 		puts "\tpub fn parse(&self, value: &str) -> Result<#{@name}Value, ParseError> {"
 		puts "\t\tmatch self {"
-		puts "\t\t\tSelf::#{@name} => ParseXsd::parse_xsd(value).map(#{@name}Value::#{@name}).map_err(|_| ParseError),"
+		puts "\t\t\tSelf::#{@name} => ParseXsd::parse_xsd(value).map(#{@name}Value::#{@name}).map_err(|_| ParseError::invalid(value)),"
 		@subclasses.each do |c|
 			if c.subclasses.empty? then
-				puts "\t\t\tSelf::#{c.name} => ParseXsd::parse_xsd(value).map(#{@name}Value::#{c.name}).map_err(|_| ParseError),"
+				puts "\t\t\tSelf::#{c.name} => ParseXsd::parse_xsd(value).map(#{@name}Value::#{c.name}).map_err(|_| ParseError::invalid(value)),"
 			else
 				puts "\t\t\tSelf::#{c.name}(t) => t.parse(value).map(Into::into),"
 			end
@@ -436,7 +454,16 @@ datatypes = [
 
 def generate_datatype_enum(classes)
 	puts "/// XSD datatype (primitive or not)."
+	puts "///"
+	puts "/// # Ordering"
+	puts "///"
+	puts "/// Datatypes are ordered following a pre-order traversal of the datatype"
+	puts "/// hierarchy: a datatype always comes before the datatypes derived from"
+	puts "/// it, and sibling datatypes are ordered by declaration order. This order"
+	puts "/// is stable across versions, new datatypes being added after their"
+	puts "/// existing siblings."
 	puts "#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]"
+	puts "#[non_exhaustive]"
 	puts "pub enum Datatype {"
 
 	classes.each do |c|
@@ -447,22 +474,28 @@ def generate_datatype_enum(classes)
 		end
 	end
 
+	puts "\t/// `xsd:error` datatype, with empty lexical and value spaces (XSD 1.1)."
+	puts "\tError,"
 	puts "}"
 
 	puts "impl Datatype {"
 	puts "\tpub fn from_iri(iri: &Iri) -> Option<Self> {"
+	puts "\t\tdatatype_from_iri(iri, Self::from_local_name, Self::iri)"
+	puts "\t}"
+	puts "\t/// Returns the datatype with the given local name in the XSD namespace"
+	puts "\t/// (for instance `integer`)."
+	puts "\tpub fn from_local_name(name: &str) -> Option<Self> {"
+	puts "\t\tmatch name {"
 	classes.each do |c|
 		if c.subclasses.empty? then
-			puts "\t\tif iri == #{c.iri} {"
-			puts "\t\t\treturn Some(Self::#{c.name})"
-			puts "\t\t}"
+			puts "\t\t\t\"#{c.local_name}\" => Some(Self::#{c.name}),"
 		else
-			puts "\t\tif let Some(t) = #{c.name}Datatype::from_iri(iri) {"
-			puts "\t\t\treturn Some(Self::#{c.name}(t))"
-			puts "\t\t}"
+			c.generate_local_name_cases(lambda { |value| "Self::#{c.name}(#{value})" })
 		end
 	end
-	puts "\t\tNone"
+	puts "\t\t\t\"error\" => Some(Self::Error),"
+	puts "\t\t\t_ => None,"
+	puts "\t\t}"
 	puts "\t}"
 	puts "\tpub fn iri(&self) -> &'static Iri {"
 	puts "\t\tmatch self {"
@@ -473,17 +506,19 @@ def generate_datatype_enum(classes)
 			puts "\t\t\tSelf::#{c.name}(t) => t.iri(),"
 		end
 	end
+	puts "\t\t\tSelf::Error => XSD_ERROR,"
 	puts "\t\t}"
 	puts "\t}"
 	puts "\tpub fn parse(&self, value: &str) -> Result<Value, ParseError> {"
 	puts "\t\tmatch self {"
 	classes.each do |c|
 		if c.subclasses.empty? then
-			puts "\t\t\tSelf::#{c.name} => ParseXsd::parse_xsd(value).map(Value::#{c.name}).map_err(|_| ParseError),"
+			puts "\t\t\tSelf::#{c.name} => ParseXsd::parse_xsd(value).map(Value::#{c.name}).map_err(|_| ParseError::invalid(value)),"
 		else
 			puts "\t\t\tSelf::#{c.name}(t) => t.parse(value).map(Into::into),"
 		end
 	end
+	puts "\t\t\tSelf::Error => Err(ParseError::EmptyValueSpace),"
 	puts "\t\t}"
 	puts "\t}"
 	puts "}"
@@ -497,7 +532,7 @@ end
 
 def generate_value_enum(classes)
 	puts "/// Any XSD value."
-	puts "#[derive(Debug, Clone)]"
+	puts "#[derive(Clone)]"
 	puts "pub enum Value {"
 	classes.each do |c|
 		c.generate_value_variants
@@ -531,7 +566,7 @@ def generate_value_enum(classes)
 	puts "}"
 
 	puts "/// Any XSD value reference."
-	puts "#[derive(Debug, Clone, Copy)]"
+	puts "#[derive(Clone, Copy)]"
 	puts "pub enum ValueRef<'a> {"
 	classes.each do |c|
 		c.generate_value_ref_variants
@@ -584,6 +619,75 @@ def generate_value_enum(classes)
 	puts "\t}"
 	puts "}"
 
+	puts "/// Implements the conversion of the given types into their `Value` variant,"
+	puts "/// as well as `FromValue`."
+	puts "///"
+	puts "/// Each entry has the form `Variant(Type) => RefType`, where `RefType` is"
+	puts "/// `FromValue::Ref<'a>`."
+	puts "macro_rules! value_conversions {"
+	puts "\t{ $( $variant:ident($ty:ty) => $ref_ty:ty ),* $(,)? } => {"
+	puts "\t\t$("
+	puts "\t\t\timpl From<$ty> for Value {"
+	puts "\t\t\t\tfn from(value: $ty) -> Self {"
+	puts "\t\t\t\t\tSelf::$variant(value)"
+	puts "\t\t\t\t}"
+	puts "\t\t\t}"
+	puts "\t\t\timpl sealed::Sealed for $ty {}"
+	puts "\t\t\timpl FromValue for $ty {"
+	puts "\t\t\t\ttype Ref<'a> = $ref_ty;"
+	puts "\t\t\t\tfn from_value(value: Value) -> Result<Self, Value> {"
+	puts "\t\t\t\t\tmatch value {"
+	puts "\t\t\t\t\t\tValue::$variant(value) => Ok(value),"
+	puts "\t\t\t\t\t\tother => Err(other),"
+	puts "\t\t\t\t\t}"
+	puts "\t\t\t\t}"
+	puts "\t\t\t\tfn from_value_ref(value: ValueRef<'_>) -> Option<Self::Ref<'_>> {"
+	puts "\t\t\t\t\tmatch value {"
+	puts "\t\t\t\t\t\tValueRef::$variant(value) => Some(value),"
+	puts "\t\t\t\t\t\t_ => None,"
+	puts "\t\t\t\t\t}"
+	puts "\t\t\t\t}"
+	puts "\t\t\t}"
+	puts "\t\t)*"
+	puts "\t};"
+	puts "}"
+
+	puts "/// Implements `TryFrom<Value>` for the given `FromValue` types."
+	puts "///"
+	puts "/// `String` is excluded since it already implements `From<Value>`."
+	puts "macro_rules! try_from_value {"
+	puts "\t{ $( $ty:ty ),* $(,)? } => {"
+	puts "\t\t$("
+	puts "\t\t\timpl TryFrom<Value> for $ty {"
+	puts "\t\t\t\ttype Error = Value;"
+	puts "\t\t\t\tfn try_from(value: Value) -> Result<Self, Value> {"
+	puts "\t\t\t\t\tSelf::from_value(value)"
+	puts "\t\t\t\t}"
+	puts "\t\t\t}"
+	puts "\t\t)*"
+	puts "\t};"
+	puts "}"
+
+	puts "value_conversions! {"
+	classes.each do |c|
+		c.each_subtype do |t|
+			if t.is_copy? then
+				puts "\t#{t.name}(#{t.owned_name}) => #{t.ref_name},"
+			else
+				puts "\t#{t.name}(#{t.owned_name}) => &'a #{t.ref_name},"
+			end
+		end
+	end
+	puts "}"
+
+	puts "try_from_value! {"
+	classes.each do |c|
+		c.each_subtype do |t|
+			puts "\t#{t.owned_name}," if t.owned_name != "String"
+		end
+	end
+	puts "}"
+
 	classes.each do |c|
 		if !c.subclasses.empty? then
 			c.generate_into_datatype("Datatype", lambda { |value| "Self::#{c.name}(#{value})" })
@@ -600,7 +704,11 @@ end
 puts "use iref::Iri;"
 puts "use std::fmt;"
 puts "use crate::{"
+puts "value::sealed,"
+puts "FromValue,"
 puts "XsdValue,"
+puts "datatype_from_iri,"
+puts "XSD_ERROR,"
 puts "ParseXsd,"
 datatypes.each do |t|
 	t.each_subtype do |t|
@@ -620,9 +728,32 @@ end
 puts "};"
 
 puts "/// XSD value parse error."
-puts "#[derive(Debug, thiserror::Error)]"
-puts "#[error(\"XSD value syntax error\")]"
-puts "pub struct ParseError;"
+puts "#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]"
+puts "#[non_exhaustive]"
+puts "pub enum ParseError {"
+puts "\t/// The lexical form is empty, which the datatype does not allow."
+puts "\t#[error(\"empty lexical form\")]"
+puts "\tEmptyLexicalForm,"
+puts "\t/// The lexical form is not valid for the datatype."
+puts "\t#[error(\"XSD value syntax error\")]"
+puts "\tInvalid,"
+puts "\t/// The datatype IRI is not a known XSD datatype."
+puts "\t#[error(\"unknown XSD datatype\")]"
+puts "\tUnknownDatatype,"
+puts "\t/// The datatype has an empty value space (`xsd:error`), hence no"
+puts "\t/// lexical form is valid."
+puts "\t#[error(\"empty value space\")]"
+puts "\tEmptyValueSpace,"
+puts "}"
+puts "impl ParseError {"
+puts "\tpub(crate) fn invalid(value: &str) -> Self {"
+puts "\t\tif value.is_empty() {"
+puts "\t\t\tSelf::EmptyLexicalForm"
+puts "\t\t} else {"
+puts "\t\t\tSelf::Invalid"
+puts "\t\t}"
+puts "\t}"
+puts "}"
 
 generate_datatype_enum(datatypes)
 generate_value_enum(datatypes)
\ No newline at end of file